@@ -77,9 +77,26 @@ where
 
         let (population, telemetry_metrics) = heuristic_ctx.on_result()?;
 
-        let solutions =
-            population.ranked().map(|solution| solution.deep_copy()).take(self.desired_solutions_amount).collect();
+        let mut solutions = population.ranked().take(self.desired_solutions_amount).collect::<Vec<_>>();
+
+        // NOTE ranked individuals can be limited by elite size, so the rest is taken from the whole population
+        if solutions.len() < self.desired_solutions_amount {
+            let mut others = population
+                .iter()
+                .filter(|solution| !solutions.iter().any(|ranked| std::ptr::eq(*ranked, *solution)))
+                .collect::<Vec<_>>();
+            others.sort_by(|a, b| population.cmp(a, b));
+
+            let remaining = self.desired_solutions_amount - solutions.len();
+            solutions.extend(others.into_iter().take(remaining));
+        }
+
+        let solutions = solutions.into_iter().map(|solution| solution.deep_copy()).collect();
 
         Ok((solutions, telemetry_metrics))
     }
+
+    fn set_desired_solutions(&mut self, desired_solutions: usize) {
+        self.desired_solutions_amount = desired_solutions.max(1);
+    }
 }
@@ -20,4 +20,8 @@ pub trait EvolutionStrategy {
         heuristic_ctx: Self::Context,
         termination: Box<dyn Termination<Context = Self::Context, Objective = Self::Objective>>,
     ) -> EvolutionResult<Self::Solution>;
+
+    /// Sets amount of the best solutions to be returned from the final population.
+    /// By default, it is ignored and the strategy decides on its own.
+    fn set_desired_solutions(&mut self, _desired_solutions: usize) {}
 }
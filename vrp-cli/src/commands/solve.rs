@@ -7,6 +7,7 @@ use super::*;
 use clap::ArgAction;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
+use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use vrp_cli::core::solver::TargetHeuristic;
@@ -30,6 +31,7 @@ const GEO_JSON_ARG_NAME: &str = "geo-json";
 const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const INIT_SIZE_ARG_NAME: &str = "init-size";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
+const TOP_SOLUTIONS_ARG_NAME: &str = "top-solutions";
 const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
 const CONFIG_ARG_NAME: &str = "config";
 const LOG_ARG_NAME: &str = "log";
@@ -102,6 +104,13 @@ pub fn get_solve_app() -> Command {
                 .long(OUT_RESULT_ARG_NAME)
                 .required(false)
         )
+        .arg(
+            Arg::new(TOP_SOLUTIONS_ARG_NAME)
+                .help("Specifies amount of the best solutions to return. Min is 1. The best solution is written to \
+                       the out result, others are written next to it with their rank as file name suffix")
+                .long(TOP_SOLUTIONS_ARG_NAME)
+                .required(false)
+        )
         .arg(
             Arg::new(GET_LOCATIONS_ARG_NAME)
                 .help("Returns list of unique locations")
@@ -200,7 +209,9 @@ pub fn run_solve(
     let init_solution = matches.get_one::<String>(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
     let config = matches.get_one::<String>(CONFIG_ARG_NAME).map(|path| open_file(path, "config"));
     let matrix_files = get_matrix_files(matches);
-    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
+    let out_result_path = matches.get_one::<String>(OUT_RESULT_ARG_NAME);
+    let top_solutions = get_top_solutions(matches, out_result_path)?;
+    let out_result = out_result_path.map(|path| create_file(path, "out solution"));
     let out_geojson = matches.get_one::<String>(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
 
     let is_get_locations_set = matches.get_one::<bool>(GET_LOCATIONS_ARG_NAME).copied().unwrap_or(false);
@@ -235,10 +246,23 @@ pub fn run_solve(
                             _ => from_cli_parameters(problem.clone(), environment, init_solutions, matches)?,
                         };
 
-                        let solution = solver.solve().map_err(|err| format!("cannot find any solution: '{err}'"))?;
+                        let mut solutions = solver
+                            .solve_many(top_solutions)
+                            .map_err(|err| format!("cannot find any solution: '{err}'"))?
+                            .into_iter();
+                        let solution = solutions.next().ok_or_else(|| "cannot find any solution".to_string())?;
 
                         solution_writer(&problem, solution, out_buffer, geo_buffer)?;
 
+                        // NOTE other solutions are written only when out result path is known (see `get_top_solutions`)
+                        if let Some(out_result_path) = out_result_path {
+                            solutions.enumerate().try_for_each(|(idx, solution)| {
+                                let path = get_ranked_path(out_result_path, idx + 2);
+                                let out_buffer = out_writer_func(Some(create_file(&path, "out solution")));
+                                solution_writer(&problem, solution, out_buffer, None)
+                            })?;
+                        }
+
                         if is_check_requested {
                             check_pragmatic_solution_with_args(matches)?;
                             println!("solution feasibility check is completed successfully");
@@ -367,6 +391,29 @@ fn get_init_size(matches: &ArgMatches) -> GenericResult<Option<usize>> {
         .unwrap_or(Ok(None))
 }
 
+fn get_top_solutions(matches: &ArgMatches, out_result_path: Option<&String>) -> GenericResult<usize> {
+    match parse_int_value::<usize>(matches, TOP_SOLUTIONS_ARG_NAME, "top solutions")? {
+        Some(0) => Err("top solutions must be an integer bigger than 0".into()),
+        Some(value) if value > 1 && out_result_path.is_none() => {
+            Err(format!("{OUT_RESULT_ARG_NAME} must be set to return more than one solution").into())
+        }
+        Some(value) => Ok(value),
+        None => Ok(1),
+    }
+}
+
+/// Returns a path for the solution with given rank: the rank is added as a suffix to the file name.
+fn get_ranked_path(path: &str, rank: usize) -> String {
+    let path = Path::new(path);
+    let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+    let file_name = match path.extension().and_then(|extension| extension.to_str()) {
+        Some(extension) => format!("{stem}_{rank}.{extension}"),
+        None => format!("{stem}_{rank}"),
+    };
+
+    path.with_file_name(file_name).to_string_lossy().to_string()
+}
+
 fn get_environment(matches: &ArgMatches) -> GenericResult<Arc<Environment>> {
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
     let quota = Some(create_interruption_quota(max_time));
@@ -0,0 +1,83 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/fleet_test.rs"]
+mod fleet_test;
+
+use vrp_core::construction::features::{JobDemandDimension, VehicleCapacityDimension};
+use vrp_core::models::common::{Demand, Load, MultiDimLoad, SingleDimLoad};
+use vrp_core::models::problem::{Job, Single};
+use vrp_core::prelude::*;
+use vrp_pragmatic::format::JobTypeDimension;
+
+/// Gets a lower bound on the amount of vehicles needed to serve all jobs of the problem. It is
+/// calculated as total static demand divided by the largest vehicle capacity in each load dimension.
+/// Time windows are not considered and capacity is ignored if vehicles can reload. Returns `None`
+/// if there is a demand which cannot be served as the largest vehicle capacity is zero.
+pub fn min_vehicles_lower_bound(problem: &Problem) -> Option<usize> {
+    let singles = problem
+        .jobs
+        .all()
+        .iter()
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![single.clone()],
+            Job::Multi(multi) => multi.jobs.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    if singles.iter().all(|single| is_conditional_job(single)) {
+        return Some(0);
+    }
+
+    if singles.iter().any(|single| single.dimens.get_job_type().is_some_and(|job_type| job_type == "reload")) {
+        return Some(1);
+    }
+
+    let (deliveries, pickups) = singles
+        .iter()
+        .filter_map(|single| get_static_demand(single))
+        .fold((MultiDimLoad::default(), MultiDimLoad::default()), |(deliveries, pickups), (delivery, pickup)| {
+            (deliveries + delivery, pickups + pickup)
+        });
+
+    let max_capacity = problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle| {
+            vehicle.dimens.get_vehicle_capacity::<MultiDimLoad>().cloned().or_else(|| {
+                vehicle.dimens.get_vehicle_capacity::<SingleDimLoad>().map(|c| MultiDimLoad::new(vec![c.value]))
+            })
+        })
+        .fold(MultiDimLoad::default(), |acc, capacity| acc.max_load(capacity));
+
+    deliveries
+        .load
+        .iter()
+        .zip(pickups.load.iter())
+        .zip(max_capacity.load.iter())
+        .map(|((&delivery, &pickup), &capacity)| {
+            let demand = delivery.max(pickup);
+            match (demand > 0, capacity > 0) {
+                (true, true) => Some((demand as usize).div_ceil(capacity as usize)),
+                (true, false) => None,
+                _ => Some(1),
+            }
+        })
+        .try_fold(1, |acc, count| count.map(|count| acc.max(count)))
+}
+
+/// Returns static delivery and pickup demand of the job as multidimensional load.
+fn get_static_demand(single: &Single) -> Option<(MultiDimLoad, MultiDimLoad)> {
+    // NOTE: try to detect whether dimensions stores multidimensional demand
+    let demand: Option<&Demand<MultiDimLoad>> = single.dimens.get_job_demand();
+    if let Some(demand) = demand {
+        return Some((demand.delivery.0, demand.pickup.0));
+    }
+
+    single.dimens.get_job_demand().map(|demand: &Demand<SingleDimLoad>| {
+        (MultiDimLoad::new(vec![demand.delivery.0.value]), MultiDimLoad::new(vec![demand.pickup.0.value]))
+    })
+}
+
+fn is_conditional_job(single: &Single) -> bool {
+    single.dimens.get_job_type().is_some_and(|job_type| matches!(job_type.as_str(), "break" | "reload" | "recharge"))
+}
@@ -38,6 +38,7 @@ pub(crate) fn generate_plan(
                             duration: get_random_item(durations.as_slice(), &rnd).cloned().unwrap(),
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
+                            parallel_service: place.parallel_service,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -70,6 +71,9 @@ pub(crate) fn generate_plan(
                 value: job_proto.value,
                 group: job_proto.group.clone(),
                 compatibility: job_proto.compatibility.clone(),
+                soft_times: job_proto.soft_times,
+                lateness_penalty: job_proto.lateness_penalty,
+                min_dwell: job_proto.min_dwell,
             }
         })
         .collect();
@@ -71,6 +71,7 @@ mod actual {
                 duration: job.duration as Float,
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
+                parallel_service: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             order: None,
@@ -99,6 +100,9 @@ mod actual {
                 value: None,
                 group: None,
                 compatibility: None,
+                soft_times: None,
+                lateness_penalty: None,
+                min_dwell: None,
             })
             .collect();
 
@@ -115,7 +119,13 @@ mod actual {
                     type_id: vehicle.id.clone(),
                     vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
                     profile: VehicleProfile { matrix: vehicle.profile, scale: None },
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005, span: None },
+                    costs: VehicleCosts {
+                        fixed: Some(25.),
+                        distance: 0.0002,
+                        time: 0.005,
+                        span: None,
+                        exclude_break_time: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: vehicle.tw_start,
@@ -123,10 +133,7 @@ mod actual {
                             location: depot_location.clone(),
                         },
                         end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
-                        breaks: None,
-                        reloads: None,
-                        recharges: None,
-                        job_times: None,
+                        ..VehicleShift::default()
                     }],
                     capacity: vec![vehicle.capacity],
                     skills: None,
@@ -161,7 +168,10 @@ mod actual {
             plan: Plan { jobs, relations: None, clustering: None },
             fleet: Fleet {
                 vehicles,
-                profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
+                profiles: matrix_profile_names
+                    .into_iter()
+                    .map(|name| MatrixProfile { name, speed: None, emissions: None })
+                    .collect(),
                 resources: None,
             },
             objectives: None,
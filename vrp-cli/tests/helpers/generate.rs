@@ -12,6 +12,9 @@ pub fn create_empty_job() -> Job {
         value: None,
         group: None,
         compatibility: None,
+        soft_times: None,
+        lateness_penalty: None,
+        min_dwell: None,
     }
 }
 
@@ -20,7 +23,13 @@ pub fn create_empty_job_task() -> JobTask {
 }
 
 pub fn create_empty_job_place() -> JobPlace {
-    JobPlace { location: Location::Coordinate { lat: 0.0, lng: 0.0 }, duration: 0.0, times: None, tag: None }
+    JobPlace {
+        location: Location::Coordinate { lat: 0.0, lng: 0.0 },
+        duration: 0.0,
+        times: None,
+        tag: None,
+        parallel_service: None,
+    }
 }
 
 pub fn create_empty_plan() -> Plan {
@@ -32,7 +41,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
         type_id: "vehicle".to_string(),
         vehicle_ids: vec!["vehicle_1".to_string()],
         profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-        costs: VehicleCosts { fixed: None, distance: 1., time: 0., span: None },
+        costs: VehicleCosts { fixed: None, distance: 1., time: 0., span: None, exclude_break_time: None },
         shifts: vec![VehicleShift {
             start: ShiftStart {
                 earliest: "2020-05-01T09:00:00.00Z".to_string(),
@@ -40,10 +49,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
                 location: Location::Coordinate { lat: 0.0, lng: 0.0 },
             },
             end: None,
-            breaks: None,
-            reloads: None,
-            recharges: None,
-            job_times: None,
+            ..VehicleShift::default()
         }],
         capacity: vec![10],
         skills: None,
@@ -53,7 +59,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
 }
 
 pub fn create_test_vehicle_profile() -> MatrixProfile {
-    MatrixProfile { name: "car".to_string(), speed: None }
+    MatrixProfile { name: "car".to_string(), speed: None, emissions: None }
 }
 
 pub fn create_test_time_window() -> Vec<String> {
@@ -159,6 +159,51 @@ fn can_use_init_size() {
     }
 }
 
+#[test]
+fn can_use_top_solutions() {
+    for (params, result) in [
+        (vec!["--top-solutions", "2", "--out-result", "solution.json"], Ok(2)),
+        (vec!["--top-solutions", "1"], Ok(1)),
+        (vec!["--top-solutions", "0"], Err("top solutions must be an integer bigger than 0".into())),
+        (vec!["--top-solutions", "2"], Err("out-result must be set to return more than one solution".into())),
+        (vec![], Ok(1)),
+    ] {
+        let matches = get_solomon_matches(params.as_slice());
+
+        let top_solutions = get_top_solutions(&matches, matches.get_one::<String>(OUT_RESULT_ARG_NAME));
+
+        assert_eq!(top_solutions, result);
+    }
+}
+
+#[test]
+fn can_get_ranked_path() {
+    assert_eq!(get_ranked_path("solution.json", 2), "solution_2.json");
+    assert_eq!(get_ranked_path("out/solution.json", 3), "out/solution_3.json");
+    assert_eq!(get_ranked_path("solution", 2), "solution_2");
+}
+
+#[test]
+fn can_solve_with_top_solutions() {
+    let out_path = std::env::temp_dir().join("vrp_cli_top_solutions.json");
+    let out_path = out_path.to_string_lossy().to_string();
+    let _ = std::fs::remove_file(get_ranked_path(&out_path, 2));
+    let matches = get_solomon_matches(&[
+        "--max-generations",
+        "10",
+        "--search-mode",
+        "deep",
+        "--top-solutions",
+        "2",
+        "--out-result",
+        &out_path,
+    ]);
+
+    run_solve_without_writer(&matches);
+
+    assert!(Path::new(&get_ranked_path(&out_path, 2)).exists());
+}
+
 #[test]
 fn can_specify_cv() {
     for (params, result) in [
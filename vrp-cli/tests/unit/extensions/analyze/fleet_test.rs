@@ -0,0 +1,49 @@
+use super::*;
+use crate::helpers::generate::*;
+use vrp_pragmatic::format::problem::{
+    Fleet as ApiFleet, Job as ApiJob, Plan, PragmaticProblem, Problem as ApiProblem, VehicleType,
+};
+
+fn create_problem(job_count: usize, capacity: i32) -> Problem {
+    ApiProblem {
+        plan: Plan {
+            jobs: (0..job_count)
+                .map(|idx| ApiJob { id: format!("job{idx}"), ..create_test_job(0., idx as f64 * 0.01) })
+                .collect(),
+            ..create_empty_plan()
+        },
+        fleet: ApiFleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["vehicle_1".to_string(), "vehicle_2".to_string(), "vehicle_3".to_string()],
+                capacity: vec![capacity],
+                ..create_test_vehicle_type()
+            }],
+            profiles: vec![create_test_vehicle_profile()],
+            resources: None,
+        },
+        objectives: None,
+    }
+    .read_pragmatic()
+    .expect("cannot read problem")
+}
+
+parameterized_test! {can_get_min_vehicles_lower_bound, (job_count, capacity, expected), {
+    can_get_min_vehicles_lower_bound_impl(job_count, capacity, expected);
+}}
+
+can_get_min_vehicles_lower_bound! {
+    case01_demand_fits_one_vehicle: (8, 10, Some(1)),
+    case02_demand_equals_capacity: (10, 10, Some(1)),
+    case03_demand_forces_two_vehicles: (12, 10, Some(2)),
+    case04_demand_forces_three_vehicles: (12, 5, Some(3)),
+    case05_no_jobs: (0, 10, Some(0)),
+    case06_zero_capacity: (5, 0, None),
+}
+
+fn can_get_min_vehicles_lower_bound_impl(job_count: usize, capacity: i32, expected: Option<usize>) {
+    let problem = create_problem(job_count, capacity);
+
+    let result = min_vehicles_lower_bound(&problem);
+
+    assert_eq!(result, expected);
+}
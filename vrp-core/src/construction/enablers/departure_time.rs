@@ -3,21 +3,236 @@
 mod departure_time_test;
 
 use crate::construction::enablers::*;
-use crate::construction::heuristics::RouteContext;
+use crate::construction::heuristics::{RouteContext, RouteState};
 use crate::models::common::{TimeSpan, Timestamp};
 use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
 use crate::models::solution::Route;
 use rosomaxa::prelude::Float;
+use std::fmt::{Display, Formatter};
+
+/// Specifies a factor which binds route's departure time at its current value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DepartureBinding {
+    /// Departure is at the latest allowed vehicle start time.
+    LatestAllowedStart,
+    /// Departure is at the earliest allowed vehicle start time.
+    EarliestAllowedStart,
+    /// Departure is chosen to arrive at the first job exactly at its time window start.
+    FirstJobWindow,
+    /// Departure is limited by the shift duration limit.
+    DurationLimit,
+}
+
+impl Display for DepartureBinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            DepartureBinding::LatestAllowedStart => "latest_allowed_start",
+            DepartureBinding::EarliestAllowedStart => "earliest_allowed_start",
+            DepartureBinding::FirstJobWindow => "first_job_window",
+            DepartureBinding::DurationLimit => "duration_limit",
+        };
+
+        write!(f, "{reason}")
+    }
+}
+
+/// Specifies what to do when route's schedule is still infeasible after departure time update,
+/// e.g. when the current departure itself became infeasible due to other route changes.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum DepartureFallback {
+    /// Keeps the current departure time as is.
+    #[default]
+    Restore,
+    /// Searches for a feasible departure time within the whole allowed vehicle start range.
+    Search,
+}
+
+custom_tour_state!(pub DepartureBinding typeof DepartureBinding);
+custom_tour_state!(pub DepartureFeasible typeof bool);
 
-/// Tries to move forward route's departure time.
+/// Tries to move forward route's departure time. A factor which binds the resulting departure is kept
+/// in route state, see [DepartureBindingTourState]. Whether the resulting schedule is feasible is kept
+/// in [DepartureFeasibleTourState]. Current departure is restored when no better one is feasible.
 pub fn advance_departure_time(
     route_ctx: &mut RouteContext,
     activity: &dyn ActivityCost,
     transport: &dyn TransportCost,
     consider_whole_tour: bool,
 ) {
-    let Some(upper) = try_advance_departure_time(route_ctx, transport, consider_whole_tour) else {
+    advance_departure_time_with_fallback(
+        route_ctx,
+        activity,
+        transport,
+        consider_whole_tour,
+        DepartureFallback::Restore,
+    );
+}
+
+/// Tries to move forward route's departure time as [advance_departure_time] does, but uses the given
+/// fallback when the resulting schedule is infeasible.
+pub fn advance_departure_time_with_fallback(
+    route_ctx: &mut RouteContext,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+    consider_whole_tour: bool,
+    fallback: DepartureFallback,
+) {
+    let is_updated = try_update_departure_forward(route_ctx, activity, transport, consider_whole_tour);
+    update_departure_feasibility(route_ctx, activity, transport, is_updated, fallback);
+    update_departure_binding(route_ctx, transport);
+}
+
+/// Tries to move backward route's departure time. A factor which binds the resulting departure is kept
+/// in route state, see [DepartureBindingTourState]. Whether the resulting schedule is feasible is kept
+/// in [DepartureFeasibleTourState]. Current departure is restored when the earlier one is infeasible.
+pub fn recede_departure_time(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, transport: &dyn TransportCost) {
+    recede_departure_time_with_fallback(route_ctx, activity, transport, DepartureFallback::Restore);
+}
+
+/// Tries to move backward route's departure time as [recede_departure_time] does, but uses the given
+/// fallback when the resulting schedule is infeasible.
+pub fn recede_departure_time_with_fallback(
+    route_ctx: &mut RouteContext,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+    fallback: DepartureFallback,
+) {
+    let is_updated = try_update_departure_backward(route_ctx, activity, transport);
+    update_departure_feasibility(route_ctx, activity, transport, is_updated, fallback);
+    update_departure_binding(route_ctx, transport);
+}
+
+/// Returns a factor which binds route's departure time, if any, by comparing the chosen departure
+/// against its candidate bounds.
+pub fn get_departure_binding(route_ctx: &RouteContext, transport: &dyn TransportCost) -> Option<DepartureBinding> {
+    const EPSILON: Float = 1e-6;
+
+    let route = route_ctx.route();
+    let start = route.tour.start()?;
+    let departure = start.schedule.departure;
+    let start_time = route.actor.detail.start.as_ref().map(|start| &start.time);
+
+    if start_time.and_then(|time| time.latest).is_some_and(|latest| (latest - departure).abs() < EPSILON) {
+        return Some(DepartureBinding::LatestAllowedStart);
+    }
+
+    let first = route.tour.get(1).filter(|first| first.job.is_some());
+    let is_first_job_window = first.is_some_and(|first| {
+        let start_to_first =
+            transport.duration(route, start.place.location, first.place.location, TravelTime::Departure(departure));
+        (departure + start_to_first - first.place.time.start).abs() < EPSILON
+    });
+    if is_first_job_window {
+        return Some(DepartureBinding::FirstJobWindow);
+    }
+
+    let state = route_ctx.state();
+    let is_duration_limit = state
+        .get_total_duration()
+        .zip(state.get_limit_duration())
+        .is_some_and(|(&total, &limit)| (limit - total).abs() < EPSILON);
+    if is_duration_limit {
+        return Some(DepartureBinding::DurationLimit);
+    }
+
+    let earliest = start_time.and_then(|time| time.earliest).unwrap_or(start.place.time.start);
+    if (departure - earliest).abs() < EPSILON {
+        return Some(DepartureBinding::EarliestAllowedStart);
+    }
+
+    None
+}
+
+fn update_departure_binding(route_ctx: &mut RouteContext, transport: &dyn TransportCost) {
+    match get_departure_binding(route_ctx, transport) {
+        Some(binding) => route_ctx.state_mut().set_departure_binding(binding),
+        None => {
+            route_ctx.state_mut().remove_departure_binding();
+        }
+    }
+}
+
+/// Keeps feasibility of the route's schedule in route state. When departure was updated, its schedule
+/// has already been checked by the update itself, so only time window ends are left to verify.
+fn update_departure_feasibility(
+    route_ctx: &mut RouteContext,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+    is_updated: bool,
+    fallback: DepartureFallback,
+) {
+    if route_ctx.route().tour.start().is_none() {
         return;
+    }
+
+    let is_feasible = if is_updated {
+        is_within_time_windows(route_ctx.route())
+    } else {
+        is_departure_feasible(route_ctx.route(), activity, transport)
+    };
+
+    let is_feasible = is_feasible
+        || (fallback == DepartureFallback::Search && try_search_feasible_departure(route_ctx, activity, transport));
+
+    route_ctx.state_mut().set_departure_feasible(is_feasible);
+}
+
+/// Tries departure candidates within the whole allowed vehicle start range, starting from the latest one.
+/// Restores current departure when no feasible candidate is found.
+fn try_search_feasible_departure(
+    route_ctx: &mut RouteContext,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+) -> bool {
+    let route = route_ctx.route();
+    let start = route.tour.start().unwrap();
+    let current = start.schedule.departure;
+
+    let start_time = route.actor.detail.start.as_ref().map(|start| &start.time);
+    let lower = start_time.and_then(|time| time.earliest).unwrap_or(start.place.time.start);
+    let upper = start_time
+        .and_then(|time| time.latest)
+        .or_else(|| route.tour.end().map(|end| end.place.time.end))
+        .unwrap_or(current)
+        .max(lower);
+
+    let candidates = std::iter::once(lower)
+        .chain(compute_critical_departures(route, lower, upper, transport))
+        .chain(std::iter::once(upper))
+        .filter(|&candidate| candidate != current)
+        .collect::<Vec<_>>();
+
+    for &candidate in candidates.iter().rev() {
+        update_route_departure(route_ctx, activity, transport, candidate);
+        if is_departure_feasible(route_ctx.route(), activity, transport) {
+            return true;
+        }
+    }
+
+    update_route_departure(route_ctx, activity, transport, current);
+
+    false
+}
+
+/// Checks that the route's schedule, already updated for its current departure, does not violate
+/// any time window or reserved time.
+fn is_departure_feasible(route: &Route, activity: &dyn ActivityCost, transport: &dyn TransportCost) -> bool {
+    is_within_time_windows(route) && is_schedule_feasible(route, activity, transport)
+}
+
+fn is_within_time_windows(route: &Route) -> bool {
+    route.tour.all_activities().all(|a| a.schedule.arrival <= a.place.time.end)
+}
+
+/// Returns true when departure was moved to a new one with feasible schedule.
+fn try_update_departure_forward(
+    route_ctx: &mut RouteContext,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+    consider_whole_tour: bool,
+) -> bool {
+    let Some(upper) = try_advance_departure_time(route_ctx, transport, consider_whole_tour) else {
+        return false;
     };
 
     let current = route_ctx.route().tour.start().unwrap().schedule.departure;
@@ -25,40 +240,48 @@ pub fn advance_departure_time(
     // Fast path: try the upper bound directly
     update_route_departure(route_ctx, activity, transport, upper);
     if is_schedule_feasible(route_ctx.route(), activity, transport) {
-        return;
+        return true;
     }
 
     // Slow path: compute critical departure points and try from highest to lowest
-    let candidates = compute_critical_departures(route_ctx.route(), current, upper);
+    let candidates = compute_critical_departures(route_ctx.route(), current, upper, transport);
     for &candidate in candidates.iter().rev() {
         if candidate <= current || candidate >= upper {
             continue;
         }
         update_route_departure(route_ctx, activity, transport, candidate);
         if is_schedule_feasible(route_ctx.route(), activity, transport) {
-            return;
+            return true;
         }
     }
 
     // Fallback: restore current departure
     update_route_departure(route_ctx, activity, transport, current);
+
+    false
 }
 
-/// Tries to move backward route's departure time.
-pub fn recede_departure_time(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, transport: &dyn TransportCost) {
+/// Returns true when departure was moved to a new one with feasible schedule.
+fn try_update_departure_backward(
+    route_ctx: &mut RouteContext,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+) -> bool {
     let Some(new_departure_time) = try_recede_departure_time(route_ctx) else {
-        return;
+        return false;
     };
 
     let current = route_ctx.route().tour.start().unwrap().schedule.departure;
 
     update_route_departure(route_ctx, activity, transport, new_departure_time);
     if is_schedule_feasible(route_ctx.route(), activity, transport) {
-        return;
+        return true;
     }
 
     // Infeasible: restore current departure
     update_route_departure(route_ctx, activity, transport, current);
+
+    false
 }
 
 fn try_advance_departure_time(
@@ -118,12 +341,33 @@ fn try_recede_departure_time(route_ctx: &RouteContext) -> Option<Timestamp> {
         .map(|(&total, &limit)| (limit - total).min(max_change))
         .unwrap_or(max_change);
 
+    // NOTE receding moves the first arrival earlier by the full change, but later arrivals only by the part
+    // not absorbed as waiting time: limit the change by the smallest slack of any job to not arrive before
+    // its window opens
+    let max_change = route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .skip(1)
+        .filter(|activity| activity.job.is_some())
+        .map(|activity| (activity.schedule.arrival - activity.place.time.start).max(0.))
+        .min_by(|a, b| a.total_cmp(b))
+        .map(|slack| slack.min(max_change))
+        .unwrap_or(max_change);
+
     if max_change > 0. { Some(start.schedule.departure - max_change) } else { None }
 }
 
 /// Computes critical departure time candidates where feasibility transitions may occur.
-/// These are departure values where break boundaries align exactly with job time window boundaries.
-fn compute_critical_departures(route: &Route, current: Timestamp, upper: Timestamp) -> Vec<Timestamp> {
+/// These are departure values where break boundaries align exactly with job time window boundaries
+/// and where arrival at a job aligns with its time window boundaries. Travel durations are re-queried
+/// at candidate departures, so time-dependent travel (e.g. traffic) is taken into account.
+fn compute_critical_departures(
+    route: &Route,
+    current: Timestamp,
+    upper: Timestamp,
+    transport: &dyn TransportCost,
+) -> Vec<Timestamp> {
     const EPSILON: f64 = 1e-6;
 
     // Collect break offset info from route activities
@@ -145,17 +389,22 @@ fn compute_critical_departures(route: &Route, current: Timestamp, upper: Timesta
     }
 
     // Collect job TW boundaries from activities with fixed time windows
-    let job_tw_boundaries: Vec<f64> = route
+    let job_tw_activities: Vec<usize> = route
         .tour
         .all_activities()
-        .filter(|a| a.job.is_some())
-        .filter(|a| {
+        .enumerate()
+        .filter(|(_, a)| {
             a.job
                 .as_ref()
                 .and_then(|j| j.places.get(a.place.idx))
                 .map(|p| p.times.iter().any(|t| matches!(t, TimeSpan::Window(_))))
                 .unwrap_or(false)
         })
+        .map(|(idx, _)| idx)
+        .collect();
+    let job_tw_boundaries: Vec<f64> = job_tw_activities
+        .iter()
+        .filter_map(|&idx| route.tour.get(idx))
         .flat_map(|a| [a.place.time.start, a.place.time.end])
         .collect();
 
@@ -176,11 +425,105 @@ fn compute_critical_departures(route: &Route, current: Timestamp, upper: Timesta
         }
     }
 
+    for &activity_idx in &job_tw_activities {
+        let activity = route.tour.get(activity_idx).unwrap();
+        for tw_boundary in [activity.place.time.start, activity.place.time.end] {
+            // arrival(D) = tw_boundary
+            if let Some(d) = find_departure_for_arrival(route, activity_idx, tw_boundary, (current, upper), transport) {
+                push_candidate(&mut candidates, d, current, upper, EPSILON);
+            }
+        }
+    }
+
     candidates.sort_by(|a, b| a.total_cmp(b));
     candidates.dedup();
     candidates
 }
 
+/// Finds departure time within the given range at which activity at `activity_idx` is reached exactly
+/// at `arrival` time. Relies on the fact that arrival is non-decreasing with departure time.
+///
+/// Arrival is a piecewise linear function of departure with pieces formed by waiting at activities,
+/// so, for short prefixes of the tour, linear interpolation finds the departure in a few steps. Otherwise,
+/// or when interpolation does not converge, bisection is used.
+fn find_departure_for_arrival(
+    route: &Route,
+    activity_idx: usize,
+    arrival: Timestamp,
+    range: (Timestamp, Timestamp),
+    transport: &dyn TransportCost,
+) -> Option<Timestamp> {
+    const EPSILON: f64 = 1e-6;
+    const MAX_ITERATIONS: usize = 64;
+    const LINEAR_SCAN_THRESHOLD: usize = 8;
+
+    let estimate = |departure: Timestamp| estimate_arrival(route, activity_idx, departure, transport);
+
+    let (mut lower, mut upper) = range;
+    let (mut lower_arrival, mut upper_arrival) = (estimate(lower), estimate(upper));
+    if lower_arrival > arrival || upper_arrival < arrival {
+        return None;
+    }
+
+    if activity_idx < LINEAR_SCAN_THRESHOLD {
+        // NOTE one piece per activity and one more for the tour start
+        for _ in 0..=activity_idx {
+            if upper - lower <= EPSILON || upper_arrival - lower_arrival <= EPSILON {
+                return Some(lower);
+            }
+
+            let candidate = lower + (arrival - lower_arrival) * (upper - lower) / (upper_arrival - lower_arrival);
+            let candidate_arrival = estimate(candidate);
+
+            if (candidate_arrival - arrival).abs() <= EPSILON {
+                return Some(candidate);
+            } else if candidate_arrival > arrival {
+                (upper, upper_arrival) = (candidate, candidate_arrival);
+            } else {
+                (lower, lower_arrival) = (candidate, candidate_arrival);
+            }
+        }
+    }
+
+    for _ in 0..MAX_ITERATIONS {
+        if upper - lower <= EPSILON {
+            break;
+        }
+
+        let middle = (lower + upper) / 2.;
+        if estimate(middle) > arrival {
+            upper = middle;
+        } else {
+            lower = middle;
+        }
+    }
+
+    Some(lower)
+}
+
+/// Estimates arrival at activity with `activity_idx` when tour starts at given departure time.
+fn estimate_arrival(
+    route: &Route,
+    activity_idx: usize,
+    departure: Timestamp,
+    transport: &dyn TransportCost,
+) -> Timestamp {
+    let start = route.tour.start().unwrap();
+
+    let (_, arrival, _) = route.tour.all_activities().skip(1).take(activity_idx).fold(
+        (start.place.location, departure, departure),
+        |(location, departure, _), activity| {
+            let arrival = departure
+                + transport.duration(route, location, activity.place.location, TravelTime::Departure(departure));
+            let departure = arrival.max(activity.place.time.start) + activity.place.duration;
+
+            (activity.place.location, departure, arrival)
+        },
+    );
+
+    arrival
+}
+
 fn push_candidate(candidates: &mut Vec<Timestamp>, d: Timestamp, current: Timestamp, upper: Timestamp, epsilon: f64) {
     for &offset in &[-epsilon, 0., epsilon] {
         let val = d + offset;
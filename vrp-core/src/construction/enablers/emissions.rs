@@ -0,0 +1,35 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/emissions_test.rs"]
+mod emissions_test;
+
+use crate::models::common::{Dimensions, Distance};
+use crate::models::solution::Route;
+use rosomaxa::prelude::Float;
+
+custom_dimension!(pub VehicleEmissionModel typeof EmissionModel);
+
+/// Specifies a simple emissions model: emissions are proportional to traveled distance and,
+/// optionally, to the vehicle load.
+#[derive(Clone, Debug)]
+pub struct EmissionModel {
+    /// Emissions per distance unit for an empty vehicle.
+    pub per_distance: Float,
+    /// Relative increase of emissions for fully loaded vehicle. Zero means that load is ignored.
+    pub load_weight: Float,
+}
+
+impl EmissionModel {
+    /// Estimates emissions for given distance and load ratio (from 0 for empty to 1 for fully loaded vehicle).
+    pub fn estimate(&self, distance: Distance, load_ratio: Float) -> Float {
+        distance * self.per_distance * (1. + self.load_weight * load_ratio)
+    }
+}
+
+/// Returns route emissions as a sum of emissions estimated on each leg of the route. A leg is specified by
+/// its distance and vehicle load ratio carried on it (from 0 for empty to 1 for fully loaded vehicle).
+/// Returns `None` if the vehicle has no emission model.
+pub fn get_route_emissions(route: &Route, legs: impl Iterator<Item = (Distance, Float)>) -> Option<Float> {
+    let model = route.actor.vehicle.dimens.get_vehicle_emission_model()?;
+
+    Some(legs.map(|(distance, load_ratio)| model.estimate(distance, load_ratio)).sum())
+}
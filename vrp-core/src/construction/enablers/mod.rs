@@ -7,6 +7,9 @@ pub use self::conditional_job::*;
 mod departure_time;
 pub use self::departure_time::*;
 
+mod emissions;
+pub use self::emissions::*;
+
 mod feature_combinator;
 pub use self::feature_combinator::*;
 
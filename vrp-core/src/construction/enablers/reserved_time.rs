@@ -2,11 +2,12 @@
 #[path = "../../../tests/unit/construction/enablers/reserved_time_test.rs"]
 mod reserved_time_test;
 
-use crate::construction::enablers::get_offset_anchor;
+use crate::construction::enablers::{get_offset_anchor, get_reload_offset_anchors, get_shift_duration};
+use crate::construction::features::JobDemandDimension;
 use crate::models::common::*;
-use crate::models::problem::{ActivityCost, Actor, TransportCost, TravelTime};
+use crate::models::problem::{ActivityCost, Actor, JobIdDimension, Single, TransportCost, TravelTime};
 use crate::models::solution::{Activity, Route};
-use rosomaxa::prelude::GenericError;
+use rosomaxa::prelude::{Float, GenericError};
 use std::collections::HashMap;
 use std::ops::ControlFlow;
 use std::sync::Arc;
@@ -39,19 +40,393 @@ pub struct ReservedTimeWindow {
 /// Specifies reserved time index type.
 pub type ReservedTimesIndex = HashMap<Arc<Actor>, Vec<ReservedTimeSpan>>;
 
+/// Represent a reserved time taken right after serving a specific job.
+#[derive(Clone, Debug)]
+pub struct JobReservedTime {
+    /// An id of the job (see `JobIdDimension`) after which reserved time is taken.
+    pub job_id: String,
+    /// An extra duration to be applied after job's service.
+    pub duration: Duration,
+}
+
+/// Specifies job anchored reserved time index type.
+pub type JobReservedTimesIndex = HashMap<Arc<Actor>, Vec<JobReservedTime>>;
+
+/// Represent a reserved time which time range is defined by fractions of the realized shift duration
+/// (see `get_shift_duration`). Such reserved time is resolved relatively to the start departure.
+#[derive(Clone, Debug)]
+pub struct FractionalReservedTime {
+    /// A fraction of the shift duration when reserved time can start at the earliest.
+    pub earliest: Float,
+    /// A fraction of the shift duration when reserved time can start at the latest.
+    pub latest: Float,
+    /// An extra duration to be applied at given time.
+    pub duration: Duration,
+}
+
+impl FractionalReservedTime {
+    /// Converts `FractionalReservedTime` to `ReservedTimeSpan` using realized shift duration of the route.
+    pub fn to_reserved_time_span(&self, route: &Route) -> ReservedTimeSpan {
+        let departure = route.tour.start().map_or(0., |start| start.schedule.departure);
+        let shift_duration = get_shift_duration(route);
+        let window =
+            TimeWindow::new(departure + self.earliest * shift_duration, departure + self.latest * shift_duration);
+
+        ReservedTimeSpan { time: TimeSpan::Window(window), duration: self.duration }
+    }
+}
+
+/// Keeps route data needed to resolve reserved time by a `BreakRule`.
+pub struct RouteBreakContext<'a> {
+    /// A route for which reserved time is resolved.
+    pub route: &'a Route,
+    /// An offset anchor of the route (see `get_offset_anchor`).
+    pub anchor: Timestamp,
+}
+
+/// Specifies a custom rule which defines when and for how long reserved time (e.g. a vehicle break)
+/// should be taken on a route. Rules are registered for a vehicle via `BreakRulesDimension` and
+/// resolved on each route evaluation, so they can depend on the route schedule.
+pub trait BreakRule: Send + Sync {
+    /// Returns absolute time windows when reserved time should be taken. As for other reserved times,
+    /// only the latest time of the window is considered and windows are not expected to intersect.
+    fn windows(&self, ctx: &RouteBreakContext) -> Vec<TimeWindow>;
+
+    /// Returns a duration of reserved time.
+    fn duration(&self, ctx: &RouteBreakContext) -> Duration;
+}
+
+custom_dimension!(pub BreakRules typeof Vec<Arc<dyn BreakRule>>);
+
+impl BreakRule for ReservedTimeSpan {
+    fn windows(&self, ctx: &RouteBreakContext) -> Vec<TimeWindow> {
+        vec![self.time.to_time_window(ctx.anchor)]
+    }
+
+    fn duration(&self, _: &RouteBreakContext) -> Duration {
+        self.duration
+    }
+}
+
+impl BreakRule for FractionalReservedTime {
+    fn windows(&self, ctx: &RouteBreakContext) -> Vec<TimeWindow> {
+        self.to_reserved_time_span(ctx.route).windows(ctx)
+    }
+
+    fn duration(&self, _: &RouteBreakContext) -> Duration {
+        self.duration
+    }
+}
+
+/// Represent a reserved time which time range is defined relatively to the start of each working period
+/// on the route (see `get_reload_offset_anchors`), so a reload starts a new working period. Reserved time
+/// of a period is not taken when the next reload happens before its latest start.
+pub struct ReloadReservedTime {
+    /// A time range relative to the start of a working period.
+    pub offset: TimeOffset,
+    /// An extra duration to be applied at given time.
+    pub duration: Duration,
+    /// Returns true if the single job is a reload.
+    pub is_reload_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+}
+
+impl BreakRule for ReloadReservedTime {
+    fn windows(&self, ctx: &RouteBreakContext) -> Vec<TimeWindow> {
+        let anchors = get_reload_offset_anchors(ctx.route, self.is_reload_single.as_ref());
+        let span = TimeSpan::Offset(self.offset.clone());
+
+        anchors
+            .iter()
+            .enumerate()
+            .map(|(idx, &anchor)| (span.to_time_window(anchor), anchors.get(idx + 1)))
+            .filter(|(tw, next_anchor)| next_anchor.is_none_or(|&next_anchor| tw.end < next_anchor))
+            .map(|(tw, _)| tw)
+            .collect()
+    }
+
+    fn duration(&self, _: &RouteBreakContext) -> Duration {
+        self.duration
+    }
+}
+
+/// Represent a reserved time which cannot be taken before the given amount of jobs is served on the route:
+/// when its time range ends before the service start of the N-th job, it is shifted to that service.
+/// No reserved time is taken while the route has fewer jobs.
+pub struct MinJobsReservedTime {
+    /// A time range when reserved time should be taken if enough jobs are served.
+    pub time: TimeSpan,
+    /// An extra duration to be applied at given time.
+    pub duration: Duration,
+    /// A minimum amount of jobs served before reserved time.
+    pub min_jobs: usize,
+    /// Returns true if the single job is counted as served job.
+    pub is_counted_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+}
+
+impl BreakRule for MinJobsReservedTime {
+    fn windows(&self, ctx: &RouteBreakContext) -> Vec<TimeWindow> {
+        let tw = self.time.to_time_window(ctx.anchor);
+        if self.min_jobs == 0 {
+            return vec![tw];
+        }
+
+        ctx.route
+            .tour
+            .all_activities()
+            .filter(|activity| activity.job.as_ref().is_some_and(|single| (self.is_counted_single)(single)))
+            .nth(self.min_jobs - 1)
+            .map(|activity| {
+                // NOTE trigger at service start, so reserved time is added to the job's departure
+                let service_start = activity.schedule.arrival.max(activity.place.time.start);
+                if tw.end < service_start { vec![TimeWindow::new(service_start, service_start)] } else { vec![tw] }
+            })
+            .unwrap_or_default()
+    }
+
+    fn duration(&self, _: &RouteBreakContext) -> Duration {
+        self.duration
+    }
+}
+
+/// Represent a reserved time which is taken right after serving the job at which cumulative load
+/// handled on the route (picked up and delivered) reaches the given amount.
+#[derive(Clone, Debug)]
+pub struct LoadReservedTime<T: LoadOps> {
+    /// A cumulative handled load which triggers reserved time.
+    pub load: T,
+    /// An extra duration to be applied after job's service.
+    pub duration: Duration,
+}
+
+impl<T: LoadOps> BreakRule for LoadReservedTime<T> {
+    fn windows(&self, ctx: &RouteBreakContext) -> Vec<TimeWindow> {
+        ctx.route
+            .tour
+            .all_activities()
+            .scan(T::default(), |handled, activity| {
+                if let Some(demand) = activity.job.as_ref().and_then(|single| single.dimens.get_job_demand::<T>()) {
+                    *handled = *handled + demand.pickup.0 + demand.pickup.1 + demand.delivery.0 + demand.delivery.1;
+                }
+
+                Some((*handled, activity))
+            })
+            .find(|(handled, activity)| activity.job.is_some() && handled.can_fit(&self.load))
+            // NOTE trigger at service start, so reserved time is added to the job's departure
+            .map(|(_, activity)| {
+                let service_start = activity.schedule.arrival.max(activity.place.time.start);
+                vec![TimeWindow::new(service_start, service_start)]
+            })
+            .unwrap_or_default()
+    }
+
+    fn duration(&self, _: &RouteBreakContext) -> Duration {
+        self.duration
+    }
+}
+
+/// Represent a recurring reserved time which is taken every `interval` after the end of the previous
+/// one. The first one is taken `interval` after the offset anchor. Fixed reserved times of the vehicle
+/// (see `VehicleReservedTimesDimension`) are considered as previous ones, so the chain is restarted
+/// from their end when they are taken in between.
+pub struct IntervalReservedTime {
+    /// A time between the end of the previous reserved time (or the offset anchor) and the next one.
+    pub interval: Duration,
+    /// An extra duration to be applied at given time.
+    pub duration: Duration,
+}
+
+impl BreakRule for IntervalReservedTime {
+    fn windows(&self, ctx: &RouteBreakContext) -> Vec<TimeWindow> {
+        if self.interval <= 0. {
+            return vec![];
+        }
+
+        let actor = ctx.route.actor.as_ref();
+        let mut fixed_times = actor
+            .vehicle
+            .dimens
+            .get_vehicle_reserved_times()
+            .into_iter()
+            .flat_map(|times| times.iter())
+            .filter_map(|reserved_time| get_reserved_time_within_shift(actor, reserved_time))
+            .map(|reserved_time| reserved_time.to_reserved_time_window(ctx.anchor))
+            .map(|rt| TimeWindow::new(rt.time.end, rt.time.end + rt.duration))
+            .collect::<Vec<_>>();
+        fixed_times.sort_by(|a, b| a.start.total_cmp(&b.start));
+        let mut fixed_times = fixed_times.into_iter().peekable();
+
+        // NOTE open shift has no end, so reserved times are generated till the one after the tour end
+        let horizon = if actor.detail.time.end < Float::MAX {
+            actor.detail.time.end
+        } else {
+            ctx.route.tour.end().map_or(ctx.anchor, |end| end.schedule.departure) + self.interval
+        };
+
+        let mut windows = vec![];
+        let mut last_end = ctx.anchor;
+        loop {
+            let next = last_end + self.interval;
+
+            if let Some(fixed_tw) = fixed_times.next_if(|tw| tw.start <= next) {
+                last_end = last_end.max(fixed_tw.end);
+                continue;
+            }
+
+            if next + self.duration > horizon {
+                break;
+            }
+
+            windows.push(TimeWindow::new(next, next));
+            last_end = next + self.duration;
+        }
+
+        windows
+    }
+
+    fn duration(&self, _: &RouteBreakContext) -> Duration {
+        self.duration
+    }
+}
+
+/// Returns reserved time spans which are resolved for the given route: reserved times defined by break
+/// rules of the route's vehicle (see `BreakRulesDimension`).
+pub fn get_route_reserved_time_spans(route: &Route) -> impl Iterator<Item = ReservedTimeSpan> + '_ {
+    route.actor.vehicle.dimens.get_break_rules().into_iter().flat_map(|rules| rules.iter()).flat_map(move |rule| {
+        let ctx = RouteBreakContext { route, anchor: get_offset_anchor(route) };
+        let duration = rule.duration(&ctx);
+
+        rule.windows(&ctx).into_iter().map(move |tw| ReservedTimeSpan { time: TimeSpan::Window(tw), duration })
+    })
+}
+
+custom_dimension!(pub VehicleReservedTimes typeof Vec<ReservedTimeSpan>);
+
+custom_dimension!(pub VehicleMinWorkAfterBreak typeof Duration);
+
+/// Checks whether reserved time placed at the latest time of its range leaves enough work time after it
+/// when the vehicle has `VehicleMinWorkAfterBreakDimension` set. Work time is counted from the reserved
+/// time end till departure from the last job in the tour, so reserved time which would be taken after
+/// the last job or close to it is skipped in the same way as the one after the tour end.
+pub fn has_enough_work_after_reserved_time(route: &Route, reserved_time: &ReservedTimeWindow) -> bool {
+    let Some(min_work_after) = route.actor.vehicle.dimens.get_vehicle_min_work_after_break() else {
+        return true;
+    };
+
+    let reserved_end = reserved_time.time.end + reserved_time.duration;
+
+    route
+        .tour
+        .all_activities()
+        .rev()
+        .find(|activity| activity.job.is_some())
+        .is_some_and(|last_job| last_job.schedule.departure - reserved_end >= *min_work_after)
+}
+
+/// Returns a total duration of reserved times placed on the route within given time window: reserved
+/// times passed as argument are combined with route dependent ones (see `get_route_reserved_time_spans`).
+/// Reserved time is considered as placed at the latest time of its range.
+pub fn get_placed_reserved_time(
+    route: &Route,
+    reserved_times: impl Iterator<Item = ReservedTimeSpan>,
+    time_window: &TimeWindow,
+) -> Duration {
+    get_placed_reserved_times_within(route, reserved_times, time_window).map(|rt| rt.duration).sum()
+}
+
+/// Returns reserved times from the index which are placed within the route's tour schedule, combined
+/// with route dependent ones. A reserved time window is collapsed to its latest start.
+pub fn get_tour_reserved_times<'a>(
+    route: &'a Route,
+    reserved_times_index: &'a ReservedTimesIndex,
+) -> impl Iterator<Item = ReservedTimeWindow> + 'a {
+    let tour_tw = route
+        .tour
+        .start()
+        .zip(route.tour.end())
+        .map(|(start, end)| TimeWindow::new(start.schedule.departure, end.schedule.arrival));
+
+    tour_tw.into_iter().flat_map(move |tour_tw| {
+        let reserved_times = reserved_times_index.get(&route.actor).into_iter().flat_map(|times| times.iter().cloned());
+
+        get_placed_reserved_times_within(route, reserved_times, &tour_tw).collect::<Vec<_>>()
+    })
+}
+
+fn get_placed_reserved_times_within<'a>(
+    route: &'a Route,
+    reserved_times: impl Iterator<Item = ReservedTimeSpan> + 'a,
+    time_window: &'a TimeWindow,
+) -> impl Iterator<Item = ReservedTimeWindow> + 'a {
+    let offset_anchor = get_offset_anchor(route);
+
+    reserved_times
+        .chain(get_route_reserved_time_spans(route))
+        .map(move |reserved_time| reserved_time.to_reserved_time_window(offset_anchor))
+        .filter(|rt| has_enough_work_after_reserved_time(route, rt))
+        .map(|rt| ReservedTimeWindow { time: TimeWindow::new(rt.time.end, rt.time.end + rt.duration), ..rt })
+        .filter(|rt| time_window.intersects(&rt.time))
+}
+
+/// Returns time windows of reserved times placed on the route: vehicle reserved times which fit into
+/// the shift are combined with route dependent ones (see `get_route_reserved_time_spans`).
+pub fn get_placed_reserved_time_windows(route: &Route) -> impl Iterator<Item = TimeWindow> + '_ {
+    let offset_anchor = get_offset_anchor(route);
+
+    route
+        .actor
+        .vehicle
+        .dimens
+        .get_vehicle_reserved_times()
+        .into_iter()
+        .flat_map(|times| times.iter())
+        .filter_map(|reserved_time| get_reserved_time_within_shift(&route.actor, reserved_time))
+        .chain(get_route_reserved_time_spans(route))
+        .map(move |reserved_time| reserved_time.to_reserved_time_window(offset_anchor))
+        .filter(|rt| has_enough_work_after_reserved_time(route, rt))
+        .map(|rt| TimeWindow::new(rt.time.end, rt.time.end + rt.duration))
+}
+
 /// Specifies a function which returns an extra reserved time window for given actor. This reserved
 /// time should be considered for planning.
+///
+/// Placement within a reserved time range is deterministic: the reserved time is triggered at the latest
+/// start of its range, so the same schedule always resolves to the same reserved time placement.
 pub(crate) type ReservedTimesFn = Arc<dyn Fn(&Route, &TimeWindow) -> Option<ReservedTimeWindow> + Send + Sync>;
 
 /// Provides way to calculate activity costs which might contain reserved time.
 pub struct DynamicActivityCost {
     reserved_times_fn: ReservedTimesFn,
+    job_reserved_times: JobReservedTimesIndex,
 }
 
 impl DynamicActivityCost {
     /// Creates a new instance of `DynamicActivityCost` with given reserved time function.
     pub fn new(reserved_times_index: ReservedTimesIndex) -> Result<Self, GenericError> {
-        Ok(Self { reserved_times_fn: create_reserved_times_fn(reserved_times_index)? })
+        Ok(Self {
+            reserved_times_fn: create_reserved_times_fn(reserved_times_index)?,
+            job_reserved_times: HashMap::new(),
+        })
+    }
+
+    /// Sets reserved times which are taken right after serving specific jobs.
+    pub fn with_job_reserved_times(mut self, job_reserved_times: JobReservedTimesIndex) -> Self {
+        self.job_reserved_times = job_reserved_times;
+        self
+    }
+
+    /// Returns a total duration reserved right after given activity's service by route's actor.
+    fn get_job_reserved_duration(&self, route: &Route, activity: &Activity) -> Duration {
+        let Some(job_id) = activity.job.as_ref().and_then(|single| single.dimens.get_job_id()) else {
+            return Duration::default();
+        };
+
+        self.job_reserved_times
+            .get(&route.actor)
+            .iter()
+            .flat_map(|times| times.iter())
+            .filter(|reserved_time| reserved_time.job_id == *job_id)
+            .map(|reserved_time| reserved_time.duration)
+            .sum()
     }
 }
 
@@ -63,7 +438,7 @@ impl ActivityCost for DynamicActivityCost {
         arrival: Timestamp,
     ) -> ControlFlow<Timestamp, Timestamp> {
         let activity_start = arrival.max(activity.place.time.start);
-        let departure = activity_start + activity.place.duration;
+        let departure = activity_start + activity.place.duration + self.get_job_reserved_duration(route, activity);
         let schedule = TimeWindow::new(arrival, departure);
 
         (self.reserved_times_fn)(route, &schedule).map_or(ControlFlow::Continue(departure), |reserved_time| {
@@ -99,7 +474,8 @@ impl ActivityCost for DynamicActivityCost {
         activity: &Activity,
         departure: Timestamp,
     ) -> ControlFlow<Timestamp, Timestamp> {
-        let arrival = activity.place.time.end.min(departure - activity.place.duration);
+        let duration = activity.place.duration + self.get_job_reserved_duration(route, activity);
+        let arrival = activity.place.time.end.min(departure - duration);
         let schedule = TimeWindow::new(arrival, departure);
 
         let value = (self.reserved_times_fn)(route, &schedule)
@@ -199,15 +575,18 @@ pub(crate) fn create_reserved_times_fn(
     reserved_times_index: ReservedTimesIndex,
 ) -> Result<ReservedTimesFn, GenericError> {
     if reserved_times_index.is_empty() {
-        return Ok(Arc::new(|_, _| None));
+        return Ok(Arc::new(get_route_reserved_time));
     }
 
     let reserved_times = reserved_times_index.into_iter().try_fold(
         HashMap::<_, PartitionedSpans>::new(),
         |mut acc, (actor, times)| -> Result<_, GenericError> {
             // Partition into window and offset groups
-            let (mut window_spans, mut offset_spans): (Vec<_>, Vec<_>) =
-                times.into_iter().partition(|span| matches!(span.time, TimeSpan::Window(_)));
+            // NOTE reserved time which does not fit into the shift is never applied
+            let (mut window_spans, mut offset_spans): (Vec<_>, Vec<_>) = times
+                .into_iter()
+                .filter_map(|span| get_reserved_time_within_shift(&actor, &span))
+                .partition(|span| matches!(span.time, TimeSpan::Window(_)));
 
             let window_group = build_span_group(&mut window_spans)?;
             let offset_group = build_span_group(&mut offset_spans)?;
@@ -217,10 +596,10 @@ pub(crate) fn create_reserved_times_fn(
         },
     )?;
 
-    // NOTE: this function considers only latest time from reserved time
-    //       reserved_time.time.start is ignored and should be handled by post processing
+    // NOTE: this function considers only latest time from reserved time which is a deterministic tie-break
+    //       for wide ranges, reserved_time.time.start is ignored and should be handled by post processing
     Ok(Arc::new(move |route: &Route, time_window: &TimeWindow| {
-        reserved_times.get(&route.actor).and_then(|partitioned| {
+        let reserved_time = reserved_times.get(&route.actor).and_then(|partitioned| {
             let offset = get_offset_anchor(route);
 
             // Search window group with absolute time
@@ -254,10 +633,65 @@ pub(crate) fn create_reserved_times_fn(
                 (None, None) => None,
             }
             .map(|reserved_time| reserved_time.to_reserved_time_window(offset))
-        })
+            .filter(|rt| has_enough_work_after_reserved_time(route, rt))
+        });
+
+        // Pick the earliest trigger between fixed and route dependent reserved times
+        match (reserved_time, get_route_reserved_time(route, time_window)) {
+            (Some(f), Some(r)) => Some(if f.time.end <= r.time.end { f } else { r }),
+            (f, r) => f.or(r),
+        }
     }))
 }
 
+/// Checks whether reserved time fits into the actor's shift time window. Reserved time with offset
+/// span is resolved relative to the route, so it is always considered as fitting.
+pub fn is_reserved_time_within_shift(actor: &Actor, reserved_time: &ReservedTimeSpan) -> bool {
+    get_reserved_time_within_shift(actor, reserved_time).is_some()
+}
+
+/// Returns reserved time with its window clipped to the actor's shift time window, so that any of its
+/// placements fits into the shift, or `None` when there is no such placement (see `is_break_window_within_shift`).
+pub fn get_reserved_time_within_shift(actor: &Actor, reserved_time: &ReservedTimeSpan) -> Option<ReservedTimeSpan> {
+    match &reserved_time.time {
+        TimeSpan::Window(tw) => {
+            let shift_time = &actor.detail.time;
+            is_break_window_within_shift(tw, reserved_time.duration, shift_time).then(|| ReservedTimeSpan {
+                time: TimeSpan::Window(TimeWindow::new(
+                    tw.start.max(shift_time.start),
+                    tw.end.min(shift_time.end - reserved_time.duration),
+                )),
+                duration: reserved_time.duration,
+            })
+        }
+        TimeSpan::Offset(_) => Some(reserved_time.clone()),
+    }
+}
+
+/// Checks whether a break which can start anywhere within given window has at least one placement
+/// which fits into the shift time window, i.e. the window clipped to the shift is not empty.
+pub fn is_break_window_within_shift(window: &TimeWindow, duration: Duration, shift_time: &TimeWindow) -> bool {
+    let start = window.start.max(shift_time.start);
+
+    start <= window.end && start + duration <= shift_time.end
+}
+
+/// Returns the earliest route dependent reserved time (see `get_route_reserved_time_spans`) which
+/// intersects with given time window.
+fn get_route_reserved_time(route: &Route, time_window: &TimeWindow) -> Option<ReservedTimeWindow> {
+    // NOTE skip offset anchor calculation when there is no break rule
+    route.actor.vehicle.dimens.get_break_rules()?;
+
+    let offset = get_offset_anchor(route);
+
+    get_route_reserved_time_spans(route)
+        .map(|reserved_time| reserved_time.to_reserved_time_window(offset))
+        .filter(|rt| has_enough_work_after_reserved_time(route, rt))
+        // NOTE use exclusive intersection as in `search_group`
+        .filter(|rt| time_window.start < rt.time.end + rt.duration && rt.time.end < time_window.end)
+        .min_by(|a, b| a.time.end.total_cmp(&b.time.end))
+}
+
 fn build_span_group(spans: &mut Vec<ReservedTimeSpan>) -> Result<Option<SpanGroup>, GenericError> {
     if spans.is_empty() {
         return Ok(None);
@@ -2,10 +2,19 @@
 #[path = "../../../tests/unit/construction/enablers/schedule_update_test.rs"]
 mod schedule_update_test;
 
+use crate::construction::enablers::{
+    BreakRulesDimension, VehicleMinWorkAfterBreakDimension, VehicleReservedTimesDimension, get_placed_reserved_time,
+    get_reserved_time_within_shift,
+};
+use crate::construction::features::get_first_job_service_arrival;
 use crate::construction::heuristics::{RouteContext, RouteState};
 use crate::models::OP_START_MSG;
-use crate::models::common::{Distance, Duration, Schedule, TimeSpan, Timestamp};
-use crate::models::problem::{ActivityCost, RouteCostSpan, RouteCostSpanDimension, TransportCost, TravelTime};
+use crate::models::common::{Distance, Duration, Location, Schedule, TimeSpan, TimeWindow, Timestamp};
+use crate::models::problem::{
+    ActivityCost, JobMinDwellDimension, JobParallelServiceDimension, JobTimeConstraintsDimension, LastJobReference,
+    RouteBlendedMetricWeightsDimension, RouteCostSpan, RouteCostSpanCompositionDimension, RouteCostSpanDimension,
+    RouteCostSpanExcludeBreakTimeDimension, Single, TransportCost, TravelTime,
+};
 use crate::models::solution::{Activity, Route};
 use rosomaxa::prelude::Float;
 use rosomaxa::utils::UnwrapValue;
@@ -15,39 +24,61 @@ custom_activity_state!(pub(crate) LatestArrival typeof Timestamp);
 custom_activity_state!(pub(crate) WaitingTime typeof Timestamp);
 custom_tour_state!(pub TotalDistance typeof Distance);
 custom_tour_state!(pub TotalDuration typeof Duration);
+custom_tour_state!(pub BillableDistance typeof Distance);
+custom_tour_state!(pub BillableDuration typeof Duration);
+custom_tour_state!(pub BlendedMetric typeof Float);
 custom_tour_state!(pub(crate) LimitDuration typeof Duration);
+custom_tour_state!(pub AnchorConverged typeof bool);
 
-/// Updates route schedule data.
+/// A tolerance used to compare slack: latest arrivals are computed by a chain of backward subtractions,
+/// so a binding time window can leave a tiny positive slack due to floating point rounding.
+const SLACK_TOLERANCE: Duration = 1e-6;
+
+/// Updates route schedule data. Whether the offset anchor fixed point has converged is recorded
+/// in `AnchorConverged` route state.
 pub fn update_route_schedule(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, transport: &dyn TransportCost) {
-    let cost_span = route_ctx.route().actor.vehicle.dimens.get_route_cost_span().copied().unwrap_or_default();
-    let needs_fixed_point = matches!(cost_span, RouteCostSpan::FirstJobToDepot | RouteCostSpan::FirstJobToLastJob);
+    let dimens = &route_ctx.route().actor.vehicle.dimens;
+    let cost_span = dimens.get_route_cost_span().copied().unwrap_or_default();
+    let needs_fixed_point = matches!(cost_span, RouteCostSpan::FirstJobToDepot | RouteCostSpan::FirstJobToLastJob)
+        || dimens.get_break_rules().is_some()
+        || dimens.get_vehicle_min_work_after_break().is_some();
 
     update_schedules(route_ctx, activity, transport);
 
+    let mut is_converged = true;
     if needs_fixed_point {
         // For FirstJobTo* spans, the offset anchor depends on first_job.arrival which is
-        // computed during update_schedules. Re-run if the anchor changed significantly.
+        // computed during update_schedules. Break rules (e.g. fractional reserved times) and min work
+        // after break depend on the route schedule in the same way. Re-run if any of them changed significantly.
         const EPSILON: f64 = 1e-6;
         const MAX_ITERATIONS: usize = 3;
 
+        is_converged = false;
         for _ in 0..MAX_ITERATIONS {
             let anchor = get_offset_anchor(route_ctx.route());
+            let shift_duration = get_shift_duration(route_ctx.route());
             update_schedules(route_ctx, activity, transport);
             let new_anchor = get_offset_anchor(route_ctx.route());
+            let new_shift_duration = get_shift_duration(route_ctx.route());
 
-            if (new_anchor - anchor).abs() <= EPSILON {
+            if (new_anchor - anchor).abs() <= EPSILON && (new_shift_duration - shift_duration).abs() <= EPSILON {
+                is_converged = true;
                 break;
             }
         }
     }
 
+    // NOTE when not converged, the last computed schedule is accepted as is
+    route_ctx.state_mut().set_anchor_converged(is_converged);
+
     update_states(route_ctx, activity, transport);
     update_statistics(route_ctx, transport);
 }
 
 /// Returns the offset anchor timestamp based on the route's `RouteCostSpan`.
 /// For `DepotToDepot`/`DepotToLastJob`, this is the start departure time.
-/// For `FirstJobToDepot`/`FirstJobToLastJob`, this is the first job's arrival time (if available).
+/// For `FirstJobToDepot`/`FirstJobToLastJob`, this is the first job's arrival time (if available,
+/// see `get_first_job_arrival` for a first job at the depot location).
 pub fn get_offset_anchor(route: &Route) -> Timestamp {
     let cost_span = route.actor.vehicle.dimens.get_route_cost_span().copied().unwrap_or_default();
     let start_departure = route.tour.start().map(|a| a.schedule.departure).unwrap_or(0.);
@@ -55,34 +86,129 @@ pub fn get_offset_anchor(route: &Route) -> Timestamp {
     match cost_span {
         RouteCostSpan::DepotToDepot | RouteCostSpan::DepotToLastJob => start_departure,
         RouteCostSpan::FirstJobToDepot | RouteCostSpan::FirstJobToLastJob => {
-            // First job is at index 1 (after start depot)
-            route.tour.get(1).filter(|a| a.job.is_some()).map(|a| a.schedule.arrival).unwrap_or(start_departure)
+            get_first_job_arrival(route).unwrap_or(start_departure)
         }
     }
 }
 
+/// Returns arrival time at the first job of the route. A first job at the depot location is reached
+/// without travel, so the start departure is used: the anchor then does not depend on a duration of
+/// zero-distance travel (e.g. a non-zero matrix diagonal) and matches the depot anchored spans.
+fn get_first_job_arrival(route: &Route) -> Option<Timestamp> {
+    let start = route.tour.start()?;
+
+    // First job is at index 1 (after start depot)
+    route.tour.get(1).filter(|a| a.job.is_some()).map(|first_job| {
+        if first_job.place.location == start.place.location {
+            start.schedule.departure
+        } else {
+            first_job.schedule.arrival
+        }
+    })
+}
+
+/// Returns offset anchors of working periods separated by reloads: the regular offset anchor
+/// (see `get_offset_anchor`) followed by a departure from each reload in the tour.
+pub fn get_reload_offset_anchors(
+    route: &Route,
+    is_reload_single: &(dyn Fn(&Single) -> bool + Send + Sync),
+) -> Vec<Timestamp> {
+    std::iter::once(get_offset_anchor(route))
+        .chain(
+            route
+                .tour
+                .all_activities()
+                .filter(|activity| activity.job.as_ref().is_some_and(|single| is_reload_single(single)))
+                .map(|activity| activity.schedule.departure),
+        )
+        .collect()
+}
+
+/// Returns the realized shift duration: time between the start departure and arrival at the last
+/// activity of the tour. Unlike the offset anchor, the start departure does not depend on reserved
+/// times placed on the route.
+pub fn get_shift_duration(route: &Route) -> Duration {
+    let start_departure = route.tour.start().map(|a| a.schedule.departure).unwrap_or(0.);
+    let end_arrival = route.tour.end().map(|a| a.schedule.arrival).unwrap_or(0.);
+
+    (end_arrival - start_departure).max(0.)
+}
+
 /// Checks whether the route schedule is feasible by simulating the forward pass of `update_schedules`.
 /// Returns `true` if no activity produces a `ControlFlow::Break` during departure estimation.
 pub fn is_schedule_feasible(route: &Route, activity: &dyn ActivityCost, transport: &dyn TransportCost) -> bool {
-    let start = route.tour.start().expect(OP_START_MSG);
-    let mut loc = start.place.location;
-    let mut dep = start.schedule.departure;
+    is_schedule_feasible_from(route, 0, None, &[], false, activity, transport)
+}
 
-    for activity_idx in 1..route.tour.total() {
-        let a = route.tour.get(activity_idx).unwrap();
-        let location = a.place.location;
-        let arrival = dep + transport.duration(route, loc, location, TravelTime::Departure(dep));
+/// Checks whether a break of the given duration can be inserted into the transit gap between two
+/// adjacent activities at `prev_idx` and `next_idx`. The break starts at departure from the previous
+/// activity and the rest of the tour is simulated as in `is_schedule_feasible`, additionally checking
+/// that no downstream activity is reached after its time window end.
+pub fn can_insert_break_between(
+    route: &Route,
+    prev_idx: usize,
+    next_idx: usize,
+    duration: Duration,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+) -> bool {
+    if next_idx != prev_idx + 1 || next_idx >= route.tour.total() {
+        return false;
+    }
 
-        match activity.estimate_departure(route, a, arrival) {
-            ControlFlow::Break(_) => return false,
-            ControlFlow::Continue(d) => {
-                loc = location;
-                dep = d;
-            }
-        }
+    is_schedule_feasible_from(route, prev_idx, None, &[duration], true, activity, transport)
+}
+
+/// Checks whether the route schedule stays feasible when a job activity at `activity_idx` is removed.
+/// The route is not modified: preceding activities keep their schedules, the rest of the tour is
+/// simulated from departure of the previous activity as in `can_insert_break_between`. No activity
+/// should be reached after its time window end. Returns `false` if there is no job activity at the
+/// given index.
+pub fn feasibility_after_removal(
+    route_ctx: &RouteContext,
+    activity_idx: usize,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+) -> bool {
+    let route = route_ctx.route();
+
+    if activity_idx == 0 || route.tour.get(activity_idx).is_none_or(|a| a.job.is_none()) {
+        return false;
+    }
+
+    if route.tour.all_activities().take(activity_idx).any(|a| a.schedule.arrival > a.place.time.end) {
+        return false;
     }
 
-    true
+    is_schedule_feasible_from(route, activity_idx - 1, Some(activity_idx), &[], true, activity, transport)
+}
+
+/// Simulates the rest of the tour starting from departure of the activity at `prev_idx`, skipping the
+/// activity at `removed_idx` if specified. The extra delay at index `i` is added to departure of the
+/// activity at `prev_idx + i`. When `check_time_windows` is set, no activity should be reached after
+/// its time window end.
+fn is_schedule_feasible_from(
+    route: &Route,
+    prev_idx: usize,
+    removed_idx: Option<usize>,
+    delays: &[Duration],
+    check_time_windows: bool,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+) -> bool {
+    let mut pass = ForwardPass::new(route, prev_idx, delays.first().copied().unwrap_or_default());
+
+    ((prev_idx + 1)..route.tour.total()).filter(|&idx| Some(idx) != removed_idx).enumerate().all(
+        |(visited, activity_idx)| {
+            let is_first = prev_idx == 0 && visited == 0;
+            let (arrival, departure) = pass.visit(route, activity_idx, is_first, activity, transport);
+            pass.delay(delays.get(activity_idx - prev_idx).copied().unwrap_or_default());
+
+            let is_late = check_time_windows && arrival > route.tour.get(activity_idx).unwrap().place.time.end;
+
+            !is_late && departure.is_continue()
+        },
+    )
 }
 
 /// Updates route departure to the new one.
@@ -106,7 +232,12 @@ pub fn update_route_departure(
 }
 
 /// Recomputes activity time windows derived from offset spans after anchor shift.
-fn recompute_offset_time_windows(route_ctx: &mut RouteContext, old_anchor: Timestamp, new_anchor: Timestamp) {
+///
+/// Use it when the route's offset anchor (see `get_offset_anchor`) is changed outside of
+/// `update_route_departure`, e.g. when start departure is modified manually. It must be called
+/// before `update_route_schedule` as schedules are calculated using activity time windows.
+/// Time windows which come from window spans are left untouched.
+pub fn recompute_offset_time_windows(route_ctx: &mut RouteContext, old_anchor: Timestamp, new_anchor: Timestamp) {
     if old_anchor == new_anchor {
         return;
     }
@@ -130,31 +261,345 @@ fn recompute_offset_time_windows(route_ctx: &mut RouteContext, old_anchor: Times
     });
 }
 
+/// Returns a human-readable table of per-activity schedules and states calculated by `update_route_schedule`,
+/// followed by route totals. Missing values are shown as `-`. When the offset anchor fixed point has not
+/// converged (see `AnchorConverged` state), it is reported within totals. Intended for debugging purposes only.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use vrp_core::construction::enablers::{dump_route_state, update_route_schedule};
+/// # use vrp_core::construction::heuristics::RouteContext;
+/// # use vrp_core::models::common::*;
+/// # use vrp_core::models::problem::*;
+/// # use vrp_core::models::solution::{Activity, Place, Route};
+/// # use vrp_core::prelude::GenericError;
+/// # struct LineTransportCost;
+/// # impl TransportCost for LineTransportCost {
+/// #     fn duration_approx(&self, _: &Profile, from: Location, to: Location) -> Duration { to.abs_diff(from) as Duration }
+/// #     fn distance_approx(&self, _: &Profile, from: Location, to: Location) -> Distance { to.abs_diff(from) as Distance }
+/// #     fn duration(&self, _: &Route, from: Location, to: Location, _: TravelTime) -> Duration { to.abs_diff(from) as Duration }
+/// #     fn distance(&self, _: &Route, from: Location, to: Location, _: TravelTime) -> Distance { to.abs_diff(from) as Distance }
+/// #     fn size(&self) -> usize { 1 }
+/// # }
+/// // a route starts and ends at location 0 and visits jobs at locations 10, 30 and 60
+/// let detail = VehicleDetailBuilder::default()
+///     .set_start_location(0)
+///     .set_start_time(0.)
+///     .set_end_location(0)
+///     .set_end_time(1000.)
+///     .build()?;
+/// let vehicle = VehicleBuilder::default().id("v1").add_detail(detail).build()?;
+/// # let costs = Costs { fixed: 0., per_distance: 0., per_driving_time: 0., per_waiting_time: 0., per_service_time: 0. };
+/// # let driver = Driver { costs, dimens: Default::default(), details: vec![] };
+/// let fleet = Fleet::new(vec![Arc::new(driver)], vec![Arc::new(vehicle)], |_| |_| 0);
+/// let mut route_ctx = RouteContext::new(fleet.actors[0].clone());
+/// for location in [10, 30, 60] {
+///     let job = SingleBuilder::default().id(&format!("job{location}")).location(location)?.build()?;
+///     route_ctx.route_mut().tour.insert_last(Activity {
+///         place: Place { idx: 0, location, duration: 0., time: TimeWindow::max() },
+///         schedule: Schedule::new(0., 0.),
+///         job: Some(Arc::new(job)),
+///         commute: None,
+///     });
+/// }
+///
+/// update_route_schedule(&mut route_ctx, &SimpleActivityCost::default(), &LineTransportCost);
+///
+/// assert_eq!(
+///     dump_route_state(&route_ctx),
+///     "idx | location | arrival | departure | latest_arrival | waiting_time\n\
+///      0   |        0 |       0 |         0 |              0 |            0\n\
+///      1   |       10 |      10 |        10 |            890 |            0\n\
+///      2   |       30 |      30 |        30 |            910 |            0\n\
+///      3   |       60 |      60 |        60 |            940 |            0\n\
+///      4   |        0 |     120 |       120 |              - |            -\n\
+///     total_distance: 120, total_duration: 120"
+/// );
+/// # Ok::<(), GenericError>(())
+/// ```
+pub fn dump_route_state(route_ctx: &RouteContext) -> String {
+    let (route, state) = (route_ctx.route(), route_ctx.state());
+    let format_value = |value: Option<&Float>| value.map_or("-".to_string(), |value| value.to_string());
+
+    let header = "idx | location | arrival | departure | latest_arrival | waiting_time".to_string();
+    let rows = route.tour.all_activities().enumerate().map(|(idx, activity)| {
+        format!(
+            "{idx:<3} | {:>8} | {:>7} | {:>9} | {:>14} | {:>12}",
+            activity.place.location,
+            activity.schedule.arrival,
+            activity.schedule.departure,
+            format_value(state.get_latest_arrival_at(idx)),
+            format_value(state.get_waiting_time_at(idx)),
+        )
+    });
+    let mut totals = format!(
+        "total_distance: {}, total_duration: {}",
+        format_value(state.get_total_distance()),
+        format_value(state.get_total_duration())
+    );
+    if state.get_anchor_converged().is_some_and(|is_converged| !is_converged) {
+        totals.push_str(", anchor_converged: false");
+    }
+
+    std::iter::once(header).chain(rows).chain(std::iter::once(totals)).collect::<Vec<_>>().join("\n")
+}
+
 fn update_schedules(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, transport: &dyn TransportCost) {
-    let init = {
-        let start = route_ctx.route().tour.start().unwrap();
-        (start.place.location, start.schedule.departure)
-    };
+    let mut pass = ForwardPass::new(route_ctx.route(), 0, Duration::default());
+
+    (1..route_ctx.route().tour.total()).for_each(|activity_idx| {
+        let (arrival, departure) = pass.visit(route_ctx.route(), activity_idx, activity_idx == 1, activity, transport);
 
-    (1..route_ctx.route().tour.total()).fold(init, |(loc, dep), activity_idx| {
-        let (location, arrival, departure) = {
-            let a = route_ctx.route().tour.get(activity_idx).unwrap();
-            let location = a.place.location;
-            let arrival = dep + transport.duration(route_ctx.route(), loc, location, TravelTime::Departure(dep));
-            let departure = activity.estimate_departure(route_ctx.route(), a, arrival).unwrap_value();
+        route_ctx.route_mut().tour.get_mut(activity_idx).unwrap().schedule =
+            Schedule::new(arrival, departure.unwrap_value());
+    });
+}
+
+/// Keeps state of the forward pass which estimates schedules of tour activities one by one, starting
+/// from realized schedule of some activity. It is shared by schedule update and feasibility checks.
+struct ForwardPass {
+    location: Location,
+    departure: Timestamp,
+    stop: StopDwell,
+    group_start: Option<Timestamp>,
+}
+
+impl ForwardPass {
+    /// Starts the pass from departure of the activity at given index delayed by the given duration.
+    fn new(route: &Route, activity_idx: usize, delay: Duration) -> Self {
+        let activity = route.tour.get(activity_idx).unwrap();
+        let group_start = Some(activity)
+            .filter(|activity| is_parallel_service(activity))
+            .and_then(|activity| get_parallel_service_start(route, activity_idx, activity));
+
+        Self {
+            location: activity.place.location,
+            departure: activity.schedule.departure + delay,
+            stop: StopDwell::new(route, activity_idx),
+            group_start,
+        }
+    }
+
+    /// Visits the activity at given index and returns its arrival and departure. Departure is
+    /// `ControlFlow::Break` when activity costs cannot schedule the activity feasibly.
+    fn visit(
+        &mut self,
+        route: &Route,
+        activity_idx: usize,
+        is_first: bool,
+        activity: &dyn ActivityCost,
+        transport: &dyn TransportCost,
+    ) -> (Timestamp, ControlFlow<Timestamp, Timestamp>) {
+        let a = route.tour.get(activity_idx).unwrap();
+        let location = a.place.location;
+        let arrival =
+            self.departure + transport.duration(route, self.location, location, TravelTime::Departure(self.departure));
+        let service_arrival = get_service_arrival(route, is_first, a, arrival);
+
+        // NOTE co-located activities with parallel service share a service start, so the stop's departure is
+        // defined by the longest service instead of their sum (see `get_parallel_service_start`)
+        self.group_start = if is_parallel_service(a) {
+            self.group_start.filter(|_| location == self.location).or(Some(service_arrival.max(a.place.time.start)))
+        } else {
+            None
+        };
 
-            (location, arrival, departure)
+        let departure = match self.group_start {
+            Some(service_start) if service_start < arrival => {
+                match activity.estimate_departure(route, a, service_start) {
+                    ControlFlow::Continue(departure) => ControlFlow::Continue(departure.max(arrival)),
+                    ControlFlow::Break(departure) => ControlFlow::Break(departure.max(arrival)),
+                }
+            }
+            _ => activity.estimate_departure(route, a, service_arrival),
+        };
+        let departure = match departure {
+            ControlFlow::Continue(departure) => {
+                ControlFlow::Continue(self.stop.visit(route, activity_idx, arrival, departure))
+            }
+            ControlFlow::Break(departure) => {
+                ControlFlow::Break(self.stop.visit(route, activity_idx, arrival, departure))
+            }
         };
 
-        route_ctx.route_mut().tour.get_mut(activity_idx).unwrap().schedule = Schedule::new(arrival, departure);
+        self.location = location;
+        self.departure = departure.unwrap_value();
 
-        (location, departure)
-    });
+        (arrival, departure)
+    }
+
+    /// Delays departure from the latest visited activity.
+    fn delay(&mut self, duration: Duration) {
+        self.departure += duration;
+    }
+}
+
+/// Returns time from which activity's service is scheduled: the first job of the tour can be delayed
+/// by job time limits of the vehicle (see `get_first_job_service_arrival`).
+fn get_service_arrival(route: &Route, is_first: bool, activity: &Activity, arrival: Timestamp) -> Timestamp {
+    if is_first && activity.job.is_some() { get_first_job_service_arrival(route, arrival) } else { arrival }
+}
+
+/// Returns the earliest departure from the activity at given index allowed by minimum dwell of jobs at
+/// its stop (see `JobMinDwellDimension`). Consecutive activities at the same location form a stop and
+/// the dwell is counted from the stop arrival, so it limits departure of the stop's last activity only:
+/// `None` is returned for other activities or when the stop has no minimum dwell.
+pub fn get_stop_dwell_departure(route: &Route, activity_idx: usize) -> Option<Timestamp> {
+    let stop = StopDwell::new(route, activity_idx);
+
+    (stop.min_dwell > 0. && stop.is_end(route, activity_idx)).then_some(stop.arrival + stop.min_dwell)
+}
+
+/// Returns arrival at the stop which the activity at given index belongs to (see `get_stop_dwell_departure`).
+pub(crate) fn get_stop_arrival(route: &Route, activity_idx: usize) -> Timestamp {
+    StopDwell::new(route, activity_idx).arrival
+}
+
+/// Returns a minimum time spent at activity's stop, counted from arrival to departure, so waiting
+/// time counts toward it. See [JobMinDwellDimension].
+pub(crate) fn get_min_dwell(activity: &Activity) -> Duration {
+    activity.job.as_ref().and_then(|single| single.dimens.get_job_min_dwell()).copied().unwrap_or_default()
+}
+
+/// Keeps arrival and the longest minimum dwell of a stop visited by schedule simulation.
+struct StopDwell {
+    location: Location,
+    arrival: Timestamp,
+    min_dwell: Duration,
+}
+
+impl StopDwell {
+    /// Creates a stop with the activity at given index as the latest visited one using realized schedules.
+    fn new(route: &Route, activity_idx: usize) -> Self {
+        let location = route.tour.get(activity_idx).map_or(Location::default(), |activity| activity.place.location);
+        let (arrival, min_dwell) = route
+            .tour
+            .all_activities()
+            .take(activity_idx + 1)
+            .rev()
+            .take_while(|activity| activity.place.location == location)
+            .fold((Timestamp::default(), Duration::default()), |(_, min_dwell), activity| {
+                (activity.schedule.arrival, min_dwell.max(get_min_dwell(activity)))
+            });
+
+        Self { location, arrival, min_dwell }
+    }
+
+    /// Checks whether the activity at given index is the last one of the stop.
+    fn is_end(&self, route: &Route, activity_idx: usize) -> bool {
+        route.tour.get(activity_idx + 1).is_none_or(|next| next.place.location != self.location)
+    }
+
+    /// Visits the activity at given index and returns its departure delayed by minimum dwell when
+    /// the activity ends the stop.
+    fn visit(&mut self, route: &Route, activity_idx: usize, arrival: Timestamp, departure: Timestamp) -> Timestamp {
+        let activity = route.tour.get(activity_idx).unwrap();
+        if activity.place.location != self.location {
+            *self = Self { location: activity.place.location, arrival, min_dwell: Duration::default() };
+        }
+        self.min_dwell = self.min_dwell.max(get_min_dwell(activity));
+
+        if self.is_end(route, activity_idx) { departure.max(self.arrival + self.min_dwell) } else { departure }
+    }
+}
+
+/// Returns service start of the parallel service group which the target would continue when placed after
+/// the activity at given index: consecutive co-located activities with parallel service share a service start
+/// (see `JobParallelServiceDimension`). Returns `None` when the target starts a new group.
+pub fn get_parallel_service_start(route: &Route, prev_idx: usize, target: &Activity) -> Option<Timestamp> {
+    if !is_parallel_service(target) {
+        return None;
+    }
+
+    let first_idx = (1..=prev_idx)
+        .rev()
+        .take_while(|&idx| {
+            route.tour.get(idx).is_some_and(|a| a.place.location == target.place.location && is_parallel_service(a))
+        })
+        .last()?;
+    let first = route.tour.get(first_idx)?;
+
+    Some(get_service_arrival(route, first_idx == 1, first, first.schedule.arrival).max(first.place.time.start))
+}
+
+fn continues_parallel_service(route: &Route, activity_idx: usize, activity: &Activity) -> bool {
+    is_parallel_service(activity)
+        && activity_idx > 0
+        && route
+            .tour
+            .get(activity_idx - 1)
+            .is_some_and(|prev| prev.place.location == activity.place.location && is_parallel_service(prev))
+}
+
+fn is_parallel_service(activity: &Activity) -> bool {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_job_parallel_service())
+        .is_some_and(|places| places.contains(&activity.place.idx))
 }
 
 fn update_states(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, transport: &dyn TransportCost) {
-    // update latest arrival and waiting states of non-terminate (jobs) activities
-    let actor = route_ctx.route().actor.clone();
+    let (latest_arrivals, waiting_times) =
+        get_latest_arrivals_and_waiting_times(route_ctx.route(), activity, transport);
+
+    route_ctx.state_mut().set_latest_arrival_states(latest_arrivals);
+    route_ctx.state_mut().set_waiting_time_states(waiting_times);
+}
+
+/// Returns slack (free time) of each activity in the route: a difference between its latest possible
+/// arrival (see `LatestArrival` state) and its realized arrival, i.e. how much the activity can be
+/// delayed without violating the schedule. Returns `None` for non-job activities.
+pub fn get_activity_slacks(
+    route: &Route,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+) -> Vec<Option<Duration>> {
+    let (latest_arrivals, _) = get_latest_arrivals_and_waiting_times(route, activity, transport);
+
+    route
+        .tour
+        .all_activities()
+        .enumerate()
+        .map(|(idx, act)| {
+            act.job
+                .as_ref()
+                .and(latest_arrivals.get(idx))
+                .map(|latest_arrival| latest_arrival - act.schedule.arrival)
+                .map(|slack| if slack > SLACK_TOLERANCE { slack } else { 0. })
+        })
+        .collect()
+}
+
+/// Returns the critical path of the route: indices of job activities whose time windows are binding,
+/// i.e. which have no slack between their realized arrival and latest arrival (see `LatestArrival`
+/// state), so any delay before them violates the schedule. Requires an updated route state.
+pub fn route_critical_path(route_ctx: &RouteContext) -> Vec<usize> {
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .enumerate()
+        .filter(|(_, activity)| activity.job.is_some())
+        .filter(|&(idx, activity)| {
+            route_ctx
+                .state()
+                .get_latest_arrival_at(idx)
+                .is_some_and(|&latest_arrival| latest_arrival - activity.schedule.arrival <= SLACK_TOLERANCE)
+        })
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+fn get_latest_arrivals_and_waiting_times(
+    route: &Route,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+) -> (Vec<Timestamp>, Vec<Timestamp>) {
+    // calculate latest arrival and waiting states of non-terminate (jobs) activities
+    let actor = route.actor.clone();
     let init = (
         actor.detail.time.end,
         actor
@@ -166,11 +611,24 @@ fn update_states(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, tran
         Float::default(),
     );
 
-    let route = route_ctx.route();
     let mut latest_arrivals = Vec::with_capacity(route.tour.total());
     let mut waiting_times = Vec::with_capacity(route.tour.total());
 
-    route.tour.all_activities().rev().fold(init, |acc, act| {
+    // NOTE the last job cannot be reached after `latest_last` limit of the vehicle
+    let mut last_job_limit = actor.vehicle.dimens.get_job_time_constraints().and_then(|constraints| {
+        constraints.latest_last.map(|latest_last| (latest_last, constraints.last_job_reference))
+    });
+
+    // NOTE minimum dwell is counted from the stop arrival, so it limits arrival at the stop's first activity
+    let mut stop_dwell = (Float::MAX, Duration::default());
+
+    // NOTE a parallel service group shares the service start of its first activity, so the group's other
+    // activities can be reached until their latest departure while the first one has to be reached early
+    // enough for the longest service in the group
+    let mut parallel_limit: Option<Timestamp> = None;
+
+    (0..route.tour.total()).rev().fold(init, |acc, activity_idx| {
+        let act = route.tour.get(activity_idx).unwrap();
         if act.job.is_none() {
             latest_arrivals.push(Default::default());
             waiting_times.push(Default::default());
@@ -178,12 +636,39 @@ fn update_states(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, tran
         }
 
         let (end_time, prev_loc, waiting) = acc;
-        let latest_arrival_time = if end_time == Float::MAX {
-            act.place.time.end
+        let (latest_departure, latest_arrival_time) = if end_time == Float::MAX {
+            (Float::MAX, act.place.time.end)
         } else {
             let latest_departure =
                 end_time - transport.duration(route, act.place.location, prev_loc, TravelTime::Arrival(end_time));
-            activity.estimate_arrival(route, act, latest_departure).unwrap_value()
+            (latest_departure, activity.estimate_arrival(route, act, latest_departure).unwrap_value())
+        };
+
+        let latest_arrival_time = if continues_parallel_service(route, activity_idx, act) {
+            parallel_limit = Some(parallel_limit.map_or(latest_arrival_time, |limit| limit.min(latest_arrival_time)));
+            act.place.time.end.min(latest_departure)
+        } else {
+            parallel_limit.take().map_or(latest_arrival_time, |limit| latest_arrival_time.min(limit))
+        };
+
+        let location = act.place.location;
+        if route.tour.get(activity_idx + 1).is_none_or(|next| next.place.location != location) {
+            stop_dwell = (latest_departure, Duration::default());
+        }
+        stop_dwell.1 = stop_dwell.1.max(get_min_dwell(act));
+        let is_stop_start =
+            activity_idx == 0 || route.tour.get(activity_idx - 1).is_some_and(|prev| prev.place.location != location);
+        let latest_arrival_time = if is_stop_start && stop_dwell.1 > 0. {
+            latest_arrival_time.min(stop_dwell.0 - stop_dwell.1)
+        } else {
+            latest_arrival_time
+        };
+        let latest_arrival_time = match last_job_limit.take() {
+            Some((latest_last, LastJobReference::Arrival)) => latest_arrival_time.min(latest_last),
+            Some((latest_last, LastJobReference::Departure)) => {
+                latest_arrival_time.min(activity.estimate_arrival(route, act, latest_last).unwrap_value())
+            }
+            None => latest_arrival_time,
         };
         let future_waiting = waiting + (act.place.time.start - act.schedule.arrival).max(0.);
 
@@ -202,8 +687,7 @@ fn update_states(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, tran
         waiting_times.pop();
     }
 
-    route_ctx.state_mut().set_latest_arrival_states(latest_arrivals);
-    route_ctx.state_mut().set_waiting_time_states(waiting_times);
+    (latest_arrivals, waiting_times)
 }
 
 fn update_statistics(route_ctx: &mut RouteContext, transport: &dyn TransportCost) {
@@ -213,13 +697,111 @@ fn update_statistics(route_ctx: &mut RouteContext, transport: &dyn TransportCost
     let end = route.tour.end().unwrap();
     let total_activities = route.tour.total();
 
-    let cost_span = route.actor.vehicle.dimens.get_route_cost_span().copied().unwrap_or_default();
-
-    let total_dur = calculate_route_duration(route, cost_span, total_activities, start, end);
+    let dimens = &route.actor.vehicle.dimens;
+    let cost_span = dimens.get_route_cost_span().copied().unwrap_or_default();
+    let total_dur = get_route_span_time_window(route, cost_span, total_activities, start, end)
+        .map_or(Duration::default(), |span_tw| span_tw.duration());
     let total_dist = calculate_route_distance(route, transport, cost_span, total_activities);
 
+    let billable_dur = get_billable_duration(route, start, end);
+    let billable_dist = if let Some(composition) = dimens.get_route_cost_span_composition() {
+        composition.reducer.reduce(
+            calculate_route_distance(route, transport, composition.first, total_activities),
+            calculate_route_distance(route, transport, composition.second, total_activities),
+        )
+    } else {
+        total_dist
+    };
+
+    if let Some(weights) = dimens.get_route_blended_metric_weights() {
+        state.set_blended_metric(weights.distance * billable_dist + weights.duration * billable_dur);
+    }
+
+    // NOTE total metrics are used by limits and scheduling, while billable ones only by costs
     state.set_total_distance(total_dist);
     state.set_total_duration(total_dur);
+    state.set_billable_distance(billable_dist);
+    state.set_billable_duration(billable_dur);
+}
+
+/// Returns the billable duration of the route, i.e. the duration measured within its cost span.
+pub fn get_route_billable_duration(route: &Route) -> Duration {
+    match (route.tour.start(), route.tour.end()) {
+        (Some(start), Some(end)) => get_billable_duration(route, start, end),
+        _ => Duration::default(),
+    }
+}
+
+/// Returns the route utilization: a ratio of billable duration to the actor's shift length.
+/// Returns `None` when the shift length is not limited.
+pub fn get_route_utilization(route: &Route) -> Option<Float> {
+    let shift = &route.actor.detail.time;
+    let shift_length = shift.duration();
+
+    (shift.end < Timestamp::MAX && shift_length > 0.).then(|| get_route_billable_duration(route) / shift_length)
+}
+
+fn get_billable_duration(route: &Route, start: &Activity, end: &Activity) -> Duration {
+    let total_activities = route.tour.total();
+    let dimens = &route.actor.vehicle.dimens;
+    let exclude_break_time = dimens.get_route_cost_span_exclude_break_time().copied().unwrap_or(false);
+    let span_duration = |cost_span| {
+        let span_tw = get_route_span_time_window(route, cost_span, total_activities, start, end);
+        span_tw.map_or(Duration::default(), |span_tw| calculate_route_duration(route, &span_tw, exclude_break_time))
+    };
+
+    if let Some(composition) = dimens.get_route_cost_span_composition() {
+        composition.reducer.reduce(span_duration(composition.first), span_duration(composition.second))
+    } else {
+        span_duration(dimens.get_route_cost_span().copied().unwrap_or_default())
+    }
+}
+
+/// Returns travel duration and distance of each leg of the route: the item at index `i` is the travel
+/// from the activity `i - 1` to the activity `i`, the first item (route start) is always zero.
+pub fn get_leg_travels(route: &Route, transport: &dyn TransportCost) -> Vec<(Duration, Distance)> {
+    if route.tour.total() == 0 {
+        return vec![];
+    }
+
+    std::iter::once((Duration::default(), Distance::default()))
+        .chain(route.tour.all_activities().zip(route.tour.all_activities().skip(1)).map(|(prev, next)| {
+            let departure = TravelTime::Departure(prev.schedule.departure);
+            (
+                transport.duration(route, prev.place.location, next.place.location, departure),
+                transport.distance(route, prev.place.location, next.place.location, departure),
+            )
+        }))
+        .collect()
+}
+
+/// Returns deadhead (non-revenue) distance of the route: travel from the start to the first job and
+/// from the last job to the end. For open tours, only the first leg is counted.
+pub fn deadhead_distance(route: &Route, transport: &dyn TransportCost) -> Distance {
+    let total_activities = route.tour.total();
+    let Some(last_job_idx) = get_last_job_idx(route, total_activities) else {
+        return Distance::default();
+    };
+
+    let leg_distance = |from_idx: usize, to_idx: usize| {
+        route.tour.get(from_idx).zip(route.tour.get(to_idx)).map_or(Distance::default(), |(from, to)| {
+            transport.distance(
+                route,
+                from.place.location,
+                to.place.location,
+                TravelTime::Departure(from.schedule.departure),
+            )
+        })
+    };
+
+    let first_leg = leg_distance(0, 1);
+    let last_leg = if last_job_idx + 1 < total_activities {
+        leg_distance(last_job_idx, last_job_idx + 1)
+    } else {
+        Distance::default()
+    };
+
+    first_leg + last_leg
 }
 
 /// Returns the index of the last job activity in the route.
@@ -253,44 +835,54 @@ fn has_jobs(route: &Route, total_activities: usize) -> bool {
     if has_end_depot { total_activities > 2 } else { total_activities > 1 }
 }
 
-fn calculate_route_duration(
+fn calculate_route_duration(route: &Route, span_tw: &TimeWindow, exclude_break_time: bool) -> Duration {
+    if !exclude_break_time {
+        return span_tw.duration();
+    }
+
+    // NOTE placed reserved time (e.g. a break) is not billable
+    let reserved_times = route
+        .actor
+        .vehicle
+        .dimens
+        .get_vehicle_reserved_times()
+        .into_iter()
+        .flat_map(|times| times.iter())
+        .filter_map(|reserved_time| get_reserved_time_within_shift(&route.actor, reserved_time));
+    let reserved_time = get_placed_reserved_time(route, reserved_times, span_tw);
+
+    (span_tw.duration() - reserved_time).max(Duration::default())
+}
+
+fn get_route_span_time_window(
     route: &Route,
     cost_span: RouteCostSpan,
     total_activities: usize,
     start: &Activity,
     end: &Activity,
-) -> Duration {
+) -> Option<TimeWindow> {
     match cost_span {
         RouteCostSpan::DepotToDepot => {
             // For open tours, DepotToDepot is effectively DepotToLastJob
-            end.schedule.departure - start.schedule.departure
-        }
-        RouteCostSpan::DepotToLastJob => {
-            if let Some(last_job_idx) = get_last_job_idx(route, total_activities) {
-                let last_job = route.tour.get(last_job_idx).unwrap();
-                last_job.schedule.departure - start.schedule.departure
-            } else {
-                Duration::default()
-            }
+            Some(TimeWindow::new(start.schedule.departure, end.schedule.departure))
         }
+        RouteCostSpan::DepotToLastJob => get_last_job_idx(route, total_activities).map(|last_job_idx| {
+            let last_job = route.tour.get(last_job_idx).unwrap();
+            TimeWindow::new(start.schedule.departure, last_job.schedule.departure)
+        }),
         RouteCostSpan::FirstJobToDepot => {
             // For open tours, there's no depot to return to, so this behaves like FirstJobToLastJob
-            if has_jobs(route, total_activities) {
-                let first_job = route.tour.get(1).unwrap();
-                end.schedule.departure - first_job.schedule.arrival
-            } else {
-                Duration::default()
-            }
+            has_jobs(route, total_activities)
+                .then(|| get_first_job_arrival(route))
+                .flatten()
+                .map(|first_job_arrival| TimeWindow::new(first_job_arrival, end.schedule.departure))
         }
-        RouteCostSpan::FirstJobToLastJob => {
-            if let Some(last_job_idx) = get_last_job_idx(route, total_activities) {
-                let first_job = route.tour.get(1).unwrap();
+        RouteCostSpan::FirstJobToLastJob => get_last_job_idx(route, total_activities)
+            .zip(get_first_job_arrival(route))
+            .map(|(last_job_idx, first_job_arrival)| {
                 let last_job = route.tour.get(last_job_idx).unwrap();
-                last_job.schedule.departure - first_job.schedule.arrival
-            } else {
-                Duration::default()
-            }
-        }
+                TimeWindow::new(first_job_arrival, last_job.schedule.departure)
+            }),
     }
 }
 
@@ -0,0 +1,56 @@
+//! Provides a feature to prefer required breaks placed on transit legs instead of stops.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/break_on_transit_test.rs"]
+mod break_on_transit_test;
+
+use super::*;
+use crate::construction::enablers::{ReservedTimesIndex, get_tour_reserved_times};
+use crate::models::solution::Route;
+
+custom_dimension!(pub VehiclePreferBreakOnTransit typeof bool);
+
+/// Creates a feature which prefers required breaks (reserved times) taken while driving between two
+/// stops over ones taken at a stop. Only vehicles with `VehiclePreferBreakOnTransit` dimension set
+/// to `true` are considered.
+///
+/// This is a soft objective: its fitness is amount of reserved times placed outside of transit legs.
+pub fn create_break_on_transit_feature(name: &str, reserved_times_index: ReservedTimesIndex) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(BreakOnTransitObjective { reserved_times_index }).build()
+}
+
+struct BreakOnTransitObjective {
+    reserved_times_index: ReservedTimesIndex,
+}
+
+impl BreakOnTransitObjective {
+    fn get_off_transit_breaks(&self, route: &Route) -> usize {
+        if !route.actor.vehicle.dimens.get_vehicle_prefer_break_on_transit().copied().unwrap_or(false) {
+            return 0;
+        }
+
+        get_tour_reserved_times(route, &self.reserved_times_index).filter(|rt| !is_on_transit(route, &rt.time)).count()
+    }
+}
+
+impl FeatureObjective for BreakOnTransitObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().map(|route_ctx| self.get_off_transit_breaks(route_ctx.route()) as Cost).sum()
+    }
+
+    fn estimate(&self, _: &MoveContext<'_>) -> Cost {
+        Cost::default()
+    }
+}
+
+/// Checks whether reserved time starts within travel between two activities, the same way as it is
+/// done when reserved times are converted to break activities in the solution.
+fn is_on_transit(route: &Route, reserved_tw: &TimeWindow) -> bool {
+    route.tour.legs().any(|(activities, _)| match activities {
+        [prev, next] => {
+            let travel_tw = TimeWindow::new(prev.schedule.departure, next.schedule.arrival);
+            travel_tw.intersects_exclusive(reserved_tw) && reserved_tw.start >= travel_tw.start
+        }
+        _ => false,
+    })
+}
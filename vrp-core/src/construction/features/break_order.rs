@@ -0,0 +1,95 @@
+//! Provides a feature to keep relative order between breaks and specific jobs in the tour.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/break_order_test.rs"]
+mod break_order_test;
+
+use super::*;
+use crate::models::solution::{Activity, Route};
+
+custom_dimension!(pub BreakJobOrder typeof BreakJobOrder);
+
+/// Specifies jobs which have to be served before and after the break in the same tour.
+#[derive(Clone, Debug, Default)]
+pub struct BreakJobOrder {
+    /// An id of the job which has to be served before the break.
+    pub after: Option<String>,
+    /// An id of the job which has to be served after the break.
+    pub before: Option<String>,
+}
+
+/// Creates a feature which enforces relative order between break jobs and jobs referenced by
+/// their [BreakJobOrder] dimension. The order is topological, it is not related to time windows.
+/// When a referenced job is not served in the tour, its part of the order is ignored.
+pub fn create_break_order_feature(name: &str, code: ViolationCode) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_constraint(BreakOrderConstraint { code }).build()
+}
+
+struct BreakOrderConstraint {
+    code: ViolationCode,
+}
+
+impl FeatureConstraint for BreakOrderConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let route = route_ctx.route();
+                let target = activity_ctx.target;
+                // NOTE target is inserted right after activity at the given index
+                let index = activity_ctx.index;
+
+                let is_violated = if let Some(order) = get_break_order(target) {
+                    let is_after_violated = order
+                        .after
+                        .as_ref()
+                        .and_then(|job_id| get_job_indices(route, job_id).max())
+                        .is_some_and(|job_idx| job_idx > index);
+                    let is_before_violated = order
+                        .before
+                        .as_ref()
+                        .and_then(|job_id| get_job_indices(route, job_id).min())
+                        .is_some_and(|job_idx| job_idx <= index);
+
+                    is_after_violated || is_before_violated
+                } else if let Some(job_id) = get_job_id(target) {
+                    route.tour.all_activities().enumerate().any(|(break_idx, activity)| {
+                        get_break_order(activity).is_some_and(|order| {
+                            let must_be_before = order.after.as_ref() == Some(&job_id);
+                            let must_be_after = order.before.as_ref() == Some(&job_id);
+
+                            (must_be_before && index >= break_idx) || (must_be_after && index < break_idx)
+                        })
+                    })
+                } else {
+                    false
+                };
+
+                if is_violated { ConstraintViolation::skip(self.code) } else { None }
+            }
+            MoveContext::Route { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        let has_order = |job: &Job| job.dimens().get_break_job_order().is_some();
+
+        if has_order(&source) || has_order(&candidate) { Err(self.code) } else { Ok(source) }
+    }
+}
+
+fn get_break_order(activity: &Activity) -> Option<&BreakJobOrder> {
+    activity.job.as_ref().and_then(|single| single.dimens.get_break_job_order())
+}
+
+fn get_job_id(activity: &Activity) -> Option<String> {
+    activity.retrieve_job().and_then(|job| job.dimens().get_job_id().cloned())
+}
+
+fn get_job_indices<'a>(route: &'a Route, job_id: &'a str) -> impl Iterator<Item = usize> + 'a {
+    route
+        .tour
+        .all_activities()
+        .enumerate()
+        .filter(move |(_, activity)| get_job_id(activity).is_some_and(|id| id == job_id))
+        .map(|(idx, _)| idx)
+}
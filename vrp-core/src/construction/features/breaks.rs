@@ -28,6 +28,9 @@ pub struct BreakFeatureBuilder {
     belongs_to_route_fn: Option<BelongsToRouteFn>,
     is_break_single_fn: Option<BreakSingleFn>,
     policy_fn: Option<BreakPolicyFn>,
+    min_work_after_fn: Option<BreakMinWorkAfterFn>,
+    max_fraction_fn: Option<BreakMaxFractionFn>,
+    transport: Option<Arc<dyn TransportCost>>,
 }
 
 impl BreakFeatureBuilder {
@@ -39,6 +42,9 @@ impl BreakFeatureBuilder {
             belongs_to_route_fn: None,
             is_break_single_fn: None,
             policy_fn: None,
+            min_work_after_fn: None,
+            max_fraction_fn: None,
+            transport: None,
         }
     }
 
@@ -67,6 +73,31 @@ impl BreakFeatureBuilder {
         self
     }
 
+    /// Sets a function which returns min work time which should follow a given break. Work time
+    /// is counted from break departure till departure from the last job in the tour. If not set,
+    /// no limit is applied. Required breaks, defined as vehicle reserved times, are skipped when
+    /// they leave not enough work time (see `VehicleMinWorkAfterBreakDimension`). Requires transport
+    /// costs to be set.
+    pub fn set_min_work_after<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Single) -> Option<Duration> + Send + Sync + 'static,
+    {
+        self.min_work_after_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function which returns max fraction of working time which can be spent on breaks by
+    /// a given actor. Working time is a tour duration without break time. Required breaks, defined
+    /// as vehicle reserved times, are counted too, but only breaks represented by jobs are skipped
+    /// to respect the limit. If not set, no limit is applied.
+    pub fn set_max_break_fraction<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Actor) -> Option<Float> + Send + Sync + 'static,
+    {
+        self.max_fraction_fn = Some(Arc::new(func));
+        self
+    }
+
     /// Sets a function which specifies whether a given route can serve a given break. This function
     /// should return false, if the job is not break. If not set, any break job can be assigned to any route.
     pub fn set_belongs_to_route<F>(mut self, func: F) -> Self
@@ -77,13 +108,26 @@ impl BreakFeatureBuilder {
         self
     }
 
+    /// Sets transport costs to estimate break departure time. Required only when min work after break
+    /// is set.
+    pub fn set_transport(mut self, transport: Arc<dyn TransportCost>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
     /// Builds a optional break feature.
     pub fn build(mut self) -> GenericResult<Feature> {
         let is_break_single_fn =
             self.is_break_single_fn.take().ok_or_else(|| GenericError::from("is_break_single must be set"))?;
+        let transport = self.transport.take();
+        if self.min_work_after_fn.is_some() && transport.is_none() {
+            return Err("transport must be set when min work after break is used".into());
+        }
 
         let code = self.violation_code.take().unwrap_or_default();
         let policy_fn = self.policy_fn.take().unwrap_or_else(|| Arc::new(|_| BreakPolicy::SkipIfNoIntersection));
+        let min_work_after_fn = self.min_work_after_fn.take().unwrap_or_else(|| Arc::new(|_| None));
+        let max_fraction_fn = self.max_fraction_fn.take().unwrap_or_else(|| Arc::new(|_| None));
         let belongs_to_route_fn = self.belongs_to_route_fn.take().unwrap_or_else(|| {
             Arc::new({
                 let is_break_single_fn = is_break_single_fn.clone();
@@ -91,7 +135,8 @@ impl BreakFeatureBuilder {
             })
         });
 
-        let break_fns = BreakFns { is_break_single_fn, belongs_to_route_fn, policy_fn };
+        let break_fns =
+            BreakFns { is_break_single_fn, belongs_to_route_fn, policy_fn, min_work_after_fn, max_fraction_fn };
 
         let context_transition = ConcreteJobContextTransition {
             remove_required: {
@@ -112,7 +157,7 @@ impl BreakFeatureBuilder {
 
         FeatureBuilder::default()
             .with_name(self.name.as_str())
-            .with_constraint(OptionalBreakConstraint { break_fns: break_fns.clone(), code })
+            .with_constraint(OptionalBreakConstraint { break_fns: break_fns.clone(), transport, code })
             .with_objective(OptionalBreakObjective { break_fns: break_fns.clone() })
             .with_state(OptionalBreakState { context_transition, break_fns })
             .build()
@@ -122,16 +167,21 @@ impl BreakFeatureBuilder {
 type BreakSingleFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
 type BelongsToRouteFn = Arc<dyn Fn(&Route, &Job) -> bool + Send + Sync>;
 type BreakPolicyFn = Arc<dyn Fn(&Single) -> BreakPolicy + Send + Sync>;
+type BreakMinWorkAfterFn = Arc<dyn Fn(&Single) -> Option<Duration> + Send + Sync>;
+type BreakMaxFractionFn = Arc<dyn Fn(&Actor) -> Option<Float> + Send + Sync>;
 
 #[derive(Clone)]
 struct BreakFns {
     is_break_single_fn: BreakSingleFn,
     belongs_to_route_fn: BelongsToRouteFn,
     policy_fn: BreakPolicyFn,
+    min_work_after_fn: BreakMinWorkAfterFn,
+    max_fraction_fn: BreakMaxFractionFn,
 }
 
 struct OptionalBreakConstraint {
     break_fns: BreakFns,
+    transport: Option<Arc<dyn TransportCost>>,
     code: ViolationCode,
 }
 
@@ -148,14 +198,73 @@ impl OptionalBreakConstraint {
         }
     }
 
-    fn evaluate_activity(&self, activity_ctx: &ActivityContext) -> Option<ConstraintViolation> {
-        activity_ctx
-            .target
-            .job
-            .as_ref()
-            // reject inserting break at the very beginning
-            .filter(|single| (self.break_fns.is_break_single_fn)(single) && activity_ctx.prev.job.is_none())
-            .and_then(|_| ConstraintViolation::skip(self.code))
+    fn evaluate_activity(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let break_single =
+            activity_ctx.target.job.as_ref().filter(|single| (self.break_fns.is_break_single_fn)(single))?;
+
+        // reject inserting break at the very beginning
+        if activity_ctx.prev.job.is_none() {
+            return ConstraintViolation::skip(self.code);
+        }
+
+        // reject inserting break which exceeds max break fraction of working time
+        if let Some(max_fraction) = (self.break_fns.max_fraction_fn)(route_ctx.route().actor.as_ref()) {
+            let (job_break_time, required_break_time, working_time) =
+                get_break_times(route_ctx.route(), &self.break_fns);
+            let break_time = job_break_time + required_break_time + activity_ctx.target.place.duration;
+            if break_time > max_fraction * working_time {
+                return ConstraintViolation::skip(self.code);
+            }
+        }
+
+        // reject inserting break too close to the end of work
+        let min_work_after = (self.break_fns.min_work_after_fn)(break_single)?;
+        let transport = self.transport.as_deref()?;
+        let has_job_after = activity_ctx.next.is_some_and(|next| next.job.is_some());
+        let work_after = get_last_job_departure(route_ctx, &self.break_fns).filter(|_| has_job_after).map_or(
+            Duration::default(),
+            |departure| {
+                let (break_departure, delay) =
+                    Self::estimate_break_departure(transport, route_ctx.route(), activity_ctx);
+                departure + delay - break_departure
+            },
+        );
+
+        if work_after < min_work_after { ConstraintViolation::skip(self.code) } else { None }
+    }
+
+    /// Estimates departure from the break inserted in the given activity context and a delay it
+    /// introduces for the next activity. Waiting time at the next activity reduces the delay.
+    fn estimate_break_departure(
+        transport: &dyn TransportCost,
+        route: &Route,
+        activity_ctx: &ActivityContext,
+    ) -> (Timestamp, Duration) {
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + transport.duration(route, prev.place.location, target.place.location, TravelTime::Departure(departure));
+        let break_departure = arrival.max(target.place.time.start) + target.place.duration;
+
+        let delay = activity_ctx.next.map_or(Duration::default(), |next| {
+            let next_arrival = break_departure
+                + transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(break_departure),
+                );
+            let next_start = next.place.time.start;
+
+            (next_arrival.max(next_start) - next.schedule.arrival.max(next_start)).max(Duration::default())
+        });
+
+        (break_departure, delay)
     }
 }
 
@@ -163,7 +272,7 @@ impl FeatureConstraint for OptionalBreakConstraint {
     fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
         match move_ctx {
             MoveContext::Route { route_ctx, job, .. } => self.evaluate_route(route_ctx, job),
-            MoveContext::Activity { activity_ctx, .. } => self.evaluate_activity(activity_ctx),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => self.evaluate_activity(route_ctx, activity_ctx),
         }
     }
 
@@ -229,11 +338,21 @@ impl<JT: JobContextTransition + Send + Sync> OptionalBreakState<JT> {
     /// Removes breaks which conditions are violated after ruin:
     /// * break without location served separately when original job is removed, but break is kept.
     /// * break is defined by interval, but its time is violated. This might happen due to departure time rescheduling.
+    /// * break is followed by less work time than required.
+    /// * break exceeds max break fraction of working time, e.g. when working time is reduced by ruin.
     fn remove_invalid_breaks(&self, solution_ctx: &mut SolutionContext) {
         let breaks_to_remove = solution_ctx
             .routes
             .iter()
             .flat_map(|route_ctx| {
+                let last_job_departure = get_last_job_departure(route_ctx, &self.break_fns);
+
+                // NOTE breaks are kept in tour order while they fit into the limit
+                let (_, required_break_time, working_time) = get_break_times(route_ctx.route(), &self.break_fns);
+                let max_break_time = (self.break_fns.max_fraction_fn)(route_ctx.route().actor.as_ref())
+                    .map(|max_fraction| max_fraction * working_time);
+                let mut kept_break_time = required_break_time;
+
                 route_ctx
                     .route()
                     .tour
@@ -262,8 +381,24 @@ impl<JT: JobContextTransition + Send + Sync> OptionalBreakState<JT> {
                         let is_not_on_time = !is_on_proper_time(route_ctx, break_single, &activity.schedule)
                             || !can_be_scheduled(route_ctx, break_single, &self.break_fns.policy_fn);
                         let is_ovrp_last = route_ctx.route().tour.end().is_some_and(|end| std::ptr::eq(activity, end));
-
-                        if is_orphan || is_not_on_time || is_ovrp_last {
+                        let is_short_work_after =
+                            (self.break_fns.min_work_after_fn)(break_single).is_some_and(|min_work_after| {
+                                let departure = last_job_departure.unwrap_or(activity.schedule.departure);
+                                (departure - activity.schedule.departure) < min_work_after
+                            });
+
+                        let is_excessive = !(is_orphan || is_not_on_time || is_ovrp_last || is_short_work_after)
+                            && max_break_time.is_some_and(|max_break_time| {
+                                let break_time = kept_break_time + activity.place.duration;
+                                let is_excessive = break_time > max_break_time;
+                                if !is_excessive {
+                                    kept_break_time = break_time;
+                                }
+
+                                is_excessive
+                            });
+
+                        if is_orphan || is_not_on_time || is_ovrp_last || is_short_work_after || is_excessive {
                             breaks.insert(Job::Single(break_single.clone()));
                         }
 
@@ -337,26 +472,66 @@ fn can_be_scheduled(route_ctx: &RouteContext, break_single: &Single, policy_fn:
 
     let policy = policy_fn(break_single);
 
-    get_break_time_windows(break_single, departure).any(|break_tw| match policy {
+    get_break_time_windows(break_single, get_offset_anchor(route_ctx.route())).any(|break_tw| match policy {
         BreakPolicy::SkipIfNoIntersection => break_tw.intersects(&tour_tw),
         BreakPolicy::SkipIfArrivalBeforeEnd => tour_tw.end > break_tw.end,
     })
 }
 
-fn get_break_time_windows(break_single: &'_ Single, departure: Timestamp) -> impl Iterator<Item = TimeWindow> + '_ {
+fn get_break_time_windows(break_single: &'_ Single, offset_anchor: Timestamp) -> impl Iterator<Item = TimeWindow> + '_ {
     break_single
         .places
         .first()
         .expect("missing time window in a break job")
         .times
         .iter()
-        .map(move |span| span.to_time_window(departure))
+        .map(move |span| span.to_time_window(offset_anchor))
+}
+
+/// Returns departure time from the last job activity which is not a break in the tour.
+fn get_last_job_departure(route_ctx: &RouteContext, break_fns: &BreakFns) -> Option<Timestamp> {
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .rev()
+        .find(|activity| activity.job.as_ref().is_some_and(|single| !(break_fns.is_break_single_fn)(single)))
+        .map(|activity| activity.schedule.departure)
 }
 
 /// Checks whether break is scheduled on time as its time can be invalid due to departure time optimizations.
 fn is_on_proper_time(route_ctx: &RouteContext, break_job: &Single, actual_schedule: &Schedule) -> bool {
-    let departure = route_ctx.route().tour.start().unwrap().schedule.departure;
     let actual_tw = TimeWindow::new(actual_schedule.arrival, actual_schedule.departure);
 
-    get_break_time_windows(break_job, departure).any(|tw| tw.intersects(&actual_tw))
+    get_break_time_windows(break_job, get_offset_anchor(route_ctx.route())).any(|tw| tw.intersects(&actual_tw))
+}
+
+/// Returns a total duration of breaks represented by jobs, a total duration of required breaks placed
+/// within the tour and a working time which is the tour duration without break time.
+fn get_break_times(route: &Route, break_fns: &BreakFns) -> (Duration, Duration, Duration) {
+    let (Some(start), Some(end)) = (route.tour.start(), route.tour.end()) else {
+        return (Duration::default(), Duration::default(), Duration::default());
+    };
+    let tour_tw = TimeWindow::new(start.schedule.departure, end.schedule.arrival);
+
+    let job_break_time = route
+        .tour
+        .all_activities()
+        .filter(|activity| activity.job.as_ref().is_some_and(|single| (break_fns.is_break_single_fn)(single)))
+        .map(|activity| activity.place.duration)
+        .sum::<Duration>();
+
+    let reserved_times = route
+        .actor
+        .vehicle
+        .dimens
+        .get_vehicle_reserved_times()
+        .into_iter()
+        .flat_map(|times| times.iter())
+        .filter_map(|reserved_time| get_reserved_time_within_shift(&route.actor, reserved_time));
+    let required_break_time = get_placed_reserved_time(route, reserved_times, &tour_tw);
+
+    let working_time = (tour_tw.duration() - job_break_time - required_break_time).max(Duration::default());
+
+    (job_break_time, required_break_time, working_time)
 }
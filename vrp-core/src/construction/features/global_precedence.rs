@@ -0,0 +1,278 @@
+//! Provides a feature to enforce precedence between jobs served by any vehicles.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/global_precedence_test.rs"]
+mod global_precedence_test;
+
+use super::*;
+use crate::models::solution::Activity;
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
+custom_solution_state!(PrecedenceSchedules typeof HashMap<Job, TimeWindow>);
+custom_solution_state!(PrecedenceViolations typeof usize);
+custom_tour_state!(LastPredecessorIndex typeof usize);
+
+/// Creates a feature which requires a predecessor job to be completed before its successor job
+/// starts, regardless of vehicles serving them. Each pair is specified as `(predecessor, successor)`.
+///
+/// On insertion, a successor is accepted only after all its predecessors are assigned and only at
+/// positions where it cannot start before their completion; a predecessor, removed while its successor
+/// stays assigned, can be reinserted only at positions where it can be completed before the successor
+/// starts. Any insertion is also rejected when it delays predecessors already in the route past their
+/// successors' start. As this delay is estimated without travel time, pairs violated by realized
+/// schedules across the whole solution, including successors assigned without their predecessors, are
+/// additionally penalized by the feature's objective.
+pub fn create_global_precedence_feature(
+    name: &str,
+    precedence_pairs: Vec<(Job, Job)>,
+    code: ViolationCode,
+) -> Result<Feature, GenericError> {
+    let precedences = Arc::new(Precedences::new(precedence_pairs));
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(GlobalPrecedenceConstraint { code, precedences: precedences.clone() })
+        .with_objective(GlobalPrecedenceObjective { precedences: precedences.clone() })
+        .with_state(GlobalPrecedenceState { precedences })
+        .build()
+}
+
+struct Precedences {
+    pairs: Vec<(Job, Job)>,
+    predecessors: HashMap<Job, Vec<Job>>,
+    successors: HashMap<Job, Vec<Job>>,
+}
+
+impl Precedences {
+    fn new(pairs: Vec<(Job, Job)>) -> Self {
+        let (predecessors, successors) = pairs.iter().fold(
+            (HashMap::<Job, Vec<Job>>::new(), HashMap::<Job, Vec<Job>>::new()),
+            |(mut predecessors, mut successors), (predecessor, successor)| {
+                predecessors.entry(successor.clone()).or_default().push(predecessor.clone());
+                successors.entry(predecessor.clone()).or_default().push(successor.clone());
+                (predecessors, successors)
+            },
+        );
+
+        Self { pairs, predecessors, successors }
+    }
+
+    fn contains(&self, job: &Job) -> bool {
+        self.predecessors.contains_key(job) || self.successors.contains_key(job)
+    }
+
+    fn get_violations(&self, schedules: &HashMap<Job, TimeWindow>) -> usize {
+        self.pairs
+            .iter()
+            .filter_map(|(predecessor, successor)| schedules.get(successor).map(|successor| (predecessor, successor)))
+            .filter(|(predecessor, successor)| {
+                schedules.get(*predecessor).is_none_or(|predecessor| predecessor.end > successor.start)
+            })
+            .count()
+    }
+}
+
+struct GlobalPrecedenceConstraint {
+    code: ViolationCode,
+    precedences: Arc<Precedences>,
+}
+
+impl FeatureConstraint for GlobalPrecedenceConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { solution_ctx, job, .. } => {
+                let schedules = solution_ctx.state.get_precedence_schedules();
+                let is_assigned = |job: &Job| schedules.is_some_and(|schedules| schedules.contains_key(job));
+
+                let has_unassigned_predecessors = self
+                    .precedences
+                    .predecessors
+                    .get(*job)
+                    .is_some_and(|predecessors| !predecessors.iter().all(is_assigned));
+
+                if has_unassigned_predecessors { ConstraintViolation::fail(self.code) } else { None }
+            }
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                let schedules = solution_ctx.state.get_precedence_schedules()?;
+
+                // NOTE a travel time is not known here, so the earliest possible start is used
+                let earliest_start = activity_ctx.prev.schedule.departure.max(activity_ctx.target.place.time.start);
+                let earliest_completion = earliest_start + activity_ctx.target.place.duration;
+
+                if self.is_delaying_predecessors(route_ctx, activity_ctx, schedules, earliest_completion) {
+                    return ConstraintViolation::skip(self.code);
+                }
+
+                let job = activity_ctx.target.retrieve_job()?;
+
+                let completion = self
+                    .precedences
+                    .predecessors
+                    .get(&job)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|predecessor| schedules.get(predecessor))
+                    .map(|schedule| schedule.end)
+                    .max_by(|a, b| a.total_cmp(b));
+
+                if completion.is_some_and(|completion| earliest_start < completion) {
+                    return ConstraintViolation::skip(self.code);
+                }
+
+                let successor_start = self.get_successor_start(&job, schedules, |_| true);
+                if successor_start.is_some_and(|successor_start| earliest_completion > successor_start) {
+                    return ConstraintViolation::skip(self.code);
+                }
+
+                None
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        // NOTE a merged job replaces the source one, so precedence of both would be lost
+        if self.precedences.contains(&source) || self.precedences.contains(&candidate) {
+            Err(self.code)
+        } else {
+            Ok(source)
+        }
+    }
+}
+
+impl GlobalPrecedenceConstraint {
+    /// Gets the earliest realized start of the job's successors accepted by the filter.
+    fn get_successor_start(
+        &self,
+        job: &Job,
+        schedules: &HashMap<Job, TimeWindow>,
+        filter: impl Fn(&Job) -> bool,
+    ) -> Option<Timestamp> {
+        self.precedences
+            .successors
+            .get(job)
+            .into_iter()
+            .flatten()
+            .filter(|successor| filter(successor))
+            .filter_map(|successor| schedules.get(successor))
+            .map(|schedule| schedule.start)
+            .min_by(|a, b| a.total_cmp(b))
+    }
+
+    /// Checks whether inserting the target activity delays predecessors, which are already in the route
+    /// after the insertion point, so that they are completed after their successors from other routes
+    /// start. Only a lower bound of the delay is known here: it is propagated through the rest of the
+    /// tour and absorbed by waiting times.
+    fn is_delaying_predecessors(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        schedules: &HashMap<Job, TimeWindow>,
+        earliest_completion: Timestamp,
+    ) -> bool {
+        let Some(&last_idx) = route_ctx.state().get_last_predecessor_index() else { return false };
+        let (Some(next), true) = (activity_ctx.next, activity_ctx.index < last_idx) else { return false };
+
+        let tour = &route_ctx.route().tour;
+        let delay = (earliest_completion - next.schedule.arrival).max(0.);
+
+        let result =
+            tour.all_activities().take(last_idx + 1).skip(activity_ctx.index + 1).try_fold(delay, |delay, activity| {
+                if delay <= 0. {
+                    return ControlFlow::Break(false);
+                }
+
+                let delay =
+                    (activity.schedule.arrival + delay).max(activity.place.time.start) - get_service_start(activity);
+
+                let is_late = activity.retrieve_job().is_some_and(|job| {
+                    self.get_successor_start(&job, schedules, |successor| !tour.contains(successor))
+                        .is_some_and(|successor_start| activity.schedule.departure + delay > successor_start)
+                });
+
+                if is_late { ControlFlow::Break(true) } else { ControlFlow::Continue(delay) }
+            });
+
+        matches!(result, ControlFlow::Break(true))
+    }
+}
+
+struct GlobalPrecedenceObjective {
+    precedences: Arc<Precedences>,
+}
+
+impl FeatureObjective for GlobalPrecedenceObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        let solution = &solution.solution;
+
+        solution.state.get_precedence_violations().copied().unwrap_or_else(|| {
+            self.precedences.get_violations(&get_schedules(solution.routes.as_slice(), &self.precedences))
+        }) as Cost
+    }
+
+    fn estimate(&self, _: &MoveContext<'_>) -> Cost {
+        Cost::default()
+    }
+}
+
+struct GlobalPrecedenceState {
+    precedences: Arc<Precedences>,
+}
+
+impl FeatureState for GlobalPrecedenceState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if !self.precedences.contains(job) {
+            // NOTE insertion can still delay jobs with precedence in the same route
+            let route_ctx = solution_ctx.routes.get(route_index).unwrap();
+            if !route_ctx.route().tour.jobs().any(|job| self.precedences.contains(job)) {
+                return;
+            }
+        }
+
+        self.accept_solution_state(solution_ctx);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let last_idx = route_ctx.route().tour.all_activities().enumerate().rev().find_map(|(idx, activity)| {
+            activity.retrieve_job().filter(|job| self.precedences.successors.contains_key(job)).map(|_| idx)
+        });
+
+        if let Some(last_idx) = last_idx {
+            route_ctx.state_mut().set_last_predecessor_index(last_idx);
+        } else {
+            route_ctx.state_mut().remove_last_predecessor_index();
+        }
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let schedules = get_schedules(solution_ctx.routes.as_slice(), &self.precedences);
+        let violations = self.precedences.get_violations(&schedules);
+
+        solution_ctx.state.set_precedence_schedules(schedules);
+        solution_ctx.state.set_precedence_violations(violations);
+    }
+}
+
+/// Gets realized schedules of jobs with precedence: from the earliest service start till the latest departure.
+fn get_schedules(routes: &[RouteContext], precedences: &Precedences) -> HashMap<Job, TimeWindow> {
+    routes
+        .iter()
+        .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+        .filter_map(|activity| {
+            activity.retrieve_job().filter(|job| precedences.contains(job)).map(|job| (job, activity))
+        })
+        .fold(HashMap::new(), |mut acc, (job, activity)| {
+            let service_start = get_service_start(activity);
+            acc.entry(job)
+                .and_modify(|schedule: &mut TimeWindow| {
+                    schedule.start = schedule.start.min(service_start);
+                    schedule.end = schedule.end.max(activity.schedule.departure);
+                })
+                .or_insert_with(|| TimeWindow::new(service_start, activity.schedule.departure));
+            acc
+        })
+}
+
+fn get_service_start(activity: &Activity) -> Timestamp {
+    activity.schedule.arrival.max(activity.place.time.start)
+}
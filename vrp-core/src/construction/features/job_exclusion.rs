@@ -0,0 +1,109 @@
+//! A job exclusion feature provides the way to forbid specific pairs of jobs in the same tour.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/job_exclusion_test.rs"]
+mod job_exclusion_test;
+
+use super::*;
+use std::collections::{HashMap, HashSet};
+
+custom_tour_state!(ExcludedJobIds typeof HashSet<String>);
+
+/// Creates a job exclusion feature as a hard constraint. Each pair is specified by job ids and is
+/// symmetric: a job cannot be inserted into a route which already contains its excluded partner.
+pub fn create_job_exclusion_feature(
+    name: &str,
+    exclusion_pairs: Vec<(String, String)>,
+    code: ViolationCode,
+) -> Result<Feature, GenericError> {
+    let exclusions = Arc::new(Exclusions::new(exclusion_pairs));
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(JobExclusionConstraint { code, exclusions: exclusions.clone() })
+        .with_state(JobExclusionState { exclusions })
+        .build()
+}
+
+struct Exclusions {
+    partners: HashMap<String, HashSet<String>>,
+}
+
+impl Exclusions {
+    fn new(pairs: Vec<(String, String)>) -> Self {
+        let partners = pairs.into_iter().fold(HashMap::<String, HashSet<String>>::new(), |mut partners, (a, b)| {
+            partners.entry(a.clone()).or_default().insert(b.clone());
+            partners.entry(b).or_default().insert(a);
+            partners
+        });
+
+        Self { partners }
+    }
+
+    fn get_partners(&self, job: &Job) -> Option<&HashSet<String>> {
+        job.dimens().get_job_id().and_then(|job_id| self.partners.get(job_id))
+    }
+
+    fn is_excluded(&self, source: &Job, candidate: &Job) -> bool {
+        self.get_partners(source)
+            .zip(candidate.dimens().get_job_id())
+            .is_some_and(|(partners, candidate_id)| partners.contains(candidate_id))
+    }
+}
+
+struct JobExclusionConstraint {
+    code: ViolationCode,
+    exclusions: Arc<Exclusions>,
+}
+
+impl FeatureConstraint for JobExclusionConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let partners = self.exclusions.get_partners(job)?;
+                let route_ids = route_ctx.state().get_excluded_job_ids()?;
+
+                if partners.iter().any(|partner| route_ids.contains(partner)) {
+                    ConstraintViolation::fail(self.code)
+                } else {
+                    None
+                }
+            }
+            MoveContext::Activity { .. } => None,
+        }
+    }
+
+    fn merge(&self, source: Job, candidate: Job) -> Result<Job, ViolationCode> {
+        if self.exclusions.is_excluded(&source, &candidate) { Err(self.code) } else { Ok(source) }
+    }
+}
+
+struct JobExclusionState {
+    exclusions: Arc<Exclusions>,
+}
+
+impl FeatureState for JobExclusionState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        if self.exclusions.get_partners(job).is_some() {
+            self.accept_route_state(solution_ctx.routes.get_mut(route_index).unwrap())
+        }
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let job_ids = route_ctx
+            .route()
+            .tour
+            .jobs()
+            .filter(|job| self.exclusions.get_partners(job).is_some())
+            .filter_map(|job| job.dimens().get_job_id().cloned())
+            .collect::<HashSet<_>>();
+
+        if job_ids.is_empty() {
+            route_ctx.state_mut().remove_excluded_job_ids();
+        } else {
+            route_ctx.state_mut().set_excluded_job_ids(job_ids);
+        }
+    }
+
+    fn accept_solution_state(&self, _: &mut SolutionContext) {}
+}
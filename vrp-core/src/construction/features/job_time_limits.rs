@@ -1,15 +1,27 @@
 //! A feature to enforce job time constraints on shifts.
 //!
 //! This allows configuring:
-//! - `earliest_first`: The earliest time a vehicle can arrive at its first job
-//! - `latest_last`: The latest time a vehicle can depart from its last job
+//! - `earliest_first`: The earliest time a vehicle can arrive at or start serving its first job
+//!   (depends on `first_job_reference`), estimated from scheduled start or actual departure
+//!   (depends on `departure_reference`)
+//! - `latest_last`: The latest time a vehicle can arrive at or depart from its last job
+//!   (depends on `last_job_reference`)
+//!
+//! Additionally, a soft objective can be used to prefer tours with more slack against these bounds.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/job_time_limits_test.rs"]
 mod job_time_limits_test;
 
 use super::*;
-use crate::models::problem::{Job, JobTimeConstraintsDimension, TransportCost, TravelTime};
+use crate::construction::enablers::LatestArrivalActivityState;
+use crate::models::problem::{
+    DepartureReference, FirstJobReference, Job, JobTimeConstraints, JobTimeConstraintsDimension, LastJobReference,
+    TransportCost, TravelTime,
+};
+use crate::models::solution::{Activity, Route};
+use std::cell::OnceCell;
+use std::ops::ControlFlow;
 
 /// Creates a feature that enforces job time constraints on shifts.
 /// This is a hard constraint - jobs that violate the constraints remain unassigned.
@@ -31,6 +43,35 @@ pub fn create_job_time_limits_feature(
         .build()
 }
 
+/// Creates a feature that minimizes pressure of job time constraints on shifts: the closer the first job
+/// is to `earliest_first` and the last job to `latest_last`, the higher the pressure is.
+///
+/// This is a soft objective: each bound of the route contributes `1 / (1 + slack)`, where slack is
+/// a non-negative distance of the first (last) job time to the bound. So, the route penalty is within
+/// `[0, 2]`: it reaches its maximum when the bounds are met exactly or violated (e.g. when the hard
+/// constraint is not used) and tends to zero when there is a lot of slack.
+pub fn create_job_time_pressure_feature(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default().with_name(name).with_objective(JobTimePressureObjective { transport, activity }).build()
+}
+
+/// Returns time from which service of the route's first job is scheduled when the vehicle arrives at
+/// given time: the vehicle waits at the first job till `earliest_first` when it limits service start
+/// (see `FirstJobReference::ServiceStart`).
+pub fn get_first_job_service_arrival(route: &Route, arrival: Timestamp) -> Timestamp {
+    route
+        .actor
+        .vehicle
+        .dimens
+        .get_job_time_constraints()
+        .filter(|constraints| constraints.first_job_reference == FirstJobReference::ServiceStart)
+        .and_then(|constraints| constraints.earliest_first)
+        .map_or(arrival, |earliest_first| arrival.max(earliest_first))
+}
+
 struct JobTimeLimitsConstraint {
     transport: Arc<dyn TransportCost>,
     activity: Arc<dyn ActivityCost>,
@@ -58,61 +99,125 @@ impl JobTimeLimitsConstraint {
         // Skip if target is not a job (e.g., it's a depot or break)
         target.job.as_ref()?;
 
+        // Tour always has a start activity, even when route costs are counted from the first job (open start),
+        // so the first job check holds there too. Last job is followed by the end depot or None for open routes.
+        let is_first_job = prev.job.is_none() && activity_ctx.index == 0;
+        let is_last_job = activity_ctx.next.is_none_or(|next| next.job.is_none());
+
+        // NOTE most of insertions happen in the middle of the tour, so there is no need to estimate travel
+        if !((is_first_job && constraints.earliest_first.is_some())
+            || (is_last_job && constraints.latest_last.is_some()))
+        {
+            return None;
+        }
+
+        let arrive_at = |departure: Timestamp| {
+            departure
+                + self.transport.duration(
+                    route,
+                    prev.place.location,
+                    target.place.location,
+                    TravelTime::Departure(departure),
+                )
+        };
+
+        // arrival from the actual departure is shared between both checks, so it is estimated at most once
         let departure = prev.schedule.departure;
-        let arr_time_at_target = departure
-            + self.transport.duration(
-                route,
-                prev.place.location,
-                target.place.location,
-                TravelTime::Departure(departure),
-            );
+        let actual_arrival = OnceCell::new();
+        let get_actual_arrival = || *actual_arrival.get_or_init(|| arrive_at(departure));
+
+        // Check earliest_first constraint: applies when this is the first job (prev is the start depot).
+        if let Some(earliest_first) = constraints.earliest_first.filter(|_| is_first_job) {
+            let arr_time_at_target = match constraints.departure_reference {
+                DepartureReference::ActualDeparture => get_actual_arrival(),
+                DepartureReference::ScheduledStart => {
+                    // NOTE departure can be advanced, so the arrival is estimated from the shift's scheduled start
+                    match actor.detail.start.as_ref().and_then(|start| start.time.earliest) {
+                        Some(scheduled) if scheduled != departure => arrive_at(scheduled),
+                        _ => get_actual_arrival(),
+                    }
+                }
+            };
 
-        // Check earliest_first constraint: applies when this is the first job
-        // (prev is the start depot, which has no job)
-        if let Some(earliest_first) = constraints.earliest_first {
-            let is_first_job = prev.job.is_none() && activity_ctx.index == 0;
-            if is_first_job && arr_time_at_target < earliest_first {
-                // Vehicle would arrive before earliest allowed time
-                // Check if we can wait - job's time window must extend past earliest_first
-                if target.place.time.end < earliest_first {
-                    return ConstraintViolation::skip(self.violation_code);
+            if arr_time_at_target < earliest_first {
+                match constraints.first_job_reference {
+                    // Vehicle would arrive before earliest allowed time and waiting on site is not allowed
+                    FirstJobReference::Arrival => return ConstraintViolation::skip(self.violation_code),
+                    // Check if we can wait - job's time window must extend past earliest_first
+                    FirstJobReference::ServiceStart if target.place.time.end < earliest_first => {
+                        return ConstraintViolation::skip(self.violation_code);
+                    }
+                    // Vehicle waits till earliest_first, so the next activity should be still reachable in time
+                    FirstJobReference::ServiceStart => {
+                        if !self.can_wait_at_first_job(route_ctx, activity_ctx, earliest_first) {
+                            return ConstraintViolation::skip(self.violation_code);
+                        }
+                    }
                 }
-                // We can wait, but we need to ensure the adjusted arrival still works
-                // The actual arrival will be max(arr_time_at_target, earliest_first)
-                // which needs to be <= target.place.time.end (already checked above)
             }
         }
 
-        // Check latest_last constraint: applies when this becomes the last job
-        // (next is the end depot or None for open routes)
-        if let Some(latest_last) = constraints.latest_last {
-            let is_last_job = activity_ctx.next.is_none_or(|next| next.job.is_none());
-            if is_last_job {
-                // Calculate when we would depart from this job
-                let actual_arr_time = if let Some(earliest_first) = constraints.earliest_first {
-                    let is_first_job = prev.job.is_none() && activity_ctx.index == 0;
-                    if is_first_job { arr_time_at_target.max(earliest_first) } else { arr_time_at_target }
-                } else {
-                    arr_time_at_target
-                };
-
-                // Respect the job's time window (might need to wait)
-                let service_start = actual_arr_time.max(target.place.time.start);
-                let departure_result = self.activity.estimate_departure(route, target, service_start);
-
-                // Extract departure time from ControlFlow (use the value regardless of Continue/Break)
-                let departure_from_target = match departure_result {
-                    std::ops::ControlFlow::Continue(t) | std::ops::ControlFlow::Break(t) => t,
-                };
+        // Check latest_last constraint: applies when this becomes the last job.
+        if let Some(latest_last) = constraints.latest_last.filter(|_| is_last_job) {
+            // Calculate when we would arrive at this job
+            let actual_arr_time = match constraints.earliest_first {
+                Some(earliest_first) if is_first_job => get_actual_arrival().max(earliest_first),
+                _ => get_actual_arrival(),
+            };
+
+            let last_job_time = match constraints.last_job_reference {
+                LastJobReference::Arrival => actual_arr_time,
+                LastJobReference::Departure => {
+                    // Respect the job's time window (might need to wait)
+                    let service_start = actual_arr_time.max(target.place.time.start);
+                    let departure_result = self.activity.estimate_departure(route, target, service_start);
 
-                if departure_from_target > latest_last {
-                    return ConstraintViolation::skip(self.violation_code);
+                    // Extract departure time from ControlFlow (use the value regardless of Continue/Break)
+                    match departure_result {
+                        std::ops::ControlFlow::Continue(t) | std::ops::ControlFlow::Break(t) => t,
+                    }
                 }
+            };
+
+            if last_job_time > latest_last {
+                return ConstraintViolation::skip(self.violation_code);
             }
         }
 
         None
     }
+
+    fn can_wait_at_first_job(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        earliest_first: Timestamp,
+    ) -> bool {
+        let route = route_ctx.route();
+        let target = activity_ctx.target;
+
+        let ControlFlow::Continue(departure) = self.activity.estimate_departure(route, target, earliest_first) else {
+            return false;
+        };
+
+        activity_ctx.next.is_none_or(|next| {
+            let latest_arrival = next
+                .job
+                .as_ref()
+                .and(route_ctx.state().get_latest_arrival_at(activity_ctx.index + 1))
+                .copied()
+                .unwrap_or(next.place.time.end);
+            let arrival = departure
+                + self.transport.duration(
+                    route,
+                    target.place.location,
+                    next.place.location,
+                    TravelTime::Departure(departure),
+                );
+
+            arrival <= latest_arrival
+        })
+    }
 }
 
 impl FeatureConstraint for JobTimeLimitsConstraint {
@@ -127,3 +232,132 @@ impl FeatureConstraint for JobTimeLimitsConstraint {
         Ok(source)
     }
 }
+
+struct JobTimePressureObjective {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+impl JobTimePressureObjective {
+    fn estimate_activity(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
+        let route = route_ctx.route();
+        let Some(constraints) = route.actor.vehicle.dimens.get_job_time_constraints() else {
+            return Cost::default();
+        };
+
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        if target.job.is_none() {
+            return Cost::default();
+        }
+
+        let is_first_job = prev.job.is_none() && activity_ctx.index == 0;
+        let is_last_job = activity_ctx.next.is_none_or(|next| next.job.is_none());
+        if !is_first_job && !is_last_job {
+            return Cost::default();
+        }
+
+        // NOTE delay of the next activities is not considered
+        let departure = prev.schedule.departure;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+        let service_start = arrival.max(target.place.time.start);
+        let (first, last) = get_first_and_last_jobs(route).unzip();
+
+        let first_delta = if is_first_job {
+            let first_job_time = match constraints.first_job_reference {
+                FirstJobReference::Arrival => arrival,
+                FirstJobReference::ServiceStart => service_start,
+            };
+
+            get_first_job_pressure(constraints, first_job_time)
+                - first.map_or(Cost::default(), |first| {
+                    get_first_job_pressure(constraints, get_first_job_time(constraints, first))
+                })
+        } else {
+            Cost::default()
+        };
+
+        let last_delta = if is_last_job {
+            let last_job_time = match constraints.last_job_reference {
+                LastJobReference::Arrival => arrival,
+                LastJobReference::Departure => match self.activity.estimate_departure(route, target, service_start) {
+                    std::ops::ControlFlow::Continue(t) | std::ops::ControlFlow::Break(t) => t,
+                },
+            };
+
+            get_last_job_pressure(constraints, last_job_time)
+                - last.map_or(Cost::default(), |last| {
+                    get_last_job_pressure(constraints, get_last_job_time(constraints, last))
+                })
+        } else {
+            Cost::default()
+        };
+
+        first_delta + last_delta
+    }
+}
+
+impl FeatureObjective for JobTimePressureObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().map(|route_ctx| get_job_time_pressure(route_ctx.route())).sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => self.estimate_activity(route_ctx, activity_ctx),
+        }
+    }
+}
+
+/// Returns job time pressure of the route: a sum of bounded pressures of its first and last jobs.
+fn get_job_time_pressure(route: &Route) -> Cost {
+    let Some(constraints) = route.actor.vehicle.dimens.get_job_time_constraints() else {
+        return Cost::default();
+    };
+
+    get_first_and_last_jobs(route).map_or(Cost::default(), |(first, last)| {
+        get_first_job_pressure(constraints, get_first_job_time(constraints, first))
+            + get_last_job_pressure(constraints, get_last_job_time(constraints, last))
+    })
+}
+
+fn get_first_and_last_jobs(route: &Route) -> Option<(&Activity, &Activity)> {
+    let mut jobs = route.tour.all_activities().filter(|activity| activity.job.is_some());
+    let first = jobs.next()?;
+    let last = jobs.next_back().unwrap_or(first);
+
+    Some((first, last))
+}
+
+fn get_first_job_time(constraints: &JobTimeConstraints, first: &Activity) -> Timestamp {
+    match constraints.first_job_reference {
+        FirstJobReference::Arrival => first.schedule.arrival,
+        FirstJobReference::ServiceStart => first.schedule.arrival.max(first.place.time.start),
+    }
+}
+
+fn get_last_job_time(constraints: &JobTimeConstraints, last: &Activity) -> Timestamp {
+    match constraints.last_job_reference {
+        LastJobReference::Arrival => last.schedule.arrival,
+        LastJobReference::Departure => last.schedule.departure,
+    }
+}
+
+fn get_first_job_pressure(constraints: &JobTimeConstraints, first_job_time: Timestamp) -> Cost {
+    constraints.earliest_first.map_or(Cost::default(), |earliest_first| get_pressure(first_job_time - earliest_first))
+}
+
+fn get_last_job_pressure(constraints: &JobTimeConstraints, last_job_time: Timestamp) -> Cost {
+    constraints.latest_last.map_or(Cost::default(), |latest_last| get_pressure(latest_last - last_job_time))
+}
+
+/// Maps a slack to the pressure within `[0, 1]`: no or negative slack gives the maximum pressure.
+fn get_pressure(slack: Float) -> Cost {
+    1. / (1. + slack.max(0.))
+}
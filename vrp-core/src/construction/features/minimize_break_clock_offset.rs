@@ -0,0 +1,127 @@
+//! Provides a feature to align break start times with clock boundaries.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/minimize_break_clock_offset_test.rs"]
+mod minimize_break_clock_offset_test;
+
+use super::*;
+use crate::construction::enablers::{ReservedTimesIndex, get_tour_reserved_times};
+use crate::models::solution::{Activity, Route};
+
+custom_solution_state!(BreakClockOffset typeof Duration);
+
+/// Creates a feature which minimizes a total distance of break start times to the nearest clock
+/// boundary, defined as a multiple of `interval` (e.g. 3600 to prefer breaks on the hour).
+///
+/// Both breaks represented by jobs and reserved times placed within tour's schedule are considered.
+pub fn create_minimize_break_clock_offset_feature<F>(
+    name: &str,
+    interval: Duration,
+    transport: Arc<dyn TransportCost>,
+    reserved_times_index: ReservedTimesIndex,
+    is_break_single: F,
+) -> GenericResult<Feature>
+where
+    F: Fn(&Single) -> bool + Send + Sync + 'static,
+{
+    if interval <= 0. {
+        return Err(format!("break clock interval should be positive, got: {interval}").into());
+    }
+
+    let is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync> = Arc::new(is_break_single);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MinimizeBreakClockOffsetObjective {
+            interval,
+            transport,
+            is_break_single: is_break_single.clone(),
+        })
+        .with_state(MinimizeBreakClockOffsetState { interval, reserved_times_index, is_break_single })
+        .build()
+}
+
+struct MinimizeBreakClockOffsetObjective {
+    interval: Duration,
+    transport: Arc<dyn TransportCost>,
+    is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+}
+
+impl FeatureObjective for MinimizeBreakClockOffsetObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.state.get_break_clock_offset().copied().unwrap_or_default()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+                let is_break = target.job.as_ref().is_some_and(|single| (self.is_break_single)(single));
+
+                if is_break {
+                    let departure = prev.schedule.departure;
+                    let arrival = departure
+                        + self.transport.duration(
+                            route_ctx.route(),
+                            prev.place.location,
+                            target.place.location,
+                            TravelTime::Departure(departure),
+                        );
+
+                    get_clock_offset(arrival.max(target.place.time.start), self.interval)
+                } else {
+                    Cost::default()
+                }
+            }
+        }
+    }
+}
+
+struct MinimizeBreakClockOffsetState {
+    interval: Duration,
+    reserved_times_index: ReservedTimesIndex,
+    is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+}
+
+impl MinimizeBreakClockOffsetState {
+    fn get_route_clock_offset(&self, route: &Route) -> Duration {
+        let break_job_offset = route
+            .tour
+            .all_activities()
+            .filter(|activity| activity.job.as_ref().is_some_and(|single| (self.is_break_single)(single)))
+            .map(|activity| get_clock_offset(get_service_start(activity), self.interval))
+            .sum::<Duration>();
+        let reserved_offset = get_tour_reserved_times(route, &self.reserved_times_index)
+            .map(|reserved_time| get_clock_offset(reserved_time.time.start, self.interval))
+            .sum::<Duration>();
+
+        break_job_offset + reserved_offset
+    }
+}
+
+impl FeatureState for MinimizeBreakClockOffsetState {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let offset = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| self.get_route_clock_offset(route_ctx.route()))
+            .sum::<Duration>();
+
+        solution_ctx.state.set_break_clock_offset(offset);
+    }
+}
+
+fn get_service_start(activity: &Activity) -> Timestamp {
+    activity.schedule.arrival.max(activity.place.time.start)
+}
+
+fn get_clock_offset(time: Timestamp, interval: Duration) -> Duration {
+    let remainder = time.rem_euclid(interval);
+
+    remainder.min(interval - remainder)
+}
@@ -0,0 +1,128 @@
+//! Provides a feature to minimize detours caused by located breaks.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/minimize_break_detour_test.rs"]
+mod minimize_break_detour_test;
+
+use super::*;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
+
+custom_solution_state!(BreakDetour typeof Distance);
+
+/// Creates a feature which minimizes a total detour distance caused by break jobs across all tours.
+///
+/// A detour of a break is the extra distance introduced by visiting its location compared to the
+/// direct leg between its neighbour activities. Breaks without own location have zero detour.
+pub fn create_minimize_break_detour_feature<F>(
+    name: &str,
+    transport: Arc<dyn TransportCost>,
+    is_break_single: F,
+) -> GenericResult<Feature>
+where
+    F: Fn(&Single) -> bool + Send + Sync + 'static,
+{
+    let is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync> = Arc::new(is_break_single);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MinimizeBreakDetourObjective {
+            transport: transport.clone(),
+            is_break_single: is_break_single.clone(),
+        })
+        .with_state(MinimizeBreakDetourState { transport, is_break_single })
+        .build()
+}
+
+struct MinimizeBreakDetourObjective {
+    transport: Arc<dyn TransportCost>,
+    is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+}
+
+impl FeatureObjective for MinimizeBreakDetourObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.state.get_break_detour().copied().unwrap_or_default()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let target = activity_ctx.target;
+                let is_break = target.job.as_ref().is_some_and(|single| (self.is_break_single)(single));
+
+                if is_break {
+                    get_detour(route_ctx.route(), self.transport.as_ref(), activity_ctx.prev, target, activity_ctx.next)
+                } else {
+                    Cost::default()
+                }
+            }
+        }
+    }
+}
+
+struct MinimizeBreakDetourState {
+    transport: Arc<dyn TransportCost>,
+    is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+}
+
+impl FeatureState for MinimizeBreakDetourState {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let detour = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| {
+                let route = route_ctx.route();
+                let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+                activities
+                    .iter()
+                    .enumerate()
+                    .skip(1)
+                    .filter(|(_, activity)| activity.job.as_ref().is_some_and(|single| (self.is_break_single)(single)))
+                    .map(|(idx, activity)| {
+                        get_detour(
+                            route,
+                            self.transport.as_ref(),
+                            activities[idx - 1],
+                            activity,
+                            activities.get(idx + 1).copied(),
+                        )
+                    })
+                    .sum::<Distance>()
+            })
+            .sum::<Distance>();
+
+        solution_ctx.state.set_break_detour(detour);
+    }
+}
+
+/// Returns an extra distance introduced by visiting the target activity between prev and next.
+fn get_detour(
+    route: &Route,
+    transport: &dyn TransportCost,
+    prev: &Activity,
+    target: &Activity,
+    next: Option<&Activity>,
+) -> Distance {
+    let distance = |from: &Activity, to: &Activity| {
+        transport.distance(
+            route,
+            from.place.location,
+            to.place.location,
+            TravelTime::Departure(from.schedule.departure),
+        )
+    };
+
+    let detour = match next {
+        Some(next) => distance(prev, target) + distance(target, next) - distance(prev, next),
+        None => distance(prev, target),
+    };
+
+    // NOTE triangle inequality might be violated by routing data
+    detour.max(Distance::default())
+}
@@ -0,0 +1,87 @@
+//! Provides a feature to minimize amount of distinct break locations.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/minimize_break_location_count_test.rs"]
+mod minimize_break_location_count_test;
+
+use super::*;
+use std::collections::HashSet;
+
+custom_solution_state!(BreakLocations typeof HashSet<Location>);
+
+/// Creates a feature which minimizes amount of distinct locations used by break jobs across all tours.
+///
+/// It is useful when breaks can be taken at a limited set of facilities (e.g. rest areas) and it is
+/// preferred to concentrate them at as few facilities as possible.
+pub fn create_minimize_break_location_count_feature<F>(name: &str, is_break_single: F) -> GenericResult<Feature>
+where
+    F: Fn(&Single) -> bool + Send + Sync + 'static,
+{
+    let is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync> = Arc::new(is_break_single);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MinimizeBreakLocationCountObjective { is_break_single: is_break_single.clone() })
+        .with_state(MinimizeBreakLocationCountState { is_break_single })
+        .build()
+}
+
+struct MinimizeBreakLocationCountObjective {
+    is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+}
+
+impl FeatureObjective for MinimizeBreakLocationCountObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.state.get_break_locations().map_or(Cost::default(), |locations| locations.len() as Cost)
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { solution_ctx, activity_ctx, .. } => {
+                let target = activity_ctx.target;
+                let is_break = target.job.as_ref().is_some_and(|single| (self.is_break_single)(single));
+                let is_known = solution_ctx
+                    .state
+                    .get_break_locations()
+                    .is_some_and(|locations| locations.contains(&target.place.location));
+
+                if is_break && !is_known { 1. } else { Cost::default() }
+            }
+        }
+    }
+}
+
+struct MinimizeBreakLocationCountState {
+    is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+}
+
+impl FeatureState for MinimizeBreakLocationCountState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        let is_break = job.as_single().is_some_and(|single| (self.is_break_single)(single));
+        if !is_break {
+            return;
+        }
+
+        let mut locations = solution_ctx.state.get_break_locations().cloned().unwrap_or_default();
+        if let Some(route_ctx) = solution_ctx.routes.get(route_index) {
+            locations.extend(route_ctx.route().tour.job_activities(job).map(|activity| activity.place.location));
+        }
+
+        solution_ctx.state.set_break_locations(locations);
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let locations = solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .filter(|activity| activity.job.as_ref().is_some_and(|single| (self.is_break_single)(single)))
+            .map(|activity| activity.place.location)
+            .collect::<HashSet<_>>();
+
+        solution_ctx.state.set_break_locations(locations);
+    }
+}
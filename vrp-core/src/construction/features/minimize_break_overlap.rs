@@ -0,0 +1,150 @@
+//! Provides a feature to limit the amount of breaks taken simultaneously across the whole fleet.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/minimize_break_overlap_test.rs"]
+mod minimize_break_overlap_test;
+
+use super::*;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::Activity;
+use std::cmp::Ordering;
+use std::iter::once;
+
+custom_solution_state!(BreakOverlap typeof Duration);
+custom_solution_state!(BreakTimeWindows typeof Vec<TimeWindow>);
+
+/// Creates a feature which penalizes breaks overlapping in time across all tours when more than
+/// `max_breaks` of them are taken simultaneously, e.g. when a shared facility has limited capacity.
+///
+/// A penalty is the time during which the limit is exceeded, multiplied by the amount of exceeding
+/// breaks. Only breaks represented by jobs are considered.
+pub fn create_minimize_break_overlap_feature<F>(
+    name: &str,
+    max_breaks: usize,
+    transport: Arc<dyn TransportCost>,
+    is_break_single: F,
+) -> GenericResult<Feature>
+where
+    F: Fn(&Single) -> bool + Send + Sync + 'static,
+{
+    if max_breaks == 0 {
+        return Err("max simultaneous breaks should be positive".into());
+    }
+
+    let is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync> = Arc::new(is_break_single);
+
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MinimizeBreakOverlapObjective {
+            max_breaks,
+            transport,
+            is_break_single: is_break_single.clone(),
+        })
+        .with_state(MinimizeBreakOverlapState { max_breaks, is_break_single })
+        .build()
+}
+
+struct MinimizeBreakOverlapObjective {
+    max_breaks: usize,
+    transport: Arc<dyn TransportCost>,
+    is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+}
+
+impl FeatureObjective for MinimizeBreakOverlapObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.state.get_break_overlap().copied().unwrap_or_default()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                let target = activity_ctx.target;
+                let is_break = target.job.as_ref().is_some_and(|single| (self.is_break_single)(single));
+                if !is_break {
+                    return Cost::default();
+                }
+
+                // NOTE schedule of other activities is assumed to be unchanged by the insertion
+                let prev = activity_ctx.prev;
+                let arrival = prev.schedule.departure
+                    + self.transport.duration(
+                        route_ctx.route(),
+                        prev.place.location,
+                        target.place.location,
+                        TravelTime::Departure(prev.schedule.departure),
+                    );
+                let start = arrival.max(target.place.time.start);
+                let break_tw = TimeWindow::new(start, start + target.place.duration);
+
+                let break_tws = solution_ctx.state.get_break_time_windows();
+                let break_tws =
+                    break_tws.iter().flat_map(|tws| tws.iter()).cloned().chain(once(break_tw)).collect::<Vec<_>>();
+                let overlap = solution_ctx.state.get_break_overlap().copied().unwrap_or_default();
+
+                (get_excess_overlap(break_tws.as_slice(), self.max_breaks) - overlap).max(Cost::default())
+            }
+        }
+    }
+}
+
+struct MinimizeBreakOverlapState {
+    max_breaks: usize,
+    is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+}
+
+impl FeatureState for MinimizeBreakOverlapState {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let break_tws = solution_ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .filter(|activity| activity.job.as_ref().is_some_and(|single| (self.is_break_single)(single)))
+            .map(get_break_time_window)
+            .collect::<Vec<_>>();
+
+        solution_ctx.state.set_break_overlap(get_excess_overlap(break_tws.as_slice(), self.max_breaks));
+        solution_ctx.state.set_break_time_windows(break_tws);
+    }
+}
+
+fn get_break_time_window(activity: &Activity) -> TimeWindow {
+    let start = activity.schedule.arrival.max(activity.place.time.start);
+
+    TimeWindow::new(start, activity.schedule.departure.max(start))
+}
+
+/// Returns a total duration of exceeding breaks: each time interval where more than `max_breaks`
+/// breaks are taken contributes its duration multiplied by the amount of breaks above the limit.
+fn get_excess_overlap(break_tws: &[TimeWindow], max_breaks: usize) -> Duration {
+    if break_tws.len() <= max_breaks {
+        return Duration::default();
+    }
+
+    // NOTE break end is processed before a start at the same time, so adjacent breaks do not overlap
+    let mut events = break_tws.iter().flat_map(|tw| [(tw.start, 1_i32), (tw.end, -1_i32)]).collect::<Vec<_>>();
+    events.sort_by(|(a_time, a_delta), (b_time, b_delta)| match a_time.total_cmp(b_time) {
+        Ordering::Equal => a_delta.cmp(b_delta),
+        other => other,
+    });
+
+    events
+        .iter()
+        .scan(0_i32, |active, &(time, delta)| {
+            *active += delta;
+            Some((time, *active))
+        })
+        .collect::<Vec<_>>()
+        .windows(2)
+        .map(|pair| {
+            let ((start, active), (end, _)) = (pair[0], pair[1]);
+            let excess = (active as usize).saturating_sub(max_breaks);
+
+            (end - start) * excess as Float
+        })
+        .sum()
+}
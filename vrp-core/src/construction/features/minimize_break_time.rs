@@ -0,0 +1,68 @@
+//! Provides a feature to minimize total break time.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/minimize_break_time_test.rs"]
+mod minimize_break_time_test;
+
+use super::*;
+use crate::construction::enablers::{ReservedTimesIndex, get_tour_reserved_times};
+use crate::models::solution::Route;
+
+/// Creates a feature which minimizes total break time of all tours. Break time is a sum of durations
+/// of served break jobs and reserved times which are placed within tour's schedule.
+///
+/// For mandatory breaks with fixed duration the value is constant, but it becomes meaningful when
+/// a break has places with different durations or can be skipped.
+pub fn create_minimize_break_time_feature<F>(
+    name: &str,
+    reserved_times_index: ReservedTimesIndex,
+    is_break_single: F,
+) -> GenericResult<Feature>
+where
+    F: Fn(&Single) -> bool + Send + Sync + 'static,
+{
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MinimizeBreakTimeObjective { reserved_times_index, is_break_single: Arc::new(is_break_single) })
+        .build()
+}
+
+struct MinimizeBreakTimeObjective {
+    reserved_times_index: ReservedTimesIndex,
+    is_break_single: Arc<dyn Fn(&Single) -> bool + Send + Sync>,
+}
+
+impl MinimizeBreakTimeObjective {
+    fn get_route_break_time(&self, route: &Route) -> Duration {
+        let break_job_time = route
+            .tour
+            .all_activities()
+            .filter(|activity| activity.job.as_ref().is_some_and(|single| (self.is_break_single)(single)))
+            .map(|activity| activity.place.duration)
+            .sum::<Duration>();
+
+        break_job_time + self.get_reserved_break_time(route)
+    }
+
+    fn get_reserved_break_time(&self, route: &Route) -> Duration {
+        get_tour_reserved_times(route, &self.reserved_times_index).map(|rt| rt.duration).sum()
+    }
+}
+
+impl FeatureObjective for MinimizeBreakTimeObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().map(|route_ctx| self.get_route_break_time(route_ctx.route())).sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { activity_ctx, .. } => activity_ctx
+                .target
+                .job
+                .as_ref()
+                .filter(|single| (self.is_break_single)(single))
+                .map_or(Cost::default(), |_| activity_ctx.target.place.duration),
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Provides a feature to minimize empty running (deadhead) distance of the tours.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/minimize_deadhead_test.rs"]
+mod minimize_deadhead_test;
+
+use super::*;
+use crate::construction::enablers::deadhead_distance;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::Activity;
+
+custom_solution_state!(DeadheadDistance typeof Distance);
+
+/// Creates a feature which minimizes a total deadhead distance: travel from the start to the first job
+/// and from the last job to the end of each tour.
+///
+/// Unlike vehicle distance related objectives, it is not about vehicle choice, but prefers tours with
+/// jobs close to both start and end locations.
+pub fn create_minimize_deadhead_feature(name: &str, transport: Arc<dyn TransportCost>) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MinimizeDeadheadObjective { transport: transport.clone() })
+        .with_state(MinimizeDeadheadState { transport })
+        .build()
+}
+
+struct MinimizeDeadheadObjective {
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureObjective for MinimizeDeadheadObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.state.get_deadhead_distance().copied().unwrap_or_default()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let (prev, target, next) = (activity_ctx.prev, activity_ctx.target, activity_ctx.next);
+                let distance = |from: &Activity, to: &Activity| {
+                    self.transport.distance(
+                        route_ctx.route(),
+                        from.place.location,
+                        to.place.location,
+                        TravelTime::Departure(from.schedule.departure),
+                    )
+                };
+
+                // NOTE only the first and the last legs of the tour contribute to deadhead
+                let first_leg = if prev.job.is_none() {
+                    distance(prev, target)
+                        - next.filter(|next| next.job.is_some()).map_or(0., |next| distance(prev, next))
+                } else {
+                    Cost::default()
+                };
+
+                let last_leg = match next {
+                    Some(next) if next.job.is_none() => {
+                        distance(target, next) - if prev.job.is_some() { distance(prev, next) } else { 0. }
+                    }
+                    _ => Cost::default(),
+                };
+
+                first_leg + last_leg
+            }
+        }
+    }
+}
+
+struct MinimizeDeadheadState {
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureState for MinimizeDeadheadState {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let distance = solution_ctx
+            .routes
+            .iter()
+            .map(|route_ctx| deadhead_distance(route_ctx.route(), self.transport.as_ref()))
+            .sum::<Distance>();
+
+        solution_ctx.state.set_deadhead_distance(distance);
+    }
+}
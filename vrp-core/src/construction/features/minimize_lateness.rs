@@ -0,0 +1,93 @@
+//! Provides a feature to minimize lateness of jobs with soft time windows.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/minimize_lateness_test.rs"]
+mod minimize_lateness_test;
+
+use super::*;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::Activity;
+
+/// Specifies soft time windows of a job: arrival after the time window end is allowed, but penalized.
+/// Job place time windows are expected to be relaxed (e.g. have no end), so the original ones are kept here.
+#[derive(Clone, Debug)]
+pub struct SoftTimeWindows {
+    /// Original time windows of each job place, indexed by place index.
+    pub places: Vec<Vec<TimeWindow>>,
+    /// A penalty per time unit of lateness.
+    pub penalty: Cost,
+}
+
+custom_dimension!(pub JobSoftTimeWindows typeof SoftTimeWindows);
+
+/// Creates a feature which minimizes lateness of jobs with `JobSoftTimeWindows` dimension: service start
+/// outside of original time windows is penalized proportionally to the job's penalty and the distance to
+/// the nearest time window.
+///
+/// This is a soft objective: its fitness is the total penalty of late arrivals.
+pub fn create_minimize_lateness_feature(name: &str, transport: Arc<dyn TransportCost>) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(MinimizeLatenessObjective { transport }).build()
+}
+
+struct MinimizeLatenessObjective {
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureObjective for MinimizeLatenessObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route().tour.all_activities())
+            .map(|activity| get_lateness_penalty(activity, activity.schedule.arrival))
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let target = activity_ctx.target;
+                if target.job.as_ref().is_none_or(|single| single.dimens.get_job_soft_time_windows().is_none()) {
+                    return Cost::default();
+                }
+
+                // NOTE delay of the next activities is not considered
+                let prev = activity_ctx.prev;
+                let departure = prev.schedule.departure;
+                let arrival = departure
+                    + self.transport.duration(
+                        route_ctx.route(),
+                        prev.place.location,
+                        target.place.location,
+                        TravelTime::Departure(departure),
+                    );
+
+                get_lateness_penalty(target, arrival)
+            }
+        }
+    }
+}
+
+/// Returns lateness penalty of the activity for given arrival time.
+fn get_lateness_penalty(activity: &Activity, arrival: Timestamp) -> Cost {
+    let Some(soft_tws) = activity.job.as_ref().and_then(|single| single.dimens.get_job_soft_time_windows()) else {
+        return Cost::default();
+    };
+
+    // NOTE relaxed time windows have no end, so service starts without waiting when arrival is between
+    //      original time windows: such start is penalized by the distance to the nearest later window
+    let service_start = arrival.max(activity.place.time.start);
+
+    let lateness = soft_tws
+        .places
+        .get(activity.place.idx)
+        .into_iter()
+        .flatten()
+        .map(|tw| if service_start > tw.end { service_start - tw.end } else { (tw.start - service_start).max(0.) })
+        .min_by(|a, b| a.total_cmp(b))
+        .unwrap_or_default();
+
+    lateness * soft_tws.penalty
+}
@@ -0,0 +1,62 @@
+//! Provides a feature to minimize the latest tour end time across the fleet.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/minimize_latest_arrival_test.rs"]
+mod minimize_latest_arrival_test;
+
+use super::*;
+use crate::models::solution::Route;
+
+custom_solution_state!(LatestArrival typeof Timestamp);
+
+/// Creates a feature which minimizes the time when the last vehicle finishes its tour: an arrival
+/// at the end depot or, for open tours, a departure from the last job.
+///
+/// Unlike total duration, it considers clock time, so it is useful to finish all work before
+/// a facility closes.
+pub fn create_minimize_latest_arrival_feature(name: &str) -> GenericResult<Feature> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MinimizeLatestArrivalObjective)
+        .with_state(MinimizeLatestArrivalState)
+        .build()
+}
+
+struct MinimizeLatestArrivalObjective;
+
+impl FeatureObjective for MinimizeLatestArrivalObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.state.get_latest_arrival().copied().unwrap_or_default()
+    }
+
+    fn estimate(&self, _: &MoveContext<'_>) -> Cost {
+        Cost::default()
+    }
+}
+
+struct MinimizeLatestArrivalState;
+
+impl FeatureState for MinimizeLatestArrivalState {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let latest_arrival = solution_ctx
+            .routes
+            .iter()
+            .filter_map(|route_ctx| get_tour_end_time(route_ctx.route()))
+            .max_by(|a, b| a.total_cmp(b))
+            .unwrap_or_default();
+
+        solution_ctx.state.set_latest_arrival(latest_arrival);
+    }
+}
+
+fn get_tour_end_time(route: &Route) -> Option<Timestamp> {
+    if route.tour.job_count() == 0 {
+        return None;
+    }
+
+    route.tour.end().map(|end| if end.job.is_none() { end.schedule.arrival } else { end.schedule.departure })
+}
@@ -7,6 +7,12 @@ use crate::models::*;
 use rosomaxa::prelude::*;
 use std::sync::Arc;
 
+mod break_on_transit;
+pub use self::break_on_transit::*;
+
+mod break_order;
+pub use self::break_order::*;
+
 mod breaks;
 pub use self::breaks::*;
 
@@ -23,12 +29,18 @@ pub use self::fast_service::FastServiceFeatureBuilder;
 mod fleet_usage;
 pub use self::fleet_usage::*;
 
+mod global_precedence;
+pub use self::global_precedence::*;
+
 mod groups;
 pub use self::groups::{JobGroupDimension, create_group_feature};
 
 mod hierarchical_areas;
 pub use self::hierarchical_areas::*;
 
+mod job_exclusion;
+pub use self::job_exclusion::create_job_exclusion_feature;
+
 mod known_edge;
 pub use self::known_edge::create_known_edge_feature;
 
@@ -38,6 +50,30 @@ pub use self::locked_jobs::*;
 mod vehicle_distance;
 pub use self::vehicle_distance::*;
 
+mod minimize_break_clock_offset;
+pub use self::minimize_break_clock_offset::create_minimize_break_clock_offset_feature;
+
+mod minimize_break_detour;
+pub use self::minimize_break_detour::create_minimize_break_detour_feature;
+
+mod minimize_break_location_count;
+pub use self::minimize_break_location_count::*;
+
+mod minimize_break_overlap;
+pub use self::minimize_break_overlap::create_minimize_break_overlap_feature;
+
+mod minimize_break_time;
+pub use self::minimize_break_time::*;
+
+mod minimize_deadhead;
+pub use self::minimize_deadhead::create_minimize_deadhead_feature;
+
+mod minimize_lateness;
+pub use self::minimize_lateness::*;
+
+mod minimize_latest_arrival;
+pub use self::minimize_latest_arrival::create_minimize_latest_arrival_feature;
+
 mod minimize_overdue;
 pub use self::minimize_overdue::*;
 
@@ -82,5 +118,5 @@ pub use self::vehicle_shifts::*;
 mod work_balance;
 pub use self::work_balance::{
     create_activity_balanced_feature, create_distance_balanced_feature, create_duration_balanced_feature,
-    create_max_load_balanced_feature,
+    create_job_count_balanced_feature, create_max_load_balanced_feature,
 };
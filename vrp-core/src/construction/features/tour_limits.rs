@@ -8,13 +8,19 @@ use std::cmp::Ordering;
 
 use super::*;
 use crate::construction::enablers::*;
-use crate::models::common::{Distance, Duration};
-use crate::models::problem::{Actor, TransportCost};
+use crate::models::common::{Distance, Duration, Location, TimeWindow};
+use crate::models::problem::{Actor, Single, TransportCost};
+use crate::models::solution::{Activity, Route};
 
 /// A function which returns activity size limit for a given actor.
 pub type ActivitySizeResolver = Arc<dyn Fn(&Actor) -> Option<usize> + Sync + Send>;
 /// A function to resolve travel limit.
 pub type TravelLimitFn<T> = Arc<dyn Fn(&Actor) -> Option<T> + Send + Sync>;
+/// A function which tells whether a single job's service time counts toward the service time limit.
+pub type ServiceSingleFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+
+custom_tour_state!(TotalServiceTime typeof Duration);
+custom_tour_state!(StopCount typeof usize);
 
 /// Creates a limit for activity amount in a tour.
 /// This is a hard constraint.
@@ -65,6 +71,45 @@ pub fn create_travel_limit_feature(
         .build()
 }
 
+/// Creates a limit for total service time of job activities in a tour. Travel and waiting times
+/// are not counted as well as service time of jobs rejected by `is_service_single_fn` (e.g. breaks).
+/// This is a hard constraint.
+pub fn create_service_time_limit_feature(
+    name: &str,
+    code: ViolationCode,
+    limit_fn: TravelLimitFn<Duration>,
+    is_service_single_fn: ServiceSingleFn,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(ServiceTimeLimitConstraint {
+            code,
+            limit_fn,
+            is_service_single_fn: is_service_single_fn.clone(),
+        })
+        .with_state(ServiceTimeLimitState { is_service_single_fn })
+        .build()
+}
+
+/// Creates a limit for amount of stops in a tour. A stop is formed by consecutive job activities
+/// at the same location; tour start and end are not counted. Reserved times (e.g. required breaks)
+/// taken while traveling are counted as separate stops. A headroom resolved by `headroom_fn`
+/// is subtracted from the limit to keep space for stops which are inserted after construction
+/// (e.g. required breaks).
+/// This is a hard constraint.
+pub fn create_stop_limit_feature(
+    name: &str,
+    code: ViolationCode,
+    limit_fn: ActivitySizeResolver,
+    headroom_fn: ActivitySizeResolver,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(StopLimitConstraint { code, limit_fn: limit_fn.clone(), headroom_fn })
+        .with_state(StopLimitState { limit_fn })
+        .build()
+}
+
 struct ActivityLimitConstraint {
     code: ViolationCode,
     limit_fn: ActivitySizeResolver,
@@ -132,6 +177,217 @@ impl FeatureObjective for MinActivityLimitObjective {
     }
 }
 
+struct StopLimitConstraint {
+    code: ViolationCode,
+    limit_fn: ActivitySizeResolver,
+    headroom_fn: ActivitySizeResolver,
+}
+
+impl StopLimitConstraint {
+    fn get_limit(&self, actor: &Actor) -> Option<usize> {
+        let limit = (self.limit_fn)(actor)?;
+
+        Some(limit.saturating_sub((self.headroom_fn)(actor).unwrap_or_default()))
+    }
+}
+
+impl FeatureConstraint for StopLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                let limit = self.get_limit(route_ctx.route().actor.as_ref())?;
+                let current = route_ctx.state().get_stop_count().copied().unwrap_or_default();
+
+                if current < limit {
+                    return None;
+                }
+
+                // NOTE when limit is reached, job can be served only at locations of existing stops
+                let tour = &route_ctx.route().tour;
+                let is_visited = |location: Location| {
+                    tour.all_activities().any(|activity| activity.job.is_some() && activity.place.location == location)
+                };
+                let can_join_stops =
+                    |single: &Arc<Single>| single.places.iter().any(|place| place.location.is_none_or(is_visited));
+                let is_joined = match job {
+                    Job::Single(single) => can_join_stops(single),
+                    Job::Multi(multi) => multi.jobs.iter().all(can_join_stops),
+                };
+
+                if is_joined { None } else { ConstraintViolation::fail(self.code) }
+            }
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let limit = self.get_limit(route_ctx.route().actor.as_ref())?;
+
+                let job_location = |activity: Option<&Activity>| {
+                    activity.filter(|activity| activity.job.is_some()).map(|activity| activity.place.location)
+                };
+                let target = Some(activity_ctx.target.place.location);
+                let prev = job_location(Some(activity_ctx.prev));
+                let next = job_location(activity_ctx.next);
+
+                // NOTE inserting between two activities of the same stop splits it
+                let is_split = prev.is_some() && prev == next;
+                let new_stops =
+                    (1 + is_split as usize).saturating_sub((prev == target) as usize + (next == target) as usize);
+                let current = route_ctx.state().get_stop_count().copied().unwrap_or_default();
+
+                if current + new_stops > limit { ConstraintViolation::skip(self.code) } else { None }
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct StopLimitState {
+    limit_fn: ActivitySizeResolver,
+}
+
+impl FeatureState for StopLimitState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        self.accept_route_state(&mut solution_ctx.routes[route_index]);
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        if (self.limit_fn)(route_ctx.route().actor.as_ref()).is_none() {
+            return;
+        }
+
+        let stops = get_stop_count(route_ctx.route());
+
+        route_ctx.state_mut().set_stop_count(stops);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        })
+    }
+}
+
+fn get_stop_count(route: &Route) -> usize {
+    let (job_stops, _) = route.tour.all_activities().filter(|activity| activity.job.is_some()).fold(
+        (0, None),
+        |(stops, prev_location), activity| {
+            let location = activity.place.location;
+            (if prev_location == Some(location) { stops } else { stops + 1 }, Some(location))
+        },
+    );
+
+    job_stops + get_transit_stop_count(route)
+}
+
+/// Counts stops formed by reserved times taken while traveling, the same way as they are written to
+/// the solution: a reserved time which starts on a leg forms a new stop there, otherwise it is taken
+/// at the previous stop which is a new one only when it is the tour start.
+fn get_transit_stop_count(route: &Route) -> usize {
+    let legs = || route.tour.all_activities().zip(route.tour.all_activities().skip(1)).enumerate();
+    let mut is_start_used = false;
+
+    get_placed_reserved_time_windows(route)
+        .filter(|reserved_tw| {
+            legs()
+                .map(|(leg_idx, (prev, next))| {
+                    (leg_idx, TimeWindow::new(prev.schedule.departure, next.schedule.arrival))
+                })
+                .find(|(_, travel_tw)| travel_tw.intersects_exclusive(reserved_tw))
+                .is_some_and(|(leg_idx, travel_tw)| {
+                    if reserved_tw.start >= travel_tw.start {
+                        true
+                    } else if leg_idx == 0 && !is_start_used {
+                        is_start_used = true;
+                        true
+                    } else {
+                        false
+                    }
+                })
+        })
+        .count()
+}
+
+struct ServiceTimeLimitConstraint {
+    code: ViolationCode,
+    limit_fn: TravelLimitFn<Duration>,
+    is_service_single_fn: ServiceSingleFn,
+}
+
+impl FeatureConstraint for ServiceTimeLimitConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        let (route_ctx, service_time) = match move_ctx {
+            MoveContext::Route { route_ctx, job, .. } => {
+                // NOTE use the shortest place duration as the job's place is not decided yet
+                let get_service_time = |single: &Single| {
+                    if !(self.is_service_single_fn)(single) {
+                        return Duration::default();
+                    }
+
+                    single.places.iter().map(|place| place.duration).min_by(|a, b| a.total_cmp(b)).unwrap_or_default()
+                };
+                let service_time = match job {
+                    Job::Single(single) => get_service_time(single),
+                    Job::Multi(multi) => multi.jobs.iter().map(|single| get_service_time(single)).sum(),
+                };
+
+                (route_ctx, service_time)
+            }
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let is_service =
+                    activity_ctx.target.job.as_ref().is_some_and(|single| (self.is_service_single_fn)(single));
+                (route_ctx, if is_service { activity_ctx.target.place.duration } else { Duration::default() })
+            }
+        };
+
+        if service_time == Duration::default() {
+            return None;
+        }
+
+        let limit = (self.limit_fn)(route_ctx.route().actor.as_ref())?;
+        let current = route_ctx.state().get_total_service_time().copied().unwrap_or_default();
+
+        if current + service_time > limit {
+            match move_ctx {
+                MoveContext::Route { .. } => ConstraintViolation::fail(self.code),
+                MoveContext::Activity { .. } => ConstraintViolation::skip(self.code),
+            }
+        } else {
+            None
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct ServiceTimeLimitState {
+    is_service_single_fn: ServiceSingleFn,
+}
+
+impl FeatureState for ServiceTimeLimitState {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        let service_time = route_ctx
+            .route()
+            .tour
+            .all_activities()
+            .filter(|activity| activity.job.as_ref().is_some_and(|single| (self.is_service_single_fn)(single)))
+            .map(|activity| activity.place.duration)
+            .sum::<Duration>();
+
+        route_ctx.state_mut().set_total_service_time(service_time);
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.is_stale()).for_each(|route_ctx| {
+            self.accept_route_state(route_ctx);
+        })
+    }
+}
+
 struct TravelLimitConstraint {
     transport: Arc<dyn TransportCost>,
     tour_distance_limit_fn: TravelLimitFn<Distance>,
@@ -229,9 +229,15 @@ impl TransportConstraint {
                 TravelTime::Arrival(latest_arr_time_at_next),
             );
 
-        let ControlFlow::Continue(latest_arr_time_at_target) =
+        // NOTE the target continuing a parallel service group shares the group's service start, so only
+        // its departure is limited by the service duration (see `get_parallel_service_start`)
+        let parallel_start = get_parallel_service_start(route, activity_ctx.index, target);
+
+        let ControlFlow::Continue(latest_arr_time_at_target) = (if parallel_start.is_some() {
+            ControlFlow::Continue(latest_departure_at_target)
+        } else {
             self.activity.estimate_arrival(route, target, latest_departure_at_target)
-        else {
+        }) else {
             return ConstraintViolation::skip(self.time_window_code);
         };
         let latest_arr_time_at_target = target.place.time.end.min(latest_arr_time_at_target);
@@ -244,11 +250,24 @@ impl TransportConstraint {
             return ConstraintViolation::success();
         }
 
-        let ControlFlow::Continue(end_time_at_target) =
-            self.activity.estimate_departure(route, target, arr_time_at_target)
-        else {
+        let ControlFlow::Continue(end_time_at_target) = (match parallel_start {
+            Some(service_start) if service_start < arr_time_at_target => {
+                match self.activity.estimate_departure(route, target, service_start) {
+                    ControlFlow::Continue(departure) => ControlFlow::Continue(departure.max(arr_time_at_target)),
+                    flow => flow,
+                }
+            }
+            _ => self.activity.estimate_departure(route, target, arr_time_at_target),
+        }) else {
             return ConstraintViolation::skip(self.time_window_code);
         };
+        // NOTE minimum dwell is counted from the stop arrival (see `get_stop_dwell_departure`)
+        let stop_arrival = if prev.place.location == target.place.location {
+            get_stop_arrival(route, activity_ctx.index)
+        } else {
+            arr_time_at_target
+        };
+        let end_time_at_target = end_time_at_target.max(stop_arrival + get_min_dwell(target));
 
         let arr_time_at_next = end_time_at_target
             + self.transport.duration(
@@ -288,7 +307,8 @@ struct DistanceObjective {
 impl FeatureObjective for DistanceObjective {
     fn fitness(&self, insertion_ctx: &InsertionContext) -> Cost {
         insertion_ctx.solution.routes.iter().fold(Cost::default(), move |acc, route_ctx| {
-            acc + route_ctx.state().get_total_distance().copied().unwrap_or(0.)
+            let state = route_ctx.state();
+            acc + state.get_billable_distance().or(state.get_total_distance()).copied().unwrap_or(0.)
         })
     }
 
@@ -311,7 +331,8 @@ struct DurationObjective {
 impl FeatureObjective for DurationObjective {
     fn fitness(&self, insertion_ctx: &InsertionContext) -> Cost {
         insertion_ctx.solution.routes.iter().fold(Cost::default(), move |acc, route_ctx| {
-            acc + route_ctx.state().get_total_duration().copied().unwrap_or(0.)
+            let state = route_ctx.state();
+            acc + state.get_billable_duration().or(state.get_total_duration()).copied().unwrap_or(0.)
         })
     }
 
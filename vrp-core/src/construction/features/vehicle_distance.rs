@@ -1,8 +1,11 @@
 //! Provides a feature to minimize vehicle distance penalties.
 //!
 //! For each job on a route, the penalty is the excess distance from the job to its
-//! assigned vehicle's start location compared to the nearest compatible vehicle's start.
-//! penalty = max(0, dist(job, assigned_vehicle) - dist(job, nearest_compatible_vehicle))
+//! assigned vehicle's start location compared to a reference distance, see `VehicleReference`.
+//! penalty = max(0, dist(job, assigned_vehicle) - dist(job, reference))
+//!
+//! When graded compatibility is used, each distance is scaled by the actor's compatibility cost
+//! multiplier, so soft-incompatible vehicles are still considered, but weighted.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/vehicle_distance_test.rs"]
@@ -16,6 +19,22 @@ custom_tour_state!(VehicleDistanceRouteData typeof RouteVehicleDistanceData);
 /// A function type that checks whether a given actor is compatible with a given job.
 pub type ActorJobCompatibilityFn = Arc<dyn Fn(&Job, &Actor) -> bool + Send + Sync>;
 
+/// A function type that returns a compatibility cost multiplier of a given actor for a given job.
+/// `None` means that the actor is incompatible with the job.
+pub type ActorJobCompatibilityCostFn = Arc<dyn Fn(&Job, &Actor) -> Option<Float> + Send + Sync>;
+
+/// Specifies how the reference distance for a job is computed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VehicleReference {
+    /// Distance to the nearest compatible vehicle's start location.
+    #[default]
+    Nearest,
+    /// Distance to the centroid of all compatible vehicles' start locations. As locations are
+    /// routing matrix indices without coordinates, it is approximated by the average distance
+    /// from the job to the compatible vehicles' start locations.
+    Centroid,
+}
+
 /// Route-level cached data for vehicle distance calculations.
 #[derive(Clone, Default)]
 pub struct RouteVehicleDistanceData {
@@ -28,13 +47,20 @@ pub struct VehicleDistanceFeatureBuilder {
     name: String,
     transport: Option<Arc<dyn TransportCost + Send + Sync>>,
     actors: Option<Vec<Arc<Actor>>>,
-    compatibility_fn: Option<ActorJobCompatibilityFn>,
+    compatibility_fn: Option<ActorJobCompatibilityCostFn>,
+    reference: VehicleReference,
 }
 
 impl VehicleDistanceFeatureBuilder {
     /// Creates a new instance of `VehicleDistanceFeatureBuilder`.
     pub fn new(name: &str) -> Self {
-        Self { name: name.to_string(), transport: None, actors: None, compatibility_fn: None }
+        Self {
+            name: name.to_string(),
+            transport: None,
+            actors: None,
+            compatibility_fn: None,
+            reference: VehicleReference::default(),
+        }
     }
 
     /// Sets the transport cost model.
@@ -43,7 +69,7 @@ impl VehicleDistanceFeatureBuilder {
         self
     }
 
-    /// Sets the fleet actors to consider when finding the nearest compatible vehicle.
+    /// Sets the fleet actors to consider when computing the reference distance.
     pub fn set_actors(mut self, actors: Vec<Arc<Actor>>) -> Self {
         self.actors = Some(actors);
         self
@@ -54,7 +80,21 @@ impl VehicleDistanceFeatureBuilder {
     where
         F: Fn(&Job, &Actor) -> bool + Send + Sync + 'static,
     {
-        self.compatibility_fn = Some(Arc::new(func));
+        self.compatibility_fn = Some(Arc::new(move |job, actor| func(job, actor).then_some(1.)));
+        self
+    }
+
+    /// Sets the graded compatibility function which returns a cost multiplier applied to the distance
+    /// between a job and an actor's start, or `None` if the actor cannot serve the job.
+    /// Overrides the function set by `set_compatibility_fn`.
+    pub fn set_compatibility_cost_fn(mut self, func: ActorJobCompatibilityCostFn) -> Self {
+        self.compatibility_fn = Some(func);
+        self
+    }
+
+    /// Sets the reference used to compute the penalty. Default is `VehicleReference::Nearest`.
+    pub fn set_vehicle_reference(mut self, reference: VehicleReference) -> Self {
+        self.reference = reference;
         self
     }
 
@@ -77,8 +117,9 @@ impl VehicleDistanceFeatureBuilder {
             transport: transport.clone(),
             actors: actors.clone(),
             compatibility_fn: compatibility_fn.clone(),
+            reference: self.reference,
         };
-        let state = VehicleDistanceState { transport, actors, compatibility_fn };
+        let state = VehicleDistanceState { transport, actors, compatibility_fn, reference: self.reference };
 
         FeatureBuilder::default().with_name(self.name.as_str()).with_objective(objective).with_state(state).build()
     }
@@ -92,26 +133,37 @@ fn get_job_location(job: &Job) -> Option<Location> {
     }
 }
 
-/// Finds the minimum distance from a job location to the start of any compatible vehicle.
-fn find_nearest_compatible_vehicle_dist(
+/// Finds the reference distance from a job location to the starts of compatible vehicles.
+/// Distances are scaled by compatibility cost multipliers.
+fn find_reference_compatible_vehicle_dist(
     job_loc: Location,
     job: &Job,
+    reference: VehicleReference,
     actors: &[Arc<Actor>],
-    compatibility_fn: &ActorJobCompatibilityFn,
+    compatibility_fn: &ActorJobCompatibilityCostFn,
     transport: &(dyn TransportCost + Send + Sync),
 ) -> Option<Float> {
-    actors
-        .iter()
-        .filter(|actor| compatibility_fn(job, actor))
-        .filter_map(|actor| actor.detail.start.as_ref().map(|s| s.location))
-        .map(|start_loc| transport.distance_approx(&actors[0].vehicle.profile, job_loc, start_loc))
-        .min_by(|a, b| a.total_cmp(b))
+    let distances = actors.iter().filter_map(|actor| {
+        let multiplier = compatibility_fn(job, actor)?;
+        let start_loc = actor.detail.start.as_ref()?.location;
+
+        Some(transport.distance_approx(&actors[0].vehicle.profile, job_loc, start_loc) * multiplier)
+    });
+
+    match reference {
+        VehicleReference::Nearest => distances.min_by(|a, b| a.total_cmp(b)),
+        VehicleReference::Centroid => {
+            let (total, count) = distances.fold((0., 0_usize), |(total, count), dist| (total + dist, count + 1));
+            (count > 0).then(|| total / count as Float)
+        }
+    }
 }
 
 struct VehicleDistanceObjective {
     transport: Arc<dyn TransportCost + Send + Sync>,
     actors: Vec<Arc<Actor>>,
-    compatibility_fn: ActorJobCompatibilityFn,
+    compatibility_fn: ActorJobCompatibilityCostFn,
+    reference: VehicleReference,
 }
 
 impl VehicleDistanceObjective {
@@ -132,18 +184,20 @@ impl VehicleDistanceObjective {
             let job_loc = activity.place.location;
             let job = Job::Single(single.clone());
 
-            let dist_assigned = self.transport.distance_approx(profile, job_loc, assigned_start);
+            let dist_assigned = self.transport.distance_approx(profile, job_loc, assigned_start)
+                * (self.compatibility_fn)(&job, &route.actor).unwrap_or(1.);
 
-            let dist_nearest = find_nearest_compatible_vehicle_dist(
+            let dist_reference = find_reference_compatible_vehicle_dist(
                 job_loc,
                 &job,
+                self.reference,
                 &self.actors,
                 &self.compatibility_fn,
                 self.transport.as_ref(),
             )
             .unwrap_or(dist_assigned);
 
-            let penalty = (dist_assigned - dist_nearest).max(0.0);
+            let penalty = (dist_assigned - dist_reference).max(0.0);
             total_penalty += penalty;
         }
 
@@ -172,18 +226,20 @@ impl FeatureObjective for VehicleDistanceObjective {
                     return Cost::default();
                 };
 
-                let dist_assigned = self.transport.distance_approx(profile, job_loc, assigned_start);
+                let dist_assigned = self.transport.distance_approx(profile, job_loc, assigned_start)
+                    * (self.compatibility_fn)(job, &route.actor).unwrap_or(1.);
 
-                let dist_nearest = find_nearest_compatible_vehicle_dist(
+                let dist_reference = find_reference_compatible_vehicle_dist(
                     job_loc,
                     job,
+                    self.reference,
                     &self.actors,
                     &self.compatibility_fn,
                     self.transport.as_ref(),
                 )
                 .unwrap_or(dist_assigned);
 
-                (dist_assigned - dist_nearest).max(0.0)
+                (dist_assigned - dist_reference).max(0.0)
             }
             MoveContext::Activity { .. } => Cost::default(),
         }
@@ -193,7 +249,8 @@ impl FeatureObjective for VehicleDistanceObjective {
 struct VehicleDistanceState {
     transport: Arc<dyn TransportCost + Send + Sync>,
     actors: Vec<Arc<Actor>>,
-    compatibility_fn: ActorJobCompatibilityFn,
+    compatibility_fn: ActorJobCompatibilityCostFn,
+    reference: VehicleReference,
 }
 
 impl VehicleDistanceState {
@@ -214,18 +271,20 @@ impl VehicleDistanceState {
             let job_loc = activity.place.location;
             let job = Job::Single(single.clone());
 
-            let dist_assigned = self.transport.distance_approx(profile, job_loc, assigned_start);
+            let dist_assigned = self.transport.distance_approx(profile, job_loc, assigned_start)
+                * (self.compatibility_fn)(&job, &route.actor).unwrap_or(1.);
 
-            let dist_nearest = find_nearest_compatible_vehicle_dist(
+            let dist_reference = find_reference_compatible_vehicle_dist(
                 job_loc,
                 &job,
+                self.reference,
                 &self.actors,
                 &self.compatibility_fn,
                 self.transport.as_ref(),
             )
             .unwrap_or(dist_assigned);
 
-            let penalty = (dist_assigned - dist_nearest).max(0.0);
+            let penalty = (dist_assigned - dist_reference).max(0.0);
             total_penalty += penalty;
         }
 
@@ -61,6 +61,26 @@ pub fn create_activity_balanced_feature(name: &str) -> Result<Feature, GenericEr
     create_feature::<ActivityBalancedKey>(name, route_estimate_fn, solution_estimate_fn)
 }
 
+/// Creates a feature which balances amount of jobs across all tours. Unlike activity balance, a job with
+/// multiple activities (e.g. pickup and delivery) is counted once.
+pub fn create_job_count_balanced_feature(name: &str) -> Result<Feature, GenericError> {
+    struct JobCountBalancedKey;
+
+    let route_estimate_fn = Arc::new(|route_ctx: &RouteContext| route_ctx.route().tour.job_count() as Float);
+    let solution_estimate_fn = Arc::new(|solution_ctx: &SolutionContext| {
+        get_cv_safe(
+            solution_ctx
+                .routes
+                .iter()
+                .map(|route_ctx| route_ctx.route().tour.job_count() as Float)
+                .collect::<Vec<_>>()
+                .as_slice(),
+        )
+    });
+
+    create_feature::<JobCountBalancedKey>(name, route_estimate_fn, solution_estimate_fn)
+}
+
 /// Creates a feature which which balances travelled durations across all tours.
 pub fn create_duration_balanced_feature(name: &str) -> Result<Feature, GenericError> {
     struct DurationBalancedKey;
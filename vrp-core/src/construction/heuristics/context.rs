@@ -2,10 +2,12 @@
 #[path = "../../../tests/unit/construction/heuristics/context_test.rs"]
 mod context_test;
 
-use crate::construction::enablers::{TotalDistanceTourState, TotalDurationTourState};
+use crate::construction::enablers::{
+    BillableDistanceTourState, BillableDurationTourState, TotalDistanceTourState, TotalDurationTourState,
+};
 use crate::construction::heuristics::factories::*;
 use crate::models::GoalContext;
-use crate::models::common::Cost;
+use crate::models::common::{Cost, Location, Schedule};
 use crate::models::problem::*;
 use crate::models::solution::*;
 use crate::models::{Problem, Solution};
@@ -72,8 +74,8 @@ impl InsertionContext {
 
         self.solution.routes.iter().try_fold(Cost::default(), |acc, route_ctx| {
             let actor = &route_ctx.route.actor;
-            let distance = route_ctx.state.get_total_distance();
-            let duration = route_ctx.state.get_total_duration();
+            let distance = route_ctx.state.get_billable_distance().or(route_ctx.state.get_total_distance());
+            let duration = route_ctx.state.get_billable_duration().or(route_ctx.state.get_total_duration());
 
             distance.zip(duration).map(|(&distance, &duration)| {
                 acc + get_cost(&actor.vehicle.costs, distance, duration)
@@ -321,6 +323,64 @@ impl RouteContext {
     pub(crate) fn mark_stale(&mut self, is_stale: bool) {
         self.cache.is_stale = is_stale;
     }
+
+    /// Captures schedule relevant state of the route: activity schedules and commutes together with
+    /// route state (e.g. latest arrivals, waiting times, totals). Route state values are shared, so
+    /// taking a snapshot is cheap.
+    pub fn snapshot_state(&self) -> RouteSnapshot {
+        let keys = self
+            .route
+            .tour
+            .all_activities()
+            .map(|activity| (activity.job.clone(), activity.place.idx, activity.place.location))
+            .collect();
+        let activities = self
+            .route
+            .tour
+            .all_activities()
+            .map(|activity| (activity.schedule.clone(), activity.commute.clone()))
+            .collect();
+
+        RouteSnapshot { keys, activities, state: self.state.clone(), is_stale: self.cache.is_stale }
+    }
+
+    /// Restores schedule relevant state from the snapshot taken by `snapshot_state`.
+    /// Snapshot can be applied only if tour has the same sequence of activities (jobs and their places)
+    /// as at the moment it was taken, otherwise nothing is changed and false is returned.
+    pub fn restore_state(&mut self, snapshot: RouteSnapshot) -> bool {
+        let is_same_tour = self.route.tour.total() == snapshot.keys.len()
+            && self.route.tour.all_activities().zip(snapshot.keys.iter()).all(|(activity, (job, idx, location))| {
+                let is_same_job = match (&activity.job, job) {
+                    (Some(lhs), Some(rhs)) => Arc::ptr_eq(lhs, rhs),
+                    (None, None) => true,
+                    _ => false,
+                };
+
+                is_same_job && activity.place.idx == *idx && activity.place.location == *location
+            });
+
+        if !is_same_tour {
+            return false;
+        }
+
+        self.route.tour.all_activities_mut().zip(snapshot.activities).for_each(|(activity, (schedule, commute))| {
+            activity.schedule = schedule;
+            activity.commute = commute;
+        });
+        self.state = snapshot.state;
+        self.cache.is_stale = snapshot.is_stale;
+
+        true
+    }
+}
+
+/// Keeps schedule relevant state of the route which can be used to roll back a tentative change
+/// without full recalculation.
+pub struct RouteSnapshot {
+    keys: Vec<(Option<Arc<Single>>, usize, Location)>,
+    activities: Vec<(Schedule, Option<Commute>)>,
+    state: RouteState,
+    is_stale: bool,
 }
 
 impl PartialEq<RouteContext> for RouteContext {
@@ -424,6 +484,11 @@ impl RegistryContext {
         self.registry.next().map(move |actor| self.index[&actor].as_ref())
     }
 
+    /// Returns empty routes of all actors available for insertion.
+    pub fn available_routes(&self) -> impl Iterator<Item = &RouteContext> {
+        self.registry.available().map(move |actor| self.index[&actor].as_ref())
+    }
+
     /// Gets route for given actor and marks it as used.
     /// Returns None if actor is already in use.
     /// NOTE: you need to call free route to make it to be available again.
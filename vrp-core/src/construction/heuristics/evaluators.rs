@@ -6,8 +6,8 @@ use rosomaxa::prelude::UnwrapValue;
 use std::ops::ControlFlow;
 use std::sync::Arc;
 
+use crate::construction::enablers::get_offset_anchor;
 use crate::construction::heuristics::*;
-use crate::models::common::Timestamp;
 use crate::models::problem::{Job, Multi, Single};
 use crate::models::solution::{Activity, Leg, Place};
 use crate::models::{ConstraintViolation, GoalContext, ViolationCode};
@@ -298,7 +298,8 @@ fn analyze_insertion_in_route_leg(
         [prev, next] => (prev, Some(next)),
         _ => return ControlFlow::Break(single_ctx),
     };
-    let start_time = route_ctx.route().tour.start().map_or(Timestamp::default(), |act| act.schedule.departure);
+    // NOTE offset times are resolved relative to the anchor defined by the route cost span
+    let offset_anchor = get_offset_anchor(route_ctx.route());
 
     // iterate over places and times to find the next best insertion point
     for (place_idx, place) in single.places.iter().enumerate() {
@@ -308,7 +309,7 @@ fn analyze_insertion_in_route_leg(
 
         // iterate over time windows of the place
         for time in place.times.iter() {
-            target.place.time = time.to_time_window(start_time);
+            target.place.time = time.to_time_window(offset_anchor);
 
             let activity_ctx = ActivityContext { index, prev, target, next };
             let move_ctx = MoveContext::activity(solution_ctx, route_ctx, &activity_ctx);
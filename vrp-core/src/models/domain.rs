@@ -1,11 +1,16 @@
+#[cfg(test)]
+#[path = "../../tests/unit/models/domain_test.rs"]
+mod domain_test;
+
 use crate::construction::heuristics::UnassignmentInfo;
-use crate::models::common::{Cost, Location};
+use crate::models::common::{Cost, Location, Schedule, TimeWindow};
 use crate::models::problem::*;
-use crate::models::solution::{Registry, Route};
+use crate::models::solution::{Activity, Place, Registry, Route, Tour};
 use crate::models::*;
 use rosomaxa::evolution::TelemetryMetrics;
 use rosomaxa::prelude::*;
 use std::fmt::{Debug, Formatter};
+use std::ops::ControlFlow;
 use std::sync::Arc;
 
 /// Defines a VRP problem. You can use a [`ProblemBuilder`] to create the one.
@@ -245,7 +250,88 @@ impl ProblemBuilder {
         // setup jobs
         let jobs = Arc::new(Jobs::new(fleet.as_ref(), self.jobs, transport.as_ref(), &logger)?);
 
-        Ok(Problem { fleet, jobs, locks: vec![], goal, activity, transport, extras })
+        let problem = Problem { fleet, jobs, locks: vec![], goal, activity, transport, extras };
+
+        // sanity check of time units
+        if let Some(warning) = problem.check_cost_units() {
+            (logger)(warning.as_str());
+        }
+
+        Ok(problem)
+    }
+}
+
+/// A median ratio between sampled travel and service durations which is considered as a gross mismatch.
+const COST_UNITS_MISMATCH_RATIO: Float = 1000.;
+
+/// Max amount of jobs sampled to compare travel and service durations.
+const COST_UNITS_SAMPLE_SIZE: usize = 11;
+
+impl Problem {
+    /// Checks that transport and activity costs use the same time units. Time units cannot be fully
+    /// verified, so travel duration from the first actor's start to a job is compared with the job's
+    /// service time estimated by activity cost for several sampled jobs. A median difference of a few
+    /// orders of magnitude usually means different units (e.g. seconds vs hours).
+    /// Returns a warning message if such mismatch is detected.
+    pub fn check_cost_units(&self) -> Option<String> {
+        let actor = self.fleet.actors.first()?;
+        let route = Route { actor: actor.clone(), tour: Tour::new(actor) };
+        let start = route.tour.start()?;
+        let departure = start.schedule.departure;
+
+        let samples = self
+            .jobs
+            .all()
+            .iter()
+            .flat_map(|job| match job {
+                Job::Single(single) => vec![single.clone()],
+                Job::Multi(multi) => multi.jobs.clone(),
+            })
+            .filter_map(|single| {
+                single
+                    .places
+                    .iter()
+                    .filter(|place| place.duration > 0.)
+                    .find_map(|place| place.location.map(|location| (location, place.duration)))
+                    .map(|(location, duration)| (single, location, duration))
+            })
+            .collect::<Vec<_>>();
+
+        let step = samples.len().div_ceil(COST_UNITS_SAMPLE_SIZE).max(1);
+        let mut ratios = samples
+            .into_iter()
+            .step_by(step)
+            .filter_map(|(single, location, duration)| {
+                let travel =
+                    self.transport.duration(&route, start.place.location, location, TravelTime::Departure(departure));
+                if travel <= 0. {
+                    return None;
+                }
+
+                let arrival = departure + travel;
+                let target = Activity {
+                    place: Place { idx: 0, location, duration, time: TimeWindow::max() },
+                    schedule: Schedule::new(arrival, arrival),
+                    job: Some(single),
+                    commute: None,
+                };
+                let service = match self.activity.estimate_departure(&route, &target, arrival) {
+                    ControlFlow::Continue(time) | ControlFlow::Break(time) => time - arrival,
+                };
+
+                (service > 0.).then(|| travel.max(service) / travel.min(service))
+            })
+            .collect::<Vec<_>>();
+
+        ratios.sort_by(|a, b| a.total_cmp(b));
+        let ratio = *ratios.get(ratios.len() / 2)?;
+
+        (ratio > COST_UNITS_MISMATCH_RATIO).then(|| {
+            format!(
+                "median ratio ({ratio}) between sampled travel durations and service times exceeds \
+                 {COST_UNITS_MISMATCH_RATIO}: check that transport and activity costs use the same time units"
+            )
+        })
     }
 }
 
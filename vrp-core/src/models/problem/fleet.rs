@@ -27,15 +27,99 @@ pub enum RouteCostSpan {
 }
 
 custom_dimension!(pub RouteCostSpan typeof RouteCostSpan);
+custom_dimension!(pub RouteCostSpanExcludeBreakTime typeof bool);
+
+/// Specifies how route metrics calculated for different cost spans are combined.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RouteCostSpanReducer {
+    /// Takes the greater value.
+    #[default]
+    Max,
+    /// Takes the sum of values.
+    Sum,
+}
+
+impl RouteCostSpanReducer {
+    /// Combines two values.
+    pub fn reduce(&self, first: Float, second: Float) -> Float {
+        match self {
+            Self::Max => first.max(second),
+            Self::Sum => first + second,
+        }
+    }
+}
+
+/// Composes two route cost spans: billable route duration and distance are calculated for each span
+/// separately and then combined using the reducer. When set, it takes precedence over `RouteCostSpan`
+/// for costs only, route limits are still checked against the single span.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RouteCostSpanComposition {
+    /// A first cost span.
+    pub first: RouteCostSpan,
+    /// A second cost span.
+    pub second: RouteCostSpan,
+    /// A reducer which combines metrics of both spans.
+    pub reducer: RouteCostSpanReducer,
+}
+
+custom_dimension!(pub RouteCostSpanComposition typeof RouteCostSpanComposition);
+
+/// Specifies weights of a blended route metric: `distance * distance_weight + duration * duration_weight`,
+/// where route distance and duration are calculated within the vehicle's cost span.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RouteBlendedMetricWeights {
+    /// A weight of the route distance.
+    pub distance: Float,
+    /// A weight of the route duration.
+    pub duration: Float,
+}
+
+custom_dimension!(pub RouteBlendedMetricWeights typeof RouteBlendedMetricWeights);
+
+/// Specifies which point of the first job visit is checked against `earliest_first`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FirstJobReference {
+    /// Vehicle is not allowed to arrive at the first job before `earliest_first`.
+    Arrival,
+    /// Vehicle can arrive earlier, but it waits and starts service not before `earliest_first` (default).
+    #[default]
+    ServiceStart,
+}
+
+/// Specifies which point of the last job visit is checked against `latest_last`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LastJobReference {
+    /// Vehicle is not allowed to arrive at the last job after `latest_last`, service can end later.
+    Arrival,
+    /// Vehicle is not allowed to depart from the last job after `latest_last` (default).
+    #[default]
+    Departure,
+}
+
+/// Specifies which departure is used to estimate arrival at the first job when `earliest_first` is checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DepartureReference {
+    /// Shift's scheduled start (earliest start time) is used, regardless of departure time advancing.
+    ScheduledStart,
+    /// Actual, possibly advanced, departure from the start location is used (default).
+    #[default]
+    ActualDeparture,
+}
 
 /// Time constraints for jobs within a shift.
 /// Controls when the first job can start and when the last job must finish.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct JobTimeConstraints {
-    /// Earliest allowed arrival time at the first job.
+    /// Earliest allowed time at the first job, see `first_job_reference` for its semantics.
     pub earliest_first: Option<Timestamp>,
-    /// Latest allowed departure time from the last job.
+    /// Latest allowed time at the last job, see `last_job_reference` for its semantics.
     pub latest_last: Option<Timestamp>,
+    /// Specifies whether `earliest_first` limits arrival or service start at the first job.
+    pub first_job_reference: FirstJobReference,
+    /// Specifies whether `latest_last` limits arrival at or departure from the last job.
+    pub last_job_reference: LastJobReference,
+    /// Specifies departure used to estimate arrival at the first job for `earliest_first` check.
+    pub departure_reference: DepartureReference,
 }
 
 custom_dimension!(pub JobTimeConstraints typeof JobTimeConstraints);
@@ -15,6 +15,8 @@ use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Weak};
 
 custom_dimension!(pub JobId typeof String);
+custom_dimension!(pub JobMinDwell typeof Duration);
+custom_dimension!(pub JobParallelService typeof Vec<usize>);
 
 /// Represents a job variant.
 #[derive(Clone)]
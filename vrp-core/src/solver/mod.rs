@@ -240,20 +240,27 @@ impl Solver {
     /// Solves a Vehicle Routing Problem and returns a feasible solution in case of success
     /// or error description if solution cannot be found.
     pub fn solve(self) -> GenericResult<Solution> {
+        // NOTE select the first best individual from population
+        self.solve_many(1)?.into_iter().next().ok_or_else(|| "cannot find any solution".into())
+    }
+
+    /// Solves a Vehicle Routing Problem and returns up to `count` best solutions from the final population,
+    /// ordered from the best to the worst. Telemetry metrics, if any, are attached to the best solution only.
+    pub fn solve_many(mut self, count: usize) -> GenericResult<Vec<Solution>> {
+        self.config.strategy.set_desired_solutions(count);
+
         (self.config.context.environment.logger)(&format!(
             "total jobs: {}, actors: {}",
             self.problem.jobs.size(),
             self.problem.fleet.actors.len()
         ));
 
-        let (mut solutions, metrics) = EvolutionSimulator::new(self.config)?.run()?;
-
-        // NOTE select the first best individual from population
-        let insertion_ctx = if solutions.is_empty() { None } else { solutions.drain(0..1).next() }
-            .ok_or_else(|| "cannot find any solution".to_string())?;
+        let (solutions, mut metrics) = EvolutionSimulator::new(self.config)?.run()?;
 
-        let solution = (insertion_ctx, metrics).into();
+        if solutions.is_empty() || count == 0 {
+            return Err("cannot find any solution".into());
+        }
 
-        Ok(solution)
+        Ok(solutions.into_iter().take(count).map(|insertion_ctx| (insertion_ctx, metrics.take()).into()).collect())
     }
 }
@@ -0,0 +1,72 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/processing/compact_schedules_test.rs"]
+mod compact_schedules_test;
+
+use super::*;
+use crate::construction::enablers::{DepartureFeasibleTourState, advance_departure_time, recede_departure_time};
+use crate::construction::heuristics::RouteContext;
+use crate::models::common::Duration;
+use crate::models::problem::{ActivityCost, TransportCost};
+use crate::models::solution::Route;
+use rosomaxa::HeuristicSolution;
+
+/// Provides way to remove avoidable idle time from schedules of the finished solution.
+#[derive(Default)]
+pub struct CompactSchedules {}
+
+impl HeuristicSolutionProcessing for CompactSchedules {
+    type Solution = InsertionContext;
+
+    fn post_process(&self, solution: Self::Solution) -> Self::Solution {
+        let mut insertion_ctx = solution.deep_copy();
+
+        compact_schedules(&mut insertion_ctx);
+
+        insertion_ctx
+    }
+}
+
+/// Tightens route schedules to minimize waiting time. Job order and break placement are kept as is:
+/// only departure time of each route is moved forward or backward using departure time enablers, and
+/// a schedule with the least waiting time is kept. Schedules which become infeasible are not accepted.
+pub fn compact_schedules(insertion_ctx: &mut InsertionContext) {
+    let problem = insertion_ctx.problem.clone();
+
+    let activity = problem.activity.as_ref();
+    let transport = problem.transport.as_ref();
+
+    insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| {
+        compact_route_schedule(route_ctx, activity, transport);
+    });
+
+    problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+}
+
+fn compact_route_schedule(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, transport: &dyn TransportCost) {
+    if !route_ctx.route().tour.has_jobs() {
+        return;
+    }
+
+    let mut advanced = route_ctx.deep_copy();
+    advance_departure_time(&mut advanced, activity, transport, true);
+
+    let mut receded = route_ctx.deep_copy();
+    recede_departure_time(&mut receded, activity, transport);
+
+    // NOTE the original schedule is kept when no candidate reduces waiting time
+    let best = [advanced, receded]
+        .into_iter()
+        .filter(|candidate| candidate.state().get_departure_feasible().copied().unwrap_or(true))
+        .map(|candidate| (get_waiting_time(candidate.route()), candidate))
+        .filter(|(waiting_time, _)| *waiting_time < get_waiting_time(route_ctx.route()))
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, candidate)| candidate);
+
+    if let Some(best) = best {
+        *route_ctx = best;
+    }
+}
+
+fn get_waiting_time(route: &Route) -> Duration {
+    route.tour.all_activities().map(|activity| (activity.place.time.start - activity.schedule.arrival).max(0.)).sum()
+}
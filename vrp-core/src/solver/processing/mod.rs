@@ -6,11 +6,16 @@ use rosomaxa::prelude::*;
 mod advance_departure;
 pub use self::advance_departure::AdvanceDeparture;
 
+mod compact_schedules;
+pub use self::compact_schedules::{CompactSchedules, compact_schedules};
+
 mod reschedule_reserved_time;
-pub use self::reschedule_reserved_time::{RescheduleReservedTime, ReservedTimesExtraProperty};
+pub use self::reschedule_reserved_time::{
+    JobReservedTimesExtraProperty, RescheduleReservedTime, ReservedTimesExtraProperty,
+};
 
 mod unassignment_reason;
-pub use self::unassignment_reason::UnassignmentReason;
+pub use self::unassignment_reason::{FeasibilityReportExtraProperty, UnassignmentReason};
 
 mod vicinity_clustering;
 pub use self::vicinity_clustering::*;
@@ -4,9 +4,19 @@ mod unassignment_reason_test;
 
 use super::*;
 use crate::construction::heuristics::*;
+use crate::models::problem::Actor;
+use crate::models::{Extras, ViolationCode};
 use rosomaxa::utils::{CollectGroupBy, parallel_into_collect};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+custom_extra_property!(pub FeasibilityReport typeof bool);
 
 /// Tries to improve job unassignment reason.
+///
+/// By default, only the most frequent reason is reported for each used route. When feasibility report
+/// is requested via `FeasibilityReportExtraProperty`, all distinct reasons are reported for each actor,
+/// including actors which are not used in the solution.
 #[derive(Default)]
 pub struct UnassignmentReason {}
 
@@ -19,6 +29,8 @@ impl HeuristicSolutionProcessing for UnassignmentReason {
         let unassigned = insertion_ctx.solution.unassigned.drain().collect::<Vec<_>>();
         let leg_selection = LegSelection::Exhaustive;
         let result_selector = BestResultSelector::default();
+        let is_full_report =
+            insertion_ctx.problem.extras.get_feasibility_report().is_some_and(|is_enabled| *is_enabled);
 
         let unassigned = parallel_into_collect(unassigned, |(job, code)| {
             let eval_ctx = EvaluationContext {
@@ -31,8 +43,9 @@ impl HeuristicSolutionProcessing for UnassignmentReason {
                 .solution
                 .routes
                 .iter()
-                .filter_map(|route_ctx| {
-                    (0..route_ctx.route().tour.legs().count())
+                .chain(is_full_report.then(|| insertion_ctx.solution.registry.available_routes()).into_iter().flatten())
+                .flat_map(|route_ctx| {
+                    let codes = (0..route_ctx.route().tour.legs().count())
                         .map(|leg_idx| {
                             eval_job_insertion_in_route(
                                 &insertion_ctx,
@@ -46,11 +59,9 @@ impl HeuristicSolutionProcessing for UnassignmentReason {
                             InsertionResult::Failure(failure) => Some(failure),
                             _ => None,
                         })
-                        .collect_group_by_key(|code| code.constraint)
-                        .into_iter()
-                        // NOTE: pick only the most frequent reason
-                        .max_by(|(_, a), (_, b)| a.len().cmp(&b.len()))
-                        .map(|(code, _)| (route_ctx.route().actor.clone(), code))
+                        .collect_group_by_key(|code| code.constraint);
+
+                    get_route_codes(route_ctx.route().actor.clone(), codes, is_full_report)
                 })
                 .collect::<Vec<_>>();
 
@@ -64,3 +75,25 @@ impl HeuristicSolutionProcessing for UnassignmentReason {
         insertion_ctx
     }
 }
+
+fn get_route_codes(
+    actor: Arc<Actor>,
+    codes: HashMap<ViolationCode, Vec<InsertionFailure>>,
+    is_full_report: bool,
+) -> Vec<(Arc<Actor>, ViolationCode)> {
+    if is_full_report {
+        let mut codes = codes.into_keys().collect::<Vec<_>>();
+        // NOTE sort to have consistent order
+        codes.sort_by_key(|code| code.0);
+
+        codes.into_iter().map(|code| (actor.clone(), code)).collect()
+    } else {
+        codes
+            .into_iter()
+            // NOTE: pick only the most frequent reason
+            .max_by(|(_, a), (_, b)| a.len().cmp(&b.len()))
+            .map(|(code, _)| (actor, code))
+            .into_iter()
+            .collect()
+    }
+}
@@ -1,4 +1,6 @@
-use crate::construction::enablers::{advance_departure_time, recede_departure_time};
+use crate::construction::enablers::{
+    DepartureFallback, advance_departure_time_with_fallback, recede_departure_time_with_fallback,
+};
 use crate::construction::heuristics::InsertionContext;
 use crate::models::solution::Activity;
 use crate::solver::RefinementContext;
@@ -7,7 +9,17 @@ use rosomaxa::prelude::*;
 
 /// Reschedules departure time of the routes in the solution.
 #[derive(Default)]
-pub struct RescheduleDeparture {}
+pub struct RescheduleDeparture {
+    fallback: DepartureFallback,
+}
+
+impl RescheduleDeparture {
+    /// Creates a new instance of `RescheduleDeparture` which uses the given fallback when the resulting
+    /// route schedule is infeasible.
+    pub fn new(fallback: DepartureFallback) -> Self {
+        Self { fallback }
+    }
+}
 
 impl LocalOperator for RescheduleDeparture {
     fn explore(
@@ -29,9 +41,15 @@ impl LocalOperator for RescheduleDeparture {
 
             match (route_ctx.route().tour.start(), earliest, random.is_head_not_tails()) {
                 (Some(start), Some(earliest), true) if can_recede_departure(start, earliest) => {
-                    recede_departure_time(route_ctx, activity, transport)
+                    recede_departure_time_with_fallback(route_ctx, activity, transport, self.fallback)
                 }
-                _ => advance_departure_time(route_ctx, activity, transport, consider_whole_tour),
+                _ => advance_departure_time_with_fallback(
+                    route_ctx,
+                    activity,
+                    transport,
+                    consider_whole_tour,
+                    self.fallback,
+                ),
             };
         });
 
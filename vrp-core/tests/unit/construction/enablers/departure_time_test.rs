@@ -65,16 +65,16 @@ parameterized_test! {can_recede_departure_time, (earliest, start_departure, late
 }}
 
 can_recede_departure_time! {
-    case01: (Some(0.), 0., 10., (10., 20.), None, None),
-    case02: (Some(0.), 5., 10., (10., 20.), None, None),
-    case03: (Some(5.), 10., 15., (10., 20.), None, Some(5.)),
-    case04: (Some(5.), 10., 20., (10., 20.), None, Some(5.)),
-    case05: (None, 10., 50., (10., 20.), None, Some(0.)),
-    case06: (Some(5.), 10., 11., (10., 20.), None, Some(9.)),
+    case01: (Some(0.), 0., 10., (0., 20.), None, None),
+    case02: (Some(0.), 5., 10., (0., 20.), None, None),
+    case03: (Some(5.), 10., 15., (0., 20.), None, Some(5.)),
+    case04: (Some(5.), 10., 20., (0., 20.), None, Some(5.)),
+    case05: (None, 10., 50., (0., 20.), None, Some(0.)),
+    case06: (Some(5.), 10., 11., (0., 20.), None, Some(9.)),
 
-    case07: (Some(0.), 10., 20., (10., 20.), Some((20., 30.)), Some(0.)),
-    case08: (Some(0.), 10., 20., (10., 20.), Some((20., 25.)), Some(5.)),
-    case09: (Some(0.), 10., 20., (10., 20.), Some((20., 20.)), None),
+    case07: (Some(0.), 10., 20., (0., 20.), Some((20., 30.)), Some(0.)),
+    case08: (Some(0.), 10., 20., (0., 20.), Some((20., 25.)), Some(5.)),
+    case09: (Some(0.), 10., 20., (0., 20.), Some((20., 20.)), None),
 }
 
 fn can_recede_departure_time_impl(
@@ -117,6 +117,139 @@ fn can_recede_departure_time_impl(
     assert_eq!(departure_time, expected);
 }
 
+parameterized_test! {can_limit_recede_departure_time_by_mid_tour_window, (first_tw_start, second_tw_start, expected), {
+    can_limit_recede_departure_time_by_mid_tour_window_impl(first_tw_start, second_tw_start, expected);
+}}
+
+can_limit_recede_departure_time_by_mid_tour_window! {
+    case01_no_limit: (0., 0., Some(0.)),
+    case02_first_at_window_start: (10., 17., None),
+    case03_first_limits: (8., 17., Some(8.)),
+    case04_second_limits: (5., 17., Some(7.)),
+    case05_second_at_window_start: (10., 20., None),
+    case06_second_at_window_start_with_first_slack: (5., 20., None),
+}
+
+fn can_limit_recede_departure_time_by_mid_tour_window_impl(
+    first_tw_start: Float,
+    second_tw_start: Float,
+    expected: Option<Float>,
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(Vehicle {
+            details: vec![VehicleDetail {
+                start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(0.), latest: None } }),
+                ..test_vehicle_detail()
+            }],
+            ..test_vehicle_with_id("v1")
+        })
+        .build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(ActivityBuilder::with_location_and_tw(10, TimeWindow::new(first_tw_start, 100.)).build())
+                .add_activity(ActivityBuilder::with_location_and_tw(20, TimeWindow::new(second_tw_start, 100.)).build())
+                .build(),
+        )
+        .build();
+    let (route, state) = route_ctx.as_mut();
+    route.tour.get_mut(0).unwrap().schedule.departure = 10.;
+    state.set_latest_arrival_states(vec![0., 100., 100.]);
+
+    let departure_time = try_recede_departure_time(&route_ctx);
+
+    assert_eq!(departure_time, expected);
+}
+
+parameterized_test! {can_record_departure_binding, (latest, first_tw_start, expected), {
+    can_record_departure_binding_impl(latest, first_tw_start, expected);
+}}
+
+can_record_departure_binding! {
+    case01: (None, 12., Some("first_job_window")),
+    case02: (Some(1.), 12., Some("latest_allowed_start")),
+    case03: (None, 0., Some("earliest_allowed_start")),
+}
+
+fn can_record_departure_binding_impl(latest: Option<Float>, first_tw_start: Float, expected: Option<&str>) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(Vehicle {
+            details: vec![VehicleDetail {
+                start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(0.), latest } }),
+                ..test_vehicle_detail()
+            }],
+            ..test_vehicle_with_id("v1")
+        })
+        .build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(ActivityBuilder::with_location_and_tw(10, TimeWindow::new(first_tw_start, 100.)).build())
+                .add_activity(ActivityBuilder::with_location_and_tw(20, TimeWindow::new(0., 100.)).build())
+                .build(),
+        )
+        .build();
+
+    advance_departure_time(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default(), false);
+
+    let binding = route_ctx.state().get_departure_binding().map(|binding| binding.to_string());
+    assert_eq!(binding.as_deref(), expected);
+}
+
+parameterized_test! {can_flag_infeasible_current_departure, (fallback, tw, expected), {
+    can_flag_infeasible_current_departure_impl(fallback, TimeWindow::new(tw.0, tw.1), expected);
+}}
+
+can_flag_infeasible_current_departure! {
+    case01_restore_feasible: (DepartureFallback::Restore, (20., 30.), (20., true)),
+    case02_restore_infeasible: (DepartureFallback::Restore, (0., 15.), (20., false)),
+    case03_search_feasible: (DepartureFallback::Search, (0., 15.), (0., true)),
+    case04_search_infeasible: (DepartureFallback::Search, (0., 5.), (20., false)),
+}
+
+fn can_flag_infeasible_current_departure_impl(
+    fallback: DepartureFallback,
+    tw: TimeWindow,
+    expected: (Timestamp, bool),
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(Vehicle {
+            details: vec![VehicleDetail {
+                start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(0.), latest: Some(20.) } }),
+                ..test_vehicle_detail()
+            }],
+            ..test_vehicle_with_id("v1")
+        })
+        .build();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(ActivityBuilder::with_location_and_tw(10, tw).build())
+                .build(),
+        )
+        .build();
+    // NOTE departure is at the latest allowed start, so it cannot be advanced anymore
+    update_route_departure(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default(), 20.);
+
+    advance_departure_time_with_fallback(
+        &mut route_ctx,
+        &TestActivityCost::default(),
+        &TestTransportCost::default(),
+        false,
+        fallback,
+    );
+
+    let departure = route_ctx.route().tour.start().unwrap().schedule.departure;
+    let is_feasible = route_ctx.state().get_departure_feasible().copied();
+    assert_eq!((departure, is_feasible), (expected.0, Some(expected.1)));
+}
+
 #[test]
 fn recomputes_offset_time_windows_on_departure_shift() {
     let offset = TimeOffset::new(10., 12.);
@@ -156,3 +289,118 @@ fn recomputes_offset_time_windows_on_departure_shift() {
     let activity = route_ctx.route().tour.get(1).unwrap();
     assert_eq!(activity.place.time, TimeSpan::Offset(offset).to_time_window(new_departure));
 }
+
+/// A transport where travel becomes slower the later the vehicle departs (e.g. growing traffic).
+struct TimeDependentTransportCost;
+
+impl TransportCost for TimeDependentTransportCost {
+    fn duration_approx(&self, _: &Profile, from: Location, to: Location) -> Duration {
+        fake_routing(from, to)
+    }
+
+    fn distance_approx(&self, _: &Profile, from: Location, to: Location) -> Distance {
+        fake_routing(from, to)
+    }
+
+    fn duration(&self, _: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let departure = match travel_time {
+            TravelTime::Departure(departure) => departure,
+            TravelTime::Arrival(arrival) => arrival,
+        };
+
+        fake_routing(from, to) * (1. + departure / 20.)
+    }
+
+    fn distance(&self, _: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
+        fake_routing(from, to)
+    }
+
+    fn size(&self) -> usize {
+        1
+    }
+}
+
+fn create_activity_with_span(location: Location, span: TimeSpan, schedule: Schedule) -> Activity {
+    let job = Arc::new(Single {
+        places: vec![JobPlace { location: Some(location), duration: 0., times: vec![span.clone()] }],
+        dimens: Dimensions::default(),
+    });
+
+    let mut activity = Activity::new_with_job(job);
+    activity.place = ActivityPlace { idx: 0, location, duration: 0., time: span.to_time_window(0.) };
+    activity.schedule = schedule;
+
+    activity
+}
+
+#[test]
+fn can_compute_critical_departures_with_time_dependent_transport() {
+    // job at location 10 must be reached by 30, travel from depot takes 10 * (1 + departure / 20):
+    // candidate which assumes travel of 10 gives departure 20 which leads to arrival at 40,
+    // while the latest feasible departure is 40 / 3 (arrival at 30)
+    let transport = TimeDependentTransportCost;
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&test_fleet(), "v1")
+                .add_activity(create_activity_with_span(
+                    10,
+                    TimeSpan::Window(TimeWindow::new(0., 30.)),
+                    Schedule::new(10., 10.),
+                ))
+                .add_activity(create_activity_with_span(
+                    10,
+                    TimeSpan::Offset(TimeOffset::new(100., 100.)),
+                    Schedule::new(100., 100.),
+                ))
+                .build(),
+        )
+        .build();
+    let route = route_ctx.route();
+    let is_close = |candidate: Timestamp, expected: Timestamp| (candidate - expected).abs() < 1e-3;
+
+    let time_dependent = compute_critical_departures(route, 0., 25., &transport);
+
+    assert!(!time_dependent.iter().any(|&candidate| is_close(candidate, 20.)));
+    assert_eq!(estimate_arrival(route, 1, 20., &transport), 40.);
+    assert!(time_dependent.iter().any(|&candidate| is_close(candidate, 40. / 3.)));
+    assert!(is_close(estimate_arrival(route, 1, 40. / 3., &transport), 30.));
+}
+
+parameterized_test! {can_find_departure_for_arrival, (activities, window_start, departure), {
+    can_find_departure_for_arrival_impl(activities, window_start, departure);
+}}
+
+can_find_departure_for_arrival! {
+    case01_short_tour: (2, 0., 3.),
+    case02_short_tour_with_waiting: (2, 5., 7.),
+    case03_long_tour: (10, 0., 3.),
+    case04_long_tour_with_waiting: (10, 5., 7.),
+}
+
+fn can_find_departure_for_arrival_impl(activities: usize, window_start: Timestamp, departure: Timestamp) {
+    let transport = TimeDependentTransportCost;
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&test_fleet(), "v1")
+                .add_activities((1..=activities).map(|idx| {
+                    // NOTE only the first activity has a window start which can cause waiting
+                    let start = if idx == 1 { window_start } else { 0. };
+                    create_activity_with_span(
+                        idx * 5,
+                        TimeSpan::Window(TimeWindow::new(start, 1000.)),
+                        Schedule::new(0., 0.),
+                    )
+                }))
+                .build(),
+        )
+        .build();
+    let route = route_ctx.route();
+    let arrival = estimate_arrival(route, activities, departure, &transport);
+
+    let result =
+        find_departure_for_arrival(route, activities, arrival, (0., 10.), &transport).expect("cannot find departure");
+
+    assert!((estimate_arrival(route, activities, result, &transport) - arrival).abs() < 1e-3);
+}
@@ -0,0 +1,49 @@
+use super::*;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_route(model: Option<EmissionModel>) -> Route {
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1");
+    if let Some(model) = model {
+        builder.dimens_mut().set_vehicle_emission_model(model);
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(builder.build()).build();
+
+    RouteBuilder::default().with_vehicle(&fleet, "v1").build()
+}
+
+parameterized_test! {can_get_route_emissions, (per_distance, load_weight, legs, expected), {
+    can_get_route_emissions_impl(per_distance, load_weight, legs, expected);
+}}
+
+can_get_route_emissions! {
+    case01_base_distance: (2., 0., vec![(100., 0.)], 200.),
+    case02_double_distance: (2., 0., vec![(200., 0.)], 400.),
+    case03_double_factor: (4., 0., vec![(100., 0.)], 400.),
+    case04_load_weight_with_half_load: (2., 0.5, vec![(100., 0.5)], 250.),
+    case05_load_weight_with_full_load: (2., 0.5, vec![(100., 1.)], 300.),
+    case06_load_ignored_without_weight: (2., 0., vec![(100., 1.)], 200.),
+    case07_load_per_leg: (2., 0.5, vec![(100., 1.), (100., 0.)], 500.),
+    case08_no_legs: (2., 0.5, vec![], 0.),
+}
+
+fn can_get_route_emissions_impl(
+    per_distance: Float,
+    load_weight: Float,
+    legs: Vec<(Distance, Float)>,
+    expected: Float,
+) {
+    let route = create_route(Some(EmissionModel { per_distance, load_weight }));
+
+    let emissions = get_route_emissions(&route, legs.into_iter());
+
+    assert_eq!(emissions, Some(expected));
+}
+
+#[test]
+fn can_skip_route_emissions_without_model() {
+    let route = create_route(None);
+
+    assert_eq!(get_route_emissions(&route, std::iter::once((100., 0.))), None);
+}
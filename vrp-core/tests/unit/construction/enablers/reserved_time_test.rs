@@ -2,12 +2,14 @@ use super::*;
 use crate::construction::enablers::LatestArrivalActivityState;
 use crate::construction::features::TransportFeatureBuilder;
 use crate::construction::heuristics::*;
+use crate::helpers::construction::features::create_simple_demand;
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
 use crate::models::problem::*;
 use crate::models::{Feature, ViolationCode};
 use rosomaxa::prelude::Float;
+use rosomaxa::utils::UnwrapValue;
 
 const VIOLATION_CODE: ViolationCode = ViolationCode(1);
 
@@ -89,20 +91,28 @@ fn create_feature_and_route(
 ) -> (ReservedTimesFn, Feature, RouteContext) {
     let (location_start, location_end, time_start, time_end) = vehicle_detail_data;
 
-    let activities = activities.into_iter().map(|(loc, (start, end), dur)| {
-        ActivityBuilder::with_location_tw_and_duration(loc, TimeWindow::new(start, end), dur).build()
-    });
-    let fleet = FleetBuilder::default()
-        .add_driver(test_driver())
-        .add_vehicles(vec![
-            TestVehicleBuilder::default()
-                .id("v1")
-                .details(vec![create_detail((Some(location_start), Some(location_end)), Some((time_start, time_end)))])
-                .build(),
-        ])
+    let activities = activities
+        .into_iter()
+        .map(|(loc, (start, end), dur)| {
+            ActivityBuilder::with_location_tw_and_duration(loc, TimeWindow::new(start, end), dur).build()
+        })
+        .collect();
+    let vehicle = TestVehicleBuilder::default()
+        .id("v1")
+        .details(vec![create_detail((Some(location_start), Some(location_end)), Some((time_start, time_end)))])
         .build();
+
+    create_feature_and_route_with_vehicle(vehicle, activities, vec![reserved_time])
+}
+
+fn create_feature_and_route_with_vehicle(
+    vehicle: Vehicle,
+    activities: Vec<Activity>,
+    reserved_times: Vec<ReservedTimeSpan>,
+) -> (ReservedTimesFn, Feature, RouteContext) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
     let reserved_times_idx =
-        vec![(fleet.actors.first().unwrap().clone(), vec![reserved_time])].into_iter().collect::<HashMap<_, _>>();
+        vec![(fleet.actors.first().unwrap().clone(), reserved_times)].into_iter().collect::<HashMap<_, _>>();
     let mut route_ctx = RouteContextBuilder::default()
         .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
         .build();
@@ -296,3 +306,352 @@ fn can_avoid_reserved_time_when_driving_impl(
 
     assert_eq!(get_schedules(&route_ctx), expected_schedules)
 }
+
+parameterized_test! {can_apply_job_reserved_time, (job_id, expected_departure, expected_arrival), {
+    can_apply_job_reserved_time_impl(job_id, expected_departure, expected_arrival);
+}}
+
+can_apply_job_reserved_time! {
+    case01_anchored_job: ("job2", 35., 10.),
+    case02_other_job: ("job1", 20., 25.),
+}
+
+fn can_apply_job_reserved_time_impl(job_id: &str, expected_departure: Timestamp, expected_arrival: Timestamp) {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route = RouteBuilder::default().with_vehicle(&fleet, "v1").build();
+    let job_reserved_times = vec![(
+        fleet.actors.first().unwrap().clone(),
+        vec![JobReservedTime { job_id: "job2".to_string(), duration: 15. }],
+    )]
+    .into_iter()
+    .collect::<HashMap<_, _>>();
+    let activity_cost = DynamicActivityCost::new(HashMap::new()).unwrap().with_job_reserved_times(job_reserved_times);
+    let activity = ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(0., 100.), 10.)
+        .job(Some(TestSingleBuilder::default().id(job_id).build_shared()))
+        .build();
+
+    let departure = activity_cost.estimate_departure(&route, &activity, 10.).unwrap_value();
+    let arrival = activity_cost.estimate_arrival(&route, &activity, 35.).unwrap_value();
+
+    assert_eq!(departure, expected_departure);
+    assert_eq!(arrival, expected_arrival);
+}
+
+parameterized_test! {can_apply_fractional_reserved_time, (fraction, span, expected_schedules), {
+    can_apply_fractional_reserved_time_impl(fraction, span, expected_schedules);
+}}
+
+can_apply_fractional_reserved_time! {
+    case01_on_travel: ((0.4, 0.5), None, vec![(0., 0.), (40., 50.), (100., 100.)]),
+    case02_on_activity: ((0.3, 0.45), None, vec![(0., 0.), (40., 60.), (100., 100.)]),
+    case03_first_job_span: ((0.4, 0.5), Some(RouteCostSpan::FirstJobToLastJob), vec![(0., 0.), (40., 50.), (100., 100.)]),
+}
+
+fn can_apply_fractional_reserved_time_impl(
+    fraction: (Float, Float),
+    span: Option<RouteCostSpan>,
+    expected_schedules: Vec<(Timestamp, Timestamp)>,
+) {
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1").details(vec![create_detail((Some(0), Some(0)), Some((0., 1000.)))]);
+    if let Some(span) = span {
+        builder.dimens_mut().set_route_cost_span(span);
+    }
+    builder.dimens_mut().set_break_rules(vec![Arc::new(FractionalReservedTime {
+        earliest: fraction.0,
+        latest: fraction.1,
+        duration: 10.,
+    })]);
+    let (_, _, route_ctx) = create_feature_and_route_with_vehicle(
+        builder.build(),
+        vec![ActivityBuilder::with_location_tw_and_duration(40, TimeWindow::new(0., 1000.), 10.).build()],
+        vec![],
+    );
+
+    assert_eq!(get_schedules(&route_ctx), expected_schedules);
+    assert_eq!(get_shift_duration(route_ctx.route()), 100.);
+}
+
+parameterized_test! {can_ignore_reserved_time_outside_shift, (reserved_time, expected), {
+    can_ignore_reserved_time_outside_shift_impl(reserved_time, expected);
+}}
+
+can_ignore_reserved_time_outside_shift! {
+    case01_inside_shift: (((85., 85.), 10.), true),
+    case02_ends_at_shift_end: (((90., 90.), 10.), true),
+    case03_ends_after_shift_end: (((95., 95.), 10.), false),
+    case04_starts_before_shift_start: (((5., 15.), 5.), true),
+    case05_ends_before_shift_start: (((0., 5.), 5.), false),
+    case06_window_runs_past_shift_end: (((85., 95.), 10.), true),
+}
+
+fn can_ignore_reserved_time_outside_shift_impl(reserved_time: ((Timestamp, Timestamp), Duration), expected: bool) {
+    let ((start, end), duration) = reserved_time;
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(
+            TestVehicleBuilder::default()
+                .id("v1")
+                .details(vec![create_detail((Some(0), Some(0)), Some((10., 100.)))])
+                .build(),
+        )
+        .build();
+    let actor = fleet.actors.first().unwrap().clone();
+    let reserved_time = ReservedTimeSpan { time: TimeSpan::Window(TimeWindow::new(start, end)), duration };
+    let route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    let reserved_times_fn =
+        create_reserved_times_fn(vec![(actor.clone(), vec![reserved_time.clone()])].into_iter().collect()).unwrap();
+
+    let is_within_shift = is_reserved_time_within_shift(&actor, &reserved_time);
+    let result = (reserved_times_fn)(route_ctx.route(), &TimeWindow::new(0., 100.));
+
+    assert_eq!(is_within_shift, expected);
+    assert_eq!(result.is_some(), expected);
+    assert!(result.is_none_or(|rt| rt.time.start >= 10. && rt.time.end + rt.duration <= 100.));
+}
+
+/// A test rule which requires a break after each `max_driving` of driving time.
+struct DrivingTimeBreakRule {
+    max_driving: Duration,
+    duration: Duration,
+}
+
+impl BreakRule for DrivingTimeBreakRule {
+    fn windows(&self, ctx: &RouteBreakContext) -> Vec<TimeWindow> {
+        let mut windows = Vec::new();
+        let mut driving = Duration::default();
+
+        ctx.route.tour.legs().for_each(|(leg, _)| {
+            let [from, to] = leg else { return };
+            let mut departure = from.schedule.departure;
+
+            // NOTE breaks taken on the leg are excluded from driving time
+            while driving + (to.schedule.arrival - departure) > self.max_driving * (windows.len() + 1) as Float {
+                let time = departure + self.max_driving * (windows.len() + 1) as Float - driving;
+                windows.push(TimeWindow::new(time, time));
+                driving += time - departure;
+                departure = time + self.duration;
+            }
+
+            driving += (to.schedule.arrival - departure).max(0.);
+        });
+
+        windows
+    }
+
+    fn duration(&self, _: &RouteBreakContext) -> Duration {
+        self.duration
+    }
+}
+
+parameterized_test! {can_apply_break_rules, (rules, expected_schedules), {
+    can_apply_break_rules_impl(rules, expected_schedules);
+}}
+
+can_apply_break_rules! {
+    case01_driving_time: (vec![Arc::new(DrivingTimeBreakRule { max_driving: 30., duration: 5. }) as Arc<dyn BreakRule>],
+        vec![(0., 0.), (45., 55.), (100., 100.)]),
+    case02_offset_span: (vec![Arc::new(ReservedTimeSpan { time: TimeSpan::Offset(TimeOffset::new(60., 60.)), duration: 5. }) as Arc<dyn BreakRule>],
+        vec![(0., 0.), (40., 50.), (95., 95.)]),
+    case03_fractional: (vec![Arc::new(FractionalReservedTime { earliest: 0.4, latest: 0.5, duration: 10. }) as Arc<dyn BreakRule>],
+        vec![(0., 0.), (40., 50.), (100., 100.)]),
+}
+
+fn can_apply_break_rules_impl(rules: Vec<Arc<dyn BreakRule>>, expected_schedules: Vec<(Timestamp, Timestamp)>) {
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1").details(vec![create_detail((Some(0), Some(0)), Some((0., 1000.)))]);
+    builder.dimens_mut().set_break_rules(rules);
+    let (_, _, route_ctx) = create_feature_and_route_with_vehicle(
+        builder.build(),
+        vec![ActivityBuilder::with_location_tw_and_duration(40, TimeWindow::new(0., 1000.), 10.).build()],
+        vec![],
+    );
+
+    assert_eq!(get_schedules(&route_ctx), expected_schedules);
+}
+
+parameterized_test! {can_skip_reserved_time_without_min_work_after, (min_work_after, expected_schedules), {
+    can_skip_reserved_time_without_min_work_after_impl(min_work_after, expected_schedules);
+}}
+
+can_skip_reserved_time_without_min_work_after! {
+    case01_no_min_work_after: (None, vec![(0., 0.), (40., 50.), (95., 95.)]),
+    case02_taken_after_last_job: (Some(10.), vec![(0., 0.), (40., 50.), (90., 90.)]),
+}
+
+fn can_skip_reserved_time_without_min_work_after_impl(
+    min_work_after: Option<Duration>,
+    expected_schedules: Vec<(Timestamp, Timestamp)>,
+) {
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1").details(vec![create_detail((Some(0), Some(0)), Some((0., 1000.)))]);
+    builder.dimens_mut().set_break_rules(vec![Arc::new(ReservedTimeSpan {
+        time: TimeSpan::Offset(TimeOffset::new(60., 60.)),
+        duration: 5.,
+    }) as Arc<dyn BreakRule>]);
+    if let Some(min_work_after) = min_work_after {
+        builder.dimens_mut().set_vehicle_min_work_after_break(min_work_after);
+    }
+    let (_, _, route_ctx) = create_feature_and_route_with_vehicle(
+        builder.build(),
+        vec![
+            ActivityBuilder::with_location_tw_and_duration(40, TimeWindow::new(0., 1000.), 10.)
+                .job(Some(TestSingleBuilder::default().build_shared()))
+                .build(),
+        ],
+        vec![],
+    );
+
+    assert_eq!(get_schedules(&route_ctx), expected_schedules);
+}
+
+parameterized_test! {can_apply_load_reserved_time, (load, expected_schedules), {
+    can_apply_load_reserved_time_impl(load, expected_schedules);
+}}
+
+can_apply_load_reserved_time! {
+    case01_first_job: (1, vec![(0., 0.), (10., 25.), (35., 40.), (50., 55.), (85., 85.)]),
+    case02_second_job: (2, vec![(0., 0.), (10., 15.), (25., 40.), (50., 55.), (85., 85.)]),
+    case03_not_reached: (4, vec![(0., 0.), (10., 15.), (25., 30.), (40., 45.), (75., 75.)]),
+}
+
+fn can_apply_load_reserved_time_impl(load: i32, expected_schedules: Vec<(Timestamp, Timestamp)>) {
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1").details(vec![create_detail((Some(0), Some(0)), Some((0., 1000.)))]);
+    builder
+        .dimens_mut()
+        .set_break_rules(vec![
+            Arc::new(LoadReservedTime { load: SingleDimLoad::new(load), duration: 10. }) as Arc<dyn BreakRule>
+        ]);
+    let create_activity = |location: Location| {
+        ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(0., 1000.), 5.)
+            .job(Some(
+                TestSingleBuilder::default().location(Some(location)).demand(create_simple_demand(-1)).build_shared(),
+            ))
+            .build()
+    };
+    let (_, _, route_ctx) = create_feature_and_route_with_vehicle(
+        builder.build(),
+        vec![create_activity(10), create_activity(20), create_activity(30)],
+        vec![],
+    );
+
+    assert_eq!(get_schedules(&route_ctx), expected_schedules);
+}
+
+parameterized_test! {can_apply_min_jobs_reserved_time, (min_jobs, expected_schedules), {
+    can_apply_min_jobs_reserved_time_impl(min_jobs, expected_schedules);
+}}
+
+can_apply_min_jobs_reserved_time! {
+    case01_natural_time: (1, vec![(0., 0.), (10., 25.), (35., 40.), (50., 55.), (85., 85.)]),
+    case02_shifted_to_second_job: (2, vec![(0., 0.), (10., 15.), (25., 40.), (50., 55.), (85., 85.)]),
+    case03_not_reached: (4, vec![(0., 0.), (10., 15.), (25., 30.), (40., 45.), (75., 75.)]),
+}
+
+fn can_apply_min_jobs_reserved_time_impl(min_jobs: usize, expected_schedules: Vec<(Timestamp, Timestamp)>) {
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1").details(vec![create_detail((Some(0), Some(0)), Some((0., 1000.)))]);
+    builder.dimens_mut().set_break_rules(vec![Arc::new(MinJobsReservedTime {
+        time: TimeSpan::Offset(TimeOffset::new(12., 12.)),
+        duration: 10.,
+        min_jobs,
+        is_counted_single: Arc::new(|_| true),
+    }) as Arc<dyn BreakRule>]);
+    let create_activity = |location: Location| {
+        ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(0., 1000.), 5.)
+            .job(Some(TestSingleBuilder::default().location(Some(location)).build_shared()))
+            .build()
+    };
+    let (_, _, route_ctx) = create_feature_and_route_with_vehicle(
+        builder.build(),
+        vec![create_activity(10), create_activity(20), create_activity(30)],
+        vec![],
+    );
+
+    assert_eq!(get_schedules(&route_ctx), expected_schedules);
+}
+
+parameterized_test! {can_apply_reload_reserved_time, (reload_id, offset, expected_schedules), {
+    can_apply_reload_reserved_time_impl(reload_id, offset, expected_schedules);
+}}
+
+can_apply_reload_reserved_time! {
+    case01_one_per_period: ("reload", 10., vec![(0., 0.), (10., 25.), (35., 40.), (50., 65.), (95., 95.)]),
+    case02_no_reload: ("job2", 10., vec![(0., 0.), (10., 25.), (35., 40.), (50., 55.), (85., 85.)]),
+    case03_period_ends_before_break: ("reload", 40., vec![(0., 0.), (10., 15.), (25., 30.), (40., 45.), (85., 85.)]),
+}
+
+fn can_apply_reload_reserved_time_impl(
+    reload_id: &str,
+    offset: Timestamp,
+    expected_schedules: Vec<(Timestamp, Timestamp)>,
+) {
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1").details(vec![create_detail((Some(0), Some(0)), Some((0., 1000.)))]);
+    builder.dimens_mut().set_break_rules(vec![Arc::new(ReloadReservedTime {
+        offset: TimeOffset::new(offset, offset),
+        duration: 10.,
+        is_reload_single: Arc::new(|single| single.dimens.get_job_id().is_some_and(|id| id == "reload")),
+    }) as Arc<dyn BreakRule>]);
+    let create_activity = |id: &str, location: Location| {
+        ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(0., 1000.), 5.)
+            .job(Some(TestSingleBuilder::default().id(id).location(Some(location)).build_shared()))
+            .build()
+    };
+    let (_, _, route_ctx) = create_feature_and_route_with_vehicle(
+        builder.build(),
+        vec![create_activity("job1", 10), create_activity(reload_id, 20), create_activity("job3", 30)],
+        vec![],
+    );
+
+    assert_eq!(get_schedules(&route_ctx), expected_schedules);
+}
+
+parameterized_test! {can_apply_interval_reserved_time, (fixed_time, expected_starts, expected_end), {
+    can_apply_interval_reserved_time_impl(fixed_time, expected_starts, expected_end);
+}}
+
+can_apply_interval_reserved_time! {
+    case01_chained_from_start: (None, vec![40., 85., 130., 175.], 220.),
+    case02_chained_from_fixed: (Some(((50., 60.), 10.)), vec![40., 110., 155.], 225.),
+    case03_restarted_by_fixed: (Some(((100., 100.), 10.)), vec![40., 85., 150., 195.], 230.),
+}
+
+fn can_apply_interval_reserved_time_impl(
+    fixed_time: Option<((Timestamp, Timestamp), Duration)>,
+    expected_starts: Vec<Timestamp>,
+    expected_end: Timestamp,
+) {
+    let fixed_times = fixed_time
+        .map(|((start, end), duration)| ReservedTimeSpan {
+            time: TimeSpan::Window(TimeWindow::new(start, end)),
+            duration,
+        })
+        .into_iter()
+        .collect::<Vec<_>>();
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1").details(vec![create_detail((Some(0), Some(0)), Some((0., 200.)))]);
+    builder.dimens_mut().set_vehicle_reserved_times(fixed_times.clone());
+    builder
+        .dimens_mut()
+        .set_break_rules(vec![Arc::new(IntervalReservedTime { interval: 40., duration: 5. }) as Arc<dyn BreakRule>]);
+    let create_activity = |location: Location| {
+        ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::new(0., 1000.), 20.)
+            .job(Some(TestSingleBuilder::default().location(Some(location)).build_shared()))
+            .build()
+    };
+    let (_, _, route_ctx) = create_feature_and_route_with_vehicle(
+        builder.build(),
+        (1..=5).map(|idx| create_activity(idx * 10)).collect(),
+        fixed_times,
+    );
+
+    let route = route_ctx.route();
+    let starts = get_route_reserved_time_spans(route)
+        .map(|reserved_time| reserved_time.to_reserved_time_window(get_offset_anchor(route)).time.end)
+        .collect::<Vec<_>>();
+    assert_eq!(starts, expected_starts);
+    assert_eq!(route.tour.end().map(|end| end.schedule.arrival), Some(expected_end));
+}
@@ -0,0 +1,53 @@
+use super::*;
+use crate::construction::enablers::ReservedTimeSpan;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_route_ctx(is_preferred: bool) -> RouteContext {
+    let mut builder = TestVehicleBuilder::default();
+    builder.id("v1");
+    builder.dimens_mut().set_vehicle_prefer_break_on_transit(is_preferred);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(builder.build()).build();
+
+    let mut route = RouteBuilder::default()
+        .with_vehicle(&fleet, "v1")
+        .add_activity(ActivityBuilder::with_location(10).schedule(Schedule::new(10., 20.)).build())
+        .add_activity(ActivityBuilder::with_location(50).schedule(Schedule::new(50., 60.)).build())
+        .build();
+    let end_idx = route.tour.total() - 1;
+    route.tour.get_mut(end_idx).unwrap().schedule = Schedule::new(110., 110.);
+
+    RouteContextBuilder::default().with_route(route).build()
+}
+
+parameterized_test! {can_count_breaks_placed_off_transit, (is_preferred, reserved_time, expected), {
+    can_count_breaks_placed_off_transit_impl(is_preferred, reserved_time, expected);
+}}
+
+can_count_breaks_placed_off_transit! {
+    case01_on_transit: (true, (25., 25.), 0.),
+    case02_at_stop: (true, (12., 12.), 1.),
+    case03_starts_at_stop_ends_on_transit: (true, (18., 18.), 1.),
+    case04_outside_tour: (true, (200., 200.), 0.),
+    case05_at_stop_without_preference: (false, (12., 12.), 0.),
+}
+
+fn can_count_breaks_placed_off_transit_impl(is_preferred: bool, reserved_time: (Timestamp, Timestamp), expected: Cost) {
+    let route_ctx = create_route_ctx(is_preferred);
+    let reserved_times_index = vec![(
+        route_ctx.route().actor.clone(),
+        vec![ReservedTimeSpan {
+            time: TimeSpan::Window(TimeWindow::new(reserved_time.0, reserved_time.1)),
+            duration: 5.,
+        }],
+    )]
+    .into_iter()
+    .collect();
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+    let feature = create_break_on_transit_feature("break_on_transit", reserved_times_index).unwrap();
+
+    let fitness = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
@@ -0,0 +1,95 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use std::sync::Arc;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_break_activity(after: Option<&str>, before: Option<&str>) -> Activity {
+    let mut single = TestSingleBuilder::default().id("break").build();
+    single.dimens.set_break_job_order(BreakJobOrder {
+        after: after.map(|id| id.to_string()),
+        before: before.map(|id| id.to_string()),
+    });
+
+    ActivityBuilder::default().job(Some(Arc::new(single))).build()
+}
+
+fn create_job_activity(id: &str) -> Activity {
+    ActivityBuilder::default().job(Some(TestSingleBuilder::default().id(id).build_shared())).build()
+}
+
+fn evaluate(activities: Vec<Activity>, index: usize, target: &Activity) -> Option<ConstraintViolation> {
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&test_fleet(), "v1").add_activities(activities).build())
+        .build();
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let constraint = create_break_order_feature("break_order", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    constraint.evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx))
+}
+
+parameterized_test! {can_evaluate_break_insertion, (order, index, expected), {
+    can_evaluate_break_insertion_impl(order, index, expected);
+}}
+
+can_evaluate_break_insertion! {
+    case01_before_job_a: ((Some("a"), Some("b")), 0, ConstraintViolation::skip(VIOLATION_CODE)),
+    case02_between_jobs: ((Some("a"), Some("b")), 1, None),
+    case03_after_job_b: ((Some("a"), Some("b")), 2, ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_no_order: ((None, None), 0, None),
+    case05_missing_job: ((Some("c"), None), 0, None),
+    case06_only_before: ((None, Some("a")), 0, None),
+    case07_only_before: ((None, Some("a")), 1, ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_evaluate_break_insertion_impl(
+    order: (Option<&str>, Option<&str>),
+    index: usize,
+    expected: Option<ConstraintViolation>,
+) {
+    let target = create_break_activity(order.0, order.1);
+
+    let result = evaluate(vec![create_job_activity("a"), create_job_activity("b")], index, &target);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_job_insertion, (job_id, index, expected), {
+    can_evaluate_job_insertion_impl(job_id, index, expected);
+}}
+
+can_evaluate_job_insertion! {
+    case01_job_a_before_break: ("a", 0, None),
+    case02_job_a_after_break: ("a", 1, ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_job_b_before_break: ("b", 0, ConstraintViolation::skip(VIOLATION_CODE)),
+    case04_job_b_after_break: ("b", 1, None),
+    case05_other_job: ("c", 0, None),
+}
+
+fn can_evaluate_job_insertion_impl(job_id: &str, index: usize, expected: Option<ConstraintViolation>) {
+    let target = create_job_activity(job_id);
+
+    let result = evaluate(vec![create_break_activity(Some("a"), Some("b"))], index, &target);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_merge_jobs_without_break_order() {
+    let constraint = create_break_order_feature("break_order", VIOLATION_CODE).unwrap().constraint.unwrap();
+    let job = |activity: Activity| Job::Single(activity.job.unwrap());
+
+    assert!(constraint.merge(job(create_job_activity("a")), job(create_job_activity("b"))).is_ok());
+    assert_eq!(
+        constraint.merge(job(create_job_activity("a")), job(create_break_activity(Some("a"), None))).err(),
+        Some(VIOLATION_CODE)
+    );
+}
@@ -1,8 +1,8 @@
 use super::*;
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
-use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::problem::{TestSingleBuilder, TestTransportCost};
 use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
-use crate::models::common::Location;
+use crate::models::common::{Duration, Location, Schedule, TimeWindow};
 use crate::models::problem::Job;
 use crate::models::problem::Single;
 use std::sync::Arc;
@@ -12,7 +12,7 @@ const VIOLATION_CODE: ViolationCode = ViolationCode(1);
 struct JobTypeDimenKey;
 struct VehicleIdDimenKey;
 
-fn create_break_feature() -> Feature {
+fn create_break_feature(min_work_after: Option<Duration>) -> Feature {
     fn is_break_job(single: &Single) -> bool {
         single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "break")
     }
@@ -28,6 +28,8 @@ fn create_break_feature() -> Feature {
 
             job_vehicle_id.zip(vehicle_id).is_some_and(|(a, b)| a == b)
         })
+        .set_min_work_after(move |_| min_work_after)
+        .set_transport(TestTransportCost::new_shared())
         .build()
         .unwrap()
 }
@@ -75,7 +77,7 @@ fn can_remove_orphan_break_impl(break_job_loc: Option<Location>, break_activity_
         ])
         .build()
         .solution;
-    let feature = create_break_feature();
+    let feature = create_break_feature(None);
 
     feature.state.unwrap().accept_solution_state(&mut solution_ctx);
 
@@ -104,9 +106,65 @@ can_skip_merge_breaks! {
 }
 
 fn can_skip_merge_breaks_impl(source: Job, candidate: Job, expected: Result<(), ViolationCode>) {
-    let feature = create_break_feature();
+    let feature = create_break_feature(None);
 
     let result = feature.constraint.unwrap().merge(source, candidate).map(|_| ());
 
     assert_eq!(result, expected);
 }
+
+parameterized_test! {can_count_work_after_break_from_break_departure, (min_work_after, expected), {
+    can_count_work_after_break_from_break_departure_impl(min_work_after, expected);
+}}
+
+can_count_work_after_break_from_break_departure! {
+    case01_enough_work_after: (25., None),
+    case02_not_enough_work_after: (26., ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_count_work_after_break_from_break_departure_impl(
+    min_work_after: Duration,
+    expected: Option<ConstraintViolation>,
+) {
+    let create_job_activity = |id: &str, location: Location, tw: (Float, Float), schedule: (Float, Float)| {
+        ActivityBuilder::with_location_and_tw(location, TimeWindow::new(tw.0, tw.1))
+            .job(Some(create_single(id, location)))
+            .schedule(Schedule::new(schedule.0, schedule.1))
+            .build()
+    };
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::with_default_vehicle()
+                .add_activity(create_job_activity("job1", 10, (0., 1000.), (10., 10.)))
+                .add_activity(create_job_activity("job2", 20, (30., 1000.), (20., 30.)))
+                .add_activity(create_job_activity("job3", 30, (0., 1000.), (40., 40.)))
+                .build(),
+        )
+        .build();
+    let break_single = TestSingleBuilder::default()
+        .id("break")
+        .duration(5.)
+        .property::<JobTypeDimenKey, _>("break".to_string())
+        .property::<VehicleIdDimenKey, _>("v1".to_string())
+        .build_shared();
+    let target = ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(0., 1000.), 5.)
+        .job(Some(break_single))
+        .build();
+    let route = route_ctx.route();
+    let activity_ctx =
+        ActivityContext { index: 1, prev: route.tour.get(1).unwrap(), target: &target, next: route.tour.get(2) };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let feature = create_break_feature(Some(min_work_after));
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_build_break_feature_without_transport() {
+    let builder = || BreakFeatureBuilder::new("break").set_is_break_single(|_| true);
+
+    assert!(builder().build().is_ok());
+    assert!(builder().set_min_work_after(|_| Some(10.)).build().is_err());
+}
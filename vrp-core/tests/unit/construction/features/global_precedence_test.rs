@@ -0,0 +1,266 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+use crate::models::common::Schedule;
+use crate::models::problem::Single;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+struct TestJobs {
+    predecessor: Arc<Single>,
+    successor: Arc<Single>,
+}
+
+impl TestJobs {
+    fn new() -> Self {
+        Self {
+            predecessor: TestSingleBuilder::default().id("predecessor").build_shared(),
+            successor: TestSingleBuilder::default().id("successor").build_shared(),
+        }
+    }
+
+    fn create_feature(&self) -> Feature {
+        let pairs = vec![(Job::Single(self.predecessor.clone()), Job::Single(self.successor.clone()))];
+        create_global_precedence_feature("global_precedence", pairs, VIOLATION_CODE).unwrap()
+    }
+}
+
+fn create_activity(single: &Arc<Single>, location: usize, schedule: (Float, Float)) -> Activity {
+    ActivityBuilder::with_location(location)
+        .job(Some(single.clone()))
+        .schedule(Schedule::new(schedule.0, schedule.1))
+        .build()
+}
+
+fn create_insertion_ctx(routes: Vec<(&str, Vec<Activity>)>, feature: &Feature) -> InsertionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let routes = routes
+        .into_iter()
+        .map(|(vehicle_id, activities)| {
+            RouteContextBuilder::default()
+                .with_route(RouteBuilder::default().with_vehicle(&fleet, vehicle_id).add_activities(activities).build())
+                .build()
+        })
+        .collect();
+    let mut insertion_ctx = TestInsertionContextBuilder::default().with_routes(routes).build();
+    let state = feature.state.as_ref().unwrap();
+    insertion_ctx.solution.routes.iter_mut().for_each(|route_ctx| state.accept_route_state(route_ctx));
+    state.accept_solution_state(&mut insertion_ctx.solution);
+
+    insertion_ctx
+}
+
+parameterized_test! {can_check_precedence_on_route_level, (assigned, job, expected), {
+    can_check_precedence_on_route_level_impl(assigned, job, expected);
+}}
+
+can_check_precedence_on_route_level! {
+    case01_successor_without_predecessor: (None, "successor", ConstraintViolation::fail(VIOLATION_CODE)),
+    case02_successor_with_predecessor: (Some("predecessor"), "successor", None),
+    case03_predecessor_without_successor: (None, "predecessor", None),
+    case04_predecessor_with_successor: (Some("successor"), "predecessor", None),
+}
+
+fn can_check_precedence_on_route_level_impl(assigned: Option<&str>, job: &str, expected: Option<ConstraintViolation>) {
+    let jobs = TestJobs::new();
+    let get_single = |id: &str| if id == "predecessor" { jobs.predecessor.clone() } else { jobs.successor.clone() };
+    let feature = jobs.create_feature();
+    let activities = assigned.map(|id| vec![create_activity(&get_single(id), 10, (10., 20.))]).unwrap_or_default();
+    let insertion_ctx = create_insertion_ctx(vec![("v1", activities)], &feature);
+    let job = Job::Single(get_single(job));
+
+    let result = feature.constraint.unwrap().evaluate(&MoveContext::route(
+        &insertion_ctx.solution,
+        &insertion_ctx.solution.routes[0],
+        &job,
+    ));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_precedence_across_routes_on_activity_level, (prev_departure, expected), {
+    can_check_precedence_across_routes_on_activity_level_impl(prev_departure, expected);
+}}
+
+can_check_precedence_across_routes_on_activity_level! {
+    case01_starts_before_completion: (30., ConstraintViolation::skip(VIOLATION_CODE)),
+    case02_starts_at_completion: (50., None),
+    case03_starts_after_completion: (60., None),
+}
+
+fn can_check_precedence_across_routes_on_activity_level_impl(
+    prev_departure: Float,
+    expected: Option<ConstraintViolation>,
+) {
+    let jobs = TestJobs::new();
+    let feature = jobs.create_feature();
+    let other = TestSingleBuilder::default().id("other").build_shared();
+    let insertion_ctx = create_insertion_ctx(
+        vec![
+            ("v1", vec![create_activity(&jobs.predecessor, 10, (40., 50.))]),
+            ("v2", vec![create_activity(&other, 20, (prev_departure - 10., prev_departure))]),
+        ],
+        &feature,
+    );
+    let route_ctx = &insertion_ctx.solution.routes[1];
+    let target = create_activity(&jobs.successor, 30, (0., 0.));
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+
+    let result =
+        feature.constraint.unwrap().evaluate(&MoveContext::activity(&insertion_ctx.solution, route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_penalize_successor_scheduled_before_predecessor, (has_predecessor, successor_schedule, expected), {
+    can_penalize_successor_scheduled_before_predecessor_impl(has_predecessor, successor_schedule, expected);
+}}
+
+can_penalize_successor_scheduled_before_predecessor! {
+    case01_successor_before_completion: (true, (10., 20.), 1.),
+    case02_successor_after_completion: (true, (60., 70.), 0.),
+    case03_successor_without_predecessor: (false, (60., 70.), 1.),
+}
+
+fn can_penalize_successor_scheduled_before_predecessor_impl(
+    has_predecessor: bool,
+    successor_schedule: (Float, Float),
+    expected: Cost,
+) {
+    let jobs = TestJobs::new();
+    let feature = jobs.create_feature();
+    let predecessors = if has_predecessor { vec![create_activity(&jobs.predecessor, 10, (40., 50.))] } else { vec![] };
+    let insertion_ctx = create_insertion_ctx(
+        vec![("v1", predecessors), ("v2", vec![create_activity(&jobs.successor, 20, successor_schedule)])],
+        &feature,
+    );
+
+    let fitness = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+parameterized_test! {can_reinsert_removed_predecessor_before_successor, (prev_departure, expected), {
+    can_reinsert_removed_predecessor_before_successor_impl(prev_departure, expected);
+}}
+
+can_reinsert_removed_predecessor_before_successor! {
+    case01_completes_before_successor: (30., None),
+    case02_completes_at_successor_start: (40., None),
+    case03_completes_after_successor_start: (45., ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_reinsert_removed_predecessor_before_successor_impl(
+    prev_departure: Float,
+    expected: Option<ConstraintViolation>,
+) {
+    let jobs = TestJobs::new();
+    let feature = jobs.create_feature();
+    let other = TestSingleBuilder::default().id("other").build_shared();
+    let mut insertion_ctx = create_insertion_ctx(
+        vec![
+            (
+                "v1",
+                vec![
+                    create_activity(&jobs.predecessor, 10, (20., 30.)),
+                    create_activity(&jobs.successor, 10, (40., 50.)),
+                ],
+            ),
+            ("v2", vec![create_activity(&other, 20, (prev_departure - 10., prev_departure))]),
+        ],
+        &feature,
+    );
+    let predecessor = Job::Single(jobs.predecessor.clone());
+    insertion_ctx.solution.routes[0].route_mut().tour.remove(&predecessor);
+    feature.state.as_ref().unwrap().accept_solution_state(&mut insertion_ctx.solution);
+    let constraint = feature.constraint.as_ref().unwrap();
+    let route_ctx = &insertion_ctx.solution.routes[1];
+    let target = create_activity(&jobs.predecessor, 30, (0., 0.));
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(2),
+    };
+
+    let route_result = constraint.evaluate(&MoveContext::route(&insertion_ctx.solution, route_ctx, &predecessor));
+    let activity_result =
+        constraint.evaluate(&MoveContext::activity(&insertion_ctx.solution, route_ctx, &activity_ctx));
+    let fitness = feature.objective.as_ref().unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(route_result, None);
+    assert_eq!(activity_result, expected);
+    assert_eq!(fitness, 1.);
+}
+
+parameterized_test! {can_check_delay_of_predecessor_in_existing_route, (duration, expected), {
+    can_check_delay_of_predecessor_in_existing_route_impl(duration, expected);
+}}
+
+can_check_delay_of_predecessor_in_existing_route! {
+    case01_no_delay: (30., None),
+    case02_completes_at_successor_start: (50., None),
+    case03_completes_after_successor_start: (55., ConstraintViolation::skip(VIOLATION_CODE)),
+}
+
+fn can_check_delay_of_predecessor_in_existing_route_impl(duration: Float, expected: Option<ConstraintViolation>) {
+    let jobs = TestJobs::new();
+    let feature = jobs.create_feature();
+    let insertion_ctx = create_insertion_ctx(
+        vec![
+            ("v1", vec![create_activity(&jobs.predecessor, 10, (40., 50.))]),
+            ("v2", vec![create_activity(&jobs.successor, 20, (60., 70.))]),
+        ],
+        &feature,
+    );
+    let route_ctx = &insertion_ctx.solution.routes[0];
+    let target = ActivityBuilder::with_location_tw_and_duration(5, TimeWindow::new(0., 1000.), duration).build();
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let result =
+        feature.constraint.unwrap().evaluate(&MoveContext::activity(&insertion_ctx.solution, route_ctx, &activity_ctx));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case01_no_precedence: ("other", "another", Ok(())),
+    case02_source_predecessor: ("predecessor", "other", Err(VIOLATION_CODE)),
+    case03_source_successor: ("successor", "other", Err(VIOLATION_CODE)),
+    case04_candidate_predecessor: ("other", "predecessor", Err(VIOLATION_CODE)),
+    case05_candidate_successor: ("other", "successor", Err(VIOLATION_CODE)),
+}
+
+fn can_merge_jobs_impl(source: &str, candidate: &str, expected: Result<(), ViolationCode>) {
+    let jobs = TestJobs::new();
+    let feature = jobs.create_feature();
+    let get_job = |id: &str| {
+        Job::Single(match id {
+            "predecessor" => jobs.predecessor.clone(),
+            "successor" => jobs.successor.clone(),
+            _ => TestSingleBuilder::default().id(id).build_shared(),
+        })
+    };
+
+    let result = feature.constraint.unwrap().merge(get_job(source), get_job(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
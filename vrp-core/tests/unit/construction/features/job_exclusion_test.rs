@@ -0,0 +1,95 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::domain::{ProblemBuilder, TestGoalContextBuilder, get_customer_ids_from_routes_sorted};
+use crate::helpers::models::problem::{FleetBuilder, TestSingleBuilder, test_driver, test_vehicle_with_id};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::helpers::solver::create_default_refinement_ctx;
+use crate::solver::search::{Recreate, RecreateWithCheapest};
+use rosomaxa::prelude::Environment;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_feature() -> Feature {
+    let pairs = vec![("food".to_string(), "chemicals".to_string())];
+    create_job_exclusion_feature("job_exclusion", pairs, VIOLATION_CODE).unwrap()
+}
+
+fn create_test_job(id: &str) -> Job {
+    Job::Single(TestSingleBuilder::default().id(id).location(Some(1)).build_shared())
+}
+
+fn create_test_route_ctx(feature: &Feature, job_id: Option<&str>) -> RouteContext {
+    let activities = job_id
+        .map(|id| vec![ActivityBuilder::with_location(1).job(create_test_job(id).as_single().cloned()).build()])
+        .unwrap_or_default();
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::with_default_vehicle().add_activities(activities).build())
+        .build();
+    feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+    route_ctx
+}
+
+parameterized_test! {can_check_exclusion_on_route_level, (route_job, job, expected), {
+    can_check_exclusion_on_route_level_impl(route_job, job, expected);
+}}
+
+can_check_exclusion_on_route_level! {
+    case01_partner_in_route: (Some("food"), "chemicals", ConstraintViolation::fail(VIOLATION_CODE)),
+    case02_partner_in_route_reversed: (Some("chemicals"), "food", ConstraintViolation::fail(VIOLATION_CODE)),
+    case03_other_job_in_route: (Some("other"), "chemicals", None),
+    case04_empty_route: (None, "chemicals", None),
+    case05_not_excluded_job: (Some("food"), "other", None),
+}
+
+fn can_check_exclusion_on_route_level_impl(route_job: Option<&str>, job: &str, expected: Option<ConstraintViolation>) {
+    let feature = create_feature();
+    let solution_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![create_test_route_ctx(&feature, route_job)])
+        .build()
+        .solution;
+    let job = create_test_job(job);
+
+    let result =
+        feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &solution_ctx.routes[0], &job));
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_merge_jobs, (source, candidate, expected), {
+    can_merge_jobs_impl(source, candidate, expected);
+}}
+
+can_merge_jobs! {
+    case01_excluded: ("food", "chemicals", Err(VIOLATION_CODE)),
+    case02_not_excluded: ("food", "other", Ok(())),
+}
+
+fn can_merge_jobs_impl(source: &str, candidate: &str, expected: Result<(), ViolationCode>) {
+    let constraint = create_feature().constraint.unwrap();
+
+    let result = constraint.merge(create_test_job(source), create_test_job(candidate)).map(|_| ());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_assign_excluded_jobs_to_separate_routes() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_vehicle_with_id("v1"), test_vehicle_with_id("v2")])
+        .build();
+    let problem = ProblemBuilder::default()
+        .with_fleet(fleet)
+        .with_jobs(vec![create_test_job("food"), create_test_job("chemicals")])
+        .with_goal(TestGoalContextBuilder::with_transport_feature().add_feature(create_feature()).build())
+        .build();
+    let insertion_ctx = InsertionContext::new(Arc::new(problem), Arc::new(Environment::default()));
+    let refinement_ctx = create_default_refinement_ctx(insertion_ctx.problem.clone());
+
+    let insertion_ctx =
+        RecreateWithCheapest::new(insertion_ctx.environment.random.clone()).run(&refinement_ctx, insertion_ctx);
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), vec![vec!["chemicals"], vec!["food"]]);
+}
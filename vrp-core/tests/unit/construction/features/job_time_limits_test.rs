@@ -3,7 +3,10 @@ use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
 use crate::models::common::{Schedule, TimeWindow};
-use crate::models::problem::{JobTimeConstraints, JobTimeConstraintsDimension};
+use crate::models::problem::{
+    DepartureReference, FirstJobReference, JobTimeConstraints, JobTimeConstraintsDimension, LastJobReference,
+    RouteCostSpan, RouteCostSpanDimension,
+};
 use crate::models::solution::{Activity, Place};
 
 const VIOLATION_CODE: ViolationCode = ViolationCode(1);
@@ -19,9 +22,24 @@ fn create_feature() -> Feature {
 }
 
 fn create_fleet_with_job_time_constraints(id: &str, earliest_first: Option<f64>, latest_last: Option<f64>) -> Fleet {
+    create_fleet_with_first_job_reference(id, earliest_first, latest_last, FirstJobReference::default())
+}
+
+fn create_fleet_with_first_job_reference(
+    id: &str,
+    earliest_first: Option<f64>,
+    latest_last: Option<f64>,
+    first_job_reference: FirstJobReference,
+) -> Fleet {
     let mut builder = TestVehicleBuilder::default();
     builder.id(id);
-    builder.dimens_mut().set_job_time_constraints(JobTimeConstraints { earliest_first, latest_last });
+    builder.dimens_mut().set_job_time_constraints(JobTimeConstraints {
+        earliest_first,
+        latest_last,
+        first_job_reference,
+        last_job_reference: LastJobReference::default(),
+        departure_reference: DepartureReference::default(),
+    });
 
     FleetBuilder::default().add_driver(test_driver()).add_vehicle(builder.build()).build()
 }
@@ -119,6 +137,214 @@ mod earliest_first_constraint {
     }
 }
 
+mod schedule {
+    use super::*;
+    use crate::construction::enablers::{LatestArrivalActivityState, update_route_schedule};
+
+    fn create_route_ctx(fleet: &Fleet, locations: &[usize]) -> RouteContext {
+        let activities = locations
+            .iter()
+            .map(|&location| ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::max(), 0.).build())
+            .collect::<Vec<_>>();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(fleet, "v1").add_activities(activities).build())
+            .build();
+        update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+
+        route_ctx
+    }
+
+    #[test]
+    fn waits_at_first_job_till_earliest_first() {
+        let fleet = create_fleet_with_job_time_constraints("v1", Some(15.), None);
+
+        let route_ctx = create_route_ctx(&fleet, &[10, 20]);
+
+        let schedules = route_ctx.route().tour.all_activities().map(|a| a.schedule.clone()).collect::<Vec<_>>();
+        assert_eq!(schedules[1], Schedule::new(10., 15.));
+        assert_eq!(schedules[2], Schedule::new(25., 25.));
+    }
+
+    #[test]
+    fn limits_latest_arrival_of_last_job() {
+        let fleet = create_fleet_with_job_time_constraints("v1", None, Some(25.));
+
+        let route_ctx = create_route_ctx(&fleet, &[10, 20]);
+
+        assert_eq!(route_ctx.state().get_latest_arrival_at(2), Some(&25.));
+        assert_eq!(route_ctx.state().get_latest_arrival_at(1), Some(&15.));
+    }
+
+    #[test]
+    fn rejects_waiting_at_first_job_when_next_job_is_late() {
+        let fleet = create_fleet_with_job_time_constraints("v1", Some(15.), None);
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let next = ActivityBuilder::with_location_tw_and_duration(20, TimeWindow::new(0., 22.), 0.).build();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activity(next.deep_copy()).build())
+            .build();
+        update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+        let feature = create_feature();
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &solution_ctx,
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: &create_depot_activity(0, 0.),
+                target: &ActivityBuilder::with_location_and_tw(10, TimeWindow::new(0., 100.)).build(),
+                next: Some(&next),
+            },
+        ));
+
+        assert_eq!(result, ConstraintViolation::skip(VIOLATION_CODE));
+    }
+}
+
+mod first_job_reference {
+    use super::*;
+
+    parameterized_test! {can_check_earliest_first_against_reference, (first_job_reference, latest_last, location, expected), {
+        can_check_earliest_first_against_reference_impl(first_job_reference, latest_last, location, expected);
+    }}
+
+    can_check_earliest_first_against_reference! {
+        case01_early_arrival_with_service_start: (FirstJobReference::ServiceStart, None, 10, None),
+        case02_early_arrival_with_arrival: (FirstJobReference::Arrival, None, 10, Some(VIOLATION_CODE)),
+        case03_late_arrival_with_service_start: (FirstJobReference::ServiceStart, None, 20, None),
+        case04_late_arrival_with_arrival: (FirstJobReference::Arrival, None, 20, None),
+        case05_waiting_exceeds_latest_last: (FirstJobReference::ServiceStart, Some(14.), 10, Some(VIOLATION_CODE)),
+    }
+
+    fn can_check_earliest_first_against_reference_impl(
+        first_job_reference: FirstJobReference,
+        latest_last: Option<f64>,
+        location: usize,
+        expected: Option<ViolationCode>,
+    ) {
+        // earliest_first is 15, vehicle departs at 0 and arrives at the job at its location
+        let fleet = create_fleet_with_first_job_reference("v1", Some(15.0), latest_last, first_job_reference);
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build())
+            .build();
+        let feature = create_feature();
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &solution_ctx,
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: &create_depot_activity(0, 0.0), // Start depot
+                target: &ActivityBuilder::with_location_and_tw(location, TimeWindow::new(0.0, 100.0)).build(),
+                next: None,
+            },
+        ));
+
+        assert_eq!(result, expected.and_then(ConstraintViolation::skip));
+    }
+}
+
+mod last_job_reference {
+    use super::*;
+
+    parameterized_test! {can_check_latest_last_against_reference, (last_job_reference, duration, expected), {
+        can_check_latest_last_against_reference_impl(last_job_reference, duration, expected);
+    }}
+
+    can_check_latest_last_against_reference! {
+        case01_short_service_with_departure: (LastJobReference::Departure, 5., None),
+        case02_long_service_with_departure: (LastJobReference::Departure, 30., Some(VIOLATION_CODE)),
+        case03_short_service_with_arrival: (LastJobReference::Arrival, 5., None),
+        case04_long_service_with_arrival: (LastJobReference::Arrival, 30., None),
+    }
+
+    fn can_check_latest_last_against_reference_impl(
+        last_job_reference: LastJobReference,
+        duration: f64,
+        expected: Option<ViolationCode>,
+    ) {
+        // latest_last is 20, vehicle departs at 0 and arrives at the job at 10
+        let mut builder = TestVehicleBuilder::default();
+        builder.id("v1");
+        builder.dimens_mut().set_job_time_constraints(JobTimeConstraints {
+            earliest_first: None,
+            latest_last: Some(20.0),
+            first_job_reference: FirstJobReference::default(),
+            last_job_reference,
+            departure_reference: DepartureReference::default(),
+        });
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(builder.build()).build();
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build())
+            .build();
+        let target = ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(0.0, 100.0), duration).build();
+
+        let result = create_feature().constraint.unwrap().evaluate(&MoveContext::activity(
+            &solution_ctx,
+            &route_ctx,
+            &ActivityContext { index: 0, prev: &create_depot_activity(0, 0.0), target: &target, next: None },
+        ));
+
+        assert_eq!(result, expected.and_then(ConstraintViolation::skip));
+    }
+}
+
+mod open_start {
+    use super::*;
+
+    parameterized_test! {can_apply_earliest_first_to_actual_first_job, (span, index, expected), {
+        can_apply_earliest_first_to_actual_first_job_impl(span, index, expected);
+    }}
+
+    can_apply_earliest_first_to_actual_first_job! {
+        case01_first_job_to_depot_as_first: (RouteCostSpan::FirstJobToDepot, 0, Some(VIOLATION_CODE)),
+        case02_first_job_to_depot_as_second: (RouteCostSpan::FirstJobToDepot, 1, None),
+        case03_first_job_to_last_job_as_first: (RouteCostSpan::FirstJobToLastJob, 0, Some(VIOLATION_CODE)),
+        case04_first_job_to_last_job_as_second: (RouteCostSpan::FirstJobToLastJob, 1, None),
+    }
+
+    fn can_apply_earliest_first_to_actual_first_job_impl(
+        span: RouteCostSpan,
+        index: usize,
+        expected: Option<ViolationCode>,
+    ) {
+        // Route costs start at the first job, but the tour still has a start activity: earliest_first is 15,
+        // the job at location 10 arrives at 10 and its time window ends at 12, so it cannot be the first job.
+        let mut builder = TestVehicleBuilder::default();
+        builder.id("v1");
+        builder.dimens_mut().set_route_cost_span(span).set_job_time_constraints(JobTimeConstraints {
+            earliest_first: Some(15.0),
+            latest_last: None,
+            first_job_reference: FirstJobReference::default(),
+            last_job_reference: LastJobReference::default(),
+            departure_reference: DepartureReference::default(),
+        });
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(builder.build()).build();
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&fleet, "v1")
+                    .add_activity(ActivityBuilder::with_location(5).schedule(Schedule::new(15., 16.)).build())
+                    .build(),
+            )
+            .build();
+        let route = route_ctx.route();
+        let (prev, next) = (route.tour.get(index).unwrap(), route.tour.get(index + 1));
+        let target = ActivityBuilder::with_location_and_tw(10, TimeWindow::new(0.0, 12.0)).build();
+
+        let result = create_feature().constraint.unwrap().evaluate(&MoveContext::activity(
+            &solution_ctx,
+            &route_ctx,
+            &ActivityContext { index, prev, target: &target, next },
+        ));
+
+        assert_eq!(result, expected.and_then(ConstraintViolation::skip));
+    }
+}
+
 mod latest_last_constraint {
     use super::*;
 
@@ -334,3 +560,250 @@ mod no_constraints {
         assert_eq!(result, None);
     }
 }
+
+mod departure_reference {
+    use super::*;
+
+    parameterized_test! {can_check_earliest_first_against_departure_reference, (departure_reference, expected), {
+        can_check_earliest_first_against_departure_reference_impl(departure_reference, expected);
+    }}
+
+    can_check_earliest_first_against_departure_reference! {
+        case01_actual_departure: (DepartureReference::ActualDeparture, None),
+        case02_scheduled_start: (DepartureReference::ScheduledStart, Some(VIOLATION_CODE)),
+    }
+
+    fn can_check_earliest_first_against_departure_reference_impl(
+        departure_reference: DepartureReference,
+        expected: Option<ViolationCode>,
+    ) {
+        // earliest_first is 15, shift starts at 0, but departure is advanced to 8:
+        // arrival at the job is 18 from actual departure and 10 from scheduled start
+        let mut builder = TestVehicleBuilder::default();
+        builder.id("v1");
+        builder.dimens_mut().set_job_time_constraints(JobTimeConstraints {
+            earliest_first: Some(15.0),
+            latest_last: None,
+            first_job_reference: FirstJobReference::Arrival,
+            last_job_reference: LastJobReference::default(),
+            departure_reference,
+        });
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(builder.build()).build();
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build())
+            .build();
+        let feature = create_feature();
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &solution_ctx,
+            &route_ctx,
+            &ActivityContext {
+                index: 0,
+                prev: &create_depot_activity(0, 8.0),
+                target: &ActivityBuilder::with_location_and_tw(10, TimeWindow::new(0.0, 100.0)).build(),
+                next: Some(&create_depot_activity(0, 30.0)),
+            },
+        ));
+
+        assert_eq!(result.map(|violation| violation.code), expected);
+    }
+}
+
+mod evaluation_cost {
+    use super::*;
+    use crate::models::common::{Distance, Duration, Location, Profile};
+    use crate::models::problem::{TransportCost, TravelTime};
+    use crate::models::solution::Route;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A transport which counts how many times route duration is requested.
+    #[derive(Default)]
+    struct CountingTransportCost {
+        inner: TestTransportCost,
+        calls: AtomicUsize,
+    }
+
+    impl TransportCost for CountingTransportCost {
+        fn duration_approx(&self, profile: &Profile, from: Location, to: Location) -> Duration {
+            self.inner.duration_approx(profile, from, to)
+        }
+
+        fn distance_approx(&self, profile: &Profile, from: Location, to: Location) -> Distance {
+            self.inner.distance_approx(profile, from, to)
+        }
+
+        fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            self.inner.duration(route, from, to, travel_time)
+        }
+
+        fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance {
+            self.inner.distance(route, from, to, travel_time)
+        }
+
+        fn size(&self) -> usize {
+            self.inner.size()
+        }
+    }
+
+    parameterized_test! {can_limit_duration_calls, (is_first, is_last, departure, expected_calls), {
+        can_limit_duration_calls_impl(is_first, is_last, departure, expected_calls);
+    }}
+
+    can_limit_duration_calls! {
+        case01_middle_job: (false, false, 0., 0),
+        case02_first_job_on_schedule: (true, false, 0., 1),
+        case03_first_job_advanced: (true, false, 8., 1),
+        case04_last_job: (false, true, 0., 1),
+        case05_single_job_on_schedule: (true, true, 0., 1),
+        case06_single_job_advanced: (true, true, 8., 2),
+    }
+
+    fn can_limit_duration_calls_impl(is_first: bool, is_last: bool, departure: Float, expected_calls: usize) {
+        let mut builder = TestVehicleBuilder::default();
+        builder.id("v1");
+        builder.dimens_mut().set_job_time_constraints(JobTimeConstraints {
+            earliest_first: Some(5.0),
+            latest_last: Some(100.0),
+            first_job_reference: FirstJobReference::Arrival,
+            last_job_reference: LastJobReference::Departure,
+            departure_reference: DepartureReference::ScheduledStart,
+        });
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(builder.build()).build();
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build())
+            .build();
+        let transport = Arc::new(CountingTransportCost::default());
+        let feature = create_job_time_limits_feature(
+            "job_time_limits",
+            transport.clone(),
+            TestActivityCost::new_shared(),
+            VIOLATION_CODE,
+        )
+        .unwrap();
+        let job_activity = |location| {
+            ActivityBuilder::with_location_and_tw(location, TimeWindow::new(0.0, 100.0))
+                .schedule(Schedule::new(departure, departure))
+                .build()
+        };
+        let prev = if is_first { create_depot_activity(0, departure) } else { job_activity(5) };
+        let next = if is_last { create_depot_activity(0, 50.0) } else { job_activity(20) };
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::activity(
+            &solution_ctx,
+            &route_ctx,
+            &ActivityContext {
+                index: if is_first { 0 } else { 1 },
+                prev: &prev,
+                target: &ActivityBuilder::with_location_and_tw(10, TimeWindow::new(0.0, 100.0)).build(),
+                next: Some(&next),
+            },
+        ));
+
+        assert_eq!(result, None);
+        assert_eq!(transport.calls.load(Ordering::Relaxed), expected_calls);
+    }
+}
+
+mod job_time_pressure_objective {
+    use super::*;
+
+    parameterized_test! {can_estimate_job_time_pressure, (bounds, schedules, expected), {
+        can_estimate_job_time_pressure_impl(bounds, schedules, expected);
+    }}
+
+    can_estimate_job_time_pressure! {
+        case01_no_jobs: ((Some(5.), Some(100.)), vec![], 0.),
+        case02_single_job_with_slack: ((Some(5.), Some(100.)), vec![(10., 11.)], 1. / 6. + 1. / 90.),
+        case03_two_jobs_with_slack: ((Some(5.), Some(100.)), vec![(10., 11.), (20., 21.)], 1. / 6. + 1. / 80.),
+        case04_marginal_last_job: ((None, Some(100.)), vec![(10., 11.), (98., 99.)], 0.5),
+        case05_exact_last_job: ((None, Some(100.)), vec![(10., 11.), (99., 100.)], 1.),
+        case06_violated_last_job: ((None, Some(100.)), vec![(10., 11.), (100., 101.)], 1.),
+        case07_violated_both: ((Some(20.), Some(100.)), vec![(10., 11.), (200., 201.)], 2.),
+        case08_no_bounds: ((None, None), vec![(10., 11.)], 0.),
+    }
+
+    fn can_estimate_job_time_pressure_impl(
+        bounds: (Option<Float>, Option<Float>),
+        schedules: Vec<(Float, Float)>,
+        expected: Cost,
+    ) {
+        let (earliest_first, latest_last) = bounds;
+        let fleet = create_fleet_with_job_time_constraints("v1", earliest_first, latest_last);
+        let activities = schedules
+            .into_iter()
+            .map(|(arrival, departure)| {
+                ActivityBuilder::with_location_and_tw(10, TimeWindow::new(0., 1000.))
+                    .schedule(Schedule::new(arrival, departure))
+                    .build()
+            })
+            .collect::<Vec<_>>();
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+            .build();
+        let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+        let feature = create_job_time_pressure_feature(
+            "job_time_pressure",
+            TestTransportCost::new_shared(),
+            TestActivityCost::new_shared(),
+        )
+        .unwrap();
+
+        let fitness = feature.objective.unwrap().fitness(&insertion_ctx);
+
+        assert_eq!(fitness, expected);
+    }
+
+    parameterized_test! {can_estimate_job_time_pressure_on_insertion, (locations, index, target_location, expected), {
+        can_estimate_job_time_pressure_on_insertion_impl(locations, index, target_location, expected);
+    }}
+
+    can_estimate_job_time_pressure_on_insertion! {
+        case01_into_empty_route: (vec![], 0, 10, 1. / 6. + 1. / 91.),
+        case02_as_last_job: (vec![10], 1, 20, 1. / 81. - 1. / 91.),
+        case03_as_first_job: (vec![20], 0, 10, 1. / 6. - 1. / 16.),
+        case04_in_the_middle: (vec![10, 30], 1, 20, 0.),
+    }
+
+    fn can_estimate_job_time_pressure_on_insertion_impl(
+        locations: Vec<Location>,
+        index: usize,
+        target_location: Location,
+        expected: Cost,
+    ) {
+        let fleet = create_fleet_with_job_time_constraints("v1", Some(5.), Some(100.));
+        let activities = locations.into_iter().map(|location| {
+            ActivityBuilder::with_location_and_tw(location, TimeWindow::new(0., 1000.))
+                .schedule(Schedule::new(location as Float, location as Float))
+                .build()
+        });
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+            .build();
+        let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+        let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+        let target = ActivityBuilder::with_location_and_tw(target_location, TimeWindow::new(0., 1000.)).build();
+        let activity_ctx = ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(index + 1),
+        };
+        let feature = create_job_time_pressure_feature(
+            "job_time_pressure",
+            TestTransportCost::new_shared(),
+            TestActivityCost::new_shared(),
+        )
+        .unwrap();
+
+        let estimate = feature.objective.unwrap().estimate(&MoveContext::activity(
+            &insertion_ctx.solution,
+            route_ctx,
+            &activity_ctx,
+        ));
+
+        assert!((estimate - expected).abs() < 1e-9);
+    }
+}
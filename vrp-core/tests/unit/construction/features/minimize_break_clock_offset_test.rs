@@ -0,0 +1,141 @@
+use super::*;
+use crate::construction::enablers::ReservedTimeSpan;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{TestSingleBuilder, TestTransportCost};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::common::{Location, Schedule, TimeSpan};
+
+struct JobTypeDimenKey;
+
+fn is_break_single(single: &Single) -> bool {
+    single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "break")
+}
+
+fn create_single(is_break: bool) -> Arc<Single> {
+    let job_type = if is_break { "break" } else { "job" };
+
+    TestSingleBuilder::default().id(job_type).property::<JobTypeDimenKey, _>(job_type.to_string()).build_shared()
+}
+
+fn create_activity(arrival: Timestamp, tw_start: Timestamp, is_break: bool) -> Activity {
+    ActivityBuilder::with_location_and_tw(0, TimeWindow::new(tw_start, 1000.))
+        .schedule(Schedule::new(arrival, arrival.max(tw_start) + 5.))
+        .job(Some(create_single(is_break)))
+        .build()
+}
+
+fn create_feature(interval: Duration, reserved_times_index: ReservedTimesIndex) -> GenericResult<Feature> {
+    create_minimize_break_clock_offset_feature(
+        "min_break_clock_offset",
+        interval,
+        TestTransportCost::new_shared(),
+        reserved_times_index,
+        is_break_single,
+    )
+}
+
+parameterized_test! {can_calculate_break_clock_offset, (activities, interval, expected), {
+    can_calculate_break_clock_offset_impl(activities, interval, expected);
+}}
+
+can_calculate_break_clock_offset! {
+    case01_on_boundary: (vec![(120., 0., true)], 60., 0.),
+    case02_after_boundary: (vec![(125., 0., true)], 60., 5.),
+    case03_before_boundary: (vec![(115., 0., true)], 60., 5.),
+    case04_waits_for_time_window: (vec![(100., 130., true)], 60., 10.),
+    case05_ignores_non_breaks: (vec![(125., 0., false), (150., 0., true)], 60., 30.),
+    case06_sums_breaks: (vec![(65., 0., true), (170., 0., true)], 60., 15.),
+    case07_no_breaks: (vec![], 60., 0.),
+}
+
+fn can_calculate_break_clock_offset_impl(
+    activities: Vec<(Timestamp, Timestamp, bool)>,
+    interval: Duration,
+    expected: Cost,
+) {
+    let route = RouteBuilder::with_default_vehicle()
+        .add_activities(
+            activities.into_iter().map(|(arrival, tw_start, is_break)| create_activity(arrival, tw_start, is_break)),
+        )
+        .build();
+    let route_ctx = RouteContextBuilder::default().with_route(route).build();
+    let mut insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+    let feature = create_feature(interval, ReservedTimesIndex::default()).unwrap();
+    let (state, objective) = (feature.state.as_ref().unwrap(), feature.objective.as_ref().unwrap());
+
+    state.accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(objective.fitness(&insertion_ctx), expected);
+}
+
+parameterized_test! {can_calculate_reserved_break_clock_offset, (reserved_times, expected), {
+    can_calculate_reserved_break_clock_offset_impl(reserved_times, expected);
+}}
+
+can_calculate_reserved_break_clock_offset! {
+    case01_on_boundary: (vec![(120., 5.)], 0.),
+    case02_after_boundary: (vec![(125., 5.)], 5.),
+    case03_multiple_reserved: (vec![(115., 5.), (170., 5.)], 15.),
+    case04_outside_tour: (vec![(2000., 5.)], 0.),
+}
+
+fn can_calculate_reserved_break_clock_offset_impl(reserved_times: Vec<(Timestamp, Duration)>, expected: Cost) {
+    let mut route = RouteBuilder::with_default_vehicle().build();
+    let end_idx = route.tour.total() - 1;
+    route.tour.get_mut(end_idx).unwrap().schedule = Schedule::new(1000., 1000.);
+    let reserved_times_index = vec![(
+        route.actor.clone(),
+        reserved_times
+            .into_iter()
+            .map(|(start, duration)| ReservedTimeSpan {
+                time: TimeSpan::Window(TimeWindow::new(start, start)),
+                duration,
+            })
+            .collect(),
+    )]
+    .into_iter()
+    .collect();
+    let route_ctx = RouteContextBuilder::default().with_route(route).build();
+    let mut insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+    let feature = create_feature(60., reserved_times_index).unwrap();
+    let (state, objective) = (feature.state.as_ref().unwrap(), feature.objective.as_ref().unwrap());
+
+    state.accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(objective.fitness(&insertion_ctx), expected);
+}
+
+parameterized_test! {can_estimate_break_clock_offset, (target, tw_start, expected), {
+    can_estimate_break_clock_offset_impl(target, tw_start, expected);
+}}
+
+can_estimate_break_clock_offset! {
+    case01_arrives_on_boundary: ((60, true), 0., 0.),
+    case02_arrives_after_boundary: ((65, true), 0., 5.),
+    case03_waits_for_time_window: ((10, true), 110., 10.),
+    case04_not_a_break: ((65, false), 0., 0.),
+}
+
+fn can_estimate_break_clock_offset_impl(target: (Location, bool), tw_start: Timestamp, expected: Cost) {
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let route_ctx = RouteContextBuilder::default().with_route(RouteBuilder::with_default_vehicle().build()).build();
+    let prev = ActivityBuilder::with_location(0).schedule(Schedule::new(0., 0.)).build();
+    let (location, is_break) = target;
+    let target = ActivityBuilder::with_location_and_tw(location, TimeWindow::new(tw_start, 1000.))
+        .job(Some(create_single(is_break)))
+        .build();
+    let feature = create_feature(60., ReservedTimesIndex::default()).unwrap();
+
+    let estimate = feature.objective.unwrap().estimate(&MoveContext::activity(
+        &solution_ctx,
+        &route_ctx,
+        &ActivityContext { index: 0, prev: &prev, target: &target, next: None },
+    ));
+
+    assert_eq!(estimate, expected);
+}
+
+#[test]
+fn can_reject_non_positive_interval() {
+    assert!(create_feature(0., ReservedTimesIndex::default()).is_err());
+}
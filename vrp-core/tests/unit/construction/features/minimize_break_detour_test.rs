@@ -0,0 +1,84 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{TestSingleBuilder, TestTransportCost};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+struct JobTypeDimenKey;
+
+fn is_break_single(single: &Single) -> bool {
+    single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "break")
+}
+
+fn create_activity(location: Location, is_break: bool) -> Activity {
+    let single = if is_break {
+        TestSingleBuilder::default().id("break").property::<JobTypeDimenKey, _>("break".to_string()).build()
+    } else {
+        TestSingleBuilder::default().id("job").build()
+    };
+
+    ActivityBuilder::with_location(location).job(Some(Arc::new(single))).build()
+}
+
+fn create_insertion_ctx(activities: Vec<(Location, bool)>) -> InsertionContext {
+    let route = RouteBuilder::with_default_vehicle()
+        .add_activities(activities.into_iter().map(|(location, is_break)| create_activity(location, is_break)))
+        .build();
+    let route_ctx = RouteContextBuilder::default().with_route(route).build();
+
+    TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build()
+}
+
+parameterized_test! {can_calculate_break_detour_fitness, (activities, expected), {
+    can_calculate_break_detour_fitness_impl(activities, expected);
+}}
+
+can_calculate_break_detour_fitness! {
+    case01_break_on_the_way: (vec![(10, false), (15, true), (20, false)], 0.),
+    case02_break_off_the_way: (vec![(10, false), (30, true), (20, false)], 20.),
+    case03_break_at_job_location: (vec![(10, false), (10, true), (20, false)], 0.),
+    case04_no_breaks: (vec![(10, false), (30, false), (20, false)], 0.),
+    case05_two_breaks: (vec![(10, false), (5, true), (20, false), (25, true)], 10. + 10.),
+}
+
+fn can_calculate_break_detour_fitness_impl(activities: Vec<(Location, bool)>, expected: Cost) {
+    let mut insertion_ctx = create_insertion_ctx(activities);
+    let feature =
+        create_minimize_break_detour_feature("min_break_detour", TestTransportCost::new_shared(), is_break_single)
+            .unwrap();
+    let (state, objective) = (feature.state.as_ref().unwrap(), feature.objective.as_ref().unwrap());
+
+    state.accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(objective.fitness(&insertion_ctx), expected);
+}
+
+parameterized_test! {can_estimate_break_detour, (index, target, expected), {
+    can_estimate_break_detour_impl(index, target, expected);
+}}
+
+can_estimate_break_detour! {
+    case01_break_on_the_way: (1, (15, true), 0.),
+    case02_break_off_the_way: (1, (30, true), 20.),
+    case03_not_a_break: (1, (30, false), 0.),
+    case04_break_before_end: (2, (30, true), 20.),
+}
+
+fn can_estimate_break_detour_impl(index: usize, target: (Location, bool), expected: Cost) {
+    let insertion_ctx = create_insertion_ctx(vec![(10, false), (20, false)]);
+    let feature =
+        create_minimize_break_detour_feature("min_break_detour", TestTransportCost::new_shared(), is_break_single)
+            .unwrap();
+    let objective = feature.objective.unwrap();
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let target = create_activity(target.0, target.1);
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let estimate = objective.estimate(&MoveContext::activity(&insertion_ctx.solution, route_ctx, &activity_ctx));
+
+    assert_eq!(estimate, expected);
+}
@@ -0,0 +1,85 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::solution::Activity;
+
+struct JobTypeDimenKey;
+
+fn is_break_single(single: &Single) -> bool {
+    single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "break")
+}
+
+fn create_break_activity(location: Location) -> Activity {
+    let single = TestSingleBuilder::default().id("break").property::<JobTypeDimenKey, _>("break".to_string()).build();
+
+    ActivityBuilder::with_location(location).job(Some(Arc::new(single))).build()
+}
+
+fn create_route_ctx(break_location: Location) -> RouteContext {
+    let route = RouteBuilder::with_default_vehicle()
+        .add_activity(ActivityBuilder::with_location(break_location).build())
+        .add_activity(create_break_activity(break_location))
+        .build();
+
+    RouteContextBuilder::default().with_route(route).build()
+}
+
+parameterized_test! {can_count_break_locations, (break_locations, candidate, expected), {
+    can_count_break_locations_impl(break_locations, candidate, expected);
+}}
+
+can_count_break_locations! {
+    case01_same_location: (vec![10, 10], (10, true), (1., 0.)),
+    case02_different_locations: (vec![10, 20], (10, true), (2., 0.)),
+    case03_new_location: (vec![10, 20], (30, true), (2., 1.)),
+    case04_not_a_break: (vec![10, 20], (30, false), (2., 0.)),
+    case05_no_breaks: (vec![], (30, true), (0., 1.)),
+}
+
+fn can_count_break_locations_impl(break_locations: Vec<Location>, candidate: (Location, bool), expected: (Cost, Cost)) {
+    let (candidate_location, is_break) = candidate;
+    let (expected_fitness, expected_estimate) = expected;
+    let routes = break_locations.into_iter().map(create_route_ctx).collect();
+    let mut insertion_ctx = TestInsertionContextBuilder::default().with_routes(routes).build();
+    let feature = create_minimize_break_location_count_feature("min_break_locations", is_break_single).unwrap();
+    let (state, objective) = (feature.state.as_ref().unwrap(), feature.objective.as_ref().unwrap());
+    let target = if is_break {
+        create_break_activity(candidate_location)
+    } else {
+        ActivityBuilder::with_location(candidate_location).build()
+    };
+    let prev = ActivityBuilder::with_location(0).build();
+    let route_ctx = RouteContextBuilder::default().with_route(RouteBuilder::with_default_vehicle().build()).build();
+
+    state.accept_solution_state(&mut insertion_ctx.solution);
+    let fitness = objective.fitness(&insertion_ctx);
+    let estimate = objective.estimate(&MoveContext::activity(
+        &insertion_ctx.solution,
+        &route_ctx,
+        &ActivityContext { index: 0, prev: &prev, target: &target, next: None },
+    ));
+
+    assert_eq!(fitness, expected_fitness);
+    assert_eq!(estimate, expected_estimate);
+}
+
+#[test]
+fn can_update_break_locations_on_insertion() {
+    let mut insertion_ctx = TestInsertionContextBuilder::default()
+        .with_routes(vec![
+            create_route_ctx(10),
+            RouteContextBuilder::default().with_route(RouteBuilder::with_default_vehicle().build()).build(),
+        ])
+        .build();
+    let feature = create_minimize_break_location_count_feature("min_break_locations", is_break_single).unwrap();
+    let (state, objective) = (feature.state.as_ref().unwrap(), feature.objective.as_ref().unwrap());
+    state.accept_solution_state(&mut insertion_ctx.solution);
+    let break_activity = create_break_activity(20);
+    let job = Job::Single(break_activity.job.clone().unwrap());
+
+    insertion_ctx.solution.routes[1].route_mut().tour.insert_last(break_activity);
+    state.accept_insertion(&mut insertion_ctx.solution, 1, &job);
+
+    assert_eq!(objective.fitness(&insertion_ctx), 2.);
+}
@@ -0,0 +1,108 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{TestSingleBuilder, TestTransportCost};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::common::Schedule;
+
+struct JobTypeDimenKey;
+
+fn is_break_single(single: &Single) -> bool {
+    single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "break")
+}
+
+fn create_activity(start: Timestamp, end: Timestamp, is_break: bool) -> Activity {
+    let job_type = if is_break { "break" } else { "job" };
+    let single = TestSingleBuilder::default().id(job_type).property::<JobTypeDimenKey, _>(job_type.to_string()).build();
+
+    ActivityBuilder::with_location_tw_and_duration(0, TimeWindow::new(start, 1000.), end - start)
+        .schedule(Schedule::new(start, end))
+        .job(Some(Arc::new(single)))
+        .build()
+}
+
+fn create_insertion_ctx(routes: Vec<Vec<(Timestamp, Timestamp, bool)>>) -> InsertionContext {
+    let routes = routes
+        .into_iter()
+        .map(|activities| {
+            let route = RouteBuilder::with_default_vehicle()
+                .add_activities(
+                    activities.into_iter().map(|(start, end, is_break)| create_activity(start, end, is_break)),
+                )
+                .build();
+            RouteContextBuilder::default().with_route(route).build()
+        })
+        .collect();
+
+    TestInsertionContextBuilder::default().with_routes(routes).build()
+}
+
+fn create_feature(max_breaks: usize) -> Feature {
+    create_minimize_break_overlap_feature(
+        "min_break_overlap",
+        max_breaks,
+        TestTransportCost::new_shared(),
+        is_break_single,
+    )
+    .unwrap()
+}
+
+parameterized_test! {can_calculate_break_overlap, (routes, max_breaks, expected), {
+    can_calculate_break_overlap_impl(routes, max_breaks, expected);
+}}
+
+can_calculate_break_overlap! {
+    case01_within_limit: (vec![vec![(10., 20., true)], vec![(10., 20., true)]], 2, 0.),
+    case02_exceeds_limit: (vec![vec![(10., 20., true)], vec![(15., 25., true)], vec![(18., 30., true)]], 2, 2.),
+    case03_exceeds_limit_by_two: (vec![vec![(10., 20., true)], vec![(10., 20., true)], vec![(10., 20., true)]], 1, 20.),
+    case04_adjacent_breaks: (vec![vec![(10., 20., true)], vec![(20., 30., true)], vec![(10., 20., true)]], 2, 0.),
+    case05_ignores_non_breaks: (vec![vec![(10., 20., true)], vec![(10., 20., false), (30., 40., true)]], 1, 0.),
+    case06_same_tour: (vec![vec![(10., 20., true), (15., 25., true)]], 1, 5.),
+    case07_no_breaks: (vec![vec![]], 1, 0.),
+}
+
+fn can_calculate_break_overlap_impl(routes: Vec<Vec<(Timestamp, Timestamp, bool)>>, max_breaks: usize, expected: Cost) {
+    let mut insertion_ctx = create_insertion_ctx(routes);
+    let feature = create_feature(max_breaks);
+    let (state, objective) = (feature.state.as_ref().unwrap(), feature.objective.as_ref().unwrap());
+
+    state.accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(objective.fitness(&insertion_ctx), expected);
+}
+
+parameterized_test! {can_estimate_break_overlap, (target, max_breaks, expected), {
+    can_estimate_break_overlap_impl(target, max_breaks, expected);
+}}
+
+can_estimate_break_overlap! {
+    case01_no_overlap: ((30., 40., true), 2, 0.),
+    case02_overlap_within_limit: ((15., 25., true), 3, 0.),
+    case03_overlap_exceeds_limit: ((15., 25., true), 2, 5.),
+    case04_not_a_break: ((15., 25., false), 2, 0.),
+}
+
+fn can_estimate_break_overlap_impl(target: (Timestamp, Timestamp, bool), max_breaks: usize, expected: Cost) {
+    let mut insertion_ctx = create_insertion_ctx(vec![vec![(10., 20., true)], vec![(10., 20., true)], vec![]]);
+    let feature = create_feature(max_breaks);
+    let (state, objective) = (feature.state.as_ref().unwrap(), feature.objective.as_ref().unwrap());
+    state.accept_solution_state(&mut insertion_ctx.solution);
+    let route_ctx = insertion_ctx.solution.routes.last().unwrap();
+    let target = create_activity(target.0, target.1, target.2);
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+
+    let estimate = objective.estimate(&MoveContext::activity(&insertion_ctx.solution, route_ctx, &activity_ctx));
+
+    assert_eq!(estimate, expected);
+}
+
+#[test]
+fn can_reject_zero_max_breaks() {
+    let transport = TestTransportCost::new_shared();
+
+    assert!(create_minimize_break_overlap_feature("min_break_overlap", 0, transport, is_break_single).is_err());
+}
@@ -0,0 +1,107 @@
+use super::*;
+use crate::construction::enablers::ReservedTimeSpan;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::TestSingleBuilder;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+struct JobTypeDimenKey;
+
+fn is_break_single(single: &Single) -> bool {
+    single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "break")
+}
+
+fn create_break(duration: Duration) -> Arc<Single> {
+    TestSingleBuilder::default()
+        .id("break")
+        .duration(duration)
+        .property::<JobTypeDimenKey, _>("break".to_string())
+        .build_shared()
+}
+
+fn create_route_ctx(break_duration: Duration) -> RouteContext {
+    let mut route = RouteBuilder::with_default_vehicle()
+        .add_activity(
+            ActivityBuilder::with_location(10)
+                .job(Some(TestSingleBuilder::default().id("job1").duration(5.).build_shared()))
+                .schedule(Schedule::new(10., 15.))
+                .build(),
+        )
+        .add_activity(
+            ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::max(), break_duration)
+                .job(Some(create_break(break_duration)))
+                .schedule(Schedule::new(15., 15. + break_duration))
+                .build(),
+        )
+        .build();
+
+    let end_idx = route.tour.total() - 1;
+    route.tour.get_mut(end_idx).unwrap().schedule = Schedule::new(50., 50.);
+
+    RouteContextBuilder::default().with_route(route).build()
+}
+
+parameterized_test! {can_calculate_break_time_fitness, (reserved_times, expected), {
+    can_calculate_break_time_fitness_impl(reserved_times, expected);
+}}
+
+can_calculate_break_time_fitness! {
+    case01_no_reserved_times: (vec![], 3.),
+    case02_reserved_time_inside_tour: (vec![((10., 10.), 5.)], 8.),
+    case03_reserved_time_outside_tour: (vec![((100., 100.), 5.)], 3.),
+    case04_mixed_reserved_times: (vec![((10., 10.), 5.), ((100., 100.), 7.)], 8.),
+}
+
+fn can_calculate_break_time_fitness_impl(reserved_times: Vec<((Timestamp, Timestamp), Duration)>, expected: Cost) {
+    let route_ctx = create_route_ctx(3.);
+    let reserved_times_index = if reserved_times.is_empty() {
+        ReservedTimesIndex::default()
+    } else {
+        vec![(
+            route_ctx.route().actor.clone(),
+            reserved_times
+                .into_iter()
+                .map(|((start, end), duration)| ReservedTimeSpan {
+                    time: TimeSpan::Window(TimeWindow::new(start, end)),
+                    duration,
+                })
+                .collect(),
+        )]
+        .into_iter()
+        .collect()
+    };
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+    let feature = create_minimize_break_time_feature("min_break_time", reserved_times_index, is_break_single).unwrap();
+
+    let fitness = feature.objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+}
+
+parameterized_test! {can_estimate_break_activity, (is_break, duration, expected), {
+    can_estimate_break_activity_impl(is_break, duration, expected);
+}}
+
+can_estimate_break_activity! {
+    case01_short_break: (true, 2., 2.),
+    case02_long_break: (true, 5., 5.),
+    case03_not_a_break: (false, 5., 0.),
+}
+
+fn can_estimate_break_activity_impl(is_break: bool, duration: Duration, expected: Cost) {
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let route_ctx = RouteContextBuilder::default().with_route(RouteBuilder::with_default_vehicle().build()).build();
+    let single = if is_break { create_break(duration) } else { TestSingleBuilder::default().build_shared() };
+    let target =
+        ActivityBuilder::with_location_tw_and_duration(5, TimeWindow::max(), duration).job(Some(single)).build();
+    let prev = ActivityBuilder::with_location(0).build();
+    let feature =
+        create_minimize_break_time_feature("min_break_time", ReservedTimesIndex::default(), is_break_single).unwrap();
+
+    let estimate = feature.objective.unwrap().estimate(&MoveContext::activity(
+        &solution_ctx,
+        &route_ctx,
+        &ActivityContext { index: 0, prev: &prev, target: &target, next: None },
+    ));
+
+    assert_eq!(estimate, expected);
+}
@@ -0,0 +1,62 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_insertion_ctx(locations: Vec<Location>) -> InsertionContext {
+    let route = RouteBuilder::with_default_vehicle()
+        .add_activities(locations.into_iter().map(|location| ActivityBuilder::with_location(location).build()))
+        .build();
+    let route_ctx = RouteContextBuilder::default().with_route(route).build();
+
+    TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build()
+}
+
+parameterized_test! {can_calculate_deadhead_fitness, (locations, expected), {
+    can_calculate_deadhead_fitness_impl(locations, expected);
+}}
+
+can_calculate_deadhead_fitness! {
+    case01_two_jobs: (vec![10, 20], 30.),
+    case02_one_job: (vec![15], 30.),
+    case03_no_jobs: (vec![], 0.),
+}
+
+fn can_calculate_deadhead_fitness_impl(locations: Vec<Location>, expected: Cost) {
+    let mut insertion_ctx = create_insertion_ctx(locations);
+    let feature = create_minimize_deadhead_feature("min_deadhead", TestTransportCost::new_shared()).unwrap();
+    let (state, objective) = (feature.state.as_ref().unwrap(), feature.objective.as_ref().unwrap());
+
+    state.accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(objective.fitness(&insertion_ctx), expected);
+}
+
+parameterized_test! {can_estimate_deadhead_change, (locations, index, target, expected), {
+    can_estimate_deadhead_change_impl(locations, index, target, expected);
+}}
+
+can_estimate_deadhead_change! {
+    case01_empty_route: (vec![], 0, 5, 10.),
+    case02_first_leg: (vec![10, 20], 0, 5, -5.),
+    case03_last_leg: (vec![10, 20], 2, 5, -15.),
+    case04_in_the_middle: (vec![10, 20], 1, 15, 0.),
+}
+
+fn can_estimate_deadhead_change_impl(locations: Vec<Location>, index: usize, target: Location, expected: Cost) {
+    let insertion_ctx = create_insertion_ctx(locations);
+    let feature = create_minimize_deadhead_feature("min_deadhead", TestTransportCost::new_shared()).unwrap();
+    let objective = feature.objective.unwrap();
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let target = ActivityBuilder::with_location(target).build();
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+
+    let estimate = objective.estimate(&MoveContext::activity(&insertion_ctx.solution, route_ctx, &activity_ctx));
+
+    assert_eq!(estimate, expected);
+}
@@ -0,0 +1,82 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::*;
+
+fn create_soft_activity(
+    location: Location,
+    arrival: Timestamp,
+    soft_tws: Option<Vec<(Timestamp, Timestamp)>>,
+) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    // NOTE relaxed time window of the first original one is used as soft jobs are read with open time windows
+    let place_tw = soft_tws
+        .as_ref()
+        .and_then(|tws| tws.first())
+        .map_or(TimeWindow::max(), |&(start, _)| TimeWindow::new(start, Float::MAX));
+    if let Some(soft_tws) = soft_tws {
+        builder.dimens_mut().set_job_soft_time_windows(SoftTimeWindows {
+            places: vec![soft_tws.into_iter().map(|(start, end)| TimeWindow::new(start, end)).collect()],
+            penalty: 2.,
+        });
+    }
+
+    ActivityBuilder::with_location_and_tw(location, place_tw)
+        .job(Some(builder.location(Some(location)).build_shared()))
+        .schedule(Schedule::new(arrival, arrival + 1.))
+        .build()
+}
+
+parameterized_test! {can_calculate_lateness_penalty, (soft_tws, arrival, expected), {
+    can_calculate_lateness_penalty_impl(soft_tws, arrival, expected);
+}}
+
+can_calculate_lateness_penalty! {
+    case01_in_time: (Some(vec![(0., 10.)]), 5., 0.),
+    case02_late: (Some(vec![(0., 10.)]), 13., 6.),
+    case03_early: (Some(vec![(20., 30.)]), 5., 0.),
+    case04_between_windows: (Some(vec![(0., 10.), (20., 30.)]), 15., 10.),
+    case05_between_windows_close_to_later: (Some(vec![(0., 10.), (20., 30.)]), 18., 4.),
+    case06_after_windows: (Some(vec![(0., 10.), (20., 30.)]), 35., 10.),
+    case07_hard_time_windows: (None, 35., 0.),
+}
+
+fn can_calculate_lateness_penalty_impl(
+    soft_tws: Option<Vec<(Timestamp, Timestamp)>>,
+    arrival: Timestamp,
+    expected: Cost,
+) {
+    let activity = create_soft_activity(10, arrival, soft_tws);
+
+    let penalty = get_lateness_penalty(&activity, arrival);
+
+    assert_eq!(penalty, expected);
+}
+
+#[test]
+fn can_calculate_fitness_and_estimate() {
+    let route = RouteBuilder::default()
+        .with_vehicle(&test_fleet(), "v1")
+        .add_activity(create_soft_activity(10, 10., Some(vec![(0., 5.)])))
+        .add_activity(create_soft_activity(20, 21., Some(vec![(0., 30.)])))
+        .build();
+    let route_ctx = RouteContextBuilder::default().with_route(route).build();
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+    let feature = create_minimize_lateness_feature("minimize_lateness", TestTransportCost::new_shared()).unwrap();
+    let objective = feature.objective.unwrap();
+    let target = create_soft_activity(25, 0., Some(vec![(0., 20.)]));
+    let route_ctx = insertion_ctx.solution.routes.first().unwrap();
+    let activity_ctx = ActivityContext {
+        index: 2,
+        prev: route_ctx.route().tour.get(2).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(3),
+    };
+
+    let fitness = objective.fitness(&insertion_ctx);
+    let estimate = objective.estimate(&MoveContext::activity(&insertion_ctx.solution, route_ctx, &activity_ctx));
+
+    assert_eq!(fitness, 10.);
+    // NOTE departure from prev at 22, arrival at 27
+    assert_eq!(estimate, 14.);
+}
@@ -0,0 +1,45 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+fn create_route_ctx(end_time: (Timestamp, Timestamp), is_open: bool, has_jobs: bool) -> RouteContext {
+    let mut builder = RouteBuilder::with_default_vehicle();
+    if is_open {
+        let mut start = ActivityBuilder::with_location(0).build();
+        start.job = None;
+        builder.with_start(start);
+    }
+    if has_jobs {
+        builder.add_activity(ActivityBuilder::with_location(10).build());
+    }
+    let mut route = builder.build();
+
+    let end = route.tour.end_idx().and_then(|idx| route.tour.get_mut(idx)).unwrap();
+    end.schedule.arrival = end_time.0;
+    end.schedule.departure = end_time.1;
+
+    RouteContextBuilder::default().with_route(route).build()
+}
+
+parameterized_test! {can_calculate_latest_arrival, (routes, expected), {
+    can_calculate_latest_arrival_impl(routes, expected);
+}}
+
+can_calculate_latest_arrival! {
+    case01_closed_tours: (vec![((100., 110.), false, true), ((120., 130.), false, true)], 120.),
+    case02_open_tour: (vec![((40., 40.), false, true), ((50., 60.), true, true)], 60.),
+    case03_empty_tour_ignored: (vec![((100., 100.), false, true), ((200., 200.), false, false)], 100.),
+    case04_no_tours: (vec![], 0.),
+}
+
+fn can_calculate_latest_arrival_impl(routes: Vec<((Timestamp, Timestamp), bool, bool)>, expected: Cost) {
+    let routes =
+        routes.into_iter().map(|(end_time, is_open, has_jobs)| create_route_ctx(end_time, is_open, has_jobs)).collect();
+    let mut insertion_ctx = TestInsertionContextBuilder::default().with_routes(routes).build();
+    let feature = create_minimize_latest_arrival_feature("min_latest_arrival").unwrap();
+    let (state, objective) = (feature.state.as_ref().unwrap(), feature.objective.as_ref().unwrap());
+
+    state.accept_solution_state(&mut insertion_ctx.solution);
+
+    assert_eq!(objective.fitness(&insertion_ctx), expected);
+}
@@ -515,3 +515,259 @@ mod notify_failure {
         Ok(())
     }
 }
+
+mod service_time {
+    use super::*;
+    use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+    use crate::models::common::{Duration, TimeWindow};
+
+    const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+    fn create_route_ctx(durations: &[Duration], feature: &Feature) -> RouteContext {
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&test_fleet(), "v1")
+                    .add_activities(durations.iter().enumerate().map(|(idx, duration)| {
+                        ActivityBuilder::with_location_tw_and_duration(idx * 100, TimeWindow::max(), *duration).build()
+                    }))
+                    .build(),
+            )
+            .build();
+        feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+        route_ctx
+    }
+
+    parameterized_test! {can_limit_service_time_on_route, (durations, job_duration, limit, expected), {
+        can_limit_service_time_on_route_impl(durations, job_duration, limit, expected);
+    }}
+
+    can_limit_service_time_on_route! {
+        case01_fits: (vec![10., 20.], 5., Some(40.), None),
+        case02_exact: (vec![10., 20.], 10., Some(40.), None),
+        case03_exceeds: (vec![10., 20.], 15., Some(40.), ConstraintViolation::fail(VIOLATION_CODE)),
+        case04_no_limit: (vec![10., 20.], 100., None, None),
+        case05_empty_route: (vec![], 50., Some(40.), ConstraintViolation::fail(VIOLATION_CODE)),
+    }
+
+    fn can_limit_service_time_on_route_impl(
+        durations: Vec<Duration>,
+        job_duration: Duration,
+        limit: Option<Duration>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let feature = create_service_time_limit_feature(
+            "service_time_limit",
+            VIOLATION_CODE,
+            Arc::new(move |_| limit),
+            Arc::new(|_| true),
+        )
+        .unwrap();
+        let route_ctx = create_route_ctx(durations.as_slice(), &feature);
+        let job = TestSingleBuilder::default().id("job").duration(job_duration).build_as_job_ref();
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn can_ignore_travel_time_for_service_time_limit() {
+        // NOTE activities are far away from each other: only their service time is counted
+        let feature = create_service_time_limit_feature(
+            "service_time_limit",
+            VIOLATION_CODE,
+            Arc::new(|_| Some(30.)),
+            Arc::new(|_| true),
+        )
+        .unwrap();
+        let route_ctx = create_route_ctx(&[5., 5.], &feature);
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let constraint = feature.constraint.unwrap();
+        let evaluate = |duration: Duration| {
+            let target = ActivityBuilder::with_location_tw_and_duration(1000, TimeWindow::max(), duration).build();
+            let activity_ctx = ActivityContext {
+                index: 0,
+                prev: route_ctx.route().tour.get(2).unwrap(),
+                target: &target,
+                next: route_ctx.route().tour.get(3),
+            };
+            constraint.evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx))
+        };
+
+        assert_eq!(evaluate(20.), None);
+        assert_eq!(evaluate(21.), ConstraintViolation::skip(VIOLATION_CODE));
+    }
+
+    #[test]
+    fn can_ignore_non_service_jobs_for_service_time_limit() {
+        let feature = create_service_time_limit_feature(
+            "service_time_limit",
+            VIOLATION_CODE,
+            Arc::new(|_| Some(10.)),
+            Arc::new(|single| single.places.first().is_some_and(|place| place.duration < 50.)),
+        )
+        .unwrap();
+        let route_ctx = create_route_ctx(&[5., 100.], &feature);
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let constraint = feature.constraint.unwrap();
+        let evaluate = |duration: Duration| {
+            let job = TestSingleBuilder::default().id("job").duration(duration).build_as_job_ref();
+            constraint.evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job))
+        };
+
+        assert_eq!(evaluate(5.), None);
+        assert_eq!(evaluate(6.), ConstraintViolation::fail(VIOLATION_CODE));
+        assert_eq!(evaluate(60.), None);
+    }
+}
+
+mod stop {
+    use super::*;
+    use crate::construction::enablers::{ReservedTimeSpan, VehicleReservedTimesDimension};
+    use crate::construction::features::tour_limits::StopCountTourState;
+    use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+    use crate::models::common::{Duration, Schedule, TimeSpan, TimeWindow, Timestamp};
+
+    const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+    parameterized_test! {can_limit_stops_on_route, (locations, index, target, limit, headroom, expected), {
+        can_limit_stops_on_route_impl(locations, index, target, limit, headroom, expected);
+    }}
+
+    can_limit_stops_on_route! {
+        case01_new_stop_fits: (vec![1, 2], 2, 3, Some(3), None, None),
+        case02_new_stop_exceeds: (vec![1, 2], 2, 3, Some(2), None, ConstraintViolation::skip(VIOLATION_CODE)),
+        case03_same_as_prev: (vec![1, 2], 2, 2, Some(2), None, None),
+        case04_same_as_next: (vec![1, 2], 1, 2, Some(2), None, None),
+        case05_merged_stops: (vec![1, 1, 2], 2, 3, Some(3), None, None),
+        case06_split_stop: (vec![1, 1, 2], 1, 3, Some(3), None, ConstraintViolation::skip(VIOLATION_CODE)),
+        case07_split_stop_fits: (vec![1, 1, 2], 1, 3, Some(4), None, None),
+        case08_inside_stop: (vec![1, 1, 2], 1, 1, Some(2), None, None),
+        case09_headroom: (vec![1, 2], 2, 3, Some(3), Some(1), ConstraintViolation::skip(VIOLATION_CODE)),
+        case10_no_limit: (vec![1, 2], 2, 3, None, Some(1), None),
+    }
+
+    fn can_limit_stops_on_route_impl(
+        locations: Vec<Location>,
+        index: usize,
+        target: Location,
+        limit: Option<usize>,
+        headroom: Option<usize>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let feature = create_stop_limit_feature(
+            "stop_limit",
+            VIOLATION_CODE,
+            Arc::new(move |_| limit),
+            Arc::new(move |_| headroom),
+        )
+        .unwrap();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&test_fleet(), "v1")
+                    .add_activities(
+                        locations.into_iter().map(|location| ActivityBuilder::with_location(location).build()),
+                    )
+                    .build(),
+            )
+            .build();
+        feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let target = ActivityBuilder::with_location(target).build();
+        let activity_ctx = ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(index).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(index + 1),
+        };
+
+        let result =
+            feature.constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+        assert_eq!(result, expected);
+    }
+
+    parameterized_test! {can_limit_stops_on_route_level, (job_location, limit, expected), {
+        can_limit_stops_on_route_level_impl(job_location, limit, expected);
+    }}
+
+    can_limit_stops_on_route_level! {
+        case01_limit_not_reached: (3, Some(3), None),
+        case02_limit_reached_new_location: (3, Some(2), ConstraintViolation::fail(VIOLATION_CODE)),
+        case03_limit_reached_visited_location: (2, Some(2), None),
+        case04_no_limit: (3, None, None),
+    }
+
+    fn can_limit_stops_on_route_level_impl(
+        job_location: Location,
+        limit: Option<usize>,
+        expected: Option<ConstraintViolation>,
+    ) {
+        let feature =
+            create_stop_limit_feature("stop_limit", VIOLATION_CODE, Arc::new(move |_| limit), Arc::new(|_| None))
+                .unwrap();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&test_fleet(), "v1")
+                    .add_activities(vec![
+                        ActivityBuilder::with_location(1).build(),
+                        ActivityBuilder::with_location(2).build(),
+                    ])
+                    .build(),
+            )
+            .build();
+        feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let job = TestSingleBuilder::default().location(Some(job_location)).build_as_job_ref();
+
+        let result = feature.constraint.unwrap().evaluate(&MoveContext::route(&solution_ctx, &route_ctx, &job));
+
+        assert_eq!(result, expected);
+    }
+
+    parameterized_test! {can_count_stops_formed_by_reserved_times, (reserved_time, expected), {
+        can_count_stops_formed_by_reserved_times_impl(reserved_time, expected);
+    }}
+
+    can_count_stops_formed_by_reserved_times! {
+        case01_no_reserved_time: (None, 2),
+        case02_on_first_leg: (Some((5., 2.)), 3),
+        case03_on_middle_leg: (Some((15., 2.)), 3),
+        case04_moved_to_job_stop: (Some((10.5, 1.)), 2),
+        case05_at_job_stop: (Some((10., 1.)), 2),
+    }
+
+    fn can_count_stops_formed_by_reserved_times_impl(reserved_time: Option<(Timestamp, Duration)>, expected: usize) {
+        let mut vehicle = test_vehicle_with_id("v1");
+        if let Some((time, duration)) = reserved_time {
+            vehicle.dimens.set_vehicle_reserved_times(vec![ReservedTimeSpan {
+                time: TimeSpan::Window(TimeWindow::new(time, time)),
+                duration,
+            }]);
+        }
+        let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+        let feature =
+            create_stop_limit_feature("stop_limit", VIOLATION_CODE, Arc::new(|_| Some(10)), Arc::new(|_| None))
+                .unwrap();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&fleet, "v1")
+                    .add_activities(vec![
+                        ActivityBuilder::with_location(10).schedule(Schedule::new(10., 11.)).build(),
+                        ActivityBuilder::with_location(20).schedule(Schedule::new(20., 21.)).build(),
+                    ])
+                    .build(),
+            )
+            .build();
+
+        feature.state.as_ref().unwrap().accept_route_state(&mut route_ctx);
+
+        assert_eq!(route_ctx.state().get_stop_count().copied(), Some(expected));
+    }
+}
@@ -29,6 +29,7 @@ mod timing {
     use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
     use crate::helpers::models::domain::test_random;
     use crate::models::solution::{Activity, Place, Registry};
+    use std::sync::Arc;
 
     fn create_feature() -> Feature {
         TransportFeatureBuilder::new("transport")
@@ -136,6 +137,52 @@ mod timing {
         assert_eq!(result, expected);
     }
 
+    parameterized_test! {can_insert_activity_with_parallel_service, (parallel, expected), {
+        can_insert_activity_with_parallel_service_impl(parallel, expected);
+    }}
+
+    can_insert_activity_with_parallel_service! {
+        case01_serial: (false, ConstraintViolation::skip(VIOLATION_CODE)),
+        case02_parallel: (true, None),
+    }
+
+    fn can_insert_activity_with_parallel_service_impl(parallel: bool, expected: Option<ConstraintViolation>) {
+        let create_activity = |duration: Duration| {
+            let mut single = TestSingleBuilder::default().duration(duration).location(Some(10)).build();
+            if parallel {
+                single.dimens.set_job_parallel_service(vec![0]);
+            }
+            ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::new(0., 100.), duration)
+                .job(Some(Arc::new(single)))
+                .build()
+        };
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![
+                TestVehicleBuilder::default()
+                    .id("v1")
+                    .details(vec![create_detail((Some(0), Some(0)), Some((0., 35.)))])
+                    .build(),
+            ])
+            .build();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activity(create_activity(10.)).build())
+            .build();
+        let feature = create_feature();
+        feature.state.unwrap().accept_route_state(&mut route_ctx);
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+
+        let prev = route_ctx.route().tour.get(1).unwrap();
+        let target = create_activity(8.);
+        let next = route_ctx.route().tour.get(2);
+        let activity_ctx = ActivityContext { index: 1, prev, target: &target, next };
+
+        let result =
+            feature.constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn can_update_activity_schedule() {
         let fleet = FleetBuilder::default()
@@ -1,9 +1,9 @@
-use crate::construction::features::VehicleDistanceFeatureBuilder;
+use crate::construction::features::{VehicleDistanceFeatureBuilder, VehicleReference};
 use crate::construction::heuristics::MoveContext;
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::problem::{TestSingleBuilder, TestTransportCost, TestVehicleBuilder, test_driver};
 use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
-use crate::models::common::{TimeInterval, TimeWindow};
+use crate::models::common::{Cost, TimeInterval, TimeWindow};
 use crate::models::problem::{Actor, ActorDetail, Job, VehiclePlace};
 use std::sync::Arc;
 
@@ -321,3 +321,95 @@ fn can_prefer_route_with_jobs_near_vehicle_start() {
     assert_eq!(fitness_b, 90.0);
     assert!(fitness_a < fitness_b);
 }
+
+// ============================================================================
+// Reference Tests
+// ============================================================================
+
+parameterized_test! {can_use_vehicle_reference_for_penalty, (reference, expected), {
+    can_use_vehicle_reference_for_penalty_impl(reference, expected);
+}}
+
+can_use_vehicle_reference_for_penalty! {
+    // dist(job=5, assigned=100) = 95, nearest = dist(5, 0) = 5 -> penalty = 90
+    case01_nearest: (VehicleReference::Nearest, 90.0),
+    // centroid = (dist(5, 0) + dist(5, 10) + dist(5, 100)) / 3 = 35 -> penalty = 60
+    case02_centroid: (VehicleReference::Centroid, 60.0),
+}
+
+fn can_use_vehicle_reference_for_penalty_impl(reference: VehicleReference, expected: Cost) {
+    let actor_100 = create_actor_at(100);
+    let actors = vec![create_actor_at(0), create_actor_at(10), actor_100.clone()];
+    let objective = VehicleDistanceFeatureBuilder::new("test_vehicle_distance")
+        .set_transport(TestTransportCost::new_shared())
+        .set_actors(actors)
+        .set_compatibility_fn(|_, _| true)
+        .set_vehicle_reference(reference)
+        .build()
+        .unwrap()
+        .objective
+        .unwrap();
+
+    let job = TestSingleBuilder::default().location(Some(5)).build_shared();
+    let route = crate::models::solution::Route {
+        actor: actor_100,
+        tour: {
+            let mut tour = crate::models::solution::Tour::default();
+            tour.set_start(ActivityBuilder::with_location(100).job(None).build());
+            tour.set_end(ActivityBuilder::with_location(100).job(None).build());
+            tour.insert_last(ActivityBuilder::with_location(5).job(Some(job.clone())).build());
+            tour
+        },
+    };
+    let route_ctx = crate::construction::heuristics::RouteContext::new_with_state(
+        route,
+        crate::construction::heuristics::RouteState::default(),
+    );
+    let estimate_ctx = TestInsertionContextBuilder::default().build();
+    let estimate = objective.estimate(&MoveContext::route(&estimate_ctx.solution, &route_ctx, &Job::Single(job)));
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    let fitness = objective.fitness(&insertion_ctx);
+
+    assert_eq!(fitness, expected);
+    assert_eq!(estimate, expected);
+}
+
+#[test]
+fn can_penalize_soft_incompatible_nearest_vehicle() {
+    // Three vehicles: v_0 is soft-incompatible (multiplier 3), v_4 is hard-incompatible, v_10 is compatible.
+    // Job at 5: weighted distances are 15 (v_0) and 5 (v_10), v_4 is ignored -> reference = 5
+    let actors = vec![create_actor_at(0), create_actor_at(4), create_actor_at(10)];
+    let feature = VehicleDistanceFeatureBuilder::new("test_vehicle_distance")
+        .set_transport(TestTransportCost::new_shared())
+        .set_actors(actors.clone())
+        .set_compatibility_cost_fn(Arc::new(|_, actor| match actor.detail.start.as_ref().map(|s| s.location) {
+            Some(0) => Some(3.),
+            Some(4) => None,
+            _ => Some(1.),
+        }))
+        .build()
+        .unwrap();
+    let objective = feature.objective.unwrap();
+    let job = Job::Single(TestSingleBuilder::default().location(Some(5)).build_shared());
+    let insertion_ctx = TestInsertionContextBuilder::default().build();
+    let estimate_for = |actor: &Arc<Actor>| {
+        let location = actor.detail.start.as_ref().unwrap().location;
+        let route_ctx = RouteContextBuilder::default()
+            .with_route(crate::models::solution::Route {
+                actor: actor.clone(),
+                tour: {
+                    let mut tour = crate::models::solution::Tour::default();
+                    tour.set_start(ActivityBuilder::with_location(location).job(None).build());
+                    tour.set_end(ActivityBuilder::with_location(location).job(None).build());
+                    tour
+                },
+            })
+            .build();
+
+        objective.estimate(&MoveContext::route(&insertion_ctx.solution, &route_ctx, &job))
+    };
+
+    assert_eq!(estimate_for(&actors[0]), 10.);
+    assert_eq!(estimate_for(&actors[2]), 0.);
+}
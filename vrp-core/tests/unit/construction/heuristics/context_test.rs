@@ -1,8 +1,11 @@
-use crate::construction::heuristics::{RouteState, UnassignmentInfo};
+use crate::construction::enablers::{TotalDistanceTourState, update_route_schedule};
+use crate::construction::heuristics::{RouteContext, RouteState, UnassignmentInfo};
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::domain::TestGoalContextBuilder;
-use crate::helpers::models::problem::{TestSingleBuilder, test_fleet};
+use crate::helpers::models::problem::{TestSingleBuilder, TestTransportCost, test_fleet};
 use crate::helpers::models::solution::*;
+use crate::models::common::Schedule;
+use crate::models::problem::SimpleActivityCost;
 
 #[test]
 fn can_set_and_get_activity_states_with_different_type_keys() {
@@ -85,3 +88,71 @@ fn can_use_debug_fmt_for_insertion_ctx() {
     assert!(result.contains("unassigned"));
     assert!(result.contains("id: \"single\""));
 }
+
+#[test]
+fn can_snapshot_and_restore_route_state() {
+    let get_schedules = |route_ctx: &RouteContext| {
+        route_ctx.route().tour.all_activities().map(|a| a.schedule.clone()).collect::<Vec<_>>()
+    };
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&test_fleet(), "v1")
+                .add_activity(ActivityBuilder::with_location(10).build())
+                .add_activity(ActivityBuilder::with_location(20).build())
+                .build(),
+        )
+        .build();
+    update_route_schedule(&mut route_ctx, &SimpleActivityCost::default(), &TestTransportCost::default());
+    route_ctx.state_mut().set_total_distance(40.);
+    route_ctx.mark_stale(false);
+    let original_schedules = get_schedules(&route_ctx);
+
+    let snapshot = route_ctx.snapshot_state();
+    route_ctx.route_mut().tour.get_mut(1).unwrap().schedule = Schedule::new(100., 110.);
+    route_ctx.state_mut().set_total_distance(100.);
+    let is_restored = route_ctx.restore_state(snapshot);
+
+    assert!(is_restored);
+    assert_eq!(get_schedules(&route_ctx), original_schedules);
+    assert_eq!(route_ctx.state().get_total_distance(), Some(&40.));
+    assert!(!route_ctx.is_stale());
+}
+
+#[test]
+fn can_reject_snapshot_restore_when_tour_is_changed() {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&test_fleet(), "v1")
+                .add_activity(ActivityBuilder::with_location(10).build())
+                .build(),
+        )
+        .build();
+
+    let snapshot = route_ctx.snapshot_state();
+    route_ctx.route_mut().tour.insert_last(ActivityBuilder::with_location(20).build());
+
+    assert!(!route_ctx.restore_state(snapshot));
+}
+
+#[test]
+fn can_reject_snapshot_restore_when_activities_are_swapped() {
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&test_fleet(), "v1")
+                .add_activity(ActivityBuilder::with_location(10).build())
+                .add_activity(ActivityBuilder::with_location(20).build())
+                .build(),
+        )
+        .build();
+
+    let snapshot = route_ctx.snapshot_state();
+    let tour = &mut route_ctx.route_mut().tour;
+    let (first, second) = (tour.get(1).unwrap().deep_copy(), tour.get(2).unwrap().deep_copy());
+    *tour.get_mut(1).unwrap() = second;
+    *tour.get_mut(2).unwrap() = first;
+
+    assert!(!route_ctx.restore_state(snapshot));
+}
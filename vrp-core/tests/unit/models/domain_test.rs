@@ -0,0 +1,62 @@
+use super::*;
+use crate::helpers::models::domain::TestGoalContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::models::common::{Duration, Timestamp};
+use std::sync::Mutex;
+
+struct ScaledActivityCost {
+    scale: Float,
+}
+
+impl ActivityCost for ScaledActivityCost {
+    fn estimate_departure(
+        &self,
+        _: &Route,
+        activity: &Activity,
+        arrival: Timestamp,
+    ) -> ControlFlow<Timestamp, Timestamp> {
+        ControlFlow::Continue(arrival + activity.place.duration * self.scale)
+    }
+
+    fn estimate_arrival(&self, _: &Route, _: &Activity, departure: Timestamp) -> ControlFlow<Timestamp, Timestamp> {
+        ControlFlow::Continue(departure)
+    }
+}
+
+parameterized_test! {can_warn_about_cost_units_mismatch, (scale, durations, expected), {
+    can_warn_about_cost_units_mismatch_impl(scale, durations, expected);
+}}
+
+can_warn_about_cost_units_mismatch! {
+    case01_same_units: (1., vec![5.], false),
+    case02_minutes_vs_seconds: (60., vec![5.], false),
+    case03_hours_vs_seconds: (3600., vec![5.], true),
+    case04_no_service_time: (3600., vec![0.], false),
+    case05_first_job_outlier: (1., vec![18000., 5., 5.], false),
+    case06_most_jobs_mismatched: (3600., vec![5., 0.001, 5.], true),
+}
+
+fn can_warn_about_cost_units_mismatch_impl(scale: Float, durations: Vec<Duration>, expected: bool) {
+    let messages = Arc::new(Mutex::new(Vec::<String>::new()));
+    let logger: InfoLogger = {
+        let messages = messages.clone();
+        Arc::new(move |msg: &str| messages.lock().unwrap().push(msg.to_string()))
+    };
+    let jobs = durations
+        .into_iter()
+        .map(|duration| TestSingleBuilder::default().location(Some(10)).duration(duration).build_as_job_ref());
+
+    let problem = ProblemBuilder::default()
+        .add_jobs(jobs)
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .with_goal(TestGoalContextBuilder::default().build())
+        .with_transport_cost(TestTransportCost::new_shared())
+        .with_activity_cost(Arc::new(ScaledActivityCost { scale }))
+        .with_logger(logger)
+        .build()
+        .expect("cannot build problem");
+
+    let has_warning = messages.lock().unwrap().iter().any(|msg| msg.contains("same time units"));
+    assert_eq!(has_warning, expected);
+    assert_eq!(problem.check_cost_units().is_some(), expected);
+}
@@ -0,0 +1,60 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::domain::{ProblemBuilder, TestGoalContextBuilder};
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::common::{TimeInterval, TimeWindow, Timestamp};
+use crate::models::problem::{Vehicle, VehicleDetail, VehiclePlace};
+use rosomaxa::prelude::Float;
+
+parameterized_test! {can_compact_schedule_with_avoidable_waiting, (latest, tw, expected), {
+    can_compact_schedule_with_avoidable_waiting_impl(latest, TimeWindow::new(tw.0, tw.1), expected);
+}}
+
+can_compact_schedule_with_avoidable_waiting! {
+    case01_removes_waiting: (None, (50., 100.), (40., 50.)),
+    case02_keeps_no_waiting: (None, (0., 100.), (0., 10.)),
+    case03_limited_by_latest_start: (Some(20.), (50., 100.), (20., 30.)),
+}
+
+fn can_compact_schedule_with_avoidable_waiting_impl(
+    latest: Option<Float>,
+    tw: TimeWindow,
+    expected: (Timestamp, Timestamp),
+) {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(Vehicle {
+            details: vec![VehicleDetail {
+                start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(0.), latest } }),
+                ..test_vehicle_detail()
+            }],
+            ..test_vehicle_with_id("v1")
+        })
+        .build();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(ActivityBuilder::with_location_and_tw(10, tw).build())
+                .build(),
+        )
+        .build();
+    let mut insertion_ctx = TestInsertionContextBuilder::default()
+        .with_problem(
+            ProblemBuilder::default()
+                .with_goal(TestGoalContextBuilder::with_transport_feature().build())
+                .with_fleet(fleet)
+                .build(),
+        )
+        .with_routes(vec![route_ctx])
+        .build();
+    insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+
+    compact_schedules(&mut insertion_ctx);
+
+    let tour = &insertion_ctx.solution.routes[0].route().tour;
+    let departure = tour.start().unwrap().schedule.departure;
+    let arrival = tour.get(1).unwrap().schedule.arrival;
+    assert_eq!((departure, arrival), expected);
+}
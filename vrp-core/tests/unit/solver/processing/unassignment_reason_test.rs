@@ -1,13 +1,18 @@
+use crate::construction::heuristics::RegistryContext;
 use crate::construction::heuristics::{InsertionContext, UnassignmentInfo};
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
-use crate::helpers::models::domain::{ProblemBuilder, TestGoalContextBuilder};
+use crate::helpers::models::domain::{ProblemBuilder, TestGoalContextBuilder, test_random};
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::{RouteBuilder, RouteContextBuilder};
+use crate::models::Extras;
+use crate::models::Problem;
 use crate::models::ViolationCode;
 use crate::models::common::TimeWindow;
 use crate::models::problem::{Job, JobIdDimension, VehicleIdDimension};
-use crate::solver::processing::UnassignmentReason;
+use crate::models::solution::Registry;
+use crate::solver::processing::{FeasibilityReportExtraProperty, UnassignmentReason};
 use rosomaxa::evolution::HeuristicSolutionProcessing;
+use std::sync::Arc;
 
 const UNASSIGNMENT_CODE: ViolationCode = ViolationCode(1);
 
@@ -119,3 +124,42 @@ fn can_handle_assignable_job_impl(code: UnassignmentInfo) {
         _ => unreachable!(),
     }
 }
+
+#[test]
+fn can_report_reasons_of_unused_actors_with_feasibility_report() {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(test_vehicle_with_id("v1"))
+        .add_vehicle(test_vehicle_with_id("v2"))
+        .build();
+    let job = create_early_delivery("job1");
+    let mut extras = Extras::default();
+    extras.set_feasibility_report(Arc::new(true));
+    let problem = ProblemBuilder::default()
+        .with_goal(TestGoalContextBuilder::with_transport_feature().build())
+        .with_fleet(fleet)
+        .with_jobs(vec![job.clone()])
+        .build();
+    let problem = Problem { extras: Arc::new(extras), ..problem };
+    let registry = RegistryContext::new(problem.goal.as_ref(), Registry::new(&problem.fleet, test_random()));
+    let mut insertion_ctx = TestInsertionContextBuilder::default()
+        .with_problem(problem)
+        .with_unassigned(vec![(job, UnassignmentInfo::Unknown)])
+        .build();
+    insertion_ctx.solution.registry = registry;
+
+    let insertion_ctx = UnassignmentReason::default().post_process(insertion_ctx);
+
+    let (_, code) = insertion_ctx.solution.unassigned.into_iter().next().unwrap();
+    match code {
+        UnassignmentInfo::Detailed(details) => {
+            let mut details = details
+                .iter()
+                .map(|(actor, code)| (actor.vehicle.dimens.get_vehicle_id().unwrap().as_str(), *code))
+                .collect::<Vec<_>>();
+            details.sort_by_key(|(vehicle_id, _)| *vehicle_id);
+            assert_eq!(details, vec![("v1", UNASSIGNMENT_CODE), ("v2", UNASSIGNMENT_CODE)]);
+        }
+        _ => unreachable!(),
+    }
+}
@@ -7,7 +7,8 @@ use vrp_pragmatic::core::prelude::*;
 use vrp_pragmatic::core::rosomaxa::evolution::TelemetryMode;
 use vrp_pragmatic::core::solver::search::{Recreate, RecreateWithCheapest};
 use vrp_pragmatic::core::solver::{RefinementContext, create_elitism_population};
-use vrp_pragmatic::format::problem::PragmaticProblem;
+use vrp_pragmatic::format::problem::{PragmaticProblem, VehicleBreak, VehicleRequiredBreakTime, deserialize_problem};
+use vrp_pragmatic::format::solution::{PragmaticOutputType, write_pragmatic};
 
 pub fn get_bench_resource(resource_path: &str) -> std::io::Result<File> {
     let mut path = std::env::current_dir()?;
@@ -66,11 +67,54 @@ fn bench_init_reload_100_benchmark(c: &mut Criterion) {
     });
 }
 
+fn bench_write_required_breaks_100_benchmark(c: &mut Criterion) {
+    c.bench_function("writing solution for a problem with 100 trivial deliveries and 100 required breaks", |b| {
+        let file = get_bench_resource("../../examples/data/pragmatic/benches/simple.deliveries.100.json")
+            .expect("cannot open problem file");
+        let mut problem = deserialize_problem(BufReader::new(file)).expect("cannot deserialize problem");
+        problem.fleet.vehicles.iter_mut().for_each(|vehicle| {
+            vehicle.vehicle_ids.truncate(1);
+            vehicle.capacity = vec![100];
+            vehicle.shifts.iter_mut().for_each(|shift| {
+                shift.end = None;
+                shift.breaks = Some(
+                    (1..=100)
+                        .map(|idx| VehicleBreak::Required {
+                            time: VehicleRequiredBreakTime::OffsetTime {
+                                earliest: idx as Float * 600.,
+                                latest: idx as Float * 600.,
+                            },
+                            duration: 60.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
+                        })
+                        .collect(),
+                );
+            });
+        });
+        let problem = Arc::new(problem.read_pragmatic().unwrap_or_else(|errs| panic!("cannot read problem: {errs}")));
+        let environment = Arc::new(Environment::default());
+        let refinement_ctx = RefinementContext::new(
+            problem.clone(),
+            Box::new(create_elitism_population(problem.goal.clone(), environment.clone())),
+            TelemetryMode::None,
+            environment,
+        );
+        let solution = Solution::from(solve_problem_with_recreate_cheapest(&refinement_ctx));
+
+        b.iter(|| {
+            let mut writer = std::io::BufWriter::new(std::io::sink());
+            black_box(write_pragmatic(&problem, &solution, PragmaticOutputType::OnlyPragmatic, &mut writer))
+        })
+    });
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(512).noise_threshold(0.05);
     targets = bench_init_deliveries_100_benchmark,
               bench_init_multi_job_100_benchmark,
               bench_init_reload_100_benchmark,
+              bench_write_required_breaks_100_benchmark,
 }
 criterion_main!(benches);
@@ -3,11 +3,13 @@
 mod assignment_test;
 
 use super::*;
-use crate::format::get_indices;
 use crate::format::solution::activity_matcher::*;
+use crate::format::{CoordIndex, JobIndex, get_indices};
 use crate::utils::combine_error_results;
 use std::collections::HashSet;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
+use vrp_core::models::common::Timestamp;
+use vrp_core::models::problem::{JobParallelServiceDimension, Single};
 use vrp_core::models::solution::Place;
 use vrp_core::prelude::GenericResult;
 use vrp_core::utils::GenericError;
@@ -157,6 +159,10 @@ fn check_jobs_match(ctx: &CheckerContext) -> GenericResult<()> {
         .tours
         .iter()
         .flat_map(move |tour| {
+            let cost_span = ctx.get_vehicle(&tour.vehicle_id).ok().and_then(|v| v.costs.span.as_ref());
+            // NOTE empty tour has no activities to match
+            let offset_anchor = get_offset_anchor(tour, is_first_job_anchored(cost_span)).unwrap_or_default();
+
             tour.stops.iter().flat_map(move |stop| {
                 stop.activities()
                     .iter()
@@ -165,7 +171,13 @@ fn check_jobs_match(ctx: &CheckerContext) -> GenericResult<()> {
                         move |(idx, activity)| {
                             match stop {
                                 Stop::Point(stop) => {
-                                    let result = try_match_point_job(tour, stop, activity, job_index, coord_index);
+                                    let result = try_match_point_job(
+                                        tour,
+                                        stop,
+                                        activity,
+                                        (job_index, coord_index),
+                                        offset_anchor,
+                                    );
                                     match result {
                                         Err(_) => {
                                             // NOTE required break is not a job
@@ -176,8 +188,16 @@ fn check_jobs_match(ctx: &CheckerContext) -> GenericResult<()> {
                                                 true
                                             }
                                         }
-                                        Ok(Some(JobInfo(_, _, place, time))) => {
-                                            is_valid_job_info(ctx, stop, activity, *idx, place, time)
+                                        Ok(Some(JobInfo(_, single, place, time))) => {
+                                            let parallel_group = get_parallel_service_group(
+                                                tour,
+                                                stop,
+                                                *idx,
+                                                (&single, &place),
+                                                (job_index, coord_index),
+                                                offset_anchor,
+                                            );
+                                            is_valid_job_info(ctx, stop, activity, *idx, place, time, parallel_group)
                                         }
                                         _ => false,
                                     }
@@ -206,6 +226,41 @@ fn check_jobs_match(ctx: &CheckerContext) -> GenericResult<()> {
     Ok(())
 }
 
+/// Returns the service start of the group and the end of the previous activity when the activity at given
+/// index continues a parallel service: consecutive activities at the same location which allow parallel
+/// service share the service start of the group's first activity.
+fn get_parallel_service_group(
+    tour: &Tour,
+    stop: &PointStop,
+    activity_idx: usize,
+    (single, place): (&Single, &Place),
+    indices: (&JobIndex, &CoordIndex),
+    offset_anchor: Timestamp,
+) -> Option<(Timestamp, Timestamp)> {
+    let is_parallel = |single: &Single, place: &Place| {
+        single.dimens.get_job_parallel_service().is_some_and(|places| places.contains(&place.idx))
+    };
+
+    if !is_parallel(single, place) {
+        return None;
+    }
+
+    let group = stop.activities[..activity_idx]
+        .iter()
+        .rev()
+        .map_while(|activity| match try_match_point_job(tour, stop, activity, indices, offset_anchor) {
+            Ok(Some(JobInfo(_, other_single, other_place, time)))
+                if other_place.location == place.location && is_parallel(&other_single, &other_place) =>
+            {
+                Some(time)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    Some((group.last()?.start, group.first()?.end))
+}
+
 fn is_valid_job_info(
     ctx: &CheckerContext,
     stop: &PointStop,
@@ -213,6 +268,7 @@ fn is_valid_job_info(
     activity_idx: usize,
     place: Place,
     time: TimeWindow,
+    parallel_group: Option<(Timestamp, Timestamp)>,
 ) -> bool {
     let not_equal = |left: Float, right: Float| left != right;
     let parking = ctx.clustering.as_ref().map(|config| config.serving.get_parking()).unwrap_or(0.);
@@ -224,7 +280,26 @@ fn is_valid_job_info(
         (_, _, Err(_)) | (_, None, Ok(Some(_))) | (_, Some(_), Ok(None)) | (&None, &Some(_), Ok(Some(_))) => true,
         (_, None, Ok(None)) => {
             let expected_departure = time.start.max(place.time.start) + place.duration + extra_time;
-            not_equal(time.end, expected_departure)
+            // NOTE parallel service shares the service start, so the stop is left after the longest service
+            let (expected_start, expected_departure) = match parallel_group {
+                Some((group_start, prev_end)) => {
+                    (Some(group_start.max(place.time.start)), expected_departure.max(prev_end))
+                }
+                None => (None, expected_departure),
+            };
+            // NOTE minimum dwell of the stop's jobs is counted from the stop arrival and delays its last activity
+            let expected_departure = if activity_idx + 1 == stop.activities.len() {
+                let min_dwell = stop
+                    .activities
+                    .iter()
+                    .filter_map(|activity| ctx.get_job_by_id(&activity.job_id).and_then(|job| job.min_dwell))
+                    .fold(0., Float::max);
+                expected_departure.max(parse_time(&stop.time.arrival) + min_dwell)
+            } else {
+                expected_departure
+            };
+
+            not_equal(time.end, expected_departure) || expected_start.is_some_and(|start| not_equal(time.start, start))
         }
         (Some(config), Some(commute), Ok(Some(d_commute))) => {
             let (service_time, parking) = match config.serving {
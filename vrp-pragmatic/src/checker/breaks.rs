@@ -4,15 +4,65 @@ mod breaks_test;
 
 use super::*;
 use crate::format::problem::RouteCostSpan as FmtRouteCostSpan;
+use crate::format::problem::parse_exact_break_time_window;
+use crate::format::solution::activity_matcher::{
+    get_after_job_break_time_window, get_after_load_break_time_window, get_first_job_arrival,
+    get_min_jobs_break_time_window, get_offset_anchor, get_reload_break_time_windows, is_first_job_anchored,
+};
 use crate::utils::combine_error_results;
 use std::iter::once;
+use vrp_core::construction::enablers::is_break_window_within_shift;
 use vrp_core::models::common::Timestamp;
 use vrp_core::prelude::GenericResult;
 use vrp_core::utils::GenericError;
 
 /// Checks that breaks are properly assigned.
 pub fn check_breaks(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_break_assignment(context)])
+    combine_error_results(&[check_break_assignment(context), check_break_fraction(context)])
+}
+
+/// Checks that break time does not exceed shift's max break fraction of working time. As required
+/// breaks are always taken, the limit is considered as violated only when an optional break is taken.
+fn check_break_fraction(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let Some(max_break_fraction) = context.get_vehicle_shift(tour)?.max_break_fraction else {
+            return Ok(());
+        };
+
+        let (optional_break_time, required_break_time) = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .filter(|(_, activity)| activity.activity_type == "break")
+            .try_fold((0., 0.), |(optional, required), (stop, activity)| {
+                let duration = get_time_window(stop, activity).duration();
+                match context.get_activity_type(tour, stop, activity)? {
+                    ActivityType::Break(VehicleBreak::Optional { .. }) => Ok((optional + duration, required)),
+                    _ => Ok::<_, GenericError>((optional, required + duration)),
+                }
+            })?;
+
+        let (departure, arrival) = match (tour.stops.first(), tour.stops.last()) {
+            (Some(first), Some(last)) => {
+                (parse_time(&first.schedule().departure), parse_time(&last.schedule().arrival))
+            }
+            _ => return Ok(()),
+        };
+
+        let break_time = optional_break_time + required_break_time;
+        let working_time = (arrival - departure - break_time).max(0.);
+
+        if optional_break_time > 0. && break_time > max_break_fraction * working_time {
+            return Err(format!(
+                "max break fraction violation, expected: not more than {} of working time '{}', got break time: {}, \
+                 vehicle id '{}', shift index: {}",
+                max_break_fraction, working_time, break_time, tour.vehicle_id, tour.shift_index
+            )
+            .into());
+        }
+
+        Ok(())
+    })
 }
 
 fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
@@ -35,10 +85,26 @@ fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
                     |acc, (from_loc, (from, to), (break_activity, vehicle_break))| {
                         // check time
                         let visit_time = get_time_window(stop, break_activity);
-                        let break_time_window = get_break_time_window(tour, &vehicle_break, cost_span)?;
-                        if !visit_time.intersects(&break_time_window) {
+                        let break_time_windows =
+                            get_break_time_windows(&context.problem, tour, &vehicle_break, cost_span)?;
+                        if !break_time_windows.iter().any(|break_tw| visit_time.intersects(break_tw)) {
                             return Err(format!(
-                                "break visit time '{visit_time:?}' is invalid: expected is in '{break_time_window:?}'",
+                                "break visit time '{visit_time:?}' is invalid: expected is in '{break_time_windows:?}'",
+                            )
+                            .into());
+                        }
+
+                        // check that offset break is anchored to the first job arrival
+                        check_offset_break_anchor(tour, &vehicle_break, &visit_time, cost_span)?;
+
+                        // check stop type: required break has no location, so it is either taken on transit
+                        // or shares a point stop with other activities
+                        if let (VehicleBreak::Required { .. }, Stop::Point(point)) = (&vehicle_break, stop)
+                            && point.activities.iter().all(|activity| activity.activity_type == "break")
+                        {
+                            return Err(format!(
+                                "required break cannot be taken at a separate point stop at '{:?}'",
+                                point.location
                             )
                             .into());
                         }
@@ -66,6 +132,18 @@ fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
                             )
                             .into());
                         }
+
+                        // check work time after break
+                        if let Some(min_work_after) = vehicle_shift.min_work_after_break {
+                            let work_after = get_last_job_departure(tour).map_or(0., |end| end - visit_time.end);
+                            if work_after < min_work_after {
+                                return Err(format!(
+                                    "break is followed by '{work_after}' work time, expected at least '{min_work_after}'"
+                                )
+                                .into());
+                            }
+                        }
+
                         Ok(acc + 1)
                     },
                 )
@@ -95,26 +173,30 @@ fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
 
         let expected_break_count =
             vehicle_shift.breaks.iter().flat_map(|breaks| breaks.iter()).fold(0, |acc, vehicle_break| {
-                let break_tw =
-                    get_break_time_window(tour, vehicle_break, cost_span).expect("cannot get break time windows");
-
-                let should_assign = match vehicle_break {
+                let assign_count = match vehicle_break {
                     VehicleBreak::Optional { policy, .. } => {
+                        let break_tw = get_break_time_window(&context.problem, tour, vehicle_break, cost_span)
+                            .expect("cannot get break time windows");
                         let policy =
                             policy.as_ref().cloned().unwrap_or(VehicleOptionalBreakPolicy::SkipIfNoIntersection);
 
-                        match policy {
+                        let should_assign = match policy {
                             VehicleOptionalBreakPolicy::SkipIfNoIntersection => break_tw.start < arrival,
                             VehicleOptionalBreakPolicy::SkipIfArrivalBeforeEnd => arrival > break_tw.end,
-                        }
+                        };
+
+                        usize::from(should_assign)
                     }
                     VehicleBreak::Required { .. } => {
-                        // NOTE: skip break if its end time is after tour end
-                        break_tw.intersects(&tour_tw) && break_tw.end < tour_tw.end
+                        get_required_break_status(&context.problem, tour, vehicle_break, (&tour_tw, &vehicle_shift), cost_span)
+                            .expect("cannot get break time windows")
+                            .into_iter()
+                            .filter(|status| *status == RequiredBreakStatus::Placed)
+                            .count()
                     }
                 };
 
-                if should_assign { acc + 1 } else { acc }
+                acc + assign_count
             });
 
         let total_break_count = actual_break_count + get_break_violation_count(&context.solution, tour);
@@ -131,6 +213,194 @@ fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
     })
 }
 
+/// Checks that a required offset break starts within its offset range counted from the first job arrival
+/// when the route cost span starts at the first job.
+fn check_offset_break_anchor(
+    tour: &Tour,
+    vehicle_break: &VehicleBreak,
+    visit_time: &TimeWindow,
+    cost_span: Option<&FmtRouteCostSpan>,
+) -> GenericResult<()> {
+    const TOLERANCE: Float = 1.;
+
+    let Some(FmtRouteCostSpan::FirstJobToDepot | FmtRouteCostSpan::FirstJobToLastJob) = cost_span else {
+        return Ok(());
+    };
+    // NOTE break shifted after jobs is not anchored to the first job arrival
+    let VehicleBreak::Required {
+        time: VehicleRequiredBreakTime::OffsetTime { earliest, latest },
+        min_jobs_before_break: None,
+        ..
+    } = vehicle_break
+    else {
+        return Ok(());
+    };
+    let Some(first_job_arrival) = get_first_job_arrival(tour) else { return Ok(()) };
+
+    let expected = TimeWindow::new(first_job_arrival + earliest - TOLERANCE, first_job_arrival + latest + TOLERANCE);
+    if expected.contains(visit_time.start) {
+        Ok(())
+    } else {
+        Err(format!(
+            "offset break start '{}' is not anchored to the first job arrival '{first_job_arrival}': expected in '[{}, {}]'",
+            visit_time.start,
+            first_job_arrival + earliest,
+            first_job_arrival + latest
+        )
+        .into())
+    }
+}
+
+fn get_shift_time_window(shift: &VehicleShift) -> TimeWindow {
+    let end = shift.end.as_ref().map_or(Float::MAX, |end| parse_time(&end.latest));
+
+    TimeWindow::new(parse_time(&shift.start.earliest), end)
+}
+
+fn get_last_job_departure(tour: &Tour) -> Option<Timestamp> {
+    tour.stops
+        .iter()
+        .rev()
+        .flat_map(|stop| stop.activities().iter().rev().map(move |activity| (stop, activity)))
+        .find(|(_, activity)| !matches!(activity.activity_type.as_str(), "departure" | "arrival" | "break"))
+        .map(|(stop, activity)| get_time_window(stop, activity).end)
+}
+
+/// Specifies whether a required break is expected to be placed in the tour and, if not, why.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RequiredBreakStatus {
+    /// Break is expected to be placed in the tour.
+    Placed,
+    /// Break is skipped as its time window does not intersect the tour time.
+    SkippedNoIntersection,
+    /// Break is skipped as its time window ends at or after the tour end or it leaves less work time
+    /// after it than required.
+    SkippedAtTourEnd,
+    /// Break is skipped as the job it is anchored to is not served in the tour.
+    SkippedJobNotServed,
+    /// Break is skipped as the load it is anchored to is not handled in the tour.
+    SkippedLoadNotReached,
+    /// Break is skipped as the tour has fewer jobs than required before the break.
+    SkippedMinJobsNotReached,
+    /// Break is skipped as none of its placements fits into the shift time.
+    SkippedOutsideShift,
+}
+
+impl std::fmt::Display for RequiredBreakStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            RequiredBreakStatus::Placed => "placed",
+            RequiredBreakStatus::SkippedNoIntersection => "skipped: does not intersect tour time",
+            RequiredBreakStatus::SkippedAtTourEnd => "skipped: ends at/after tour end",
+            RequiredBreakStatus::SkippedJobNotServed => "skipped: anchor job is not served",
+            RequiredBreakStatus::SkippedLoadNotReached => "skipped: anchor load is not handled",
+            RequiredBreakStatus::SkippedMinJobsNotReached => "skipped: minimum jobs are not served",
+            RequiredBreakStatus::SkippedOutsideShift => "skipped: does not fit into shift time",
+        };
+
+        write!(f, "{status}")
+    }
+}
+
+/// Gets statuses of the shift's required breaks for the given tour in the order of their definition.
+/// A break anchored to reloads has a status per working period of the tour. The same decision logic
+/// as in the solution checker is used.
+pub fn get_required_break_statuses(
+    problem: &Problem,
+    tour: &Tour,
+    shift: &VehicleShift,
+    cost_span: Option<&FmtRouteCostSpan>,
+) -> GenericResult<Vec<RequiredBreakStatus>> {
+    let departure = tour.stops.first().map(|stop| parse_time(&stop.schedule().departure));
+    let arrival = tour.stops.last().map(|stop| parse_time(&stop.schedule().arrival));
+    let tour_tw = departure
+        .zip(arrival)
+        .map(|(departure, arrival)| TimeWindow::new(departure, arrival))
+        .ok_or_else(|| GenericError::from(format!("cannot get schedule for tour '{}'", tour.vehicle_id)))?;
+    shift
+        .breaks
+        .iter()
+        .flat_map(|breaks| breaks.iter())
+        .filter(|vehicle_break| matches!(vehicle_break, VehicleBreak::Required { .. }))
+        .map(|vehicle_break| get_required_break_status(problem, tour, vehicle_break, (&tour_tw, shift), cost_span))
+        .collect::<GenericResult<Vec<_>>>()
+        .map(|statuses| statuses.into_iter().flatten().collect())
+}
+
+fn get_required_break_status(
+    problem: &Problem,
+    tour: &Tour,
+    vehicle_break: &VehicleBreak,
+    (tour_tw, shift): (&TimeWindow, &VehicleShift),
+    cost_span: Option<&FmtRouteCostSpan>,
+) -> GenericResult<Vec<RequiredBreakStatus>> {
+    let shift_tw = &get_shift_time_window(shift);
+
+    // NOTE exact break is expected only when any of its placements fits into the shift
+    if let VehicleBreak::Required {
+        time: VehicleRequiredBreakTime::ExactTime { earliest, latest, utc_offset },
+        duration,
+        ..
+    } = vehicle_break
+        && !is_break_window_within_shift(
+            &parse_exact_break_time_window(earliest, latest, *utc_offset)?,
+            *duration,
+            shift_tw,
+        )
+    {
+        return Ok(vec![RequiredBreakStatus::SkippedOutsideShift]);
+    }
+
+    // NOTE break anchored to a job is expected only when the job is served in the tour
+    if let VehicleBreak::Required { time: VehicleRequiredBreakTime::AfterJob { .. }, .. } = vehicle_break {
+        let is_served = get_break_time_window(problem, tour, vehicle_break, cost_span).is_ok();
+        let status = if is_served { RequiredBreakStatus::Placed } else { RequiredBreakStatus::SkippedJobNotServed };
+        return Ok(vec![status]);
+    }
+
+    // NOTE break anchored to a load is expected only when the load is handled in the tour
+    if let VehicleBreak::Required { time: VehicleRequiredBreakTime::AfterLoad { .. }, .. } = vehicle_break {
+        let is_reached = get_break_time_window(problem, tour, vehicle_break, cost_span).is_ok();
+        let status = if is_reached { RequiredBreakStatus::Placed } else { RequiredBreakStatus::SkippedLoadNotReached };
+        return Ok(vec![status]);
+    }
+
+    // NOTE break shifted after jobs is expected only when enough jobs are served in the tour
+    if let VehicleBreak::Required { min_jobs_before_break: Some(_), .. } = vehicle_break
+        && get_break_time_window(problem, tour, vehicle_break, cost_span).is_err()
+    {
+        return Ok(vec![RequiredBreakStatus::SkippedMinJobsNotReached]);
+    }
+
+    get_break_time_windows(problem, tour, vehicle_break, cost_span)?
+        .into_iter()
+        .map(|break_tw| {
+            // NOTE exact break is placed within its window clipped to the shift
+            let break_tw = match vehicle_break {
+                VehicleBreak::Required { time: VehicleRequiredBreakTime::ExactTime { .. }, .. } => {
+                    TimeWindow::new(break_tw.start.max(shift_tw.start), break_tw.end.min(shift_tw.end))
+                }
+                _ => break_tw,
+            };
+
+            // NOTE: skip break if its end time is after tour end or it leaves not enough work time after it
+            let is_short_work_after = match (vehicle_break, shift.min_work_after_break) {
+                (VehicleBreak::Required { duration, .. }, Some(min_work_after)) => get_last_job_departure(tour)
+                    .is_none_or(|departure| departure - (break_tw.end + *duration) < min_work_after),
+                _ => false,
+            };
+
+            Ok(if !break_tw.intersects(tour_tw) {
+                RequiredBreakStatus::SkippedNoIntersection
+            } else if break_tw.end >= tour_tw.end || is_short_work_after {
+                RequiredBreakStatus::SkippedAtTourEnd
+            } else {
+                RequiredBreakStatus::Placed
+            })
+        })
+        .collect()
+}
+
 /// Represents information about break and neighbour activity.
 type LegBreakInfo<'a> = (Option<Location>, (Option<&'a Activity>, &'a Activity), (&'a Activity, VehicleBreak));
 
@@ -148,7 +418,8 @@ fn as_leg_info_with_break<'a>(
 
     if let Some((from, to)) = leg
         && let Some((break_activity, vehicle_break)) = once(to)
-            .chain(from.iter().cloned())
+            // NOTE break as the leg start is considered only at the stop start, otherwise it is matched by previous leg
+            .chain(from.filter(|from| stop.activities().first().is_some_and(|first| std::ptr::eq(first, *from))))
             .flat_map(|activity| context.get_activity_type(tour, stop, activity).map(|at| (activity, at)))
             .filter_map(|(activity, activity_type)| match activity_type {
                 ActivityType::Break(vehicle_break) => Some((activity, vehicle_break)),
@@ -165,8 +436,30 @@ fn as_leg_info_with_break<'a>(
     None
 }
 
+/// Gets break time windows: a break anchored to reloads has one per working period of the tour
+/// (see `get_reload_break_time_windows`), others have a single one (see `get_break_time_window`).
+pub(crate) fn get_break_time_windows(
+    problem: &Problem,
+    tour: &Tour,
+    vehicle_break: &VehicleBreak,
+    cost_span: Option<&FmtRouteCostSpan>,
+) -> GenericResult<Vec<TimeWindow>> {
+    match vehicle_break {
+        VehicleBreak::Required {
+            time: VehicleRequiredBreakTime::AnchoredOffsetTime { earliest, latest, anchor: BreakOffsetAnchor::Reload },
+            duration,
+            ..
+        } => {
+            let offset_anchor = get_offset_anchor(tour, is_first_job_anchored(cost_span))?;
+            Ok(get_reload_break_time_windows(tour, offset_anchor, (*earliest, *latest), *duration))
+        }
+        _ => get_break_time_window(problem, tour, vehicle_break, cost_span).map(|tw| vec![tw]),
+    }
+}
+
 /// Gets break time window, using the RouteCostSpan to determine the anchor for offset breaks.
 pub(crate) fn get_break_time_window(
+    problem: &Problem,
     tour: &Tour,
     vehicle_break: &VehicleBreak,
     cost_span: Option<&FmtRouteCostSpan>,
@@ -177,10 +470,136 @@ pub(crate) fn get_break_time_window(
         .map(|stop| parse_time(&stop.schedule().departure))
         .ok_or_else(|| format!("cannot get departure time for tour: '{}'", tour.vehicle_id))?;
 
+    match vehicle_break {
+        VehicleBreak::Required { time: VehicleRequiredBreakTime::AfterJob { job_id }, duration, .. } => {
+            get_after_job_break_time_window(tour, job_id, *duration)
+                .ok_or_else(|| format!("cannot find job '{job_id}' for break in tour: '{}'", tour.vehicle_id).into())
+        }
+        VehicleBreak::Required { time: VehicleRequiredBreakTime::AfterLoad { load }, .. } => {
+            get_after_load_break_time_window(problem, tour, load).ok_or_else(|| {
+                format!("cannot find stop with handled load '{load:?}' for break in tour: '{}'", tour.vehicle_id).into()
+            })
+        }
+        _ => {
+            let arrival = tour.stops.last().map(|stop| parse_time(&stop.schedule().arrival));
+            let break_tw = get_anchored_break_time_window(
+                vehicle_break,
+                departure,
+                get_first_job_arrival(tour),
+                arrival,
+                cost_span,
+            )
+            .map_err(|err| format!("{err} for tour: '{}'", tour.vehicle_id))?;
+
+            match vehicle_break {
+                VehicleBreak::Required { min_jobs_before_break: Some(min_jobs), duration, .. } => {
+                    get_min_jobs_break_time_window(tour, *min_jobs, break_tw, *duration).ok_or_else(|| {
+                        format!("cannot find '{min_jobs}' jobs served before break in tour: '{}'", tour.vehicle_id)
+                            .into()
+                    })
+                }
+                _ => Ok(break_tw),
+            }
+        }
+    }
+}
+
+/// Gets time windows of the shift breaks without a solved tour, assuming that the vehicle departs
+/// at the shift's earliest start and, optionally, arrives at the first job at given time.
+/// When the first job arrival is not specified, the departure is used as the anchor for offset
+/// breaks regardless of the route cost span. Fractional breaks are resolved using the latest shift
+/// end as the tour end. Breaks anchored to a reload are resolved as if the tour has no reloads.
+/// Breaks anchored to a job or a load cannot be resolved this way.
+pub fn get_shift_break_time_windows(
+    shift: &VehicleShift,
+    cost_span: Option<&FmtRouteCostSpan>,
+    first_job_arrival: Option<Timestamp>,
+) -> GenericResult<Vec<TimeWindow>> {
+    let departure = parse_time(&shift.start.earliest);
+    let arrival = shift.end.as_ref().map(|end| parse_time(&end.latest));
+
+    shift
+        .breaks
+        .iter()
+        .flat_map(|breaks| breaks.iter())
+        .map(|vehicle_break| {
+            get_anchored_break_time_window(vehicle_break, departure, first_job_arrival, arrival, cost_span)
+        })
+        .collect()
+}
+
+/// Validates breaks of all fleet shifts against their start/end envelope without a matrix or
+/// a solution, using the same assumed anchors as [get_shift_break_time_windows]. Reports breaks
+/// which are longer than the shift or which start after the shift end. Breaks anchored to a job
+/// or a load are not checked.
+pub fn validate_fleet_breaks(fleet: &Fleet) -> Result<(), Vec<String>> {
+    let errors = fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| {
+            vehicle.shifts.iter().enumerate().map(move |(shift_idx, shift)| (vehicle, shift_idx, shift))
+        })
+        .flat_map(|(vehicle, shift_idx, shift)| {
+            shift.breaks.iter().flat_map(|breaks| breaks.iter().enumerate()).filter_map(
+                move |(break_idx, vehicle_break)| {
+                    check_shift_break(shift, vehicle_break, vehicle.costs.span.as_ref()).map(|err| {
+                        format!("vehicle type '{}', shift {shift_idx}, break {break_idx}: {err}", vehicle.type_id)
+                    })
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+fn check_shift_break(
+    shift: &VehicleShift,
+    vehicle_break: &VehicleBreak,
+    cost_span: Option<&FmtRouteCostSpan>,
+) -> Option<String> {
+    let departure = parse_time(&shift.start.earliest);
+    let arrival = shift.end.as_ref().map(|end| parse_time(&end.latest));
+
+    let duration = match vehicle_break {
+        VehicleBreak::Required {
+            time: VehicleRequiredBreakTime::AfterJob { .. } | VehicleRequiredBreakTime::AfterLoad { .. },
+            ..
+        } => return None,
+        // NOTE fractional breaks of an open shift depend on the tour end
+        VehicleBreak::Required { time: VehicleRequiredBreakTime::FractionalOffset { .. }, .. } if arrival.is_none() => {
+            return None;
+        }
+        VehicleBreak::Required { duration, .. } => *duration,
+        VehicleBreak::Optional { places, .. } => places.iter().map(|place| place.duration).fold(0., Float::max),
+    };
+
+    if let Some(shift_duration) = arrival.map(|arrival| arrival - departure).filter(|&shift| duration > shift) {
+        return Some(format!("duration '{duration}' exceeds shift duration '{shift_duration}'"));
+    }
+
+    match get_anchored_break_time_window(vehicle_break, departure, None, arrival, cost_span) {
+        Ok(break_tw) if arrival.is_some_and(|arrival| break_tw.start > arrival) => {
+            Some("break starts after shift end".to_string())
+        }
+        Ok(_) => None,
+        Err(err) => Some(err.to_string()),
+    }
+}
+
+/// Gets break time window using explicit departure and first job arrival as anchors. Fractional breaks
+/// are resolved within the time between departure and tour end arrival.
+fn get_anchored_break_time_window(
+    vehicle_break: &VehicleBreak,
+    departure: Timestamp,
+    first_job_arrival: Option<Timestamp>,
+    arrival: Option<Timestamp>,
+    cost_span: Option<&FmtRouteCostSpan>,
+) -> GenericResult<TimeWindow> {
     // Compute the offset anchor based on RouteCostSpan
     let offset_anchor = match cost_span {
         Some(FmtRouteCostSpan::FirstJobToDepot | FmtRouteCostSpan::FirstJobToLastJob) => {
-            get_first_job_arrival(tour).unwrap_or(departure)
+            first_job_arrival.unwrap_or(departure)
         }
         _ => departure,
     };
@@ -189,17 +608,32 @@ pub(crate) fn get_break_time_window(
         VehicleBreak::Optional { time: VehicleOptionalBreakTime::TimeWindow(tw), .. } => Ok(parse_time_window(tw)),
         VehicleBreak::Optional { time: VehicleOptionalBreakTime::TimeOffset(offset), .. } => {
             if offset.len() != 2 {
-                return Err(format!("invalid offset break for tour: '{}'", tour.vehicle_id).into());
+                return Err("invalid offset break".into());
             }
 
-            Ok(TimeWindow::new(departure + *offset.first().unwrap(), departure + *offset.last().unwrap()))
+            Ok(TimeWindow::new(offset_anchor + *offset.first().unwrap(), offset_anchor + *offset.last().unwrap()))
         }
-        VehicleBreak::Required { time, duration } => {
+        VehicleBreak::Required { time, duration, .. } => {
             let (start, end) = match time {
-                VehicleRequiredBreakTime::OffsetTime { earliest, latest } => {
+                VehicleRequiredBreakTime::OffsetTime { earliest, latest }
+                | VehicleRequiredBreakTime::AnchoredOffsetTime { earliest, latest, .. } => {
                     (offset_anchor + *earliest, offset_anchor + *latest)
                 }
-                VehicleRequiredBreakTime::ExactTime { earliest, latest } => (parse_time(earliest), parse_time(latest)),
+                VehicleRequiredBreakTime::ExactTime { earliest, latest, utc_offset } => {
+                    let tw = parse_exact_break_time_window(earliest, latest, *utc_offset)?;
+                    (tw.start, tw.end)
+                }
+                VehicleRequiredBreakTime::FractionalOffset { earliest_frac, latest_frac } => {
+                    let arrival = arrival.ok_or_else(|| GenericError::from("cannot get end of fractional break"))?;
+                    let shift_duration = (arrival - departure).max(0.);
+                    (departure + earliest_frac * shift_duration, departure + latest_frac * shift_duration)
+                }
+                VehicleRequiredBreakTime::AfterJob { job_id } => {
+                    return Err(format!("cannot get time window of break anchored to job '{job_id}'").into());
+                }
+                VehicleRequiredBreakTime::AfterLoad { load } => {
+                    return Err(format!("cannot get time window of break anchored to load '{load:?}'").into());
+                }
             };
 
             Ok(TimeWindow::new(start, end + duration))
@@ -207,24 +641,6 @@ pub(crate) fn get_break_time_window(
     }
 }
 
-/// Gets the arrival time of the first job activity in the tour.
-fn get_first_job_arrival(tour: &Tour) -> Option<Timestamp> {
-    // The first stop is departure, so first job is the second stop (or first non-departure activity)
-    tour.stops
-        .iter()
-        .flat_map(|stop| stop.activities().iter())
-        .find(|a| !matches!(a.activity_type.as_str(), "departure" | "arrival"))
-        .and_then(|_| {
-            // Find the stop that contains the first job activity and get its arrival
-            tour.stops
-                .iter()
-                .find(|stop| {
-                    stop.activities().iter().any(|a| !matches!(a.activity_type.as_str(), "departure" | "arrival"))
-                })
-                .map(|stop| parse_time(&stop.schedule().arrival))
-        })
-}
-
 fn get_break_violation_count(solution: &Solution, tour: &Tour) -> usize {
     solution.violations.as_ref().map_or(0, |violations| {
         violations
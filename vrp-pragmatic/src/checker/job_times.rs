@@ -0,0 +1,70 @@
+#[cfg(test)]
+#[path = "../../tests/unit/checker/job_times_test.rs"]
+mod job_times_test;
+
+use super::*;
+use crate::format::problem::get_job_time_bounds;
+use crate::utils::combine_error_results;
+use vrp_core::prelude::GenericResult;
+
+/// Checks that first and last jobs of tours respect vehicle shift's job time constraints.
+pub fn check_job_time_limits(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
+    combine_error_results(&[check_job_time_bounds(context)])
+}
+
+fn check_job_time_bounds(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let shift = context.get_vehicle_shift(tour)?;
+        let Some(job_times) = shift.job_times.as_ref() else {
+            return Ok(());
+        };
+
+        // NOTE breaks are not jobs, so they are not limited by job times
+        let mut jobs = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .filter(|(_, activity)| !matches!(activity.activity_type.as_str(), "departure" | "arrival" | "break"));
+
+        let Some(first) = jobs.next() else {
+            return Ok(());
+        };
+        let last = jobs.next_back().unwrap_or(first);
+
+        let (earliest_first, latest_last) = get_job_time_bounds(job_times);
+
+        if let Some(earliest_first) = earliest_first {
+            let (stop, activity) = first;
+            let first_job_time = match job_times.first_job_reference.clone().unwrap_or_default() {
+                FirstJobReference::Arrival => parse_time(&stop.schedule().arrival),
+                FirstJobReference::ServiceStart => context.get_activity_time(stop, activity).start,
+            };
+
+            if first_job_time < earliest_first {
+                return Err(format!(
+                    "first job '{}' time '{}' is earlier than earliest first '{}', vehicle id '{}', shift index: {}",
+                    activity.job_id, first_job_time, earliest_first, tour.vehicle_id, tour.shift_index
+                )
+                .into());
+            }
+        }
+
+        if let Some(latest_last) = latest_last {
+            let (stop, activity) = last;
+            let last_job_time = match job_times.last_job_reference.clone().unwrap_or_default() {
+                LastJobReference::Arrival => parse_time(&stop.schedule().arrival),
+                LastJobReference::Departure => context.get_activity_time(stop, activity).end,
+            };
+
+            if last_job_time > latest_last {
+                return Err(format!(
+                    "last job '{}' time '{}' is later than latest last '{}', vehicle id '{}', shift index: {}",
+                    activity.job_id, last_job_time, latest_last, tour.vehicle_id, tour.shift_index
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    })
+}
@@ -9,7 +9,13 @@ use vrp_core::prelude::GenericResult;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context), check_recharge_limits(context)])
+    combine_error_results(&[
+        check_shift_limits(context),
+        check_shift_time(context),
+        check_service_time_limits(context),
+        check_stop_limits(context),
+        check_recharge_limits(context),
+    ])
 }
 
 /// Check that shift limits are not violated:
@@ -71,6 +77,70 @@ fn check_shift_limits(context: &CheckerContext) -> GenericResult<()> {
     })
 }
 
+/// Checks that total service time of jobs in the tour does not exceed shift's max service time.
+fn check_service_time_limits(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let shift = context.get_vehicle_shift(tour)?;
+
+        let Some(max_service_time) = shift.max_service_time else {
+            return Ok(());
+        };
+
+        let service_time = tour
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+            .filter(|(_, activity)| matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement"))
+            .map(|(stop, activity)| match activity.time.as_ref() {
+                Some(time) => parse_time(&time.end) - parse_time(&time.start),
+                None => parse_time(&stop.schedule().departure) - parse_time(&stop.schedule().arrival),
+            })
+            .sum::<Float>();
+
+        if service_time > max_service_time {
+            return Err(format!(
+                "max service time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                max_service_time, service_time, tour.vehicle_id, tour.shift_index
+            )
+            .into());
+        }
+
+        Ok(())
+    })
+}
+
+/// Checks that amount of stops with jobs or breaks does not exceed shift's max stops. Transit stops
+/// formed by breaks taken while traveling are counted too, the same way as the core feature does.
+fn check_stop_limits(context: &CheckerContext) -> GenericResult<()> {
+    context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
+        let shift = context.get_vehicle_shift(tour)?;
+
+        let Some(max_stops) = shift.max_stops else {
+            return Ok(());
+        };
+
+        let stops = tour
+            .stops
+            .iter()
+            .filter(|stop| {
+                stop.activities()
+                    .iter()
+                    .any(|activity| !matches!(activity.activity_type.as_str(), "departure" | "arrival"))
+            })
+            .count();
+
+        if stops > max_stops {
+            return Err(format!(
+                "max stops limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                max_stops, stops, tour.vehicle_id, tour.shift_index
+            )
+            .into());
+        }
+
+        Ok(())
+    })
+}
+
 fn check_shift_time(context: &CheckerContext) -> GenericResult<()> {
     context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
         let vehicle = context.get_vehicle(&tour.vehicle_id)?;
@@ -109,7 +179,7 @@ fn check_recharge_limits(context: &CheckerContext) -> GenericResult<()> {
 
         let Some(recharge) = shift.recharges.as_ref() else { return Ok(()) };
 
-        let stops = tour.stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
+        let stops = tour.point_stops().collect::<Vec<_>>();
         if stops.len() < 2 {
             return Ok(());
         }
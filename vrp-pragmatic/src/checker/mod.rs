@@ -79,6 +79,7 @@ impl CheckerContext {
             .chain(check_assignment(self).err())
             .chain(check_routing(self).err())
             .chain(check_limits(self).err())
+            .chain(check_job_time_limits(self).err())
             .flatten()
             .fold((HashSet::new(), Vec::default()), |(mut used, mut errors), error| {
                 if !used.contains(&error) {
@@ -186,7 +187,8 @@ impl CheckerContext {
                     .as_ref()
                     .and_then(|breaks| {
                         breaks.iter().find(|b| {
-                            get_break_time_window(tour, b, cost_span).map(|tw| tw.intersects(&time)).unwrap_or(false)
+                            get_break_time_windows(&self.problem, tour, b, cost_span)
+                                .is_ok_and(|tws| tws.iter().any(|tw| tw.intersects(&time)))
                         })
                     })
                     .map(|b| ActivityType::Break(b.clone()))
@@ -456,11 +458,17 @@ use crate::checker::assignment::check_assignment;
 mod capacity;
 use crate::checker::capacity::check_vehicle_load;
 
+mod job_times;
+use crate::checker::job_times::check_job_time_limits;
+
 mod limits;
 use crate::checker::limits::check_limits;
 
 mod breaks;
-use crate::checker::breaks::{check_breaks, get_break_time_window};
+pub use crate::checker::breaks::{
+    RequiredBreakStatus, get_required_break_statuses, get_shift_break_time_windows, validate_fleet_breaks,
+};
+use crate::checker::breaks::{check_breaks, get_break_time_windows};
 
 mod relations;
 use crate::checker::relations::check_relations;
@@ -52,17 +52,14 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
                         (distance, duration, to.distance)
                     }
                     (prev, Stop::Transit(transit)) => {
+                        // NOTE transit stop can be anywhere on the leg, so the part of travel done before it
+                        // is taken as is and the whole leg duration is checked on the next point stop
                         let prev_departure = parse_time(&prev.schedule().departure);
                         let next_arrival = parse_time(&transit.time.arrival);
-                        // NOTE an edge case: duration of break will be counted in transit stop
-                        let duration = if next_arrival == prev_departure {
-                            0.
-                        } else {
-                            parse_time(&transit.time.departure) - next_arrival
-                        };
+                        let duration = (next_arrival - prev_departure).max(0.);
                         (0_i64, duration as i64, total_distance)
                     }
-                    (Stop::Transit(_), Stop::Point(to)) => {
+                    (Stop::Transit(transit), Stop::Point(to)) => {
                         assert!(leg_idx > 0);
                         let from = tour
                             .stops
@@ -71,7 +68,9 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
                             .as_point()
                             .expect("two consistent transit stops are not supported");
                         let (distance, duration) = get_matrix_data(from, to)?;
-                        (distance, duration, to.distance)
+                        let traveled =
+                            (parse_time(&transit.time.arrival) - parse_time(&from.time.departure)).max(0.) as i64;
+                        (distance, (duration - traveled).max(0), to.distance)
                     }
                 };
 
@@ -169,12 +168,7 @@ fn check_solution_statistic(solution: &Solution) -> GenericResult<()> {
 
 /// A workaround method for hre format output where distance is not defined.
 fn skip_distance_check(solution: &Solution) -> bool {
-    let skip_distance_check = solution
-        .tours
-        .iter()
-        .flat_map(|tour| tour.stops.iter())
-        .filter_map(|stop| stop.as_point())
-        .all(|stop| stop.distance == 0);
+    let skip_distance_check = solution.tours.iter().flat_map(|tour| tour.point_stops()).all(|stop| stop.distance == 0);
 
     if skip_distance_check {
         // TODO use logging lib instead of println
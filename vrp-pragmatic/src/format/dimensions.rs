@@ -13,6 +13,14 @@ custom_dimension!(pub TourSize typeof usize);
 
 custom_dimension!(pub MinTourSize typeof usize);
 
+custom_dimension!(pub MaxServiceTime typeof Float);
+
+custom_dimension!(pub MaxBreakFraction typeof Float);
+
+custom_dimension!(pub MaxStops typeof usize);
+
+custom_dimension!(pub ReservedBreakStops typeof usize);
+
 custom_dimension!(pub PlaceTags typeof Vec<(usize, String)>);
 
 custom_dimension!(pub JobOrder typeof i32);
@@ -24,3 +32,7 @@ custom_dimension!(pub JobDueDate typeof Float);
 custom_dimension!(pub JobType typeof String);
 
 custom_dimension!(pub BreakPolicy typeof BreakPolicy);
+
+custom_dimension!(pub BreakMinWorkAfter typeof Float);
+
+custom_dimension!(pub BreakSkippableToAssign typeof bool);
@@ -7,9 +7,9 @@ extern crate serde_json;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use vrp_core::construction::enablers::ReservedTimesIndex;
+use vrp_core::construction::enablers::{JobReservedTimesIndex, ReservedTimesIndex};
 use vrp_core::models::common::{Distance, Duration};
-use vrp_core::models::problem::{Job as CoreJob, Single, VehicleIdDimension};
+use vrp_core::models::problem::{Actor, Job as CoreJob, Single, VehicleIdDimension};
 use vrp_core::models::solution::Route;
 use vrp_core::models::{Extras as CoreExtras, Problem as CoreProblem, ViolationCode};
 use vrp_core::prelude::{Float, GenericError};
@@ -51,6 +51,12 @@ pub enum Location {
     },
 }
 
+impl Default for Location {
+    fn default() -> Self {
+        Self::new_coordinate(0., 0.)
+    }
+}
+
 impl Location {
     /// Creates a new [`Location`] as coordinate.
     pub fn new_coordinate(lat: f64, lng: f64) -> Self {
@@ -198,19 +204,45 @@ const RECHARGE_CONSTRAINT_CODE: ViolationCode = ViolationCode(15);
 const MIN_VEHICLE_SHIFTS_CONSTRAINT_CODE: ViolationCode = ViolationCode(16);
 const MIN_TOUR_SIZE_CONSTRAINT_CODE: ViolationCode = ViolationCode(17);
 const JOB_TIME_CONSTRAINT_CODE: ViolationCode = ViolationCode(18);
+const SERVICE_TIME_LIMIT_CONSTRAINT_CODE: ViolationCode = ViolationCode(19);
+const STOP_LIMIT_CONSTRAINT_CODE: ViolationCode = ViolationCode(20);
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
 
-pub use self::properties::{CoordIndexExtraProperty, JobIndexExtraProperty};
+/// Ids of required break definitions per actor, aligned by index with reserved times in `ReservedTimesIndex`.
+/// Reserved times without a definition in the problem have no id.
+pub type ReservedTimeSourcesIndex = HashMap<Arc<Actor>, Vec<Option<String>>>;
+
+pub use self::properties::{
+    BaseTransportCostExtraProperty, BreakAttachmentOutputExtraProperty, BreakSourceOutputExtraProperty,
+    CoordIndexExtraProperty, DepartureBindingOutputExtraProperty, DistanceOutputScaleExtraProperty,
+    DurationOutputScaleExtraProperty, JobIndexExtraProperty, RequiredBreakSkipHandlerExtraProperty,
+    ReservedTimeSourcesExtraProperty, StopSlackOutputExtraProperty, StopTravelOutputExtraProperty,
+    TourUtilizationOutputExtraProperty,
+};
 
 mod properties {
-    use crate::format::{CoordIndex, JobIndex};
+    use crate::format::solution::RequiredBreakSkipFn;
+    use crate::format::{CoordIndex, JobIndex, ReservedTimeSourcesIndex};
+    use std::sync::Arc;
     use vrp_core::custom_extra_property;
     use vrp_core::models::Extras;
+    use vrp_core::models::problem::TransportCost;
 
     custom_extra_property!(pub JobIndex typeof JobIndex);
     custom_extra_property!(pub CoordIndex typeof CoordIndex);
+    custom_extra_property!(pub RequiredBreakSkipHandler typeof RequiredBreakSkipFn);
+    custom_extra_property!(pub StopSlackOutput typeof bool);
+    custom_extra_property!(pub StopTravelOutput typeof bool);
+    custom_extra_property!(pub TourUtilizationOutput typeof bool);
+    custom_extra_property!(pub DepartureBindingOutput typeof bool);
+    custom_extra_property!(pub BreakSourceOutput typeof bool);
+    custom_extra_property!(pub BreakAttachmentOutput typeof bool);
+    custom_extra_property!(pub ReservedTimeSources typeof ReservedTimeSourcesIndex);
+    custom_extra_property!(pub DistanceOutputScale typeof f64);
+    custom_extra_property!(pub DurationOutputScale typeof f64);
+    custom_extra_property!(pub BaseTransportCost typeof Arc<dyn TransportCost>);
 }
 
 /// Get job and coord indices from extras
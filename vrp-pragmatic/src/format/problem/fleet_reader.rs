@@ -5,14 +5,24 @@ mod fleet_reader_test;
 use super::*;
 use crate::Location as ApiLocation;
 use crate::format::UnknownLocationFallback;
+use crate::format::problem::model::{
+    DepartureReference as ApiDepartureReference, FirstJobReference as ApiFirstJobReference,
+    LastJobReference as ApiLastJobReference,
+};
 use crate::get_unique_locations;
 use crate::utils::get_approx_transportation;
 use std::collections::HashSet;
-use vrp_core::construction::enablers::create_typed_actor_groups;
-use vrp_core::construction::features::{VehicleCapacityDimension, VehicleSkillsDimension};
+use vrp_core::construction::enablers::{
+    BreakRule, BreakRulesDimension, EmissionModel, FractionalReservedTime, LoadReservedTime, MinJobsReservedTime,
+    ReloadReservedTime, ReservedTimeSpan, VehicleEmissionModelDimension, VehicleMinWorkAfterBreakDimension,
+    VehicleReservedTimesDimension, create_typed_actor_groups,
+};
+use vrp_core::construction::features::{
+    VehicleCapacityDimension, VehiclePreferBreakOnTransitDimension, VehicleSkillsDimension,
+};
 use vrp_core::models::common::*;
-use vrp_core::models::problem::RouteCostSpanDimension;
 use vrp_core::models::problem::*;
+use vrp_core::models::problem::{DepartureReference, FirstJobReference, LastJobReference, RouteCostSpanDimension};
 
 pub(super) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String, usize> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
@@ -112,6 +122,17 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         let index = *profile_indices.get(&vehicle.profile.matrix).unwrap();
         let profile = Profile::new(index, vehicle.profile.scale);
 
+        let emission_model = api_problem
+            .fleet
+            .profiles
+            .iter()
+            .find(|profile| profile.name == vehicle.profile.matrix)
+            .and_then(|profile| profile.emissions.as_ref())
+            .map(|emissions| EmissionModel {
+                per_distance: emissions.factor,
+                load_weight: emissions.load_weight.unwrap_or(0.),
+            });
+
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
         let min_tour_size = vehicle.limits.as_ref().and_then(|l| l.min_tour_size);
 
@@ -184,10 +205,140 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_route_cost_span(core_span);
                 }
 
+                if let Some(max_break_fraction) = shift.max_break_fraction {
+                    dimens.set_max_break_fraction(max_break_fraction);
+                }
+
+                let has_required_breaks = shift
+                    .breaks
+                    .iter()
+                    .flat_map(|breaks| breaks.iter())
+                    .any(|vehicle_break| matches!(vehicle_break, VehicleBreak::Required { .. }));
+                if let Some(min_work_after_break) = shift.min_work_after_break.filter(|_| has_required_breaks) {
+                    dimens.set_vehicle_min_work_after_break(min_work_after_break);
+                }
+
+                if vehicle.costs.exclude_break_time.unwrap_or(false) {
+                    dimens.set_route_cost_span_exclude_break_time(true);
+                }
+
+                let reserved_times = shift
+                    .breaks
+                    .iter()
+                    .flat_map(|breaks| breaks.iter())
+                    .filter_map(|br| match br {
+                        VehicleBreak::Required { skippable_to_assign: Some(true), .. } => None,
+                        // NOTE such breaks are route dependent and resolved by break rules
+                        VehicleBreak::Required { min_jobs_before_break: Some(_), .. } => None,
+                        VehicleBreak::Required { time, duration, .. } => get_required_break_time_span(time)
+                            .map(|time| ReservedTimeSpan { time, duration: *duration }),
+                        VehicleBreak::Optional { .. } => None,
+                    })
+                    .collect::<Vec<_>>();
+                if !reserved_times.is_empty() {
+                    dimens.set_vehicle_reserved_times(reserved_times);
+                }
+
+                if let Some(emission_model) = emission_model.clone() {
+                    dimens.set_vehicle_emission_model(emission_model);
+                }
+
+                if let Some(prefer_break_on_transit) = shift.prefer_break_on_transit {
+                    dimens.set_vehicle_prefer_break_on_transit(prefer_break_on_transit);
+                }
+
+                let break_rules = shift
+                    .breaks
+                    .iter()
+                    .flat_map(|breaks| breaks.iter())
+                    .filter_map(|br| match br {
+                        VehicleBreak::Required {
+                            time: VehicleRequiredBreakTime::FractionalOffset { earliest_frac, latest_frac },
+                            duration,
+                            ..
+                        } => Some(Arc::new(FractionalReservedTime {
+                            earliest: *earliest_frac,
+                            latest: *latest_frac,
+                            duration: *duration,
+                        }) as Arc<dyn BreakRule>),
+                        VehicleBreak::Required {
+                            time: VehicleRequiredBreakTime::AfterLoad { load }, duration, ..
+                        } => {
+                            let duration = *duration;
+                            Some(if props.has_multi_dimen_capacity {
+                                Arc::new(LoadReservedTime { load: MultiDimLoad::new(load.clone()), duration })
+                                    as Arc<dyn BreakRule>
+                            } else {
+                                let load = SingleDimLoad::new(load.first().copied().unwrap_or_default());
+                                Arc::new(LoadReservedTime { load, duration }) as Arc<dyn BreakRule>
+                            })
+                        }
+                        VehicleBreak::Required {
+                            time:
+                                VehicleRequiredBreakTime::AnchoredOffsetTime {
+                                    earliest,
+                                    latest,
+                                    anchor: BreakOffsetAnchor::Reload,
+                                },
+                            duration,
+                            ..
+                        } => Some(Arc::new(ReloadReservedTime {
+                            offset: TimeOffset::new(*earliest, *latest),
+                            duration: *duration,
+                            is_reload_single: Arc::new(|single: &Single| {
+                                single.dimens.get_job_type().is_some_and(|job_type| job_type == "reload")
+                            }),
+                        }) as Arc<dyn BreakRule>),
+                        VehicleBreak::Required {
+                            time,
+                            duration,
+                            skippable_to_assign: None | Some(false),
+                            min_jobs_before_break: Some(min_jobs),
+                        } => get_required_break_time_span(time).map(|time| {
+                            Arc::new(MinJobsReservedTime {
+                                time,
+                                duration: *duration,
+                                min_jobs: *min_jobs,
+                                is_counted_single: Arc::new(|single: &Single| {
+                                    single.dimens.get_job_type().is_some_and(|job_type| {
+                                        matches!(job_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
+                                    })
+                                }),
+                            }) as Arc<dyn BreakRule>
+                        }),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                if !break_rules.is_empty() {
+                    dimens.set_break_rules(break_rules);
+                }
+
+                if let Some(max_service_time) = shift.max_service_time {
+                    dimens.set_max_service_time(max_service_time);
+                }
+
+                if let Some(max_stops) = shift.max_stops {
+                    dimens.set_max_stops(max_stops);
+
+                    if shift.reserve_break_stops.unwrap_or(false) {
+                        let required_breaks = shift
+                            .breaks
+                            .iter()
+                            .flatten()
+                            .filter(|br| matches!(br, VehicleBreak::Required { .. }))
+                            .count();
+                        dimens.set_reserved_break_stops(required_breaks);
+                    }
+                }
+
                 if let Some(job_times) = shift.job_times.as_ref() {
+                    let (earliest_first, latest_last) = get_job_time_bounds(job_times);
                     let core_job_times = vrp_core::models::problem::JobTimeConstraints {
-                        earliest_first: job_times.earliest_first.as_ref().map(|t| parse_time(t)),
-                        latest_last: job_times.latest_last.as_ref().map(|t| parse_time(t)),
+                        earliest_first,
+                        latest_last,
+                        first_job_reference: job_times.first_job_reference.clone().unwrap_or_default().into(),
+                        last_job_reference: job_times.last_job_reference.clone().unwrap_or_default().into(),
+                        departure_reference: job_times.departure_reference.clone().unwrap_or_default().into(),
                     };
                     dimens.set_job_time_constraints(core_job_times);
                 }
@@ -258,3 +409,30 @@ pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
         })
         .collect()
 }
+
+impl From<ApiFirstJobReference> for FirstJobReference {
+    fn from(reference: ApiFirstJobReference) -> Self {
+        match reference {
+            ApiFirstJobReference::Arrival => FirstJobReference::Arrival,
+            ApiFirstJobReference::ServiceStart => FirstJobReference::ServiceStart,
+        }
+    }
+}
+
+impl From<ApiLastJobReference> for LastJobReference {
+    fn from(reference: ApiLastJobReference) -> Self {
+        match reference {
+            ApiLastJobReference::Arrival => LastJobReference::Arrival,
+            ApiLastJobReference::Departure => LastJobReference::Departure,
+        }
+    }
+}
+
+impl From<ApiDepartureReference> for DepartureReference {
+    fn from(reference: ApiDepartureReference) -> Self {
+        match reference {
+            ApiDepartureReference::ScheduledStart => DepartureReference::ScheduledStart,
+            ApiDepartureReference::ActualDeparture => DepartureReference::ActualDeparture,
+        }
+    }
+}
@@ -34,6 +34,20 @@ pub(super) fn create_goal_context(
         )?)
     }
 
+    if props.has_service_time_limits {
+        features.push(create_service_time_limit_feature(
+            "service_time_limit",
+            SERVICE_TIME_LIMIT_CONSTRAINT_CODE,
+            Arc::new(|actor| actor.vehicle.dimens.get_max_service_time().copied()),
+            Arc::new(|single| {
+                !single
+                    .dimens
+                    .get_job_type()
+                    .is_some_and(|job_type| matches!(job_type.as_str(), "break" | "reload" | "recharge"))
+            }),
+        )?)
+    }
+
     if props.has_job_time_constraints {
         features.push(create_job_time_limits_feature(
             "job_time_limits",
@@ -44,7 +58,7 @@ pub(super) fn create_goal_context(
     }
 
     if props.has_breaks {
-        features.push(create_optional_break_feature("break")?)
+        features.push(create_optional_break_feature("break", blocks.transport.clone())?)
     }
 
     if props.has_recharges {
@@ -76,6 +90,15 @@ pub(super) fn create_goal_context(
         )?);
     }
 
+    if props.has_stop_limits {
+        features.push(create_stop_limit_feature(
+            "stop_limit",
+            STOP_LIMIT_CONSTRAINT_CODE,
+            Arc::new(|actor| actor.vehicle.dimens.get_max_stops().copied()),
+            Arc::new(|actor| actor.vehicle.dimens.get_reserved_break_stops().copied()),
+        )?);
+    }
+
     if props.has_tour_size_limits {
         features.push(create_activity_limit_feature(
             "activity_limit",
@@ -107,12 +130,10 @@ fn get_objective_feature_layers(
     blocks: &ProblemBlocks,
     props: &ProblemProperties,
 ) -> GenericResult<Vec<FeatureLayer>> {
-    let objectives = get_objectives(api_problem, props);
-
-    objectives
+    get_objectives(api_problem, props)
         .iter()
         .map(|objective| get_objective_feature_layer(objective, blocks, props))
-        .collect::<GenericResult<_>>()
+        .collect::<GenericResult<Vec<_>>>()
 }
 
 fn get_objective_feature_layer(
@@ -178,6 +199,10 @@ fn get_objective_feature_layer(
                 move |_, job| {
                     if let Some(clusters) = job.dimens().get_cluster_info() {
                         clusters.len() as Float * default_value
+                    } else if job.dimens().get_break_skippable_to_assign().copied().unwrap_or(false) {
+                        // NOTE skippable break is worth less than a job, so it is dropped only when
+                        // this allows to serve another job
+                        default_value / 2.
                     } else {
                         job.dimens().get_job_type().map_or(default_value, |job_type| match job_type.as_str() {
                             "break" => break_value.unwrap_or(default_value),
@@ -189,6 +214,8 @@ fn get_objective_feature_layer(
             .build(),
 
         Objective::MinimizeArrivalTime => create_minimize_arrival_time_feature("min_arrival_time"),
+        Objective::MinimizeLatestArrival => create_minimize_latest_arrival_feature("min_latest_arrival"),
+        Objective::MinimizeDeadhead => create_minimize_deadhead_feature("min_deadhead", blocks.transport.clone()),
         Objective::BalanceMaxLoad => {
             if props.has_multi_dimen_capacity {
                 create_max_load_balanced_feature::<MultiDimLoad>(
@@ -214,6 +241,7 @@ fn get_objective_feature_layer(
             }
         }
         Objective::BalanceActivities => create_activity_balanced_feature("activity_balance"),
+        Objective::BalanceJobCount => create_job_count_balanced_feature("job_count_balance"),
         Objective::BalanceDistance => create_distance_balanced_feature("distance_balance"),
         Objective::BalanceDuration => create_duration_balanced_feature("duration_balance"),
         Objective::CompactTour { job_radius } => {
@@ -248,7 +276,7 @@ fn get_objective_feature_layer(
                 if has_due_date { 10000.0 } else { 0.0 }
             })
             .build(),
-        Objective::MinimizeVehicleDistance => VehicleDistanceFeatureBuilder::new("min_vehicle_distance")
+        Objective::MinimizeVehicleDistance { reference } => VehicleDistanceFeatureBuilder::new("min_vehicle_distance")
             .set_transport(blocks.transport.clone())
             .set_actors(blocks.fleet.actors.clone())
             .set_compatibility_fn(|job, actor| {
@@ -260,7 +288,46 @@ fn get_objective_feature_layer(
                 }
                 true
             })
+            .set_vehicle_reference(match reference.clone().unwrap_or_default() {
+                VehicleDistanceReference::Nearest => VehicleReference::Nearest,
+                VehicleDistanceReference::Centroid => VehicleReference::Centroid,
+            })
             .build(),
+        Objective::MinimizeBreakTime => {
+            create_minimize_break_time_feature("min_break_time", blocks.reserved_times_index.clone(), |single| {
+                single.dimens.get_job_type().is_some_and(|job_type| job_type == "break")
+            })
+        }
+        Objective::MinimizeBreakLocationCount => {
+            create_minimize_break_location_count_feature("min_break_location_count", |single| {
+                single.dimens.get_job_type().is_some_and(|job_type| job_type == "break")
+            })
+        }
+        Objective::MinimizeBreakDetour => {
+            create_minimize_break_detour_feature("min_break_detour", blocks.transport.clone(), |single| {
+                single.dimens.get_job_type().is_some_and(|job_type| job_type == "break")
+            })
+        }
+        Objective::MinimizeBreakOverlap { max_breaks } => create_minimize_break_overlap_feature(
+            "min_break_overlap",
+            *max_breaks,
+            blocks.transport.clone(),
+            |single| single.dimens.get_job_type().is_some_and(|job_type| job_type == "break"),
+        ),
+        Objective::MinimizeBreakClockOffset { interval } => create_minimize_break_clock_offset_feature(
+            "min_break_clock_offset",
+            *interval,
+            blocks.transport.clone(),
+            blocks.reserved_times_index.clone(),
+            |single| single.dimens.get_job_type().is_some_and(|job_type| job_type == "break"),
+        ),
+        Objective::PreferBreakOnTransit => {
+            create_break_on_transit_feature("break_on_transit", blocks.reserved_times_index.clone())
+        }
+        Objective::MinimizeJobTimePressure => {
+            create_job_time_pressure_feature("min_job_time_pressure", blocks.transport.clone(), blocks.activity.clone())
+        }
+        Objective::MinimizeLateness => create_minimize_lateness_feature("min_lateness", blocks.transport.clone()),
         Objective::HierarchicalAreas { levels } => get_hierarchical_areas_feature(blocks, *levels),
         Objective::MultiObjective { objectives, strategy: composition_type } => {
             let features = objectives
@@ -312,6 +379,16 @@ fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Ob
         let mut objectives =
             vec![Objective::MinimizeUnassigned { breaks: Some(1.) }, Objective::MinimizeTours, Objective::MinimizeCost];
 
+        if props.has_soft_time_windows {
+            // NOTE lateness penalty should dominate over transport costs only
+            objectives.insert(objectives.len() - 1, Objective::MinimizeLateness)
+        }
+
+        if props.has_break_on_transit_preference {
+            // NOTE break placement preference should dominate over transport costs only
+            objectives.insert(objectives.len() - 1, Objective::PreferBreakOnTransit)
+        }
+
         if props.has_value {
             objectives.insert(0, Objective::MaximizeValue { breaks: None })
         }
@@ -643,7 +720,7 @@ where
         .collect()
 }
 
-fn create_optional_break_feature(name: &str) -> GenericResult<Feature> {
+fn create_optional_break_feature(name: &str, transport: Arc<dyn TransportCost>) -> GenericResult<Feature> {
     fn is_break_job(single: &Single) -> bool {
         single.dimens.get_job_type().is_some_and(|job_type| job_type == "break")
     }
@@ -656,6 +733,9 @@ fn create_optional_break_feature(name: &str) -> GenericResult<Feature> {
             is_correct_vehicle(route, single)
         })
         .set_policy(|single| single.dimens.get_break_policy().cloned().unwrap_or(BreakPolicy::SkipIfNoIntersection))
+        .set_min_work_after(|single| single.dimens.get_break_min_work_after().copied())
+        .set_max_break_fraction(|actor| actor.vehicle.dimens.get_max_break_fraction().copied())
+        .set_transport(transport)
         .build()
 }
 
@@ -685,7 +765,13 @@ mod tests {
                     type_id: "vehicle_type".to_string(),
                     vehicle_ids: vec!["vehicle_1".to_string()],
                     profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-                    costs: VehicleCosts { fixed: Some(0.), distance: 1., time: 1., span: None },
+                    costs: VehicleCosts {
+                        fixed: Some(0.),
+                        distance: 1.,
+                        time: 1.,
+                        span: None,
+                        exclude_break_time: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: "1970-01-01T00:00:00Z".to_string(),
@@ -693,17 +779,14 @@ mod tests {
                             location: Location::new_coordinate(0., 0.),
                         },
                         end: None,
-                        breaks: None,
-                        reloads: None,
-                        recharges: None,
-                        job_times: None,
+                        ..VehicleShift::default()
                     }],
                     capacity: vec![1],
                     skills: None,
                     limits: None,
                     min_shifts,
                 }],
-                profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
+                profiles: vec![MatrixProfile { name: "car".to_string(), speed: None, emissions: None }],
                 resources: None,
             },
             objectives: None,
@@ -2,7 +2,7 @@ use crate::format::coord_index::CoordIndex;
 use crate::format::dimensions::JobDueDateDimension;
 use crate::format::problem::JobSkills as ApiJobSkills;
 use crate::format::problem::*;
-use crate::format::{JobIndex, Location};
+use crate::format::{FormatError, JobIndex, Location};
 use crate::parse_time;
 use crate::utils::VariableJobPermutation;
 use std::collections::HashMap;
@@ -10,11 +10,12 @@ use std::sync::Arc;
 use vrp_core::{
     construction::features::{
         BreakPolicy, JobCompatibilityDimension, JobDemandDimension, JobGroupDimension, JobSkills as FeatureJobSkills,
-        JobSkillsDimension,
+        JobSkillsDimension, JobSoftTimeWindowsDimension, SoftTimeWindows,
     },
     models::common::*,
     models::problem::{
-        Actor, Fleet, Job, JobIdDimension, Jobs, Multi, Place, Single, TransportCost, VehicleIdDimension,
+        Actor, Fleet, Job, JobIdDimension, JobMinDwellDimension, JobParallelServiceDimension, Jobs, Multi, Place,
+        Single, TransportCost, VehicleIdDimension,
     },
     models::{Lock, LockDetail, LockOrder, LockPosition},
 };
@@ -33,16 +34,16 @@ pub(super) fn read_jobs_with_extra_locks(
     transport: &(dyn TransportCost + Sync + Send),
     job_index: &mut JobIndex,
     environment: &Environment,
-) -> (Jobs, Vec<Arc<Lock>>) {
+) -> Result<(Jobs, Vec<Arc<Lock>>), FormatError> {
     let random = &environment.random;
     let logger = &environment.logger;
 
     let (mut jobs, locks) = read_required_jobs(api_problem, props, coord_index, job_index, random);
-    let conditional_jobs = read_conditional_jobs(api_problem, coord_index, job_index);
+    let conditional_jobs = read_conditional_jobs(api_problem, coord_index, job_index)?;
 
     jobs.extend(conditional_jobs);
 
-    (Jobs::new(fleet, jobs, transport, logger).unwrap(), locks)
+    Ok((Jobs::new(fleet, jobs, transport, logger).unwrap(), locks))
 }
 
 pub(super) fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Lock>> {
@@ -117,58 +118,116 @@ fn read_required_jobs(
     let mut jobs = vec![];
     let has_multi_dimens = props.has_multi_dimen_capacity;
 
-    let get_single_from_task = |task: &JobTask, activity_type: &str, is_static_demand: bool| {
-        let absent = (empty(), empty());
-        let capacity = task.demand.clone().map_or_else(empty, MultiDimLoad::new);
-        let demand = if is_static_demand { (capacity, empty()) } else { (empty(), capacity) };
-
-        let demand = match activity_type {
-            "pickup" => Demand { pickup: demand, delivery: absent },
-            "delivery" => Demand { pickup: absent, delivery: demand },
-            "replacement" => Demand { pickup: demand, delivery: demand },
-            "service" => Demand { pickup: absent, delivery: absent },
-            _ => panic!("invalid activity type."),
+    let get_single_with_soft_times =
+        |task: &JobTask, activity_type: &str, is_static_demand: bool, soft_penalty: Option<Cost>| {
+            let absent = (empty(), empty());
+            let capacity = task.demand.clone().map_or_else(empty, MultiDimLoad::new);
+            let demand = if is_static_demand { (capacity, empty()) } else { (empty(), capacity) };
+
+            let demand = match activity_type {
+                "pickup" => Demand { pickup: demand, delivery: absent },
+                "delivery" => Demand { pickup: absent, delivery: demand },
+                "replacement" => Demand { pickup: demand, delivery: demand },
+                "service" => Demand { pickup: absent, delivery: absent },
+                _ => panic!("invalid activity type."),
+            };
+
+            let places = task
+                .places
+                .iter()
+                .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
+                .collect::<Vec<PlaceData>>();
+
+            let Some(penalty) = soft_penalty else {
+                return get_single_with_dimens(
+                    places,
+                    demand,
+                    &task.order,
+                    &task.due_date,
+                    activity_type,
+                    has_multi_dimens,
+                    coord_index,
+                );
+            };
+
+            // NOTE keep original time windows for lateness penalty and relax their end in the job itself
+            let soft_tws = places
+                .iter()
+                .map(|(_, _, times, _)| times.iter().filter_map(|span| span.as_time_window()).collect())
+                .collect();
+            let places = places
+                .into_iter()
+                .map(|(location, duration, times, tag)| {
+                    let times = times
+                        .into_iter()
+                        .map(|span| match span {
+                            TimeSpan::Window(tw) => TimeSpan::Window(TimeWindow::new(tw.start, Float::MAX)),
+                            span => span,
+                        })
+                        .collect();
+                    (location, duration, times, tag)
+                })
+                .collect();
+
+            let mut single = get_single_with_dimens(
+                places,
+                demand,
+                &task.order,
+                &task.due_date,
+                activity_type,
+                has_multi_dimens,
+                coord_index,
+            );
+            single.dimens.set_job_soft_time_windows(SoftTimeWindows { places: soft_tws, penalty });
+
+            single
         };
 
-        let places = task
-            .places
-            .iter()
-            .map(|p| (Some(p.location.clone()), p.duration, parse_times(&p.times), p.tag.clone()))
-            .collect();
-
-        get_single_with_dimens(
-            places,
-            demand,
-            &task.order,
-            &task.due_date,
-            activity_type,
-            has_multi_dimens,
-            coord_index,
-        )
-    };
+    let get_single_from_task =
+        |task: &JobTask, activity_type: &str, is_static_demand: bool, soft_penalty: Option<Cost>| {
+            let mut single = get_single_with_soft_times(task, activity_type, is_static_demand, soft_penalty);
+
+            let parallel_places = task
+                .places
+                .iter()
+                .enumerate()
+                .filter(|(_, place)| place.parallel_service.unwrap_or(false))
+                .map(|(idx, _)| idx)
+                .collect::<Vec<_>>();
+            if !parallel_places.is_empty() {
+                single.dimens.set_job_parallel_service(parallel_places);
+            }
+
+            single
+        };
 
     api_problem.plan.jobs.iter().for_each(|job| {
         let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
         let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
         let is_static_demand = pickups == 0 || deliveries == 0;
+        let soft_penalty = job.soft_times.unwrap_or(false).then(|| job.lateness_penalty.unwrap_or(1.));
 
         let singles =
             job.pickups
                 .iter()
-                .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand)))
+                .flat_map(|tasks| {
+                    tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand, soft_penalty))
+                })
                 .chain(job.deliveries.iter().flat_map(|tasks| {
-                    tasks.iter().map(|task| get_single_from_task(task, "delivery", is_static_demand))
+                    tasks.iter().map(|task| get_single_from_task(task, "delivery", is_static_demand, soft_penalty))
                 }))
-                .chain(
-                    job.replacements
-                        .iter()
-                        .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "replacement", true))),
-                )
-                .chain(
-                    job.services
-                        .iter()
-                        .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "service", false))),
-                )
+                .chain(job.replacements.iter().flat_map(|tasks| {
+                    tasks.iter().map(|task| get_single_from_task(task, "replacement", true, soft_penalty))
+                }))
+                .chain(job.services.iter().flat_map(|tasks| {
+                    tasks.iter().map(|task| get_single_from_task(task, "service", false, soft_penalty))
+                }))
+                .map(|mut single| {
+                    if let Some(min_dwell) = job.min_dwell {
+                        single.dimens.set_job_min_dwell(min_dwell);
+                    }
+                    single
+                })
                 .collect::<Vec<_>>();
 
         assert!(!singles.is_empty());
@@ -187,13 +246,25 @@ fn read_required_jobs(
     (jobs, vec![])
 }
 
-fn read_conditional_jobs(api_problem: &ApiProblem, coord_index: &CoordIndex, job_index: &mut JobIndex) -> Vec<Job> {
+fn read_conditional_jobs(
+    api_problem: &ApiProblem,
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+) -> Result<Vec<Job>, FormatError> {
     let mut jobs = vec![];
 
-    api_problem.fleet.vehicles.iter().for_each(|vehicle| {
+    for vehicle in api_problem.fleet.vehicles.iter() {
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             if let Some(breaks) = &shift.breaks {
-                read_optional_breaks(coord_index, job_index, &mut jobs, vehicle, shift_index, breaks);
+                read_optional_breaks(
+                    coord_index,
+                    job_index,
+                    &mut jobs,
+                    vehicle,
+                    shift_index,
+                    breaks,
+                    shift.min_work_after_break,
+                )?;
             }
 
             if let Some(reloads) = &shift.reloads {
@@ -204,9 +275,9 @@ fn read_conditional_jobs(api_problem: &ApiProblem, coord_index: &CoordIndex, job
                 read_recharges(coord_index, job_index, &mut jobs, vehicle, shift_index, recharges);
             }
         }
-    });
+    }
 
-    jobs
+    Ok(jobs)
 }
 
 fn read_optional_breaks(
@@ -216,53 +287,82 @@ fn read_optional_breaks(
     vehicle: &VehicleType,
     shift_index: usize,
     breaks: &[VehicleBreak],
-) {
+    min_work_after_break: Option<Float>,
+) -> Result<(), FormatError> {
+    let breaks = breaks
+        .iter()
+        .map(|vehicle_break| match vehicle_break {
+            VehicleBreak::Optional { time, places, policy } => {
+                let times = match time {
+                    VehicleOptionalBreakTime::TimeWindow(time) if time.len() != 2 => {
+                        panic!("break with invalid time window specified: must have start and end!")
+                    }
+                    VehicleOptionalBreakTime::TimeOffset(offsets) if offsets.len() != 2 => {
+                        panic!("break with invalid offset specified: must have start and end!")
+                    }
+                    VehicleOptionalBreakTime::TimeWindow(time) => vec![TimeSpan::Window(parse_time_window(time))],
+                    VehicleOptionalBreakTime::TimeOffset(offset) => {
+                        vec![TimeSpan::Offset(TimeOffset::new(*offset.first().unwrap(), *offset.last().unwrap()))]
+                    }
+                };
+                let places = places.iter().map(|place| (place.location.clone(), place.duration, place.tag.clone()));
+                let policy = policy.as_ref().map(|policy| match policy {
+                    VehicleOptionalBreakPolicy::SkipIfNoIntersection => BreakPolicy::SkipIfNoIntersection,
+                    VehicleOptionalBreakPolicy::SkipIfArrivalBeforeEnd => BreakPolicy::SkipIfArrivalBeforeEnd,
+                });
+
+                Ok(Some((times, places.collect::<Vec<_>>(), policy, false)))
+            }
+            // NOTE skippable required break is modeled as a conditional job which is taken on route and
+            // skipped only when it is still expected at the tour end, as a non-skippable one
+            VehicleBreak::Required { time, duration, skippable_to_assign: Some(true), .. } => {
+                let time = get_required_break_time_span(time).ok_or_else(|| {
+                    FormatError::new(
+                        "E1303".to_string(),
+                        "invalid break time windows in vehicle shift".to_string(),
+                        format!("use exact or offset time for skippable break, vehicle type id: '{}'", vehicle.type_id),
+                    )
+                })?;
+
+                Ok(Some((vec![time], vec![(None, *duration, None)], Some(BreakPolicy::SkipIfArrivalBeforeEnd), true)))
+            }
+            VehicleBreak::Required { .. } => Ok(None),
+        })
+        .collect::<Result<Vec<_>, FormatError>>()?;
+
     (1..)
-        .zip(breaks.iter().filter_map(|vehicle_break| match vehicle_break {
-            VehicleBreak::Optional { time, places, policy } => Some((time, places, policy)),
-            VehicleBreak::Required { .. } => None,
-        }))
-        .flat_map(|(break_idx, (break_time, break_places, policy))| {
+        .zip(breaks.into_iter().flatten())
+        .flat_map(|(break_idx, (times, break_places, policy, is_skippable))| {
             vehicle
                 .vehicle_ids
                 .iter()
                 .map(|vehicle_id| {
-                    let times = match &break_time {
-                        VehicleOptionalBreakTime::TimeWindow(time) if time.len() != 2 => {
-                            panic!("break with invalid time window specified: must have start and end!")
-                        }
-                        VehicleOptionalBreakTime::TimeOffset(offsets) if offsets.len() != 2 => {
-                            panic!("break with invalid offset specified: must have start and end!")
-                        }
-                        VehicleOptionalBreakTime::TimeWindow(time) => vec![TimeSpan::Window(parse_time_window(time))],
-                        VehicleOptionalBreakTime::TimeOffset(offset) => {
-                            vec![TimeSpan::Offset(TimeOffset::new(*offset.first().unwrap(), *offset.last().unwrap()))]
-                        }
-                    };
-
                     let job_id = format!("{vehicle_id}_break_{shift_index}_{break_idx}");
                     let places = break_places
                         .iter()
-                        .map(|place| (place.location.clone(), place.duration, times.clone(), place.tag.clone()))
+                        .map(|(location, duration, tag)| (location.clone(), *duration, times.clone(), tag.clone()))
                         .collect();
 
                     let mut job =
                         get_conditional_job(coord_index, vehicle_id.clone(), &job_id, "break", shift_index, places);
 
-                    if let Some(policy) = policy {
-                        let policy = match policy {
-                            VehicleOptionalBreakPolicy::SkipIfNoIntersection => BreakPolicy::SkipIfNoIntersection,
-                            VehicleOptionalBreakPolicy::SkipIfArrivalBeforeEnd => BreakPolicy::SkipIfArrivalBeforeEnd,
-                        };
-
+                    if let Some(policy) = policy.clone() {
                         job.dimens.set_break_policy(policy);
                     }
 
+                    if is_skippable {
+                        job.dimens.set_break_skippable_to_assign(true);
+                    } else if let Some(min_work) = min_work_after_break {
+                        job.dimens.set_break_min_work_after(min_work);
+                    }
+
                     (job_id, job)
                 })
                 .collect::<Vec<_>>()
         })
         .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
+
+    Ok(())
 }
 
 fn read_reloads(
@@ -285,6 +385,7 @@ fn read_reloads(
             duration: reload.duration,
             times: reload.times.clone(),
             tag: reload.tag.clone(),
+            parallel_service: None,
         }),
     )
 }
@@ -1,11 +1,11 @@
 //! Specifies logic to read problem and routing matrix from json input.
 
 use super::*;
-use crate::parse_time;
+use crate::{parse_time, parse_time_safe};
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::models::Lock;
-use vrp_core::models::common::TimeWindow;
+use vrp_core::models::common::{TimeOffset, TimeSpan, TimeWindow, Timestamp};
 use vrp_core::prelude::{ActivityCost, Fleet as CoreFleet, Jobs as CoreJobs, TransportCost};
 use vrp_core::utils::*;
 
@@ -27,7 +27,9 @@ mod goal_reader;
 mod job_reader;
 
 mod problem_reader;
-use self::problem_reader::{map_to_problem_with_approx, map_to_problem_with_matrices};
+use self::problem_reader::{
+    map_to_problem_with_approx, map_to_problem_with_break_windows, map_to_problem_with_matrices,
+};
 
 /// Reads specific problem definition from various sources.
 pub trait PragmaticProblem {
@@ -95,6 +97,33 @@ impl PragmaticProblem for (ApiProblem, Option<Vec<Matrix>>) {
     }
 }
 
+/// Provides information about a vehicle shift used to resolve its break windows at solve time.
+pub struct VehicleShiftContext {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle type id.
+    pub type_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Time window when the vehicle is allowed to work within the shift.
+    pub time: TimeWindow,
+}
+
+/// A function which returns break windows for the given vehicle shift. Each window specifies the
+/// exact interval occupied by a required break.
+pub type BreakWindowsFn = Arc<dyn Fn(&VehicleShiftContext) -> Vec<TimeWindow> + Send + Sync>;
+
+/// Reads problem defined in pragmatic format with extra required breaks which windows are produced
+/// by the given function for each vehicle shift. Breaks defined in the problem are kept as is.
+/// When no routing matrices are passed, approximated ones are used.
+pub fn read_pragmatic_with_break_windows(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    break_windows_fn: BreakWindowsFn,
+) -> Result<CoreProblem, MultiFormatError> {
+    map_to_problem_with_break_windows(problem, matrices, break_windows_fn)
+}
+
 /// Keeps track of problem properties (e.g. features).
 struct ProblemProperties {
     has_multi_dimen_capacity: bool,
@@ -109,8 +138,12 @@ struct ProblemProperties {
     has_compatibility: bool,
     has_tour_size_limits: bool,
     has_tour_travel_limits: bool,
+    has_service_time_limits: bool,
+    has_stop_limits: bool,
     has_job_time_constraints: bool,
     has_min_vehicle_shifts: bool,
+    has_break_on_transit_preference: bool,
+    has_soft_time_windows: bool,
 }
 
 /// Keeps track of materialized problem building blocks.
@@ -119,9 +152,64 @@ struct ProblemBlocks {
     fleet: Arc<CoreFleet>,
     job_index: Option<Arc<JobIndex>>,
     transport: Arc<dyn TransportCost>,
+    base_transport: Arc<dyn TransportCost>,
     activity: Arc<dyn ActivityCost>,
     locks: Vec<Arc<Lock>>,
     reserved_times_index: ReservedTimesIndex,
+    reserved_time_sources_index: ReservedTimeSourcesIndex,
+    job_reserved_times_index: JobReservedTimesIndex,
+}
+
+/// Parses exact break time range, converting it from the local time when offset from UTC is specified.
+pub(crate) fn parse_exact_break_time_window(
+    earliest: &str,
+    latest: &str,
+    utc_offset: Option<Float>,
+) -> GenericResult<TimeWindow> {
+    let utc_offset = utc_offset.unwrap_or_default();
+
+    Ok(TimeWindow::new(parse_time_safe(earliest)? - utc_offset, parse_time_safe(latest)? - utc_offset))
+}
+
+/// Returns an id of the required break definition: a 1-based position of the break in the shift's break list.
+pub(crate) fn get_required_break_source_id(vehicle_id: &str, shift_index: usize, break_idx: usize) -> String {
+    format!("{vehicle_id}_required_break_{shift_index}_{break_idx}")
+}
+
+/// Returns a time span of the required break which time is not route dependent. Returns `None` for
+/// route dependent breaks or invalid exact time which is rejected by validation.
+pub(crate) fn get_required_break_time_span(time: &VehicleRequiredBreakTime) -> Option<TimeSpan> {
+    match time {
+        VehicleRequiredBreakTime::ExactTime { earliest, latest, utc_offset } => {
+            parse_exact_break_time_window(earliest, latest, *utc_offset).ok().map(TimeSpan::Window)
+        }
+        VehicleRequiredBreakTime::OffsetTime { earliest, latest } => {
+            Some(TimeSpan::Offset(TimeOffset::new(*earliest, *latest)))
+        }
+        VehicleRequiredBreakTime::AfterJob { .. }
+        | VehicleRequiredBreakTime::AfterLoad { .. }
+        | VehicleRequiredBreakTime::AnchoredOffsetTime { .. }
+        | VehicleRequiredBreakTime::FractionalOffset { .. } => None,
+    }
+}
+
+/// Returns absolute `earliest_first` and `latest_last` bounds taking into account optional daylight window:
+/// the most restrictive of explicit and daylight derived bounds is used.
+pub(crate) fn get_job_time_bounds(job_times: &JobTimeConstraints) -> (Option<Timestamp>, Option<Timestamp>) {
+    let earliest_first = job_times.earliest_first.as_ref().map(|time| parse_time(time));
+    let latest_last = job_times.latest_last.as_ref().map(|time| parse_time(time));
+
+    let Some(daylight) = job_times.daylight.as_ref() else {
+        return (earliest_first, latest_last);
+    };
+
+    let daylight_first = parse_time(&daylight.sunrise) + daylight.after_sunrise.unwrap_or_default();
+    let daylight_last = parse_time(&daylight.sunset) - daylight.before_sunset.unwrap_or_default();
+
+    (
+        Some(earliest_first.map_or(daylight_first, |time| time.max(daylight_first))),
+        Some(latest_last.map_or(daylight_last, |time| time.min(daylight_last))),
+    )
 }
 
 fn parse_time_window(tw: &[String]) -> TimeWindow {
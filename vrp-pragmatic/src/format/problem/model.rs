@@ -55,6 +55,7 @@ pub struct JobSkills {
 
 /// Specifies a place for sub job.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct JobPlace {
     /// A job place location.
     pub location: Location,
@@ -67,6 +68,10 @@ pub struct JobPlace {
     /// You can use it to identify used place in solution.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+    /// Allows service at this place to run in parallel with other consecutive services at the same location
+    /// which allow it too: they share a service start and the stop lasts as long as the longest service.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parallel_service: Option<bool>,
 }
 
 /// Specifies a job task.
@@ -125,6 +130,19 @@ pub struct Job {
     /// A compatibility group: jobs with different compatibility cannot be assigned to the same tour.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compatibility: Option<String>,
+
+    /// Makes time windows of the job soft: arrival after time window end is allowed, but penalized.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soft_times: Option<bool>,
+
+    /// A penalty per second of lateness when time windows are soft. Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness_penalty: Option<Float>,
+
+    /// A minimum time spent at the job's stop, from arrival to departure. Unlike service duration,
+    /// waiting time and service of other jobs at the stop count toward it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_dwell: Option<Float>,
 }
 
 // region Clustering
@@ -251,6 +269,7 @@ pub enum RouteCostSpan {
 
 /// Specifies vehicle costs.
 #[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VehicleCosts {
     /// Fixed is cost of vehicle usage per tour.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -266,10 +285,15 @@ pub struct VehicleCosts {
     /// Defaults to depot-to-depot for full round trip costs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub span: Option<RouteCostSpan>,
+
+    /// Specifies whether time of placed required breaks is excluded from the billable duration
+    /// of the route cost span. Default is false.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude_break_time: Option<bool>,
 }
 
 /// Specifies vehicle shift start.
-#[derive(Clone, Deserialize, Debug, Serialize)]
+#[derive(Clone, Deserialize, Debug, Default, Serialize)]
 pub struct ShiftStart {
     /// Earliest possible departure date time in RFC3339 format.
     pub earliest: String,
@@ -299,22 +323,84 @@ pub struct ShiftEnd {
     pub location: Location,
 }
 
+/// Specifies which point of the first job visit is checked against `earliest_first`.
+#[derive(Clone, Deserialize, Debug, Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum FirstJobReference {
+    /// Arrival at the first job cannot be earlier than `earliest_first`.
+    Arrival,
+    /// Service of the first job cannot start earlier than `earliest_first` (default).
+    #[default]
+    ServiceStart,
+}
+
+/// Specifies which departure is used to estimate arrival at the first job for `earliest_first` check.
+#[derive(Clone, Deserialize, Debug, Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum DepartureReference {
+    /// Arrival is estimated from the shift's scheduled start, ignoring departure time advancing.
+    ScheduledStart,
+    /// Arrival is estimated from the actual, possibly advanced, departure (default).
+    #[default]
+    ActualDeparture,
+}
+
+/// Specifies which point of the last job visit is checked against `latest_last`.
+#[derive(Clone, Deserialize, Debug, Serialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum LastJobReference {
+    /// Arrival at the last job cannot be later than `latest_last`.
+    Arrival,
+    /// Departure from the last job cannot be later than `latest_last` (default).
+    #[default]
+    Departure,
+}
+
 /// Time constraints for jobs within a shift.
 /// Controls when the first job can start and when the last job must finish.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct JobTimeConstraints {
-    /// Earliest allowed arrival at first job (RFC3339 format).
+    /// Earliest allowed arrival at or service start of first job (RFC3339 format).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub earliest_first: Option<String>,
-    /// Latest allowed departure from last job (RFC3339 format).
+    /// Latest allowed arrival at or departure from last job (RFC3339 format).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latest_last: Option<String>,
+    /// Specifies whether `earliest_first` limits arrival or service start at the first job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_job_reference: Option<FirstJobReference>,
+    /// Specifies whether `latest_last` limits arrival at or departure from the last job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_job_reference: Option<LastJobReference>,
+    /// Specifies which departure is used to estimate arrival at the first job for `earliest_first` check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub departure_reference: Option<DepartureReference>,
+    /// Daylight window used to derive `earliest_first` and `latest_last` from day boundaries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub daylight: Option<DaylightWindow>,
 }
 
-/// Specifies vehicle shift.
+/// Specifies daylight boundaries of the shift's day. When combined with explicit `earliest_first`
+/// and `latest_last`, the most restrictive bounds are used.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
+pub struct DaylightWindow {
+    /// Sunrise date time in RFC3339 format.
+    pub sunrise: String,
+    /// Sunset date time in RFC3339 format.
+    pub sunset: String,
+    /// Time after sunrise (in seconds) before which the first job cannot be served. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after_sunrise: Option<Float>,
+    /// Time before sunset (in seconds) after which the last job cannot be finished. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_sunset: Option<Float>,
+}
+
+/// Specifies vehicle shift.
+#[derive(Clone, Deserialize, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VehicleShift {
     /// Vehicle shift start.
     pub start: ShiftStart,
@@ -339,6 +425,33 @@ pub struct VehicleShift {
     /// Time constraints for the first and last jobs in this shift.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub job_times: Option<JobTimeConstraints>,
+
+    /// Prefer required breaks taken while driving between stops over ones taken at a stop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefer_break_on_transit: Option<bool>,
+
+    /// Max total service time of jobs served in this shift.
+    /// Travel, waiting and break times are not counted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_service_time: Option<Float>,
+
+    /// Max amount of stops in this shift. Tour start and end are not counted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_stops: Option<usize>,
+
+    /// Reserves stops for required breaks when max stops limit is checked.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reserve_break_stops: Option<bool>,
+
+    /// Min work time which should follow any break in this shift. An optional break placed closer
+    /// to the end of work is moved earlier or skipped, a required break is skipped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_work_after_break: Option<Float>,
+
+    /// Max fraction of working time which can be spent on breaks in this shift. Working time is
+    /// a tour duration without break time. Optional breaks are skipped to respect the limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_break_fraction: Option<Float>,
 }
 
 /// Specifies a place where vehicle can load or unload cargo.
@@ -420,11 +533,27 @@ pub enum VehicleOptionalBreakTime {
 pub enum VehicleRequiredBreakTime {
     /// Break time is defined by exact time in RFC3339 format.
     /// Break should be taken not earlier and not later than time range specified.
+    #[serde(rename_all = "camelCase")]
     ExactTime {
         /// Start of the range.
         earliest: String,
         /// End of the range.
         latest: String,
+        /// An offset of the local time from UTC in seconds. When specified, the range is treated as a local
+        /// time and converted to absolute time by subtracting the offset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        utc_offset: Option<Float>,
+    },
+    /// Break time is defined by amount of seconds since the given anchor.
+    /// Break should be taken not earlier and not later than time range specified.
+    /// NOTE should be declared before `OffsetTime` as it is distinguished only by the anchor.
+    AnchoredOffsetTime {
+        /// Start of the range.
+        earliest: Float,
+        /// End of the range.
+        latest: Float,
+        /// An anchor of the range.
+        anchor: BreakOffsetAnchor,
     },
     /// Break time is defined by amount of seconds since driving time.
     /// Break should be taken not earlier and not later than time range specified.
@@ -434,6 +563,38 @@ pub enum VehicleRequiredBreakTime {
         /// End of the range.
         latest: Float,
     },
+    /// Break time is defined by fractions of the realized shift duration, e.g. 0.4 means that the break
+    /// is taken after 40% of the shift. Break should be taken not earlier and not later than range specified.
+    #[serde(rename_all = "camelCase")]
+    FractionalOffset {
+        /// Start of the range as a fraction of the shift duration.
+        earliest_frac: Float,
+        /// End of the range as a fraction of the shift duration.
+        latest_frac: Float,
+    },
+    /// Break time is defined by a specific job: break should be taken right after the job is served.
+    /// Break is skipped when the job is not served by the vehicle.
+    #[serde(rename_all = "camelCase")]
+    AfterJob {
+        /// An id of the job.
+        job_id: String,
+    },
+    /// Break time is defined by cumulative load handled: break should be taken right after the job
+    /// at which total picked up and delivered load reaches the given amount in every dimension.
+    /// Break is skipped when the amount is not reached.
+    AfterLoad {
+        /// A cumulative load handled.
+        load: Vec<i32>,
+    },
+}
+
+/// Specifies an anchor of the offset break time range.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BreakOffsetAnchor {
+    /// A start of each working period in the tour: a reload departure starts a new one, so the break
+    /// is taken once per period. The first period is anchored as a regular offset break.
+    Reload,
 }
 
 /// Vehicle break place.
@@ -479,6 +640,14 @@ pub enum VehicleBreak {
         time: VehicleRequiredBreakTime,
         /// Break duration.
         duration: Float,
+        /// Allows to skip the break when it prevents an otherwise unassigned job from being served.
+        /// Only breaks with exact or offset time are supported.
+        #[serde(rename = "skippableToAssign", default, skip_serializing_if = "Option::is_none")]
+        skippable_to_assign: Option<bool>,
+        /// A minimum amount of jobs served before the break. When break time falls earlier, the break is
+        /// shifted to the service of the last required job. Only breaks with exact or offset time are supported.
+        #[serde(rename = "minJobsBeforeBreak", default, skip_serializing_if = "Option::is_none")]
+        min_jobs_before_break: Option<usize>,
     },
 }
 
@@ -550,6 +719,22 @@ pub struct MatrixProfile {
     /// Default value is 10.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub speed: Option<Float>,
+
+    /// Emissions model used to report route emissions in solution statistic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emissions: Option<ProfileEmissions>,
+}
+
+/// Specifies emissions model of the routing profile.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileEmissions {
+    /// Emissions per distance unit for an empty vehicle.
+    pub factor: Float,
+
+    /// Relative increase of emissions for fully loaded vehicle. Default is 0 (load is ignored).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_weight: Option<Float>,
 }
 
 /// Specifies vehicle resource type.
@@ -621,12 +806,22 @@ pub enum Objective {
     /// An objective to minimize sum of arrival times from all routes.
     MinimizeArrivalTime,
 
+    /// An objective to minimize the latest tour end time across all routes.
+    MinimizeLatestArrival,
+
+    /// An objective to minimize empty running (deadhead) distance: travel from the start to the first job
+    /// and from the last job to the end of each tour.
+    MinimizeDeadhead,
+
     /// An objective to balance max load across all tours.
     BalanceMaxLoad,
 
     /// An objective to balance activities across all tours.
     BalanceActivities,
 
+    /// An objective to balance amount of jobs across all tours.
+    BalanceJobCount,
+
     /// An objective to balance distance across all tours.
     BalanceDistance,
 
@@ -664,8 +859,43 @@ pub enum Objective {
     MinimizeOverdue,
 
     /// An objective to minimize the distance from jobs to their assigned vehicle,
-    /// compared to the nearest compatible vehicle in the fleet.
-    MinimizeVehicleDistance,
+    /// compared to the reference distance to compatible vehicles in the fleet.
+    MinimizeVehicleDistance {
+        /// Specifies how the reference distance is computed. Default is nearest vehicle.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reference: Option<VehicleDistanceReference>,
+    },
+
+    /// An objective to minimize total break time.
+    MinimizeBreakTime,
+
+    /// An objective to minimize amount of distinct locations used by breaks across all tours.
+    MinimizeBreakLocationCount,
+
+    /// An objective to minimize total detour distance caused by visiting break locations.
+    MinimizeBreakDetour,
+
+    /// An objective to limit the amount of breaks taken simultaneously across all tours.
+    #[serde(rename_all = "camelCase")]
+    MinimizeBreakOverlap {
+        /// A maximum amount of breaks which can be taken at the same time without a penalty.
+        max_breaks: usize,
+    },
+
+    /// An objective to prefer breaks which start at round clock times.
+    MinimizeBreakClockOffset {
+        /// A clock grid interval: break start times are compared to its nearest multiple.
+        interval: Float,
+    },
+
+    /// An objective to prefer required breaks taken on transit legs instead of stops.
+    PreferBreakOnTransit,
+
+    /// An objective to prefer tours with more slack against vehicle shift job time constraints.
+    MinimizeJobTimePressure,
+
+    /// An objective to minimize lateness of jobs with soft time windows.
+    MinimizeLateness,
 
     /// An objective to consider hierarchy of areas while serving jobs.
     HierarchicalAreas {
@@ -697,6 +927,18 @@ pub enum MultiStrategy {
     },
 }
 
+/// Specifies a reference used by vehicle distance objective.
+#[derive(Clone, Deserialize, Debug, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum VehicleDistanceReference {
+    /// Distance to the nearest compatible vehicle's start location.
+    #[default]
+    Nearest,
+
+    /// Distance to the centroid of all compatible vehicles' start locations.
+    Centroid,
+}
+
 // endregion
 
 // region Common
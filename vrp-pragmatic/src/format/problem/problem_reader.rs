@@ -1,20 +1,21 @@
 use super::*;
+use crate::CoordIndex;
 use crate::format::problem::clustering_reader::create_cluster_config;
 use crate::format::problem::fleet_reader::*;
 use crate::format::problem::goal_reader::create_goal_context;
 use crate::format::problem::job_reader::{read_jobs_with_extra_locks, read_locks};
 use crate::format::{FormatError, JobIndex};
 use crate::validation::ValidationContext;
-use crate::{CoordIndex, parse_time};
 use vrp_core::construction::enablers::*;
 use vrp_core::models::Extras;
-use vrp_core::models::common::{TimeOffset, TimeSpan, TimeWindow};
-use vrp_core::solver::processing::{ClusterConfigExtraProperty, ReservedTimesExtraProperty};
+use vrp_core::solver::processing::{
+    ClusterConfigExtraProperty, JobReservedTimesExtraProperty, ReservedTimesExtraProperty,
+};
 
 pub(super) fn map_to_problem_with_approx(problem: ApiProblem) -> Result<CoreProblem, MultiFormatError> {
     let coord_index = CoordIndex::new(&problem);
     let matrices = if coord_index.has_indices() { vec![] } else { create_approx_matrices(&problem) };
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem(problem, matrices, coord_index, None)
 }
 
 pub(super) fn map_to_problem_with_matrices(
@@ -22,13 +23,25 @@ pub(super) fn map_to_problem_with_matrices(
     matrices: Vec<Matrix>,
 ) -> Result<CoreProblem, MultiFormatError> {
     let coord_index = CoordIndex::new(&problem);
-    map_to_problem(problem, matrices, coord_index)
+    map_to_problem(problem, matrices, coord_index, None)
+}
+
+pub(super) fn map_to_problem_with_break_windows(
+    problem: ApiProblem,
+    matrices: Option<Vec<Matrix>>,
+    break_windows_fn: BreakWindowsFn,
+) -> Result<CoreProblem, MultiFormatError> {
+    let coord_index = CoordIndex::new(&problem);
+    let matrices =
+        matrices.unwrap_or_else(|| if coord_index.has_indices() { vec![] } else { create_approx_matrices(&problem) });
+    map_to_problem(problem, matrices, coord_index, Some(&break_windows_fn))
 }
 
 pub(super) fn map_to_problem(
     api_problem: ApiProblem,
     matrices: Vec<Matrix>,
     coord_index: CoordIndex,
+    break_windows_fn: Option<&BreakWindowsFn>,
 ) -> Result<CoreProblem, MultiFormatError> {
     ValidationContext::new(&api_problem, Some(&matrices), &coord_index).validate()?;
 
@@ -40,7 +53,7 @@ pub(super) fn map_to_problem(
     let mut job_index = JobIndex::default();
 
     let props = get_problem_properties(&api_problem, &matrices);
-    let mut blocks = get_problem_blocks(&api_problem, matrices, coord_index, &mut job_index, &props)?;
+    let mut blocks = get_problem_blocks(&api_problem, matrices, coord_index, &mut job_index, &props, break_windows_fn)?;
 
     let job_index = Arc::new(job_index);
     extras.set_job_index(job_index.clone());
@@ -48,7 +61,21 @@ pub(super) fn map_to_problem(
 
     let goal = Arc::new(create_goal_context(&api_problem, &blocks, &props).map_err(to_multi_format_error)?);
 
-    let ProblemBlocks { jobs, fleet, transport, activity, locks, reserved_times_index, .. } = blocks;
+    let ProblemBlocks {
+        jobs,
+        fleet,
+        transport,
+        base_transport,
+        activity,
+        locks,
+        reserved_times_index,
+        reserved_time_sources_index,
+        job_reserved_times_index,
+        ..
+    } = blocks;
+
+    // NOTE keep transport costs without reserved times to be able to reschedule routes with other ones
+    extras.set_base_transport_cost(Arc::new(base_transport));
 
     if let Some(config) = create_cluster_config(&api_problem).map_err(to_multi_format_error)? {
         extras.set_cluster_config(Arc::new(config));
@@ -56,52 +83,135 @@ pub(super) fn map_to_problem(
 
     if !reserved_times_index.is_empty() {
         extras.set_reserved_times(Arc::new(reserved_times_index));
+        extras.set_reserved_time_sources(Arc::new(reserved_time_sources_index));
+    }
+
+    if !job_reserved_times_index.is_empty() {
+        extras.set_job_reserved_times(Arc::new(job_reserved_times_index));
     }
 
     Ok(CoreProblem { fleet, jobs, locks, goal, activity, transport, extras: Arc::new(extras) })
 }
 
-fn read_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> ReservedTimesIndex {
+/// Reads reserved times of required breaks per actor together with ids of their sources: a source id
+/// is specified for breaks from the problem definition and is aligned with the reserved time by index.
+fn read_reserved_times_index(
+    api_problem: &ApiProblem,
+    fleet: &CoreFleet,
+    break_windows_fn: Option<&BreakWindowsFn>,
+) -> (ReservedTimesIndex, ReservedTimeSourcesIndex) {
     let breaks_map = api_problem
         .fleet
         .vehicles
         .iter()
         .flat_map(|vehicle| {
             vehicle.shifts.iter().enumerate().flat_map(move |(shift_idx, shift)| {
-                shift.breaks.iter().flat_map(|br| br.iter()).filter_map(move |br| match br {
-                    VehicleBreak::Required { time, duration } => {
-                        Some((vehicle.type_id.clone(), shift_idx, time.clone(), *duration))
+                // NOTE break index is 1-based position in the shift's break list
+                (1..).zip(shift.breaks.iter().flat_map(|br| br.iter())).filter_map(move |(break_idx, br)| match br {
+                    VehicleBreak::Required { time: VehicleRequiredBreakTime::AfterJob { .. }, .. } => None,
+                    VehicleBreak::Required { time: VehicleRequiredBreakTime::AfterLoad { .. }, .. } => None,
+                    VehicleBreak::Required { time: VehicleRequiredBreakTime::FractionalOffset { .. }, .. } => None,
+                    VehicleBreak::Required { time: VehicleRequiredBreakTime::AnchoredOffsetTime { .. }, .. } => None,
+                    // NOTE skippable breaks are modeled as conditional jobs
+                    VehicleBreak::Required { skippable_to_assign: Some(true), .. } => None,
+                    // NOTE breaks shifted after jobs are modeled as break rules
+                    VehicleBreak::Required { min_jobs_before_break: Some(_), .. } => None,
+                    VehicleBreak::Required { time, duration, .. } => {
+                        Some((vehicle.type_id.clone(), shift_idx, break_idx, time.clone(), *duration))
                     }
                     VehicleBreak::Optional { .. } => None,
                 })
             })
         })
-        .collect_group_by_key(|(type_id, shift_idx, _, _)| (type_id.clone(), *shift_idx));
+        .collect_group_by_key(|(type_id, shift_idx, ..)| (type_id.clone(), *shift_idx));
 
     fleet
         .actors
         .iter()
         .filter_map(|actor| {
+            let vehicle_id = actor.vehicle.dimens.get_vehicle_id().unwrap().clone();
             let type_id = actor.vehicle.dimens.get_vehicle_type().unwrap().clone();
             let shift_idx = actor.vehicle.dimens.get_shift_index().copied().unwrap();
 
-            let times = breaks_map
-                .get(&(type_id, shift_idx))
+            let mut times = breaks_map
+                .get(&(type_id.clone(), shift_idx))
                 .iter()
                 .flat_map(|data| data.iter())
-                .map(|(_, _, time, duration)| {
-                    let time = match &time {
-                        VehicleRequiredBreakTime::ExactTime { earliest, latest } => {
-                            TimeSpan::Window(TimeWindow::new(parse_time(earliest), parse_time(latest)))
-                        }
-                        VehicleRequiredBreakTime::OffsetTime { earliest, latest } => {
-                            TimeSpan::Offset(TimeOffset::new(*earliest, *latest))
-                        }
+                .filter_map(|(_, _, break_idx, time, duration)| {
+                    let reserved_time =
+                        ReservedTimeSpan { time: get_required_break_time_span(time)?, duration: *duration };
+
+                    Some((reserved_time, Some(get_required_break_source_id(&vehicle_id, shift_idx, *break_idx))))
+                })
+                .chain(break_windows_fn.into_iter().flat_map(|break_windows_fn| {
+                    let shift_ctx = VehicleShiftContext {
+                        vehicle_id: vehicle_id.clone(),
+                        type_id: type_id.clone(),
+                        shift_index: shift_idx,
+                        time: actor.detail.time.clone(),
                     };
-                    let duration = *duration;
 
-                    ReservedTimeSpan { time, duration }
+                    // NOTE break occupies the whole window returned by the function
+                    break_windows_fn(&shift_ctx).into_iter().map(|window| {
+                        let reserved_time = ReservedTimeSpan {
+                            time: TimeSpan::Window(TimeWindow::new(window.start, window.start)),
+                            duration: window.duration(),
+                        };
+
+                        (reserved_time, None)
+                    })
+                }))
+                .collect::<Vec<_>>();
+
+            // NOTE keep breaks in time order regardless of their declaration order: offset breaks are
+            // resolved relatively to the earliest shift start
+            let offset_anchor = actor.detail.time.start;
+            times.sort_by(|(a, _), (b, _)| {
+                a.time.to_time_window(offset_anchor).start.total_cmp(&b.time.to_time_window(offset_anchor).start)
+            });
+
+            if times.is_empty() { None } else { Some((actor.clone(), times.into_iter().unzip())) }
+        })
+        .fold(
+            (ReservedTimesIndex::default(), ReservedTimeSourcesIndex::default()),
+            |(mut times_index, mut sources_index), (actor, (times, sources))| {
+                times_index.insert(actor.clone(), times);
+                sources_index.insert(actor, sources);
+
+                (times_index, sources_index)
+            },
+        )
+}
+
+fn read_job_reserved_times_index(api_problem: &ApiProblem, fleet: &CoreFleet) -> JobReservedTimesIndex {
+    let breaks_map = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| {
+            vehicle.shifts.iter().enumerate().flat_map(move |(shift_idx, shift)| {
+                shift.breaks.iter().flat_map(|br| br.iter()).filter_map(move |br| match br {
+                    VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::AfterJob { job_id }, duration, ..
+                    } => Some((vehicle.type_id.clone(), shift_idx, job_id.clone(), *duration)),
+                    _ => None,
                 })
+            })
+        })
+        .collect_group_by_key(|(type_id, shift_idx, _, _)| (type_id.clone(), *shift_idx));
+
+    fleet
+        .actors
+        .iter()
+        .filter_map(|actor| {
+            let type_id = actor.vehicle.dimens.get_vehicle_type().unwrap().clone();
+            let shift_idx = actor.vehicle.dimens.get_shift_index().copied().unwrap();
+
+            let times = breaks_map
+                .get(&(type_id, shift_idx))
+                .iter()
+                .flat_map(|data| data.iter())
+                .map(|(_, _, job_id, duration)| JobReservedTime { job_id: job_id.clone(), duration: *duration })
                 .collect::<Vec<_>>();
 
             if times.is_empty() { None } else { Some((actor.clone(), times)) }
@@ -135,6 +245,8 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
     let has_breaks = shift_has_fn(|s| s.breaks.as_ref().is_some_and(|b| !b.is_empty()));
     let has_reloads = shift_has_fn(|s| s.reloads.as_ref().is_some_and(|r| !r.is_empty()));
     let has_recharges = shift_has_fn(|s| s.recharges.as_ref().is_some());
+    let has_break_on_transit_preference = shift_has_fn(|s| s.prefer_break_on_transit.unwrap_or(false));
+    let has_soft_time_windows = api_problem.plan.jobs.iter().any(|job| job.soft_times.unwrap_or(false));
 
     let has_order = api_problem
         .plan
@@ -161,6 +273,11 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
     let has_job_time_constraints =
         api_problem.fleet.vehicles.iter().any(|v| v.shifts.iter().any(|s| s.job_times.is_some()));
 
+    let has_service_time_limits =
+        api_problem.fleet.vehicles.iter().any(|v| v.shifts.iter().any(|s| s.max_service_time.is_some()));
+
+    let has_stop_limits = api_problem.fleet.vehicles.iter().any(|v| v.shifts.iter().any(|s| s.max_stops.is_some()));
+
     ProblemProperties {
         has_multi_dimen_capacity,
         has_breaks,
@@ -174,8 +291,12 @@ fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matrix]) -> Prob
         has_compatibility,
         has_tour_size_limits,
         has_tour_travel_limits,
+        has_service_time_limits,
+        has_stop_limits,
         has_job_time_constraints,
         has_min_vehicle_shifts,
+        has_break_on_transit_preference,
+        has_soft_time_windows,
     }
 }
 
@@ -185,12 +306,15 @@ fn get_problem_blocks(
     coord_index: Arc<CoordIndex>,
     job_index: &mut JobIndex,
     problem_props: &ProblemProperties,
+    break_windows_fn: Option<&BreakWindowsFn>,
 ) -> Result<ProblemBlocks, MultiFormatError> {
     // TODO pass environment from outside to allow parametrization
     let environment = Environment::default();
 
     let fleet = read_fleet(api_problem, problem_props, &coord_index);
-    let reserved_times_index = read_reserved_times_index(api_problem, &fleet);
+    let (reserved_times_index, reserved_time_sources_index) =
+        read_reserved_times_index(api_problem, &fleet, break_windows_fn);
+    let job_reserved_times_index = read_job_reserved_times_index(api_problem, &fleet);
 
     let transport = Timer::measure_duration_with_callback(
         || {
@@ -207,25 +331,30 @@ fn get_problem_blocks(
         },
     )?;
     let activity: Arc<dyn ActivityCost> = Arc::new(OnlyVehicleActivityCost::default());
-
-    let (transport, activity) = if reserved_times_index.is_empty() {
-        (transport, activity)
-    } else {
-        DynamicTransportCost::new(reserved_times_index.clone(), transport)
-            .and_then(|transport| {
-                DynamicActivityCost::new(reserved_times_index.clone()).map(|activity| (transport, activity))
-            })
-            .map_err(|err| {
-                vec![FormatError::new(
-                    "E0002".to_string(),
-                    "cannot create transport costs".to_string(),
-                    format!("check fleet definition: '{err}'"),
-                )]
-            })
-            .map::<(Arc<dyn TransportCost>, Arc<dyn ActivityCost>), _>(|(transport, activity)| {
-                (Arc::new(transport), Arc::new(activity))
-            })?
-    };
+    let base_transport = transport.clone();
+
+    let has_route_reserved_times = fleet.actors.iter().any(|actor| actor.vehicle.dimens.get_break_rules().is_some());
+    let (transport, activity) =
+        if reserved_times_index.is_empty() && job_reserved_times_index.is_empty() && !has_route_reserved_times {
+            (transport, activity)
+        } else {
+            DynamicTransportCost::new(reserved_times_index.clone(), transport)
+                .and_then(|transport| {
+                    DynamicActivityCost::new(reserved_times_index.clone())
+                        .map(|activity| activity.with_job_reserved_times(job_reserved_times_index.clone()))
+                        .map(|activity| (transport, activity))
+                })
+                .map_err(|err| {
+                    vec![FormatError::new(
+                        "E0002".to_string(),
+                        "cannot create transport costs".to_string(),
+                        format!("check fleet definition: '{err}'"),
+                    )]
+                })
+                .map::<(Arc<dyn TransportCost>, Arc<dyn ActivityCost>), _>(|(transport, activity)| {
+                    (Arc::new(transport), Arc::new(activity))
+                })?
+        };
 
     let (jobs, locks) = read_jobs_with_extra_locks(
         api_problem,
@@ -235,7 +364,8 @@ fn get_problem_blocks(
         transport.as_ref(),
         job_index,
         &environment,
-    );
+    )
+    .map_err(|err| vec![err])?;
     let locks = locks.into_iter().chain(read_locks(api_problem, job_index)).collect::<Vec<_>>();
 
     Ok(ProblemBlocks {
@@ -243,8 +373,11 @@ fn get_problem_blocks(
         fleet: Arc::new(fleet),
         job_index: None,
         transport,
+        base_transport,
         activity,
         locks,
         reserved_times_index,
+        reserved_time_sources_index,
+        job_reserved_times_index,
     })
 }
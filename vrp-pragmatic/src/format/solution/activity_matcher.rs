@@ -1,7 +1,7 @@
-use crate::format::problem::VehicleBreak;
-use crate::format::problem::{Problem as FormatProblem, VehicleRequiredBreakTime};
+use crate::format::problem::{BreakOffsetAnchor, RouteCostSpan, VehicleBreak};
+use crate::format::problem::{Problem as FormatProblem, VehicleRequiredBreakTime, parse_exact_break_time_window};
 use crate::format::solution::{Activity as FormatActivity, Schedule as FormatSchedule, Tour as FormatTour};
-use crate::format::solution::{PointStop, TransitStop};
+use crate::format::solution::{PointStop, Stop as FormatStop, TransitStop};
 use crate::format::{CoordIndex, JobIndex, PlaceTagsDimension};
 use crate::parse_time;
 use std::collections::HashSet;
@@ -17,16 +17,17 @@ use vrp_core::utils::Either;
 pub(crate) struct JobInfo(pub Job, pub Arc<Single>, pub Place, pub TimeWindow);
 
 /// Tries to match given activity to core job models. None is returned in case of
-/// non-job activity (departure, arrival).
+/// non-job activity (departure, arrival). Offset times are resolved using given offset anchor,
+/// see `get_offset_anchor`.
 pub(crate) fn try_match_point_job(
     tour: &FormatTour,
     stop: &PointStop,
     activity: &FormatActivity,
-    job_index: &JobIndex,
-    coord_index: &CoordIndex,
+    (job_index, coord_index): (&JobIndex, &CoordIndex),
+    offset_anchor: Timestamp,
 ) -> Result<Option<JobInfo>, GenericError> {
     let ctx = ActivityContext {
-        route_start_time: get_route_start_time(tour)?,
+        offset_anchor,
         location: coord_index
             .get_by_loc(activity.location.as_ref().unwrap_or(&stop.location))
             .ok_or_else(|| format!("cannot get location for activity for job '{}'", activity.job_id))?,
@@ -110,24 +111,238 @@ pub(crate) fn try_match_break_activity(
         .vehicles
         .iter()
         .filter(|vehicle| vehicle.vehicle_ids.contains(&tour.vehicle_id))
-        .flat_map(|vehicle| vehicle.shifts.get(tour.shift_index).into_iter())
-        .flat_map(|shift| shift.breaks.iter())
-        .flat_map(|brs| brs.iter())
-        .filter_map(|br| match br {
-            VehicleBreak::Required { time: VehicleRequiredBreakTime::ExactTime { earliest, latest }, duration } => {
-                Some(TimeWindow::new(parse_time(earliest), parse_time(latest) + *duration))
-            }
-            VehicleBreak::Required { time: VehicleRequiredBreakTime::OffsetTime { earliest, latest }, duration } => {
-                Some(TimeWindow::new(route_start_time + *earliest, route_start_time + *latest + *duration))
-            }
-            VehicleBreak::Optional { .. } => None,
+        .flat_map(|vehicle| {
+            // NOTE offset range can start before its anchor, so the anchor has to follow the route cost span
+            let offset_anchor = if is_first_job_anchored(vehicle.costs.span.as_ref()) {
+                get_first_job_arrival(tour).unwrap_or(route_start_time)
+            } else {
+                route_start_time
+            };
+
+            vehicle
+                .shifts
+                .get(tour.shift_index)
+                .into_iter()
+                .flat_map(|shift| shift.breaks.iter())
+                .flat_map(|brs| brs.iter())
+                .map(move |br| (br, offset_anchor))
+        })
+        .flat_map(|(br, offset_anchor)| {
+            let time = match br {
+                VehicleBreak::Required {
+                    time: VehicleRequiredBreakTime::ExactTime { earliest, latest, utc_offset },
+                    duration,
+                    ..
+                } => parse_exact_break_time_window(earliest, latest, *utc_offset)
+                    .ok()
+                    .map(|tw| TimeWindow::new(tw.start, tw.end + *duration)),
+                VehicleBreak::Required {
+                    time: VehicleRequiredBreakTime::OffsetTime { earliest, latest }, duration, ..
+                } => Some(TimeWindow::new(offset_anchor + *earliest, offset_anchor + *latest + *duration)),
+                VehicleBreak::Required {
+                    time:
+                        VehicleRequiredBreakTime::AnchoredOffsetTime {
+                            earliest,
+                            latest,
+                            anchor: BreakOffsetAnchor::Reload,
+                        },
+                    duration,
+                    ..
+                } => return get_reload_break_time_windows(tour, offset_anchor, (*earliest, *latest), *duration),
+                VehicleBreak::Required {
+                    time: VehicleRequiredBreakTime::FractionalOffset { earliest_frac, latest_frac },
+                    duration,
+                    ..
+                } => tour.stops.last().map(|stop| {
+                    let shift_duration = (parse_time(&stop.schedule().arrival) - route_start_time).max(0.);
+                    TimeWindow::new(
+                        route_start_time + earliest_frac * shift_duration,
+                        route_start_time + latest_frac * shift_duration + *duration,
+                    )
+                }),
+                VehicleBreak::Required { time: VehicleRequiredBreakTime::AfterJob { job_id }, duration, .. } => {
+                    get_after_job_break_time_window(tour, job_id, *duration)
+                }
+                VehicleBreak::Required { time: VehicleRequiredBreakTime::AfterLoad { load }, .. } => {
+                    get_after_load_break_time_window(problem, tour, load)
+                }
+                VehicleBreak::Optional { .. } => None,
+            };
+
+            let time = match br {
+                VehicleBreak::Required { min_jobs_before_break: Some(min_jobs), duration, .. } => {
+                    time.and_then(|time| get_min_jobs_break_time_window(tour, *min_jobs, time, *duration))
+                }
+                _ => time,
+            };
+
+            time.into_iter().collect()
         })
         .find(|time| activity_time.intersects(time))
         .ok_or_else(|| "cannot match activity to required break".into())
 }
 
+/// Returns an anchor of offset times within the tour: the first job arrival when the route cost span
+/// starts at the first job, otherwise the tour departure.
+pub(crate) fn get_offset_anchor(tour: &FormatTour, is_first_job_anchored: bool) -> Result<Timestamp, GenericError> {
+    let route_start_time = get_route_start_time(tour)?;
+
+    Ok(if is_first_job_anchored { get_first_job_arrival(tour).unwrap_or(route_start_time) } else { route_start_time })
+}
+
+/// Checks whether offset times are anchored to the first job arrival under given route cost span.
+pub(crate) fn is_first_job_anchored(cost_span: Option<&RouteCostSpan>) -> bool {
+    matches!(cost_span, Some(RouteCostSpan::FirstJobToDepot | RouteCostSpan::FirstJobToLastJob))
+}
+
+/// Gets the arrival time of the first job activity in the tour.
+pub(crate) fn get_first_job_arrival(tour: &FormatTour) -> Option<Timestamp> {
+    // The first stop is departure, so first job is the second stop (or first non-departure activity)
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .find(|a| !matches!(a.activity_type.as_str(), "departure" | "arrival"))
+        .and_then(|_| {
+            // Find the stop that contains the first job activity and get its arrival
+            tour.stops
+                .iter()
+                .enumerate()
+                .find(|(_, stop)| {
+                    stop.activities().iter().any(|a| !matches!(a.activity_type.as_str(), "departure" | "arrival"))
+                })
+                .map(|(stop_idx, stop)| {
+                    // NOTE a job at the depot location shares the departure stop and is reached at departure
+                    let departure_time = stop
+                        .activities()
+                        .iter()
+                        .find(|a| a.activity_type == "departure")
+                        .and_then(|a| a.time.as_ref())
+                        .filter(|_| stop_idx == 0);
+
+                    departure_time.map_or_else(|| parse_time(&stop.schedule().arrival), |time| parse_time(&time.end))
+                })
+        })
+}
+
+/// Returns time window of a break which cannot be taken before given amount of jobs is served in the tour:
+/// when the break time window ends before the service start of the last required job, the break is
+/// expected right after that job. Returns `None` when the tour has fewer jobs.
+pub(crate) fn get_min_jobs_break_time_window(
+    tour: &FormatTour,
+    min_jobs: usize,
+    break_tw: TimeWindow,
+    duration: Float,
+) -> Option<TimeWindow> {
+    if min_jobs == 0 {
+        return Some(break_tw);
+    }
+
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| {
+            matches!(activity.activity_type.as_str(), "pickup" | "delivery" | "service" | "replacement")
+        })
+        .nth(min_jobs - 1)
+        .map(|(stop, activity)| {
+            let (start, end) = activity.time.as_ref().map_or_else(
+                || (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)),
+                |time| (parse_time(&time.start), parse_time(&time.end)),
+            );
+
+            // NOTE break time window includes break duration
+            if break_tw.end - duration < start { TimeWindow::new(start, end + duration) } else { break_tw }
+        })
+}
+
+/// Returns time window of a break taken right after the job with given id is served in the tour.
+pub(crate) fn get_after_job_break_time_window(tour: &FormatTour, job_id: &str, duration: Float) -> Option<TimeWindow> {
+    tour.stops.iter().find_map(|stop| {
+        stop.activities().iter().find(|activity| activity.job_id == job_id && activity.activity_type != "break").map(
+            |activity| {
+                let end = activity
+                    .time
+                    .as_ref()
+                    .map_or_else(|| parse_time(&stop.schedule().departure), |time| parse_time(&time.end));
+                TimeWindow::new(end, end + duration)
+            },
+        )
+    })
+}
+
+/// Returns time window of the stop where cumulative load handled in the tour reaches given amount.
+/// Handled load is a sum of demands picked up and delivered by job activities, as in the core.
+pub(crate) fn get_after_load_break_time_window(
+    problem: &FormatProblem,
+    tour: &FormatTour,
+    load: &[i32],
+) -> Option<TimeWindow> {
+    tour.stops
+        .iter()
+        .scan(vec![0; load.len()], |handled, stop| {
+            stop.activities().iter().for_each(|activity| {
+                // NOTE replacement both picks up and delivers its demand
+                let factor = if activity.activity_type == "replacement" { 2 } else { 1 };
+                get_activity_demand(problem, activity).into_iter().flatten().zip(handled.iter_mut()).for_each(
+                    |(demand, value)| {
+                        *value += demand * factor;
+                    },
+                );
+            });
+
+            Some((handled.iter().zip(load.iter()).all(|(handled, load)| handled >= load), stop))
+        })
+        .find(|(is_reached, stop)| *is_reached && matches!(stop, FormatStop::Point(_)))
+        .map(|(_, stop)| TimeWindow::new(parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)))
+}
+
+/// Returns a demand of the job task served by the activity. Tasks of the same type are distinguished by tag.
+fn get_activity_demand<'a>(problem: &'a FormatProblem, activity: &FormatActivity) -> Option<&'a Vec<i32>> {
+    let job = problem.plan.jobs.iter().find(|job| job.id == activity.job_id)?;
+    let tasks = match activity.activity_type.as_str() {
+        "pickup" => job.pickups.as_ref(),
+        "delivery" => job.deliveries.as_ref(),
+        "replacement" => job.replacements.as_ref(),
+        _ => None,
+    }?;
+
+    tasks
+        .iter()
+        .find(|task| {
+            activity.job_tag.as_ref().is_none_or(|tag| task.places.iter().any(|place| place.tag.as_ref() == Some(tag)))
+        })
+        .and_then(|task| task.demand.as_ref())
+}
+
+/// Returns time windows of a required break anchored to reloads: one per working period started by
+/// the offset anchor or by departure from a reload. As in `ReloadReservedTime`, a period's break is
+/// not expected when the next reload departs before its latest start.
+pub(crate) fn get_reload_break_time_windows(
+    tour: &FormatTour,
+    offset_anchor: Timestamp,
+    (earliest, latest): (Float, Float),
+    duration: Duration,
+) -> Vec<TimeWindow> {
+    let anchors = std::iter::once(offset_anchor)
+        .chain(
+            tour.stops
+                .iter()
+                .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+                .filter(|(_, activity)| activity.activity_type == "reload")
+                .map(|(stop, activity)| get_activity_time(activity, stop.schedule()).end),
+        )
+        .collect::<Vec<_>>();
+
+    anchors
+        .iter()
+        .enumerate()
+        .filter(|&(idx, anchor)| anchors.get(idx + 1).is_none_or(|next_anchor| anchor + latest < *next_anchor))
+        .map(|(_, anchor)| TimeWindow::new(anchor + earliest, anchor + latest + duration))
+        .collect()
+}
+
 struct ActivityContext<'a> {
-    route_start_time: Timestamp,
+    offset_anchor: Timestamp,
     location: Location,
     time: TimeWindow,
     act_type: &'a String,
@@ -137,8 +352,7 @@ struct ActivityContext<'a> {
 
 fn match_place(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &ActivityContext) -> Option<Place> {
     let job_id = get_job_id(single);
-    let job_tag =
-        get_job_tag(single, (activity_ctx.location, (activity_ctx.time.clone(), activity_ctx.route_start_time)));
+    let job_tag = get_job_tag(single, (activity_ctx.location, (activity_ctx.time.clone(), activity_ctx.offset_anchor)));
 
     let is_same_ids = *activity_ctx.job_id == job_id;
     let is_same_tags = match (job_tag, activity_ctx.tag) {
@@ -156,7 +370,7 @@ fn match_place(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &Activ
             .find(|(_, place)| {
                 let is_same_location = place.location.is_none_or(|l| l == activity_ctx.location);
                 let is_proper_time =
-                    place.times.iter().any(|time| time.intersects(activity_ctx.route_start_time, &activity_ctx.time));
+                    place.times.iter().any(|time| time.intersects(activity_ctx.offset_anchor, &activity_ctx.time));
 
                 is_same_location && is_proper_time
             })
@@ -165,7 +379,7 @@ fn match_place(single: &Arc<Single>, is_job_activity: bool, activity_ctx: &Activ
                 let time = place
                     .times
                     .iter()
-                    .rfind(|time| time.intersects(activity_ctx.route_start_time, &activity_ctx.time))
+                    .rfind(|time| time.intersects(activity_ctx.offset_anchor, &activity_ctx.time))
                     .unwrap();
 
                 let time = match time {
@@ -0,0 +1,67 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/break_schedule_test.rs"]
+mod break_schedule_test;
+
+use super::*;
+use serde::{Deserialize, Serialize};
+use vrp_core::prelude::Float;
+
+/// Specifies a break entry of the tour's break schedule.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakScheduleEntry {
+    /// Break start time specified in RFC3339 format.
+    pub start: String,
+    /// Break end time specified in RFC3339 format.
+    pub end: String,
+    /// Break duration.
+    pub duration: Float,
+    /// Index of the stop in the tour where break is taken.
+    pub stop_index: usize,
+}
+
+/// Specifies break schedule of the tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourBreakSchedule {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Breaks in the order they are taken.
+    pub breaks: Vec<BreakScheduleEntry>,
+}
+
+/// Creates break schedules of all tours in the solution, e.g. to show them in driver-facing apps.
+pub fn create_break_schedules(solution: &ApiSolution) -> Vec<TourBreakSchedule> {
+    solution
+        .tours
+        .iter()
+        .map(|tour| TourBreakSchedule {
+            vehicle_id: tour.vehicle_id.clone(),
+            shift_index: tour.shift_index,
+            breaks: get_break_schedule(tour),
+        })
+        .collect()
+}
+
+/// Returns break schedule of the tour.
+pub fn get_break_schedule(tour: &Tour) -> Vec<BreakScheduleEntry> {
+    tour.stops
+        .iter()
+        .enumerate()
+        .flat_map(|(stop_index, stop)| {
+            stop.activities().iter().filter(|activity| activity.activity_type == "break").map(move |activity| {
+                // NOTE time is omitted when activity is the only one at the stop
+                let (start, end) = activity
+                    .time
+                    .as_ref()
+                    .map(|time| (time.start.clone(), time.end.clone()))
+                    .unwrap_or_else(|| (stop.schedule().arrival.clone(), stop.schedule().departure.clone()));
+                let duration = parse_time(&end) - parse_time(&start);
+
+                BreakScheduleEntry { start, end, duration, stop_index }
+            })
+        })
+        .collect()
+}
@@ -1,15 +1,55 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/break_writer_test.rs"]
+mod break_writer_test;
+
 use super::*;
 use std::cmp::Ordering;
-use vrp_core::construction::enablers::{ReservedTimesIndex, get_offset_anchor};
+use std::sync::Arc;
+use vrp_core::construction::enablers::{
+    JobReservedTimesIndex, ReservedTimesIndex, get_offset_anchor, get_reserved_time_within_shift,
+    get_route_reserved_time_spans, has_enough_work_after_reserved_time,
+};
 use vrp_core::models::common::{Cost, TimeWindow};
+use vrp_core::models::problem::Costs;
 use vrp_core::models::solution::Route;
 use vrp_core::prelude::Float;
 
-/// Converts reserved time duration applied to activity or travel time to break activity.
+/// Specifies a reason why a required break is skipped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RequiredBreakSkipReason {
+    /// Break ends before the tour start.
+    BeforeTourStart,
+    /// Break starts after the tour end or leaves less work time after it than required.
+    AfterTourEnd,
+    /// Break is anchored to a job which is not served in the tour.
+    JobNotServed,
+    /// Break does not fit into the vehicle's shift time.
+    OutsideShift,
+}
+
+/// Keeps information about a required break which is skipped in the tour.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequiredBreakSkip {
+    /// An id of a vehicle break belongs to.
+    pub vehicle_id: String,
+    /// Index of the shift.
+    pub shift_index: usize,
+    /// A reason why the break is skipped.
+    pub reason: RequiredBreakSkipReason,
+}
+
+/// A callback invoked when a required break is skipped. Can be registered within problem's extras
+/// using `RequiredBreakSkipHandlerExtraProperty`.
+pub type RequiredBreakSkipFn = Arc<dyn Fn(&RequiredBreakSkip) + Send + Sync>;
+
+/// Converts reserved time duration applied to activity or travel time to break activity. When sources
+/// index is passed, break activity keeps an id of the break definition its reserved time comes from.
 pub(super) fn insert_reserved_times_as_breaks(
     route: &Route,
     tour: &mut Tour,
     reserved_times_index: &ReservedTimesIndex,
+    reserved_time_sources: Option<&ReservedTimeSourcesIndex>,
+    skip_handler: Option<&RequiredBreakSkipFn>,
 ) {
     let shift_time = route
         .tour
@@ -19,94 +59,285 @@ pub(super) fn insert_reserved_times_as_breaks(
         .expect("empty tour");
 
     let offset_anchor = get_offset_anchor(route);
+    let source_ids = reserved_time_sources.and_then(|sources| sources.get(&route.actor));
 
-    reserved_times_index
+    let mut reserved_times = reserved_times_index
         .get(&route.actor)
         .iter()
-        .flat_map(|times| times.iter())
-        .map(|reserved_time| reserved_time.to_reserved_time_window(offset_anchor))
-        .map(|rt| (TimeWindow::new(rt.time.end, rt.time.end + rt.duration), rt))
-        .filter(|(reserved_tw, _)| shift_time.intersects(reserved_tw))
-        .for_each(|(reserved_tw, reserved_time)| {
-            // NOTE scan and insert a new stop if necessary
-            let break_info = tour.stops.windows(2).enumerate().find_map(|(leg_idx, stops)| {
-                if let &[prev, next] = &stops {
-                    let travel_tw =
-                        TimeWindow::new(parse_time(&prev.schedule().departure), parse_time(&next.schedule().arrival));
-
-                    if travel_tw.intersects_exclusive(&reserved_tw) {
-                        // NOTE: should be moved to the last activity on previous stop by post-processing
-                        return if reserved_tw.start < travel_tw.start {
-                            let break_tw = TimeWindow::new(travel_tw.start - reserved_tw.duration(), travel_tw.start);
-                            Some(BreakInsertion::TransitBreakMoved { leg_idx, break_tw })
-                        } else {
-                            Some(BreakInsertion::TransitBreakUsed { leg_idx, load: prev.load().clone() })
-                        };
-                    }
-                }
+        .flat_map(|times| times.iter().cloned().enumerate())
+        .map(|(idx, reserved_time)| {
+            let source_id = source_ids.and_then(|ids| ids.get(idx)).cloned().flatten();
+            match get_reserved_time_within_shift(&route.actor, &reserved_time) {
+                Some(reserved_time) => (reserved_time.to_reserved_time_window(offset_anchor), true, source_id),
+                None => (reserved_time.to_reserved_time_window(offset_anchor), false, source_id),
+            }
+        })
+        // NOTE route dependent reserved times are resolved relative to the route as offset ones, so their
+        //      range can start before the tour start (e.g. negative earliest offset) and still be placed
+        .chain(
+            get_route_reserved_time_spans(route)
+                .map(|reserved_time| (reserved_time.to_reserved_time_window(offset_anchor), true, None)),
+        )
+        .map(|(rt, is_within_shift, source_id)| {
+            (TimeWindow::new(rt.time.end, rt.time.end + rt.duration), rt, is_within_shift, source_id)
+        })
+        .collect::<Vec<_>>();
 
-                None
-            });
+    // NOTE process breaks in time order as offset and exact breaks are resolved differently and
+    //      stops are searched using a cursor
+    reserved_times.sort_by(|(a, ..), (b, ..)| a.start.total_cmp(&b.start));
 
-            if let Some(BreakInsertion::TransitBreakUsed { leg_idx, load }) = break_info.clone() {
-                tour.stops.insert(
-                    leg_idx + 1,
-                    Stop::Transit(TransitStop {
-                        time: ApiSchedule {
-                            arrival: format_time(reserved_tw.start),
-                            departure: format_time(reserved_tw.end),
-                        },
-                        load,
-                        activities: vec![],
-                    }),
-                )
+    let costs = &route.actor.vehicle.costs;
+    let waiting_tws = get_waiting_tws(route);
+    let mut cursor = BreakCursor::default();
+
+    for (reserved_tw, reserved_time, is_within_shift, source_id) in reserved_times {
+        let reason = if !is_within_shift {
+            Some(RequiredBreakSkipReason::OutsideShift)
+        } else if reserved_tw.start > shift_time.end || !has_enough_work_after_reserved_time(route, &reserved_time) {
+            Some(RequiredBreakSkipReason::AfterTourEnd)
+        } else if reserved_tw.end < shift_time.start {
+            Some(RequiredBreakSkipReason::BeforeTourStart)
+        } else {
+            None
+        };
+
+        if let Some(reason) = reason {
+            if let Some(skip_handler) = skip_handler {
+                (skip_handler)(&RequiredBreakSkip {
+                    vehicle_id: tour.vehicle_id.clone(),
+                    shift_index: tour.shift_index,
+                    reason,
+                });
+            }
+            continue;
+        }
+
+        insert_reserved_time_as_break(
+            tour,
+            (&reserved_tw, reserved_time.duration, source_id),
+            (costs, &waiting_tws),
+            &mut cursor,
+        );
+    }
+}
+
+/// Keeps position in the tour's stops from which search for the next reserved time starts.
+/// As reserved times are processed in time order, stops which are departed before the reserved
+/// time starts, cannot be used by it or any of the later reserved times. Also, as stops are ordered
+/// by time, the search ends at the first stop which is reached after the reserved time ends.
+#[derive(Default)]
+struct BreakCursor {
+    stop_idx: usize,
+}
+
+/// Converts a single reserved time to break activity.
+fn insert_reserved_time_as_break(
+    tour: &mut Tour,
+    reserved_data: (&TimeWindow, Float, Option<String>),
+    cost_data: (&Costs, &[TimeWindow]),
+    cursor: &mut BreakCursor,
+) {
+    let (reserved_tw, duration, source_id) = reserved_data;
+
+    while tour
+        .stops
+        .get(cursor.stop_idx)
+        .is_some_and(|stop| parse_time(&stop.schedule().departure) <= reserved_tw.start)
+    {
+        cursor.stop_idx += 1;
+    }
+
+    // NOTE scan and insert a new stop if necessary
+    let first_leg_idx = cursor.stop_idx.saturating_sub(1);
+    let break_info = tour
+        .stops
+        .windows(2)
+        .enumerate()
+        .skip(first_leg_idx)
+        .take_while(|(_, stops)| parse_time(&stops[0].schedule().departure) < reserved_tw.end)
+        .find_map(|(leg_idx, stops)| {
+            if let &[prev, next] = &stops {
+                let travel_tw =
+                    TimeWindow::new(parse_time(&prev.schedule().departure), parse_time(&next.schedule().arrival));
+
+                if travel_tw.intersects_exclusive(reserved_tw) {
+                    // NOTE: should be moved to the last activity on previous stop by post-processing
+                    return if reserved_tw.start < travel_tw.start {
+                        let break_tw = TimeWindow::new(travel_tw.start - reserved_tw.duration(), travel_tw.start);
+                        Some(BreakInsertion::TransitBreakMoved { leg_idx, break_tw })
+                    } else {
+                        Some(BreakInsertion::TransitBreakUsed { leg_idx, load: prev.load().clone() })
+                    };
+                }
             }
 
-            let break_time = reserved_time.duration as i64;
-            let break_cost = break_time as Float * route.actor.vehicle.costs.per_service_time;
+            None
+        });
+
+    if let Some(BreakInsertion::TransitBreakUsed { leg_idx, load }) = break_info.clone() {
+        tour.stops.insert(
+            leg_idx + 1,
+            Stop::Transit(TransitStop {
+                time: ApiSchedule { arrival: format_time(reserved_tw.start), departure: format_time(reserved_tw.end) },
+                load,
+                activities: vec![],
+            }),
+        )
+    }
+
+    let break_time = duration as i64;
+    let break_cost = break_time as Float * cost_data.0.per_service_time;
 
-            if let Some(BreakInsertion::TransitBreakMoved { leg_idx, .. }) = &break_info {
-                // NOTE: when break was moved to the previous stop, its time window may not
-                // intersect the original reserved_tw (especially with wide offset ranges).
-                // Directly use the stop at leg_idx instead of searching by reserved_tw.
-                let stop = &mut tour.stops[*leg_idx];
-                let stop_tw =
-                    TimeWindow::new(parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure));
+    if let Some(BreakInsertion::TransitBreakMoved { leg_idx, .. }) = &break_info {
+        // NOTE: when break was moved to the previous stop, its time window may not
+        // intersect the original reserved_tw (especially with wide offset ranges).
+        // Directly use the stop at leg_idx instead of searching by reserved_tw.
+        let stop = &mut tour.stops[*leg_idx];
+        let stop_tw = TimeWindow::new(parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure));
+        insert_break(
+            (stop, stop_tw, *leg_idx),
+            (break_time, break_cost, break_info.clone(), source_id.clone()),
+            reserved_tw,
+            cost_data,
+            &mut tour.statistic,
+        );
+    } else {
+        for (stop_idx, stop) in tour
+            .stops
+            .iter_mut()
+            .enumerate()
+            .skip(cursor.stop_idx)
+            .take_while(|(_, stop)| parse_time(&stop.schedule().arrival) < reserved_tw.end)
+        {
+            let stop_tw = TimeWindow::new(parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure));
+
+            if stop_tw.intersects_exclusive(reserved_tw) {
                 insert_break(
-                    (stop, stop_tw, *leg_idx),
-                    (break_time, break_cost, break_info.clone()),
-                    &reserved_tw,
+                    (stop, stop_tw, stop_idx),
+                    (break_time, break_cost, break_info.clone(), source_id.clone()),
+                    reserved_tw,
+                    cost_data,
                     &mut tour.statistic,
-                );
-            } else {
-                for (stop_idx, stop) in tour.stops.iter_mut().enumerate() {
-                    let stop_tw =
-                        TimeWindow::new(parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure));
-
-                    if stop_tw.intersects_exclusive(&reserved_tw) {
-                        insert_break(
-                            (stop, stop_tw, stop_idx),
-                            (break_time, break_cost, break_info.clone()),
-                            &reserved_tw,
-                            &mut tour.statistic,
-                        )
-                    }
-                }
+                )
             }
+        }
+    }
+
+    tour.statistic.times.break_time += break_time;
+}
+
+/// Converts reserved time taken right after specific jobs to break activities.
+pub(super) fn insert_job_reserved_times_as_breaks(
+    route: &Route,
+    tour: &mut Tour,
+    job_reserved_times_index: &JobReservedTimesIndex,
+    skip_handler: Option<&RequiredBreakSkipFn>,
+) {
+    job_reserved_times_index.get(&route.actor).iter().flat_map(|times| times.iter()).for_each(|reserved_time| {
+        // NOTE job activity is served only at point stop, a transit stop keeps breaks only
+        let position = tour
+            .stops
+            .iter_mut()
+            .filter_map(|stop| match stop {
+                Stop::Point(stop) => Some(stop),
+                Stop::Transit(_) => None,
+            })
+            .find_map(|stop| {
+                stop.activities
+                    .iter()
+                    .position(|activity| activity.job_id == reserved_time.job_id && activity.activity_type != "break")
+                    .map(|activity_idx| (stop, activity_idx))
+            });
+
+        let Some((stop, activity_idx)) = position else {
+            if let Some(skip_handler) = skip_handler {
+                (skip_handler)(&RequiredBreakSkip {
+                    vehicle_id: tour.vehicle_id.clone(),
+                    shift_index: tour.shift_index,
+                    reason: RequiredBreakSkipReason::JobNotServed,
+                });
+            }
+            return;
+        };
+
+        // NOTE reserved time is added to activity's departure, so the job activity time ends before it
+        let start = stop.activities[activity_idx]
+            .time
+            .as_ref()
+            .map_or_else(|| parse_time(&stop.time.departure) - reserved_time.duration, |time| parse_time(&time.end));
 
-            tour.statistic.times.break_time += break_time;
+        let break_time = reserved_time.duration as i64;
+        let break_cost = break_time as Float * route.actor.vehicle.costs.per_service_time;
+
+        stop.activities.insert(
+            activity_idx + 1,
+            ApiActivity {
+                job_id: "break".to_string(),
+                activity_type: "break".to_string(),
+                location: None,
+                time: Some(Interval { start: format_time(start), end: format_time(start + reserved_time.duration) }),
+                job_tag: None,
+                commute: None,
+                source_id: None,
+                break_attachment: None,
+            },
+        );
+
+        tour.statistic.cost += break_cost;
+        tour.statistic.times.break_time += break_time;
+    });
+}
+
+/// Sets attachment info to each break activity of the tour: whether it is taken at a separate transit
+/// stop or shares a point stop with a job.
+pub(super) fn set_break_attachments(tour: &mut Tour) {
+    tour.stops.iter_mut().enumerate().for_each(|(stop_index, stop)| {
+        let is_transit = matches!(stop, Stop::Transit(_));
+        let is_job = |activity: &&Activity| activity.activity_type != "break";
+
+        let attachments = stop
+            .activities()
+            .iter()
+            .enumerate()
+            .map(|(idx, activity)| {
+                if activity.activity_type != "break" {
+                    None
+                } else if is_transit {
+                    Some(BreakAttachment::Transit { stop_index })
+                } else {
+                    let activities = stop.activities();
+                    let job_id = activities[..idx]
+                        .iter()
+                        .rev()
+                        .find(is_job)
+                        .or_else(|| activities[idx + 1..].iter().find(is_job))
+                        .map(|activity| activity.job_id.clone());
+
+                    Some(BreakAttachment::Point { stop_index, job_id })
+                }
+            })
+            .collect::<Vec<_>>();
+
+        stop.activities_mut().iter_mut().zip(attachments).for_each(|(activity, attachment)| {
+            if attachment.is_some() {
+                activity.break_attachment = attachment;
+            }
         });
+    });
 }
 
 /// Inserts a break activity into the tour and updates schedules and statistics.
 fn insert_break(
     stop_data: (&mut Stop, TimeWindow, usize),
-    break_data: (i64, Cost, Option<BreakInsertion>),
+    break_data: (i64, Cost, Option<BreakInsertion>, Option<String>),
     reserved_tw: &TimeWindow,
+    cost_data: (&Costs, &[TimeWindow]),
     statistic: &mut Statistic,
 ) {
     let (stop, stop_tw, stop_idx) = stop_data;
-    let (break_time, break_cost, break_insertion) = break_data;
+    let (costs, waiting_tws) = cost_data;
+    let (break_time, break_cost, break_insertion, source_id) = break_data;
     let break_idx = stop
         .activities()
         .iter()
@@ -141,7 +372,10 @@ fn insert_break(
 
     let activities = match stop {
         Stop::Point(point) => {
-            statistic.cost += break_cost;
+            // NOTE break taken during waiting consumes waiting time instead of adding it on top
+            let waiting_overlap = get_waiting_overlap(waiting_tws, &activity_time);
+            statistic.cost += break_cost - waiting_overlap * costs.per_waiting_time;
+            statistic.times.waiting -= waiting_overlap as i64;
             &mut point.activities
         }
         Stop::Transit(transit) => {
@@ -159,6 +393,8 @@ fn insert_break(
             time: Some(Interval { start: format_time(activity_time.start), end: format_time(activity_time.end) }),
             job_tag: None,
             commute: None,
+            source_id,
+            break_attachment: None,
         },
     );
 
@@ -183,6 +419,21 @@ fn insert_break(
     })
 }
 
+/// Returns waiting time intervals of the route: from the arrival to the activity's time window start.
+fn get_waiting_tws(route: &Route) -> Vec<TimeWindow> {
+    route
+        .tour
+        .all_activities()
+        .filter(|activity| activity.schedule.arrival < activity.place.time.start)
+        .map(|activity| TimeWindow::new(activity.schedule.arrival, activity.place.time.start))
+        .collect()
+}
+
+/// Returns a duration of the waiting time which overlaps with the break time.
+fn get_waiting_overlap(waiting_tws: &[TimeWindow], break_tw: &TimeWindow) -> Float {
+    waiting_tws.iter().filter_map(|waiting_tw| waiting_tw.overlapping(break_tw)).map(|tw| tw.duration()).sum()
+}
+
 fn align_break_to_activity_boundary(
     activities: &[ApiActivity],
     break_idx: usize,
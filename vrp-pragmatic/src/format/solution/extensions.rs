@@ -1,4 +1,4 @@
-use crate::format::solution::{Statistic, Timing};
+use crate::format::solution::{ScaledStatistic, Statistic, Timing};
 use std::ops::Add;
 
 impl Add for Statistic {
@@ -17,6 +17,24 @@ impl Add for Statistic {
                 commuting: self.times.commuting + rhs.times.commuting,
                 parking: self.times.parking + rhs.times.parking,
             },
+            emissions: match (self.emissions, rhs.emissions) {
+                (Some(lhs), Some(rhs)) => Some(lhs + rhs),
+                (lhs, rhs) => lhs.or(rhs),
+            },
+            // NOTE utilization is a per tour ratio which cannot be summed up
+            utilization: None,
+            scaled: match (self.scaled, rhs.scaled) {
+                (Some(lhs), Some(rhs)) => Some(lhs + rhs),
+                (lhs, rhs) => lhs.or(rhs),
+            },
         }
     }
 }
+
+impl Add for ScaledStatistic {
+    type Output = ScaledStatistic;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        ScaledStatistic { distance: self.distance + rhs.distance, duration: self.duration + rhs.duration }
+    }
+}
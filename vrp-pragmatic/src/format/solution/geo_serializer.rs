@@ -265,7 +265,7 @@ fn get_unassigned_points(
 }
 
 fn get_tour_line(tour_idx: usize, tour: &Tour, color: &str) -> Result<Feature, Error> {
-    let stops = tour.stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
+    let stops = tour.point_stops().collect::<Vec<_>>();
 
     let coordinates = stops.iter().map(|stop| get_lng_lat(&stop.location)).collect::<Result<_, Error>>()?;
 
@@ -5,7 +5,7 @@ mod initial_reader_test;
 use crate::format::solution::Activity as FormatActivity;
 use crate::format::solution::Stop as FormatStop;
 use crate::format::solution::Tour as FormatTour;
-use crate::format::solution::activity_matcher::{JobInfo, try_match_point_job};
+use crate::format::solution::activity_matcher::{JobInfo, get_offset_anchor, try_match_point_job};
 use crate::format::solution::{deserialize_solution, map_reason_code};
 use crate::format::{CoordIndex, JobIndex, ShiftIndexDimension, VehicleTypeDimension, get_indices};
 use crate::parse_time;
@@ -14,7 +14,9 @@ use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Actor, Job, JobIdDimension, VehicleIdDimension};
+use vrp_core::models::problem::{
+    Actor, Job, JobIdDimension, RouteCostSpan, RouteCostSpanDimension, VehicleIdDimension,
+};
 use vrp_core::models::solution::Tour as CoreTour;
 use vrp_core::models::solution::{Activity, Registry, Route};
 use vrp_core::prelude::*;
@@ -52,8 +54,7 @@ pub fn read_init_solution<R: Read>(
                         tour,
                         stop,
                         activity,
-                        job_index.as_ref(),
-                        coord_index.as_ref(),
+                        (job_index.as_ref(), coord_index.as_ref()),
                         &mut added_jobs,
                     )
                 })
@@ -103,8 +104,7 @@ fn try_insert_activity(
     tour: &FormatTour,
     stop: &FormatStop,
     activity: &FormatActivity,
-    job_index: &JobIndex,
-    coord_index: &CoordIndex,
+    indices: (&JobIndex, &CoordIndex),
     added_jobs: &mut HashSet<Job>,
 ) -> Result<(), GenericError> {
     if activity.commute.is_some() {
@@ -116,7 +116,12 @@ fn try_insert_activity(
         FormatStop::Point(stop) => stop,
     };
 
-    match try_match_point_job(tour, stop, activity, job_index, coord_index)? {
+    let cost_span = route.actor.vehicle.dimens.get_route_cost_span();
+    let is_first_job_anchored =
+        matches!(cost_span, Some(RouteCostSpan::FirstJobToDepot | RouteCostSpan::FirstJobToLastJob));
+    let offset_anchor = get_offset_anchor(tour, is_first_job_anchored)?;
+
+    match try_match_point_job(tour, stop, activity, indices, offset_anchor)? {
         Some(JobInfo(job, single, place, time)) => {
             let is_inserted = added_jobs.insert(job.clone());
             if !is_inserted && matches!(job, Job::Single(_)) {
@@ -2,8 +2,12 @@
 
 pub(crate) mod activity_matcher;
 
+mod break_schedule;
+pub use self::break_schedule::*;
+
 mod break_writer;
-use self::break_writer::insert_reserved_times_as_breaks;
+pub use self::break_writer::{RequiredBreakSkip, RequiredBreakSkipFn, RequiredBreakSkipReason};
+use self::break_writer::{insert_job_reserved_times_as_breaks, insert_reserved_times_as_breaks, set_break_attachments};
 
 mod extensions;
 
@@ -16,13 +20,27 @@ pub use self::initial_reader::read_init_solution;
 mod model;
 pub use self::model::*;
 
+mod schedule_repair;
+pub use self::schedule_repair::*;
+
+mod solution_diff;
+pub use self::solution_diff::*;
+
 mod solution_writer;
 pub(crate) use self::solution_writer::create_solution;
+use self::solution_writer::create_solution_with_reserved_times;
 
 use super::*;
 use crate::{format_time, parse_time};
 use std::io::{BufWriter, Write};
+use vrp_core::construction::enablers::{
+    DynamicActivityCost, DynamicTransportCost, ReservedTimeSpan, update_route_schedule,
+};
+use vrp_core::construction::heuristics::{RouteContext, RouteState};
+use vrp_core::models::common::TimeSpan;
+use vrp_core::models::problem::{ActivityCost, TransportCost};
 use vrp_core::prelude::GenericError;
+use vrp_core::solver::processing::{JobReservedTimesExtraProperty, ReservedTimesExtraProperty};
 
 type ApiActivity = model::Activity;
 type ApiSolution = model::Solution;
@@ -70,6 +88,105 @@ pub fn write_pragmatic<W: Write>(
     Ok(())
 }
 
+/// Re-derives required breaks of the solution from the given reserved times index without rerouting:
+/// job sequences are kept untouched, while route schedules are recalculated with the new reserved
+/// times, so the time reserved by previous breaks is released.
+pub fn reinsert_breaks(
+    problem: &DomainProblem,
+    solution: &DomainSolution,
+    reserved_times_index: &ReservedTimesIndex,
+) -> Result<ApiSolution, GenericError> {
+    let transport = problem.extras.get_base_transport_cost().ok_or("no base transport costs")?;
+    let job_reserved_times = problem.extras.get_job_reserved_times().map(|times| times.as_ref().clone());
+
+    let transport: Arc<dyn TransportCost> =
+        Arc::new(DynamicTransportCost::new(reserved_times_index.clone(), transport.as_ref().clone())?);
+    let activity: Arc<dyn ActivityCost> = Arc::new(
+        DynamicActivityCost::new(reserved_times_index.clone())?
+            .with_job_reserved_times(job_reserved_times.unwrap_or_default()),
+    );
+
+    let routes = solution
+        .routes
+        .iter()
+        .map(|route| {
+            let mut route_ctx = RouteContext::new_with_state(route.deep_copy(), RouteState::default());
+            update_route_schedule(&mut route_ctx, activity.as_ref(), transport.as_ref());
+
+            route_ctx.route().deep_copy()
+        })
+        .collect();
+
+    // NOTE break source ids are aligned with reserved times by index, so they are rebuilt for the new index
+    let mut extras = problem.extras.as_ref().clone();
+    extras.set_reserved_time_sources(Arc::new(get_reinserted_time_sources(problem, reserved_times_index)));
+
+    let problem = DomainProblem {
+        fleet: problem.fleet.clone(),
+        jobs: problem.jobs.clone(),
+        locks: problem.locks.clone(),
+        goal: problem.goal.clone(),
+        activity,
+        transport,
+        extras: Arc::new(extras),
+    };
+    let solution = DomainSolution {
+        cost: solution.cost,
+        registry: solution.registry.deep_copy(),
+        routes,
+        unassigned: solution.unassigned.clone(),
+        telemetry: None,
+    };
+
+    Ok(create_solution_with_reserved_times(
+        &problem,
+        &solution,
+        &PragmaticOutputType::OnlyPragmatic,
+        reserved_times_index,
+    ))
+}
+
+/// Returns source ids of the reinserted reserved times: a reserved time keeps the source id of the same
+/// reserved time in the original problem, otherwise it has no source id.
+fn get_reinserted_time_sources(
+    problem: &DomainProblem,
+    reserved_times_index: &ReservedTimesIndex,
+) -> ReservedTimeSourcesIndex {
+    let original_times = problem.extras.get_reserved_times();
+    let original_sources = problem.extras.get_reserved_time_sources();
+
+    let is_same_time = |lhs: &ReservedTimeSpan, rhs: &ReservedTimeSpan| {
+        lhs.duration == rhs.duration
+            && match (&lhs.time, &rhs.time) {
+                (TimeSpan::Window(lhs), TimeSpan::Window(rhs)) => lhs == rhs,
+                (TimeSpan::Offset(lhs), TimeSpan::Offset(rhs)) => lhs == rhs,
+                _ => false,
+            }
+    };
+
+    reserved_times_index
+        .iter()
+        .map(|(actor, times)| {
+            let originals = original_times
+                .as_ref()
+                .and_then(|index| index.get(actor))
+                .zip(original_sources.as_ref().and_then(|index| index.get(actor)));
+
+            let sources = times
+                .iter()
+                .map(|time| {
+                    let (original_times, original_sources) = originals?;
+                    let idx = original_times.iter().position(|original| is_same_time(original, time))?;
+
+                    original_sources.get(idx).cloned().flatten()
+                })
+                .collect();
+
+            (actor.clone(), sources)
+        })
+        .collect()
+}
+
 fn map_code_reason(code: ViolationCode) -> (&'static str, &'static str) {
     match code {
         SKILL_CONSTRAINT_CODE => ("SKILL_CONSTRAINT", "cannot serve required skill"),
@@ -104,6 +221,12 @@ fn map_code_reason(code: ViolationCode) -> (&'static str, &'static str) {
             ("MIN_TOUR_SIZE_CONSTRAINT", "cannot be assigned due to min tour size constraint of vehicle")
         }
         JOB_TIME_CONSTRAINT_CODE => ("JOB_TIME_CONSTRAINT", "cannot be assigned due to shift job time constraints"),
+        SERVICE_TIME_LIMIT_CONSTRAINT_CODE => {
+            ("MAX_SERVICE_TIME_CONSTRAINT", "cannot be assigned due to max service time constraint of vehicle")
+        }
+        STOP_LIMIT_CONSTRAINT_CODE => {
+            ("MAX_STOPS_CONSTRAINT", "cannot be assigned due to max stops constraint of vehicle")
+        }
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -128,6 +251,8 @@ fn map_reason_code(reason: &str) -> ViolationCode {
         "MIN_SHIFT_CONSTRAINT" => MIN_VEHICLE_SHIFTS_CONSTRAINT_CODE,
         "MIN_TOUR_SIZE_CONSTRAINT" => MIN_TOUR_SIZE_CONSTRAINT_CODE,
         "JOB_TIME_CONSTRAINT" => JOB_TIME_CONSTRAINT_CODE,
+        "MAX_SERVICE_TIME_CONSTRAINT" => SERVICE_TIME_LIMIT_CONSTRAINT_CODE,
+        "MAX_STOPS_CONSTRAINT" => STOP_LIMIT_CONSTRAINT_CODE,
         _ => ViolationCode::unknown(),
     }
 }
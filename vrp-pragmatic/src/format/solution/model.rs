@@ -1,3 +1,7 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/model_test.rs"]
+mod model_test;
+
 use super::FeatureCollection;
 use crate::format::{CoordIndex, Location};
 use crate::{format_time, parse_time};
@@ -39,6 +43,26 @@ pub struct Statistic {
     pub duration: i64,
     /// Timing statistic.
     pub times: Timing,
+    /// Total emissions. Present only when emissions model is defined for vehicle profiles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emissions: Option<Float>,
+    /// Ratio of billable duration to shift length. Present only for tours when requested via
+    /// `TourUtilizationOutputExtraProperty`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utilization: Option<Float>,
+    /// Distance and duration converted to the desired units. Present only when output scale is
+    /// requested via `DistanceOutputScaleExtraProperty` or `DurationOutputScaleExtraProperty`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scaled: Option<ScaledStatistic>,
+}
+
+/// Represents distance and duration statistic converted to the desired units.
+#[derive(Clone, Deserialize, Default, Serialize, PartialEq, Debug)]
+pub struct ScaledStatistic {
+    /// Total distance.
+    pub distance: Float,
+    /// Total duration.
+    pub duration: Float,
 }
 
 /// Represents a schedule.
@@ -102,6 +126,35 @@ pub struct Activity {
     /// Commute information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commute: Option<Commute>,
+    /// An id of the definition which produced the activity, e.g. a required break taken as reserved time.
+    /// Present only when requested via `BreakSourceOutputExtraProperty`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_id: Option<String>,
+    /// Specifies where a break activity is taken in the tour.
+    /// Present only when requested via `BreakAttachmentOutputExtraProperty`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub break_attachment: Option<BreakAttachment>,
+}
+
+/// Specifies where a break is taken in the tour.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum BreakAttachment {
+    /// A break is taken at a separate transit stop, e.g. during travel between two point stops.
+    #[serde(rename_all = "camelCase")]
+    Transit {
+        /// An index of the stop in the tour.
+        stop_index: usize,
+    },
+    /// A break shares a point stop with other activities.
+    #[serde(rename_all = "camelCase")]
+    Point {
+        /// An index of the stop in the tour.
+        stop_index: usize,
+        /// An id of the job served at the stop right before the break or, if none, right after it.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        job_id: Option<String>,
+    },
 }
 
 /// A stop is a place where vehicle is supposed to do some work.
@@ -213,10 +266,26 @@ pub struct PointStop {
     /// Parking time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parking: Option<Interval>,
+    /// Slack (free time) in seconds: how much arrival at the stop can be delayed without violating
+    /// the tour schedule. Reported only when requested via `StopSlackOutputExtraProperty`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack: Option<f64>,
+    /// Travel from the previous stop. Reported only when requested via `StopTravelOutputExtraProperty`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub travel: Option<LegTravel>,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
 }
 
+/// Travel details of the leg between two consecutive stops.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Eq, Debug)]
+pub struct LegTravel {
+    /// Travel duration in seconds.
+    pub duration: i64,
+    /// Travel distance in meters.
+    pub distance: i64,
+}
+
 /// A tour is list of stops with their activities performed by specific vehicle.
 #[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -232,6 +301,25 @@ pub struct Tour {
     pub stops: Vec<Stop>,
     /// Tour statistic.
     pub statistic: Statistic,
+    /// A factor which binds the tour's departure time, e.g. `first_job_window`. Present only when
+    /// requested via `DepartureBindingOutputExtraProperty`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub departure_binding: Option<String>,
+}
+
+impl Tour {
+    /// Returns a lazy iterator over point stops of the tour.
+    pub fn point_stops(&self) -> impl Iterator<Item = &PointStop> + '_ {
+        self.stops.iter().filter_map(|stop| stop.as_point())
+    }
+
+    /// Returns a lazy iterator over transit stops of the tour.
+    pub fn transit_stops(&self) -> impl Iterator<Item = &TransitStop> + '_ {
+        self.stops.iter().filter_map(|stop| match stop {
+            Stop::Transit(transit) => Some(transit),
+            Stop::Point(_) => None,
+        })
+    }
 }
 
 /// Unassigned job reason.
@@ -278,6 +366,9 @@ pub enum Violation {
         vehicle_id: String,
         /// Index of the shift.
         shift_index: usize,
+        /// A reason of the violation, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
     },
 }
 
@@ -0,0 +1,120 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/schedule_repair_test.rs"]
+mod schedule_repair_test;
+
+use super::*;
+use crate::format::CoordIndex;
+use crate::format::problem::Matrix;
+use vrp_core::models::common::Timestamp;
+use vrp_core::prelude::Float;
+
+/// Rechecks the tour schedule after manual edits, e.g. in interactive editors, and repairs it in place.
+/// Stop arrivals and distances are recomputed from the previous stop departure and the routing matrix,
+/// while time spent at each stop is kept. Transit stops keep their position on the leg. Inconsistencies
+/// which cannot be repaired, such as unknown locations or departures before arrivals, are reported
+/// and the tour is left untouched.
+pub fn recheck_and_repair_schedule(
+    tour: &mut Tour,
+    matrix: &Matrix,
+    coord_index: &CoordIndex,
+) -> Result<(), Vec<String>> {
+    let matrix_size = (matrix.travel_times.len() as Float).sqrt().round() as usize;
+    let get_location_idx = |location: &Location| {
+        coord_index.get_by_loc(location).filter(|&idx| idx < matrix_size || coord_index.is_special_index(idx))
+    };
+
+    let errors = tour
+        .stops
+        .iter()
+        .enumerate()
+        .flat_map(|(stop_idx, stop)| {
+            let schedule = stop.schedule();
+            let departure_error = (parse_time(&schedule.departure) < parse_time(&schedule.arrival)).then(|| {
+                format!(
+                    "departure '{}' is before arrival '{}' at stop {stop_idx} in tour '{}'",
+                    schedule.departure, schedule.arrival, tour.vehicle_id
+                )
+            });
+            let location_error =
+                stop.location().filter(|location| get_location_idx(location).is_none()).map(|location| {
+                    format!(
+                        "cannot find location '{location:?}' of stop {stop_idx} in routing matrix, tour '{}'",
+                        tour.vehicle_id
+                    )
+                });
+
+            departure_error.into_iter().chain(location_error)
+        })
+        .collect::<Vec<_>>();
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let get_travel = |from: usize, to: usize| {
+        if coord_index.is_special_index(from) || coord_index.is_special_index(to) {
+            (0, 0.)
+        } else {
+            let idx = from * matrix_size + to;
+            (matrix.distances[idx], matrix.travel_times[idx] as Float)
+        }
+    };
+
+    // NOTE keeps (location index, departure, distance) of the last point stop and departures of the last stop
+    let mut last_point: Option<(usize, Timestamp, i64)> = None;
+    let mut last_departures: Option<(Timestamp, Timestamp)> = None;
+    let mut transit_time = 0.;
+
+    tour.stops.iter_mut().for_each(|stop| {
+        let (arrival, departure) = (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure));
+
+        let new_arrival = match (&mut *stop, last_point, last_departures) {
+            (Stop::Point(point), Some((from_idx, point_departure, point_distance)), _) => {
+                let to_idx = get_location_idx(&point.location).expect("location is checked");
+                let (distance, duration) = get_travel(from_idx, to_idx);
+                point.distance = point_distance + distance;
+
+                point_departure + duration + transit_time
+            }
+            (Stop::Transit(_), _, Some((old_departure, new_departure))) => {
+                transit_time += departure - arrival;
+                new_departure + arrival - old_departure
+            }
+            _ => arrival,
+        };
+        let new_departure = new_arrival + departure - arrival;
+
+        shift_stop_time(stop, new_arrival - arrival);
+
+        if let Stop::Point(point) = stop {
+            let location_idx = get_location_idx(&point.location).expect("location is checked");
+            last_point = Some((location_idx, new_departure, point.distance));
+            transit_time = 0.;
+        }
+        last_departures = Some((departure, new_departure));
+    });
+
+    Ok(())
+}
+
+fn shift_stop_time(stop: &mut Stop, delta: Float) {
+    if delta == 0. {
+        return;
+    }
+
+    let shift = |time: &mut String| *time = format_time(parse_time(time) + delta);
+
+    let schedule = stop.schedule_mut();
+    shift(&mut schedule.arrival);
+    shift(&mut schedule.departure);
+
+    if let Stop::Point(PointStop { parking: Some(parking), .. }) = stop {
+        shift(&mut parking.start);
+        shift(&mut parking.end);
+    }
+
+    stop.activities_mut().iter_mut().filter_map(|activity| activity.time.as_mut()).for_each(|time| {
+        shift(&mut time.start);
+        shift(&mut time.end);
+    });
+}
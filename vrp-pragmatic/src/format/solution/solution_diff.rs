@@ -0,0 +1,119 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/solution_diff_test.rs"]
+mod solution_diff_test;
+
+use super::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use vrp_core::prelude::Float;
+
+/// Specifies a change of the break time between two solutions.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakTimeDelta {
+    /// Index of the break in the tour's break schedule.
+    pub break_index: usize,
+    /// Break start time in the old solution, if break was taken there.
+    pub old_start: Option<String>,
+    /// Break start time in the new solution, if break is taken there.
+    pub new_start: Option<String>,
+    /// Difference between new and old break start times in seconds, if break is taken in both.
+    pub delta: Option<Float>,
+}
+
+/// Specifies changes of the tour between two solutions.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TourDiff {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Shift index.
+    pub shift_index: usize,
+    /// Ids of jobs which are served by the tour only in the new solution.
+    pub added_jobs: Vec<String>,
+    /// Ids of jobs which are served by the tour only in the old solution.
+    pub removed_jobs: Vec<String>,
+    /// Breaks which are taken at a different time.
+    pub breaks: Vec<BreakTimeDelta>,
+}
+
+/// Specifies changes between two solutions.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionDiff {
+    /// Changed tours ordered by vehicle id and shift index.
+    pub tours: Vec<TourDiff>,
+}
+
+/// Compares two solutions, e.g. to show what has changed after incremental re-optimization.
+/// Tours are matched by vehicle id and shift index, the unchanged ones are omitted.
+pub fn solution_diff(old: &ApiSolution, new: &ApiSolution) -> SolutionDiff {
+    let (old_tours, new_tours) = (get_tours(old), get_tours(new));
+
+    let tours = old_tours
+        .keys()
+        .chain(new_tours.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .filter_map(|key| {
+            let (old_tour, new_tour) = (old_tours.get(key).copied(), new_tours.get(key).copied());
+            let (old_jobs, new_jobs) = (get_job_ids(old_tour), get_job_ids(new_tour));
+
+            let diff = TourDiff {
+                vehicle_id: key.0.clone(),
+                shift_index: key.1,
+                added_jobs: new_jobs.difference(&old_jobs).cloned().collect(),
+                removed_jobs: old_jobs.difference(&new_jobs).cloned().collect(),
+                breaks: get_break_deltas(old_tour, new_tour),
+            };
+
+            let is_changed = !diff.added_jobs.is_empty() || !diff.removed_jobs.is_empty() || !diff.breaks.is_empty();
+
+            is_changed.then_some(diff)
+        })
+        .collect();
+
+    SolutionDiff { tours }
+}
+
+fn get_tours(solution: &ApiSolution) -> BTreeMap<(String, usize), &Tour> {
+    solution.tours.iter().map(|tour| ((tour.vehicle_id.clone(), tour.shift_index), tour)).collect()
+}
+
+fn get_job_ids(tour: Option<&Tour>) -> BTreeSet<String> {
+    tour.iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| {
+            !matches!(
+                activity.activity_type.as_str(),
+                "departure" | "arrival" | "break" | "reload" | "recharge" | "dispatch"
+            )
+        })
+        .map(|activity| activity.job_id.clone())
+        .collect()
+}
+
+fn get_break_deltas(old_tour: Option<&Tour>, new_tour: Option<&Tour>) -> Vec<BreakTimeDelta> {
+    let old_breaks = old_tour.map(get_break_schedule).unwrap_or_default();
+    let new_breaks = new_tour.map(get_break_schedule).unwrap_or_default();
+
+    (0..old_breaks.len().max(new_breaks.len()))
+        .filter_map(|break_index| {
+            let old_start = old_breaks.get(break_index).map(|entry| entry.start.clone());
+            let new_start = new_breaks.get(break_index).map(|entry| entry.start.clone());
+
+            match (&old_start, &new_start) {
+                (Some(old_time), Some(new_time)) if old_time == new_time => None,
+                _ => {
+                    let delta = old_start
+                        .as_ref()
+                        .zip(new_start.as_ref())
+                        .map(|(old_time, new_time)| parse_time(new_time) - parse_time(old_time));
+
+                    Some(BreakTimeDelta { break_index, old_start, new_start, delta })
+                }
+            }
+        })
+        .collect()
+}
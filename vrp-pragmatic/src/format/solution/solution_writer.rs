@@ -6,15 +6,20 @@ use crate::format::CoordIndex;
 use crate::format::solution::activity_matcher::get_job_tag;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
-use vrp_core::construction::enablers::{ReservedTimesIndex, get_route_intervals};
-use vrp_core::construction::features::JobDemandDimension;
-use vrp_core::construction::heuristics::UnassignmentInfo;
+use vrp_core::construction::enablers::{
+    ReservedTimesIndex, get_activity_slacks, get_departure_binding, get_leg_travels, get_parallel_service_start,
+    get_route_emissions, get_route_intervals, get_route_utilization, get_stop_dwell_departure,
+};
+use vrp_core::construction::features::{JobDemandDimension, VehicleCapacityDimension, get_first_job_service_arrival};
+use vrp_core::construction::heuristics::{RouteContext, RouteState, UnassignmentInfo};
 use vrp_core::models::common::*;
 use vrp_core::models::problem::{JobIdDimension, Multi, TravelTime, VehicleIdDimension};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::prelude::Float;
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
-use vrp_core::solver::processing::{ClusterConfigExtraProperty, ReservedTimesExtraProperty};
+use vrp_core::solver::processing::{
+    ClusterConfigExtraProperty, JobReservedTimesExtraProperty, ReservedTimesExtraProperty,
+};
 use vrp_core::utils::CollectGroupBy;
 
 struct Leg {
@@ -39,16 +44,29 @@ pub(crate) fn create_solution(
     solution: &DomainSolution,
     output_type: &PragmaticOutputType,
 ) -> ApiSolution {
-    let coord_index = problem.extras.get_coord_index().expect("no coord index");
-
     let empty_reserved_times = Default::default();
     let reserved_times_index = problem.extras.get_reserved_times();
     let reserved_times_index = reserved_times_index.as_ref().unwrap_or(&empty_reserved_times);
 
+    create_solution_with_reserved_times(problem, solution, output_type, reserved_times_index)
+}
+
+/// Creates solution with required breaks derived from the given reserved times index.
+pub(crate) fn create_solution_with_reserved_times(
+    problem: &DomainProblem,
+    solution: &DomainSolution,
+    output_type: &PragmaticOutputType,
+    reserved_times_index: &ReservedTimesIndex,
+) -> ApiSolution {
+    let coord_index = problem.extras.get_coord_index().expect("no coord index");
+    let distance_scale = problem.extras.get_distance_output_scale().map_or(1., |scale| *scale);
+    let duration_scale = problem.extras.get_duration_output_scale().map_or(1., |scale| *scale);
+
     let tours = solution
         .routes
         .iter()
         .map(|r| create_tour(problem, r, &coord_index, reserved_times_index))
+        .map(|tour| scale_tour_units(tour, distance_scale, duration_scale))
         .collect::<Vec<Tour>>();
 
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
@@ -63,6 +81,21 @@ pub(crate) fn create_solution(
     ApiSolution { extras, ..api_solution }
 }
 
+/// Adds distance and duration of the tour converted to the desired units. Original values are kept
+/// in routing matrix units as they are consistent with stop schedules.
+fn scale_tour_units(mut tour: Tour, distance_scale: Float, duration_scale: Float) -> Tour {
+    if distance_scale == 1. && duration_scale == 1. {
+        return tour;
+    }
+
+    tour.statistic.scaled = Some(ScaledStatistic {
+        distance: tour.statistic.distance as Float * distance_scale,
+        duration: tour.statistic.duration as Float * duration_scale,
+    });
+
+    tour
+}
+
 fn create_tour(
     problem: &DomainProblem,
     route: &Route,
@@ -82,8 +115,26 @@ fn create_tour(
         shift_index: vehicle.dimens.get_shift_index().copied().unwrap(),
         stops: vec![],
         statistic: Statistic::default(),
+        departure_binding: problem
+            .extras
+            .get_departure_binding_output()
+            .is_some_and(|is_enabled| *is_enabled)
+            .then(|| get_route_departure_binding(problem, route))
+            .flatten(),
     };
 
+    let slacks = problem
+        .extras
+        .get_stop_slack_output()
+        .is_some_and(|is_enabled| *is_enabled)
+        .then(|| get_activity_slacks(route, problem.activity.as_ref(), transport));
+
+    let travels = problem
+        .extras
+        .get_stop_travel_output()
+        .is_some_and(|is_enabled| *is_enabled)
+        .then(|| get_leg_travels(route, transport));
+
     let intervals = get_route_intervals(route, |a| get_activity_type(a).is_some_and(|t| t == "reload"));
 
     let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
@@ -123,17 +174,21 @@ fn create_tour(
                     },
                     job_tag: None,
                     commute: None,
+                    source_id: None,
+                    break_attachment: None,
                 }],
                 parking: None,
+                slack: None,
+                travel: None,
             }));
             (start_idx + 1, start)
         } else {
             (start_idx, route.tour.get(start_idx - 1).unwrap())
         };
 
-        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().fold(
+        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().enumerate().fold(
             Leg::new(Some((start.place.location, start.schedule.departure)), Some(start_delivery), leg.statistic),
-            |leg, act| {
+            |leg, (offset, act)| {
                 let activity_type = get_activity_type(act).cloned();
                 let (prev_location, prev_departure) = leg.last_detail.unwrap();
                 let prev_load = if activity_type.is_some() {
@@ -182,11 +237,26 @@ fn create_tour(
                     };
 
                 let activity_arrival = parking + act.schedule.arrival + commute.forward.duration;
-                let service_start = activity_arrival.max(act.place.time.start);
-                let waiting = service_start - activity_arrival;
+                // NOTE service of the first job can be delayed by vehicle's job time limits
+                let activity_arrival = if start_idx + offset == 1 && act.job.is_some() {
+                    get_first_job_service_arrival(route, activity_arrival)
+                } else {
+                    activity_arrival
+                };
+                // NOTE service continuing a parallel service group starts together with the group, so only
+                // its part after the previous activity is reported as serving time
+                let service_start = get_parallel_service_start(route, start_idx + offset - 1, act)
+                    .unwrap_or(activity_arrival)
+                    .max(act.place.time.start);
                 let serving = act.place.duration - parking;
                 let service_end = service_start + serving;
-                let activity_departure = service_end;
+                let activity_departure = get_stop_dwell_departure(route, start_idx + offset)
+                    .map_or(service_end, |dwell_departure| service_end.max(dwell_departure))
+                    .max(activity_arrival);
+                let serving = service_end.max(activity_arrival) - service_start.max(activity_arrival);
+                // NOTE time spent at the stop only to satisfy minimum dwell is reported as waiting
+                let waiting =
+                    (service_start - activity_arrival).max(0.) + activity_departure - service_end.max(activity_arrival);
 
                 // TODO: add better support of time based activity costs
                 let serving_cost = problem.activity.cost(route, act, service_start);
@@ -217,6 +287,11 @@ fn create_tour(
                         } else {
                             None
                         },
+                        slack: None,
+                        travel: travels.as_ref().map(|travels| {
+                            let (duration, distance) = travels[start_idx + offset];
+                            LegTravel { duration: duration as i64, distance: distance as i64 }
+                        }),
                         activities: vec![],
                     }));
                 }
@@ -231,19 +306,22 @@ fn create_tour(
 
                 last.time.departure = format_time(act.schedule.departure);
                 last.load = load.as_vec();
+                // NOTE stop slack is limited by the most constrained activity on the stop
+                if let Some(slack) = slacks.as_ref().and_then(|slacks| slacks[start_idx + offset]) {
+                    last.slack = Some(last.slack.map_or(slack, |last_slack| last_slack.min(slack)));
+                }
                 last.activities.push(ApiActivity {
                     job_id,
                     activity_type: activity_type.clone(),
                     location: Some(coord_index.get_by_idx(act.place.location).unwrap()),
-                    time: Some(Interval {
-                        start: format_time(activity_arrival.max(act.place.time.start)),
-                        end: format_time(activity_departure),
-                    }),
+                    time: Some(Interval { start: format_time(service_start), end: format_time(activity_departure) }),
                     job_tag,
                     commute: act
                         .commute
                         .as_ref()
                         .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
+                    source_id: None,
+                    break_attachment: None,
                 });
 
                 // NOTE detect when vehicle returns after activity to stop point
@@ -271,6 +349,9 @@ fn create_tour(
                             commuting: leg.statistic.times.commuting + commuting as i64,
                             parking: leg.statistic.times.parking + parking as i64,
                         },
+                        emissions: None,
+                        utilization: None,
+                        scaled: None,
                     },
                     load: Some(load),
                 }
@@ -284,8 +365,35 @@ fn create_tour(
 
     leg.statistic.cost += vehicle.costs.fixed;
     tour.statistic = leg.statistic;
+    tour.statistic.emissions = get_tour_emissions(route, &tour);
+    tour.statistic.utilization = problem
+        .extras
+        .get_tour_utilization_output()
+        .is_some_and(|is_enabled| *is_enabled)
+        .then(|| get_route_utilization(route))
+        .flatten();
+
+    let skip_handler = problem.extras.get_required_break_skip_handler();
+    let reserved_time_sources = problem
+        .extras
+        .get_break_source_output()
+        .is_some_and(|is_enabled| *is_enabled)
+        .then(|| problem.extras.get_reserved_time_sources())
+        .flatten();
+    insert_reserved_times_as_breaks(
+        route,
+        &mut tour,
+        reserved_times_index,
+        reserved_time_sources.as_deref(),
+        skip_handler.as_deref(),
+    );
+    if let Some(job_reserved_times_index) = problem.extras.get_job_reserved_times() {
+        insert_job_reserved_times_as_breaks(route, &mut tour, &job_reserved_times_index, skip_handler.as_deref());
+    }
 
-    insert_reserved_times_as_breaks(route, &mut tour, reserved_times_index);
+    if problem.extras.get_break_attachment_output().is_some_and(|is_enabled| *is_enabled) {
+        set_break_attachments(&mut tour);
+    }
 
     // NOTE remove redundant info from single activity on the stop
     tour.stops
@@ -325,6 +433,33 @@ fn calculate_load(current: MultiDimLoad, act: &Activity) -> MultiDimLoad {
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
+/// Returns a factor which binds the route's departure time. Route state is not kept in the solution,
+/// so it is restored on a copy of the route.
+fn get_route_departure_binding(problem: &DomainProblem, route: &Route) -> Option<String> {
+    let mut route_ctx = RouteContext::new_with_state(route.deep_copy(), RouteState::default());
+    problem.goal.accept_route_state(&mut route_ctx);
+
+    get_departure_binding(&route_ctx, problem.transport.as_ref()).map(|binding| binding.to_string())
+}
+
+fn get_tour_emissions(route: &Route, tour: &Tour) -> Option<Float> {
+    let vehicle = route.actor.vehicle.as_ref();
+
+    let capacity =
+        vehicle.dimens.get_vehicle_capacity::<MultiDimLoad>().cloned().or_else(|| {
+            vehicle.dimens.get_vehicle_capacity::<SingleDimLoad>().map(|c| MultiDimLoad::new(vec![c.value]))
+        });
+    // NOTE load is not changed at transit stop, so legs are defined by point stops
+    let stops = tour.point_stops().collect::<Vec<_>>();
+    let legs = stops.iter().zip(stops.iter().skip(1)).map(|(prev, next)| {
+        let load_ratio = capacity.as_ref().map_or(0., |capacity| MultiDimLoad::new(prev.load.clone()).ratio(capacity));
+
+        ((next.distance - prev.distance) as Float, load_ratio)
+    });
+
+    get_route_emissions(route, legs)
+}
+
 fn create_unassigned(solution: &DomainSolution) -> Option<Vec<UnassignedJob>> {
     let create_simple_reasons = |code: ViolationCode| {
         let (code, reason) = map_code_reason(code);
@@ -386,15 +521,37 @@ fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_job_type().is_some_and(|t| t == "break"))
-        .map(|(job, _)| Violation::Break {
+        .map(|(job, info)| Violation::Break {
             vehicle_id: job.dimens().get_vehicle_id().expect("vehicle id").clone(),
             shift_index: job.dimens().get_shift_index().copied().expect("shift index"),
+            reason: job
+                .dimens()
+                .get_break_skippable_to_assign()
+                .filter(|&&is_skippable| is_skippable)
+                .map(|_| get_skipped_break_reason(info)),
         })
         .collect::<Vec<_>>();
 
     if violations.is_empty() { None } else { Some(violations) }
 }
 
+/// Returns a reason why skippable break is not assigned: when break's time is taken by jobs,
+/// skipping the break has enabled their assignment.
+fn get_skipped_break_reason(info: &UnassignmentInfo) -> String {
+    let code = match info {
+        UnassignmentInfo::Simple(code) => *code,
+        UnassignmentInfo::Detailed(details) => details.first().map_or(ViolationCode(0), |(_, code)| *code),
+        UnassignmentInfo::Unknown => ViolationCode(0),
+    };
+
+    match code {
+        TIME_CONSTRAINT_CODE | DURATION_LIMIT_CONSTRAINT_CODE | JOB_TIME_CONSTRAINT_CODE => {
+            "enabled_job_assignment".to_string()
+        }
+        code => map_code_reason(code).0.to_string(),
+    }
+}
+
 fn get_activity_type(activity: &Activity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_job_type())
 }
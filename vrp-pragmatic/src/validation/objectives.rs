@@ -198,6 +198,60 @@ fn check_e1608_vehicles_with_min_tour_size_but_no_objective(
     }
 }
 
+/// Checks that lateness objective is specified when some jobs have soft time windows.
+fn check_e1609_jobs_with_soft_times_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_no_lateness_objective =
+        !get_objectives_flattened(objectives).any(|objective| matches!(objective, MinimizeLateness));
+    let has_jobs_with_soft_times = ctx.problem.plan.jobs.iter().any(|job| job.soft_times.unwrap_or(false));
+
+    if has_no_lateness_objective && has_jobs_with_soft_times {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "missing lateness objective".to_string(),
+            "specify 'minimize-lateness' objective, remove objectives property or remove softTimes from jobs"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that break on transit objective is specified when some shifts prefer breaks on transit.
+fn check_e1610_shifts_with_break_on_transit_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_no_break_on_transit_objective =
+        !get_objectives_flattened(objectives).any(|objective| matches!(objective, PreferBreakOnTransit));
+    let has_shifts_with_break_on_transit = ctx
+        .vehicles()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .any(|shift| shift.prefer_break_on_transit.unwrap_or(false));
+
+    if has_no_break_on_transit_objective && has_shifts_with_break_on_transit {
+        Err(FormatError::new(
+            "E1610".to_string(),
+            "missing break on transit objective".to_string(),
+            "specify 'prefer-break-on-transit' objective, remove objectives property or remove preferBreakOnTransit \
+             from vehicle shifts"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().collect())
 }
@@ -221,6 +275,8 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
             check_e1608_vehicles_with_min_tour_size_but_no_objective(ctx, &objectives),
+            check_e1609_jobs_with_soft_times_but_no_objective(ctx, &objectives),
+            check_e1610_shifts_with_break_on_transit_but_no_objective(ctx, &objectives),
         ])
         .map_err(From::from)
     } else {
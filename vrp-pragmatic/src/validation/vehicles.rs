@@ -3,6 +3,7 @@
 mod vehicles_test;
 
 use super::*;
+use crate::format::problem::parse_exact_break_time_window;
 use crate::parse_time_safe;
 use crate::utils::combine_error_results;
 use crate::validation::common::get_time_windows;
@@ -74,12 +75,33 @@ fn check_e1303_vehicle_breaks_time_is_correct(ctx: &ValidationContext) -> Result
                 .as_ref()
                 .map(|breaks| {
                     // OffsetTime breaks: only structural validation (no absolute time computation
-                    // against shift start, since the actual anchor is unknown at validation time)
+                    // against shift start, since the actual anchor is unknown at validation time).
+                    // NOTE negative earliest is allowed: the range can straddle the anchor, but the break
+                    // is triggered at its latest time which should not precede the anchor
                     let offset_valid = breaks.iter().all(|b| match b {
                         VehicleBreak::Required {
-                            time: VehicleRequiredBreakTime::OffsetTime { earliest, latest },
+                            time:
+                                VehicleRequiredBreakTime::OffsetTime { earliest, latest }
+                                | VehicleRequiredBreakTime::AnchoredOffsetTime { earliest, latest, .. },
                             duration,
-                        } => *earliest >= 0. && *latest >= 0. && *earliest <= *latest && *duration > 0.,
+                            ..
+                        } => *latest >= 0. && *earliest <= *latest && *duration > 0.,
+                        VehicleBreak::Required {
+                            time: VehicleRequiredBreakTime::FractionalOffset { earliest_frac, latest_frac },
+                            duration,
+                            ..
+                        } => {
+                            *earliest_frac >= 0.
+                                && *earliest_frac <= *latest_frac
+                                && *latest_frac <= 1.
+                                && *duration > 0.
+                        }
+                        VehicleBreak::Required {
+                            time: VehicleRequiredBreakTime::AfterJob { .. }, duration, ..
+                        } => *duration > 0.,
+                        VehicleBreak::Required {
+                            time: VehicleRequiredBreakTime::AfterLoad { load }, duration, ..
+                        } => !load.is_empty() && load.iter().all(|value| *value >= 0) && *duration > 0.,
                         _ => true,
                     });
 
@@ -87,6 +109,20 @@ fn check_e1303_vehicle_breaks_time_is_correct(ctx: &ValidationContext) -> Result
                         return false;
                     }
 
+                    // NOTE only breaks with route independent time can be skipped or shifted after jobs
+                    let conditional_valid = breaks.iter().all(|b| match b {
+                        VehicleBreak::Required { time, skippable_to_assign: Some(true), .. }
+                        | VehicleBreak::Required { time, min_jobs_before_break: Some(_), .. } => matches!(
+                            time,
+                            VehicleRequiredBreakTime::ExactTime { .. } | VehicleRequiredBreakTime::OffsetTime { .. }
+                        ),
+                        _ => true,
+                    });
+
+                    if !conditional_valid {
+                        return false;
+                    }
+
                     // ExactTime and optional breaks: validate against shift time windows as before
                     let tws = breaks
                         .iter()
@@ -95,13 +131,13 @@ fn check_e1303_vehicle_breaks_time_is_correct(ctx: &ValidationContext) -> Result
                                 Some(get_time_window_from_vec(tw))
                             }
                             VehicleBreak::Required {
-                                time: VehicleRequiredBreakTime::ExactTime { earliest, latest },
+                                time: VehicleRequiredBreakTime::ExactTime { earliest, latest, utc_offset },
                                 duration,
+                                ..
                             } => Some(
-                                parse_time_safe(earliest)
+                                parse_exact_break_time_window(earliest, latest, *utc_offset)
                                     .ok()
-                                    .zip(parse_time_safe(latest).ok())
-                                    .map(|(start, end)| TimeWindow::new(start, end + *duration)),
+                                    .map(|tw| TimeWindow::new(tw.start, tw.end + *duration)),
                             ),
                             _ => None,
                         })
@@ -228,6 +264,153 @@ fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), F
     }
 }
 
+/// Checks that required breaks of the same shift have no overlapping time windows.
+fn check_e1309_vehicle_required_breaks_do_not_overlap(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            let (offset_tws, exact_tws): (Vec<_>, Vec<_>) = shift
+                .breaks
+                .iter()
+                .flat_map(|breaks| breaks.iter())
+                .filter_map(|b| match b {
+                    VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::OffsetTime { earliest, latest }, ..
+                    } => Some((true, Some(TimeWindow::new(*earliest, *latest)))),
+                    VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::ExactTime { earliest, latest, utc_offset },
+                        ..
+                    } => Some((false, parse_exact_break_time_window(earliest, latest, *utc_offset).ok())),
+                    VehicleBreak::Required { time: VehicleRequiredBreakTime::AfterJob { .. }, .. }
+                    | VehicleBreak::Required { time: VehicleRequiredBreakTime::AfterLoad { .. }, .. }
+                    | VehicleBreak::Required { time: VehicleRequiredBreakTime::FractionalOffset { .. }, .. }
+                    | VehicleBreak::Required { time: VehicleRequiredBreakTime::AnchoredOffsetTime { .. }, .. }
+                    | VehicleBreak::Optional { .. } => None,
+                })
+                .partition(|(is_offset, _)| *is_offset);
+            // NOTE fractional ranges overlap regardless of the actual shift duration if fractions overlap
+            let fractional_tws = shift
+                .breaks
+                .iter()
+                .flat_map(|breaks| breaks.iter())
+                .filter_map(|b| match b {
+                    VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::FractionalOffset { earliest_frac, latest_frac },
+                        ..
+                    } => Some((true, Some(TimeWindow::new(*earliest_frac, *latest_frac)))),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            // NOTE malformed time windows are reported by E1303
+            let has_no_overlaps = |tws: Vec<(bool, Option<TimeWindow>)>| {
+                let tws = tws.into_iter().filter_map(|(_, tw)| tw).collect::<Vec<_>>();
+                tws.iter().enumerate().all(|(idx, a)| tws.iter().skip(idx + 1).all(|b| !a.intersects(b)))
+            };
+
+            has_no_overlaps(offset_tws) && has_no_overlaps(exact_tws) && has_no_overlaps(fractional_tws)
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1309".to_string(),
+            "overlapping required break time windows in vehicle shift".to_string(),
+            format!(
+                "ensure that required breaks of the same shift have no overlapping time ranges, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that daylight window of vehicle shift job times is valid.
+fn check_e1310_vehicle_job_times_daylight_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift.job_times.as_ref().and_then(|job_times| job_times.daylight.as_ref()).is_none_or(|daylight| {
+                let after_sunrise = daylight.after_sunrise.unwrap_or_default();
+                let before_sunset = daylight.before_sunset.unwrap_or_default();
+
+                after_sunrise >= 0.
+                    && before_sunset >= 0.
+                    && get_time_window(&daylight.sunrise, &daylight.sunset)
+                        .is_some_and(|tw| tw.start + after_sunrise <= tw.end - before_sunset)
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1310".to_string(),
+            "invalid daylight window in vehicle shift job times".to_string(),
+            format!(
+                "ensure that sunrise is before sunset and offsets are non-negative and fit between them, \
+                 vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle shift's max break fraction is in the (0, 1] range.
+fn check_e1311_vehicle_max_break_fraction_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| shift.max_break_fraction.is_none_or(|fraction| fraction > 0. && fraction <= 1.)),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1311".to_string(),
+            "invalid max break fraction in vehicle shift".to_string(),
+            format!(
+                "ensure that max break fraction is greater than 0 and not greater than 1, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that explicit bounds of vehicle shift job times are valid date times in proper order.
+fn check_e1312_vehicle_job_times_bounds_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(|_, shift, _| {
+            shift.job_times.as_ref().is_none_or(|job_times| {
+                let parse = |time: Option<&String>| time.map(|time| parse_time_safe(time)).transpose();
+
+                match (parse(job_times.earliest_first.as_ref()), parse(job_times.latest_last.as_ref())) {
+                    (Ok(Some(earliest_first)), Ok(Some(latest_last))) => earliest_first <= latest_last,
+                    (Ok(_), Ok(_)) => true,
+                    _ => false,
+                }
+            })
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1312".to_string(),
+            "invalid bounds in vehicle shift job times".to_string(),
+            format!(
+                "ensure that earliest first and latest last are date times in RFC3339 format and earliest first \
+                 is not after latest last, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 type CheckShiftFn = Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>;
 
 fn get_invalid_type_ids(ctx: &ValidationContext, check_shift_fn: CheckShiftFn) -> Vec<String> {
@@ -270,6 +453,10 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1304_vehicle_reload_time_is_correct(ctx),
         check_e1306_vehicle_has_no_zero_costs(ctx),
         check_e1308_vehicle_reload_resources(ctx),
+        check_e1309_vehicle_required_breaks_do_not_overlap(ctx),
+        check_e1310_vehicle_job_times_daylight_is_correct(ctx),
+        check_e1311_vehicle_max_break_fraction_is_correct(ctx),
+        check_e1312_vehicle_job_times_bounds_are_correct(ctx),
     ])
     .map_err(From::from)
 }
@@ -132,7 +132,7 @@ mod required {
          time in time_proto,
          duration in duration_proto,
         ) -> VehicleBreak {
-            VehicleBreak::Required { time, duration }
+            VehicleBreak::Required { time, duration, skippable_to_assign: None, min_jobs_before_break: None }
         }
     }
 
@@ -152,7 +152,7 @@ mod required {
          time in from_hours_as_usize(10)..from_hours_as_usize(13),
         ) -> VehicleRequiredBreakTime {
             let time = time as Float;
-            VehicleRequiredBreakTime::ExactTime{ earliest: format_time(time - 1.), latest: format_time(time) }
+            VehicleRequiredBreakTime::ExactTime{ earliest: format_time(time - 1.), latest: format_time(time), utc_offset: None }
         }
     }
 
@@ -0,0 +1,70 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_problem_with_after_job_break(job_id: &str) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(0, 100)], 5.),
+                create_delivery_job_with_times("job2", (20., 0.), vec![(0, 100)], 5.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::AfterJob { job_id: job_id.to_string() },
+                        duration: 7.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_assign_break_right_after_job() {
+    let problem = create_problem_with_after_job_break("job2");
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    let (stop, activity_idx) = tour
+        .stops
+        .iter()
+        .find_map(|stop| stop.activities().iter().position(|a| a.job_id == "job2").map(|idx| (stop, idx)))
+        .expect("cannot find job2");
+    let job = &stop.activities()[activity_idx];
+    let break_activity = stop.activities().get(activity_idx + 1).expect("no activity after job2");
+    assert_eq!(break_activity.activity_type, "break");
+    let (job_time, break_time) = (job.time.as_ref().unwrap(), break_activity.time.as_ref().unwrap());
+    assert_eq!(break_time.start, job_time.end);
+    assert_eq!(parse_time(&break_time.end) - parse_time(&break_time.start), 7.);
+    assert_eq!(break_time.end, stop.schedule().departure);
+    assert_eq!(tour.statistic.times.break_time, 7);
+}
+
+#[test]
+fn can_skip_break_after_job_which_is_not_served() {
+    let problem = create_problem_with_after_job_break("job3");
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(solution.tours[0].stops.iter().flat_map(|stop| stop.activities()).all(|a| a.activity_type != "break"));
+    assert_eq!(solution.tours[0].statistic.times.break_time, 0);
+}
@@ -0,0 +1,95 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_problem_with_after_load_break(load: i32) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_delivery_job("job3", (30., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::AfterLoad { load: vec![load] },
+                        duration: 7.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_assign_break_after_cumulative_load_is_handled() {
+    let problem = create_problem_with_after_load_break(2);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    let (stop_idx, break_stop) = tour
+        .stops
+        .iter()
+        .enumerate()
+        .find(|(_, stop)| stop.activities().iter().any(|activity| activity.activity_type == "break"))
+        .expect("cannot find break");
+    let activity_types =
+        break_stop.activities().iter().map(|activity| activity.activity_type.as_str()).collect::<Vec<_>>();
+    assert_eq!(stop_idx, 2);
+    assert_eq!(activity_types, vec!["delivery", "break"]);
+    assert_eq!(break_stop.load(), &vec![1]);
+    assert_eq!(tour.statistic.times.break_time, 7);
+}
+
+#[test]
+fn can_skip_break_when_cumulative_load_is_not_handled() {
+    let problem = create_problem_with_after_load_break(4);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(solution.tours[0].stops.iter().flat_map(|stop| stop.activities()).all(|a| a.activity_type != "break"));
+    assert_eq!(solution.tours[0].statistic.times.break_time, 0);
+}
+
+#[test]
+fn can_count_handled_load_at_stop_with_pickup_and_delivery() {
+    let mut problem = create_problem_with_after_load_break(2);
+    problem.plan.jobs = vec![
+        create_delivery_job("job1", (10., 0.)),
+        create_pickup_job("job2", (10., 0.)),
+        create_delivery_job("job3", (20., 0.)),
+    ];
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let break_stop = solution.tours[0]
+        .stops
+        .iter()
+        .find(|stop| stop.activities().iter().any(|activity| activity.activity_type == "break"))
+        .expect("cannot find break");
+    let mut job_ids = break_stop
+        .activities()
+        .iter()
+        .filter(|activity| activity.activity_type != "break")
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    job_ids.sort();
+    assert_eq!(job_ids, vec!["job1", "job2"]);
+    assert_eq!(solution.tours[0].statistic.times.break_time, 7);
+}
@@ -0,0 +1,92 @@
+use crate::format::BreakAttachmentOutputExtraProperty;
+use crate::format::problem::*;
+use crate::format::solution::{BreakAttachment, Solution};
+use crate::format_time;
+use crate::helpers::*;
+use std::sync::Arc;
+
+fn create_problem_with_exact_break(jobs: Vec<Job>, break_start: f64) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::ExactTime {
+                            earliest: format_time(break_start),
+                            latest: format_time(break_start),
+                            utc_offset: None,
+                        },
+                        duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn solve_with_break_attachments(problem: Problem) -> Solution {
+    let matrix = create_matrix_from_problem(&problem);
+
+    solve_with_metaheuristic_and_extras(problem, Some(vec![matrix]), |extras| {
+        extras.set_break_attachment_output(Arc::new(true));
+    })
+}
+
+fn get_break_attachments(solution: &Solution) -> Vec<Option<BreakAttachment>> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "break")
+        .map(|activity| activity.break_attachment.clone())
+        .collect()
+}
+
+#[test]
+fn can_report_break_attached_to_transit_stop() {
+    let problem = create_problem_with_exact_break(
+        vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (10., 0.))],
+        7.,
+    );
+
+    let solution = solve_with_break_attachments(problem);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_break_attachments(&solution), vec![Some(BreakAttachment::Transit { stop_index: 2 })]);
+}
+
+#[test]
+fn can_report_break_attached_to_point_stop() {
+    let problem = create_problem_with_exact_break(vec![create_delivery_job_with_duration("job1", (5., 0.), 3.)], 7.);
+
+    let solution = solve_with_break_attachments(problem);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_break_attachments(&solution),
+        vec![Some(BreakAttachment::Point { stop_index: 1, job_id: Some("job1".to_string()) })]
+    );
+}
+
+#[test]
+fn can_omit_break_attachment_by_default() {
+    let problem = create_problem_with_exact_break(vec![create_delivery_job_with_duration("job1", (5., 0.), 3.)], 7.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_break_attachments(&solution), vec![None]);
+}
@@ -0,0 +1,77 @@
+use crate::format::problem::*;
+use crate::format::solution::Stop;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_prefer_break_on_transit, (prefer_break_on_transit, expected_order, expected_on_transit), {
+    can_prefer_break_on_transit_impl(prefer_break_on_transit, expected_order, expected_on_transit);
+}}
+
+can_prefer_break_on_transit! {
+    case01_no_preference: (None, vec!["departure", "job1", "break", "job2", "arrival"], false),
+    case02_with_preference: (Some(true), vec!["departure", "break", "job2", "job1", "arrival"], true),
+}
+
+fn can_prefer_break_on_transit_impl(
+    prefer_break_on_transit: Option<bool>,
+    expected_order: Vec<&str>,
+    expected_on_transit: bool,
+) {
+    // Serving job1 first is cheaper, but then the break at 105 is taken while job1 is served.
+    // Serving job2 first makes the break happen while driving to job2.
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (100., 0.), vec![(0, 1000)], 10.),
+                create_delivery_job_with_times("job2", (200., 0.), vec![(0, 1000)], 10.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (200., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::ExactTime {
+                            earliest: format_time(105.),
+                            latest: format_time(105.),
+                            utc_offset: None,
+                        },
+                        duration: 10.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    prefer_break_on_transit,
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations_without_check(problem, Some(vec![matrix]), 200);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = &solution.tours[0];
+    let order = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(|activity| activity.job_id.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(order, expected_order);
+    let break_stop = tour
+        .stops
+        .iter()
+        .find(|stop| stop.activities().iter().any(|activity| activity.activity_type == "break"))
+        .expect("break should be assigned");
+    assert_eq!(matches!(break_stop, Stop::Transit(_)), expected_on_transit);
+}
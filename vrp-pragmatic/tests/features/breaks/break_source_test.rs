@@ -0,0 +1,74 @@
+use crate::format::BreakSourceOutputExtraProperty;
+use crate::format::problem::*;
+use crate::format::solution::Solution;
+use crate::helpers::*;
+use std::sync::Arc;
+
+fn create_problem_with_two_required_breaks() -> Problem {
+    let create_required_break = |offset: f64| VehicleBreak::Required {
+        time: VehicleRequiredBreakTime::OffsetTime { earliest: offset, latest: offset },
+        duration: 2.,
+        skippable_to_assign: None,
+        min_jobs_before_break: None,
+    };
+
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (5., 0.)),
+                create_delivery_job("job2", (10., 0.)),
+                create_delivery_job("job3", (15., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![create_required_break(20.), create_required_break(5.)]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_break_source_ids(solution: &Solution) -> Vec<Option<String>> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "break")
+        .map(|activity| activity.source_id.clone())
+        .collect()
+}
+
+#[test]
+fn can_report_source_id_of_required_breaks() {
+    let problem = create_problem_with_two_required_breaks();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_extras(problem, Some(vec![matrix]), |extras| {
+        extras.set_break_source_output(Arc::new(true));
+    });
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        get_break_source_ids(&solution),
+        vec![Some("my_vehicle_1_required_break_0_2".to_string()), Some("my_vehicle_1_required_break_0_1".to_string())]
+    );
+}
+
+#[test]
+fn can_omit_source_id_of_required_breaks_by_default() {
+    let problem = create_problem_with_two_required_breaks();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_break_source_ids(&solution), vec![None, None]);
+}
@@ -0,0 +1,41 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::models::common::TimeWindow;
+
+#[test]
+fn can_place_break_with_window_from_function_based_on_shift_length() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(40.), location: (0., 0.).to_loc() }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let break_windows_fn: BreakWindowsFn = Arc::new(|shift_ctx: &VehicleShiftContext| {
+        let middle = shift_ctx.time.start + shift_ctx.time.duration() / 2.;
+        vec![TimeWindow::new(middle, middle + 2.)]
+    });
+
+    let solution = solve_with_metaheuristic_and_break_windows(problem, Some(vec![matrix]), break_windows_fn);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.times.break_time, 2);
+    let break_stop = solution.tours[0].transit_stops().next().expect("no break stop");
+    assert_eq!(break_stop.activities[0].job_id, "break");
+    assert_eq!(break_stop.time.arrival, format_time(20.));
+    assert_eq!(break_stop.time.departure, format_time(22.));
+}
@@ -0,0 +1,62 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+
+parameterized_test! {can_anchor_optional_offset_break_by_route_cost_span, (span, expected_break_start), {
+    can_anchor_optional_offset_break_by_route_cost_span_impl(span, expected_break_start);
+}}
+
+can_anchor_optional_offset_break_by_route_cost_span! {
+    case01_departure_anchor: (RouteCostSpan::DepotToDepot, None),
+    case02_first_job_anchor: (RouteCostSpan::FirstJobToDepot, Some(13.)),
+}
+
+fn can_anchor_optional_offset_break_by_route_cost_span_impl(span: RouteCostSpan, expected_break_start: Option<f64>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (20., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeOffset(vec![3., 6.]),
+                        places: vec![VehicleOptionalBreakPlace {
+                            duration: 2.,
+                            location: Some((10., 0.).to_loc()),
+                            tag: None,
+                        }],
+                        policy: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                costs: VehicleCosts { span: Some(span), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let break_start = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .find(|(_, activity)| activity.activity_type == "break")
+        .map(|(stop, activity)| {
+            parse_time(activity.time.as_ref().map_or(&stop.schedule().arrival, |time| &time.start))
+        });
+    assert_eq!(break_start, expected_break_start);
+    assert_eq!(solution.violations.is_some(), expected_break_start.is_none());
+}
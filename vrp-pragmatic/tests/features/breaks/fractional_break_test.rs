@@ -0,0 +1,54 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use crate::parse_time;
+
+#[test]
+fn can_assign_break_at_fraction_of_shift() {
+    // Shift takes 100 time units: 40 to reach job1, 10 to serve it, 40 to get back and 10 for the break.
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (40., 0.), vec![(0, 1000)], 10.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::FractionalOffset { earliest_frac: 0.4, latest_frac: 0.5 },
+                        duration: 10.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    let arrival = tour.stops.last().map(|stop| parse_time(&stop.schedule().arrival)).unwrap();
+    assert_eq!(arrival, 100.);
+    let break_stop = tour
+        .stops
+        .iter()
+        .find(|stop| stop.activities().iter().any(|activity| activity.activity_type == "break"))
+        .expect("break should be assigned");
+    let break_start = parse_time(&break_stop.schedule().arrival);
+    assert!((40. ..=50.).contains(&break_start), "unexpected break start: {break_start}");
+    assert_eq!(parse_time(&break_stop.schedule().departure) - break_start, 10.);
+    assert_eq!(tour.statistic.times.break_time, 10);
+}
@@ -116,8 +116,7 @@ fn can_assign_interval_break_with_reload() {
                         duration: 3.0,
                         ..create_default_reload()
                     }]),
-                    recharges: None,
-                    job_times: None,
+                    ..VehicleShift::default()
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -0,0 +1,60 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+parameterized_test! {can_limit_break_time_by_fraction_of_working_time, (max_break_fraction, expected), {
+    can_limit_break_time_by_fraction_of_working_time_impl(max_break_fraction, expected);
+}}
+
+can_limit_break_time_by_fraction_of_working_time! {
+    case01_both_breaks_fit: (0.3, (2, 12)),
+    case02_short_break_fits: (0.05, (1, 2)),
+    case03_no_break_fits: (0.01, (0, 0)),
+}
+
+fn can_limit_break_time_by_fraction_of_working_time_impl(max_break_fraction: Float, expected: (usize, i64)) {
+    let (expected_breaks, expected_break_time) = expected;
+    let create_break = |start: Float, end: Float, duration: Float| VehicleBreak::Optional {
+        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(start), format_time(end)]),
+        places: vec![VehicleOptionalBreakPlace { duration, location: None, tag: None }],
+        policy: None,
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_delivery_job("job3", (30., 0.)),
+                create_delivery_job("job4", (40., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![create_break(0., 25., 2.), create_break(26., 100., 10.)]),
+                    max_break_fraction: Some(max_break_fraction),
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let breaks = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities())
+        .filter(|activity| activity.activity_type == "break")
+        .count();
+    assert_eq!(breaks, expected_breaks);
+    assert_eq!(solution.tours[0].statistic.times.break_time, expected_break_time);
+}
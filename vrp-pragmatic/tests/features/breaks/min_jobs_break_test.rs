@@ -0,0 +1,62 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+#[test]
+fn can_shift_offset_break_after_min_jobs() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_delivery_job("job3", (30., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::OffsetTime { earliest: 5., latest: 5. },
+                        duration: 5.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: Some(2),
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let activities = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .collect::<Vec<_>>();
+    let break_idx =
+        activities.iter().position(|(_, activity)| activity.activity_type == "break").expect("cannot find break");
+    let get_time = |idx: usize, is_start: bool| {
+        let (stop, activity) = activities[idx];
+        match (activity.time.as_ref(), is_start) {
+            (Some(time), true) => parse_time(&time.start),
+            (Some(time), false) => parse_time(&time.end),
+            (None, true) => parse_time(&stop.schedule().arrival),
+            (None, false) => parse_time(&stop.schedule().departure),
+        }
+    };
+    // NOTE break time starts before the first job, but the break is taken right after the second one
+    let deliveries_before = activities[..break_idx].iter().filter(|(_, a)| a.activity_type == "delivery").count();
+    assert_eq!(deliveries_before, 2);
+    assert_eq!(activities[break_idx - 1].1.activity_type, "delivery");
+    assert!(get_time(break_idx, true) > 5.);
+    assert_eq!(get_time(break_idx, true), get_time(break_idx - 1, false));
+    assert_eq!(solution.tours[0].statistic.times.break_time, 5);
+}
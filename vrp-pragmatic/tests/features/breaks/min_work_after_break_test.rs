@@ -0,0 +1,105 @@
+use crate::format::problem::*;
+use crate::format::solution::Violation;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_problem_with_min_work_after_break(min_work_after_break: Float) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_delivery_job("job3", (30., 0.)),
+                create_delivery_job("job4", (40., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(20.), format_time(100.)]),
+                        places: vec![VehicleOptionalBreakPlace { duration: 2., location: None, tag: None }],
+                        policy: None,
+                    }]),
+                    min_work_after_break: Some(min_work_after_break),
+                    ..create_default_open_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_move_break_earlier_to_leave_min_work_after_it() {
+    let problem = create_problem_with_min_work_after_break(20.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    let break_stop = tour
+        .stops
+        .iter()
+        .find(|stop| stop.activities().iter().any(|activity| activity.activity_type == "break"))
+        .expect("cannot find break");
+    let job_ids = break_stop.activities().iter().map(|activity| activity.job_id.as_str()).collect::<Vec<_>>();
+    assert_eq!(job_ids, vec!["job2", "break"]);
+    assert_eq!(tour.statistic.times.break_time, 2);
+}
+
+#[test]
+fn can_skip_break_when_min_work_after_it_cannot_be_done() {
+    let problem = create_problem_with_min_work_after_break(200.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.tours[0].stops.iter().flat_map(|stop| stop.activities()).all(|a| a.activity_type != "break"));
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        solution.violations,
+        Some(vec![Violation::Break { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0, reason: None }])
+    );
+}
+
+parameterized_test! {can_skip_required_break_when_min_work_after_it_cannot_be_done, (min_work_after_break, has_break), {
+    can_skip_required_break_when_min_work_after_it_cannot_be_done_impl(min_work_after_break, has_break);
+}}
+
+can_skip_required_break_when_min_work_after_it_cannot_be_done! {
+    case01_enough_work_after: (5., true),
+    case02_not_enough_work_after: (10., false),
+}
+
+fn can_skip_required_break_when_min_work_after_it_cannot_be_done_impl(min_work_after_break: Float, has_break: bool) {
+    let mut problem = create_problem_with_min_work_after_break(min_work_after_break);
+    problem.fleet.vehicles[0].shifts[0].breaks = Some(vec![VehicleBreak::Required {
+        time: VehicleRequiredBreakTime::ExactTime {
+            earliest: format_time(35.),
+            latest: format_time(35.),
+            utc_offset: None,
+        },
+        duration: 2.,
+        skippable_to_assign: None,
+        min_jobs_before_break: None,
+    }]);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(solution.violations.is_none());
+    let break_count = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities())
+        .filter(|activity| activity.activity_type == "break")
+        .count();
+    assert_eq!(break_count, if has_break { 1 } else { 0 });
+}
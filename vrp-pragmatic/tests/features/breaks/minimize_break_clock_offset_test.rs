@@ -0,0 +1,77 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_align_break_start_with_clock, (objectives, expected_tag), {
+    can_align_break_start_with_clock_impl(objectives, expected_tag);
+}}
+
+can_align_break_start_with_clock! {
+    case01_min_cost: (
+        vec![Objective::MinimizeUnassigned { breaks: None }, Objective::MinimizeCost],
+        "on_route"
+    ),
+    case02_min_break_clock_offset: (
+        vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::MinimizeBreakClockOffset { interval: 12. },
+            Objective::MinimizeCost,
+        ],
+        "aligned"
+    ),
+}
+
+fn can_align_break_start_with_clock_impl(objectives: Vec<Objective>, expected_tag: &str) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (20., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (30., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(5.), format_time(30.)]),
+                        places: vec![
+                            VehicleOptionalBreakPlace {
+                                duration: 2.0,
+                                location: Some((10., 0.).to_loc()),
+                                tag: Some("on_route".to_string()),
+                            },
+                            VehicleOptionalBreakPlace {
+                                duration: 2.0,
+                                location: Some((10., 1.).to_loc()),
+                                tag: Some("aligned".to_string()),
+                            },
+                        ],
+                        policy: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let breaks = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.activity_type == "break")
+        .map(|(stop, activity)| (activity.job_tag.clone(), stop.schedule().arrival.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(breaks.len(), 1);
+    assert_eq!(breaks[0].0, Some(expected_tag.to_string()));
+    if expected_tag == "aligned" {
+        assert_eq!(breaks[0].1, format_time(12.));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_minimize_break_detour, (objectives, expected_tag), {
+    can_minimize_break_detour_impl(objectives, expected_tag);
+}}
+
+can_minimize_break_detour! {
+    case01_min_cost: (
+        vec![Objective::MinimizeUnassigned { breaks: None }, Objective::MinimizeCost],
+        "far"
+    ),
+    case02_min_break_detour: (
+        vec![Objective::MinimizeUnassigned { breaks: None }, Objective::MinimizeBreakDetour, Objective::MinimizeCost],
+        "near"
+    ),
+}
+
+fn can_minimize_break_detour_impl(objectives: Vec<Objective>, expected_tag: &str) {
+    let create_place = |location: (f64, f64), duration: f64, tag: &str| VehicleOptionalBreakPlace {
+        duration,
+        location: Some(location.to_loc()),
+        tag: Some(tag.to_string()),
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(0.), format_time(100.)]),
+                        // NOTE break at the rest area on the route lasts longer, so it is more expensive
+                        places: vec![create_place((5., 0.), 20., "near"), create_place((5., 5.), 2., "far")],
+                        policy: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let break_tags = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "break")
+        .filter_map(|activity| activity.job_tag.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(break_tags, vec![expected_tag.to_string()]);
+}
@@ -0,0 +1,77 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use std::collections::HashSet;
+
+fn create_vehicle_with_break_places(id: &str, places: Vec<((f64, f64), &str)>) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift {
+            breaks: Some(vec![VehicleBreak::Optional {
+                time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(0.), format_time(100.)]),
+                places: places
+                    .into_iter()
+                    .map(|(location, tag)| VehicleOptionalBreakPlace {
+                        duration: 2.0,
+                        location: Some(location.to_loc()),
+                        tag: Some(tag.to_string()),
+                    })
+                    .collect(),
+                policy: None,
+            }]),
+            ..create_default_vehicle_shift()
+        }],
+        ..create_vehicle_with_capacity(id, vec![1])
+    }
+}
+
+parameterized_test! {can_minimize_break_location_count, (objectives, expected_tags), {
+    can_minimize_break_location_count_impl(objectives, expected_tags);
+}}
+
+can_minimize_break_location_count! {
+    case01_min_cost: (
+        vec![Objective::MinimizeUnassigned { breaks: None }, Objective::MinimizeCost],
+        vec!["own", "shared"]
+    ),
+    case02_min_break_location_count: (
+        vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::MinimizeBreakLocationCount,
+            Objective::MinimizeCost,
+        ],
+        vec!["shared"]
+    ),
+}
+
+fn can_minimize_break_location_count_impl(objectives: Vec<Objective>, expected_tags: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (0., 10.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_break_places("v1", vec![((10., 0.), "own"), ((5., 5.), "shared")]),
+                create_vehicle_with_break_places("v2", vec![((5., 5.), "shared")]),
+            ],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let break_tags = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "break")
+        .filter_map(|activity| activity.job_tag.clone())
+        .collect::<HashSet<_>>();
+    assert_eq!(break_tags, expected_tags.into_iter().map(|tag| tag.to_string()).collect());
+}
@@ -0,0 +1,71 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use crate::parse_time;
+
+#[test]
+fn can_stagger_breaks_across_fleet() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_delivery_job("job3", (0., 10.)),
+                create_delivery_job("job4", (0., 20.)),
+                create_delivery_job("job5", (-10., 0.)),
+                create_delivery_job("job6", (-20., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string(), "v3".to_string()],
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(5.), format_time(40.)]),
+                        places: vec![VehicleOptionalBreakPlace { duration: 5.0, location: None, tag: None }],
+                        policy: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![2],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::MinimizeBreakOverlap { max_breaks: 2 },
+            Objective::MinimizeCost,
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 3);
+    let breaks = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.activity_type == "break")
+        .map(|(stop, activity)| {
+            activity.time.as_ref().map_or_else(
+                || (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)),
+                |time| (parse_time(&time.start), parse_time(&time.end)),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(breaks.len(), 3);
+    let max_overlap = breaks
+        .iter()
+        .map(|&(start, _)| {
+            breaks.iter().filter(|&&(other_start, other_end)| other_start <= start && start < other_end).count()
+        })
+        .max()
+        .unwrap_or_default();
+    assert!(max_overlap <= 2, "breaks are not staggered: {breaks:?}");
+}
@@ -0,0 +1,70 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_prefer_shorter_break_place, (objectives, expected_tag, expected_break_time), {
+    can_prefer_shorter_break_place_impl(objectives, expected_tag, expected_break_time);
+}}
+
+can_prefer_shorter_break_place! {
+    case01_min_cost: (
+        vec![Objective::MinimizeUnassigned { breaks: None }, Objective::MinimizeCost],
+        "long", 3
+    ),
+    case02_min_break_time: (
+        vec![Objective::MinimizeUnassigned { breaks: None }, Objective::MinimizeBreakTime, Objective::MinimizeCost],
+        "short", 2
+    ),
+}
+
+fn can_prefer_shorter_break_place_impl(objectives: Vec<Objective>, expected_tag: &str, expected_break_time: i64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (20., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (30., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Optional {
+                        time: VehicleOptionalBreakTime::TimeWindow(vec![format_time(10.), format_time(30.)]),
+                        places: vec![
+                            VehicleOptionalBreakPlace {
+                                duration: 3.0,
+                                location: Some((10., 0.).to_loc()),
+                                tag: Some("long".to_string()),
+                            },
+                            VehicleOptionalBreakPlace {
+                                duration: 2.0,
+                                location: Some((10., 5.).to_loc()),
+                                tag: Some("short".to_string()),
+                            },
+                        ],
+                        policy: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let break_tags = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "break")
+        .map(|activity| activity.job_tag.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(break_tags, vec![Some(expected_tag.to_string())]);
+    assert_eq!(solution.tours[0].statistic.times.break_time, expected_break_time);
+}
@@ -1,9 +1,29 @@
+mod after_job_break_test;
+mod after_load_break_test;
 mod basic_break_test;
+mod break_attachment_test;
+mod break_on_transit_test;
+mod break_source_test;
+mod break_windows_fn_test;
 mod break_with_multiple_locations;
+mod first_job_offset_break_test;
+mod fractional_break_test;
 mod interval_break_test;
+mod max_break_fraction_test;
+mod min_jobs_break_test;
+mod min_work_after_break_test;
+mod minimize_break_clock_offset_test;
+mod minimize_break_detour_test;
+mod minimize_break_location_count_test;
+mod minimize_break_overlap_test;
+mod minimize_break_time_test;
 mod multi_break_test;
 mod open_end_by_interval_break;
 mod policy_break_test;
+mod reinsert_breaks_test;
 mod relation_break_test;
+mod reload_offset_break_test;
 mod required_break;
 mod required_break_flexible_start;
+mod skippable_break_test;
+mod straddling_offset_break_test;
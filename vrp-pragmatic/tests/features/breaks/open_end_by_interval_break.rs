@@ -14,7 +14,12 @@ fn create_optional_break(time_window: (Timestamp, Timestamp), duration: Duration
 }
 
 fn create_required_break(earliest: Timestamp, latest: Timestamp, duration: Duration) -> VehicleBreak {
-    VehicleBreak::Required { time: VehicleRequiredBreakTime::OffsetTime { earliest, latest }, duration }
+    VehicleBreak::Required {
+        time: VehicleRequiredBreakTime::OffsetTime { earliest, latest },
+        duration,
+        skippable_to_assign: None,
+        min_jobs_before_break: None,
+    }
 }
 
 fn create_vehicle_shift_with_breaks(breaks: Vec<VehicleBreak>) -> VehicleShift {
@@ -156,7 +156,11 @@ fn can_skip_break_when_jobs_completed_impl(policy: Option<VehicleOptionalBreakPo
                     .statistic(StatisticBuilder::default().driving(2).serving(10).build())
                     .build()
             )
-            .violations(Some(vec![Violation::Break { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]))
+            .violations(Some(vec![Violation::Break {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: 0,
+                reason: None
+            }]))
             .build()
     );
 }
@@ -293,3 +297,50 @@ fn can_skip_break_depending_on_policy_impl(
     assert!(solution.violations.is_none());
     assert_eq!(solution.statistic.times.break_time, expected);
 }
+
+parameterized_test! {can_mix_required_break_with_optional_break_policy, (optional_time, expected), {
+    can_mix_required_break_with_optional_break_policy_impl(optional_time, expected);
+}}
+
+can_mix_required_break_with_optional_break_policy! {
+    case_01: ((5., 8.), 4),
+    case_02: ((5., 20.), 2),
+}
+
+fn can_mix_required_break_with_optional_break_policy_impl(optional_time: (Timestamp, Timestamp), expected: i64) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_duration("job1", (5., 0.), 0.)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![
+                        VehicleBreak::Required {
+                            time: VehicleRequiredBreakTime::OffsetTime { earliest: 2., latest: 2. },
+                            duration: 2.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
+                        },
+                        VehicleBreak::Optional {
+                            time: VehicleOptionalBreakTime::TimeWindow(vec![
+                                format_time(optional_time.0),
+                                format_time(optional_time.1),
+                            ]),
+                            places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
+                            policy: Some(VehicleOptionalBreakPolicy::SkipIfArrivalBeforeEnd),
+                        },
+                    ]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.violations.is_none());
+    assert_eq!(solution.statistic.times.break_time, expected);
+}
@@ -0,0 +1,149 @@
+use crate::format::BreakSourceOutputExtraProperty;
+use crate::format::problem::*;
+use crate::format::solution::{Solution, Stop, create_solution, reinsert_breaks};
+use crate::format_time;
+use crate::helpers::*;
+use crate::parse_time;
+use std::sync::Arc;
+use vrp_core::construction::enablers::{ReservedTimeSpan, ReservedTimesIndex};
+use vrp_core::models::Problem as CoreProblem;
+use vrp_core::models::common::{TimeOffset, TimeSpan};
+use vrp_core::solver::processing::ReservedTimesExtraProperty;
+
+fn create_test_problem(break_offset: f64, break_duration: f64) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_duration("job1", (10., 0.), 1.),
+                create_delivery_job_with_duration("job2", (20., 0.), 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    end: None,
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::OffsetTime { earliest: break_offset, latest: break_offset },
+                        duration: break_duration,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_job_ids(solution: &Solution) -> Vec<String> {
+    get_ids_from_tour(&solution.tours[0]).into_iter().flatten().filter(|id| id != "break").collect()
+}
+
+fn get_point_stop_schedules(solution: &Solution) -> Vec<(String, String)> {
+    solution.tours[0].point_stops().map(|stop| (stop.time.arrival.clone(), stop.time.departure.clone())).collect()
+}
+
+fn get_expected_schedules(schedules: &[(i32, i32)]) -> Vec<(String, String)> {
+    schedules.iter().map(|&(arrival, departure)| (format_time(arrival as f64), format_time(departure as f64))).collect()
+}
+
+fn get_break_starts(solution: &Solution) -> Vec<f64> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| {
+            stop.activities().iter().filter(|activity| activity.activity_type == "break").map(move |activity| {
+                activity.time.as_ref().map_or_else(
+                    || match stop {
+                        Stop::Point(point) => parse_time(&point.time.arrival),
+                        Stop::Transit(transit) => parse_time(&transit.time.arrival),
+                    },
+                    |time| parse_time(&time.start),
+                )
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn can_reinsert_breaks_without_rerouting() {
+    let problem = create_test_problem(5., 2.);
+    let matrix = create_matrix_from_problem(&problem);
+    let (core_problem, core_solution) = solve_core_with_metaheuristic(problem, Some(vec![matrix.clone()]));
+    let reserved_times_index: ReservedTimesIndex = core_problem
+        .extras
+        .get_reserved_times()
+        .expect("no reserved times")
+        .keys()
+        .map(|actor| {
+            let time = TimeSpan::Offset(TimeOffset::new(15., 15.));
+            (actor.clone(), vec![ReservedTimeSpan { time, duration: 4. }])
+        })
+        .collect();
+
+    let original = create_solution(&core_problem, &core_solution, &Default::default());
+    let reinserted = reinsert_breaks(&core_problem, &core_solution, &reserved_times_index).expect("cannot reinsert");
+
+    assert!(core_solution.unassigned.is_empty());
+    assert_eq!(get_job_ids(&reinserted), get_job_ids(&original));
+    assert_eq!(get_break_starts(&original), vec![5.]);
+    assert_eq!(get_break_starts(&reinserted), vec![15.]);
+    assert_eq!(get_point_stop_schedules(&original), get_expected_schedules(&[(0, 0), (12, 13), (23, 24)]));
+    assert_eq!(get_point_stop_schedules(&reinserted), get_expected_schedules(&[(0, 0), (10, 11), (25, 26)]));
+    check_solution(core_problem, &create_test_problem(15., 4.), &Some(vec![matrix]), &reinserted);
+}
+
+#[test]
+fn can_rebuild_break_source_ids_when_breaks_are_reinserted() {
+    let problem = create_test_problem(5., 2.);
+    let matrix = create_matrix_from_problem(&problem);
+    let (core_problem, core_solution) = solve_core_with_metaheuristic(problem, Some(vec![matrix]));
+    let core_problem = CoreProblem {
+        fleet: core_problem.fleet.clone(),
+        jobs: core_problem.jobs.clone(),
+        locks: core_problem.locks.clone(),
+        goal: core_problem.goal.clone(),
+        activity: core_problem.activity.clone(),
+        transport: core_problem.transport.clone(),
+        extras: {
+            let mut extras = core_problem.extras.as_ref().clone();
+            extras.set_break_source_output(Arc::new(true));
+            Arc::new(extras)
+        },
+    };
+    let reserved_times_index: ReservedTimesIndex = core_problem
+        .extras
+        .get_reserved_times()
+        .expect("no reserved times")
+        .iter()
+        .map(|(actor, times)| {
+            // NOTE a new break is added before the original one, so positions of source ids are changed
+            let time = TimeSpan::Offset(TimeOffset::new(15., 15.));
+            (
+                actor.clone(),
+                std::iter::once(ReservedTimeSpan { time, duration: 4. }).chain(times.iter().cloned()).collect(),
+            )
+        })
+        .collect();
+
+    let reinserted = reinsert_breaks(&core_problem, &core_solution, &reserved_times_index).expect("cannot reinsert");
+
+    let source_ids = reinserted.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "break")
+        .map(|activity| activity.source_id.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(get_break_starts(&reinserted), vec![5., 15.]);
+    assert_eq!(source_ids, vec![Some("my_vehicle_1_required_break_0_1".to_string()), None]);
+}
@@ -0,0 +1,115 @@
+use crate::format::problem::*;
+use crate::format::solution::Solution;
+use crate::helpers::*;
+use crate::parse_time;
+use vrp_core::models::common::Timestamp;
+
+fn get_activity_times(solution: &Solution, activity_type: &str) -> Vec<(Timestamp, Timestamp)> {
+    solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.activity_type == activity_type)
+        .map(|(stop, activity)| match activity.time.as_ref() {
+            Some(time) => (parse_time(&time.start), parse_time(&time.end)),
+            None => (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)),
+        })
+        .collect()
+}
+
+#[test]
+fn can_anchor_offset_break_to_reload() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(10, 12)], 20.),
+                create_delivery_job_with_times("job2", (-10., 0.), vec![(80, 1000)], 20.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::AnchoredOffsetTime {
+                            earliest: 30.,
+                            latest: 30.,
+                            anchor: BreakOffsetAnchor::Reload,
+                        },
+                        duration: 5.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    reloads: Some(vec![create_default_reload()]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let get_times = |activity_type: &str| get_activity_times(&solution, activity_type);
+    // NOTE the second break is taken while waiting for job2 and it is anchored to the reload, not to the tour start
+    let (_, tour_departure) = get_times("departure")[0];
+    let (_, reload_departure) = get_times("reload")[0];
+    let break_starts = get_times("break").into_iter().map(|(start, _)| start).collect::<Vec<_>>();
+    assert!(reload_departure > tour_departure + 30.);
+    assert_eq!(break_starts, vec![tour_departure + 30., reload_departure + 30.]);
+    assert_eq!(solution.tours[0].statistic.times.break_time, 10);
+}
+
+#[test]
+fn can_take_offset_break_in_each_reload_period() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_duration("job1", (10., 0.), 1.),
+                create_delivery_job_with_duration("job2", (10., 0.), 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::AnchoredOffsetTime {
+                            earliest: 15.,
+                            latest: 15.,
+                            anchor: BreakOffsetAnchor::Reload,
+                        },
+                        duration: 5.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    reloads: Some(vec![create_default_reload()]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let get_times = |activity_type: &str| get_activity_times(&solution, activity_type);
+    // NOTE each working period has its own break: the first is anchored to the tour start, the second to the reload
+    let (_, tour_departure) = get_times("departure")[0];
+    let (_, reload_departure) = get_times("reload")[0];
+    let break_starts = get_times("break").into_iter().map(|(start, _)| start).collect::<Vec<_>>();
+    assert_eq!(break_starts, vec![tour_departure + 15., reload_departure + 15.]);
+    assert_eq!(solution.tours[0].statistic.times.break_time, 10);
+}
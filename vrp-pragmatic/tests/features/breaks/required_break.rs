@@ -1,7 +1,10 @@
 use crate::format::Location;
+use crate::format::RequiredBreakSkipHandlerExtraProperty;
 use crate::format::problem::*;
+use crate::format::solution::{RequiredBreakSkip, RequiredBreakSkipFn, RequiredBreakSkipReason};
 use crate::format_time;
 use crate::helpers::*;
+use std::sync::{Arc, Mutex};
 
 fn create_shift_start() -> ShiftStart {
     ShiftStart { earliest: format_time(0.), latest: Some(format_time(0.)), location: (0., 0.).to_loc() }
@@ -33,8 +36,14 @@ fn can_assign_break_during_travel() {
     let problem = create_problem(
         vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (10., 0.))],
         VehicleBreak::Required {
-            time: VehicleRequiredBreakTime::ExactTime { earliest: format_time(7.), latest: format_time(7.) },
+            time: VehicleRequiredBreakTime::ExactTime {
+                earliest: format_time(7.),
+                latest: format_time(7.),
+                utc_offset: None,
+            },
             duration: 2.,
+            skippable_to_assign: None,
+            min_jobs_before_break: None,
         },
         is_open,
     );
@@ -86,8 +95,14 @@ fn can_assign_break_during_activity() {
     let problem = create_problem(
         vec![create_delivery_job_with_duration("job1", (5., 0.), 3.)],
         VehicleBreak::Required {
-            time: VehicleRequiredBreakTime::ExactTime { earliest: format_time(7.), latest: format_time(7.) },
+            time: VehicleRequiredBreakTime::ExactTime {
+                earliest: format_time(7.),
+                latest: format_time(7.),
+                utc_offset: None,
+            },
             duration: 2.,
+            skippable_to_assign: None,
+            min_jobs_before_break: None,
         },
         is_open,
     );
@@ -140,8 +155,14 @@ fn can_handle_required_break_when_its_start_falls_at_activity_end() {
     let problem = create_problem(
         vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (10., 0.))],
         VehicleBreak::Required {
-            time: VehicleRequiredBreakTime::ExactTime { earliest: format_time(6.), latest: format_time(6.) },
+            time: VehicleRequiredBreakTime::ExactTime {
+                earliest: format_time(6.),
+                latest: format_time(6.),
+                utc_offset: None,
+            },
             duration: 2.,
+            skippable_to_assign: None,
+            min_jobs_before_break: None,
         },
         is_open,
     );
@@ -187,8 +208,14 @@ fn can_skip_break_if_it_is_after_start_before_end_range() {
     let problem = create_problem(
         vec![create_delivery_job("job1", (5., 0.))],
         VehicleBreak::Required {
-            time: VehicleRequiredBreakTime::ExactTime { earliest: format_time(5.), latest: format_time(7.) },
+            time: VehicleRequiredBreakTime::ExactTime {
+                earliest: format_time(5.),
+                latest: format_time(7.),
+                utc_offset: None,
+            },
             duration: 2.,
+            skippable_to_assign: None,
+            min_jobs_before_break: None,
         },
         is_open,
     );
@@ -205,8 +232,14 @@ fn can_reschedule_break_early_from_transport_to_activity() {
     let problem = create_problem(
         vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (10., 0.))],
         VehicleBreak::Required {
-            time: VehicleRequiredBreakTime::ExactTime { earliest: format_time(4.), latest: format_time(7.) },
+            time: VehicleRequiredBreakTime::ExactTime {
+                earliest: format_time(4.),
+                latest: format_time(7.),
+                utc_offset: None,
+            },
             duration: 2.,
+            skippable_to_assign: None,
+            min_jobs_before_break: None,
         },
         is_open,
     );
@@ -262,6 +295,7 @@ fn can_handle_required_break_with_infeasible_sequence_relation() {
                 duration,
                 times: Some(vec![vec![times.0, times.1]]),
                 tag: None,
+                parallel_service: None,
             }],
             demand: None,
             order: None,
@@ -303,6 +337,8 @@ fn can_handle_required_break_with_infeasible_sequence_relation() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 15303., latest: 15303. },
                         duration: 1800.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -335,3 +371,124 @@ fn can_handle_required_break_with_infeasible_sequence_relation() {
     assert_eq!(solution.unassigned.as_ref().map(|u| u.len()), Some(1));
     assert_eq!(solution.unassigned.as_ref().and_then(|u| u.first()).map(|j| j.job_id.as_str()), Some("0"));
 }
+
+#[test]
+fn can_notify_about_required_break_skipped_after_tour_end() {
+    let problem = create_problem(
+        vec![create_delivery_job("job1", (5., 0.))],
+        VehicleBreak::Required {
+            time: VehicleRequiredBreakTime::OffsetTime { earliest: 12., latest: 12. },
+            duration: 2.,
+            skippable_to_assign: None,
+            min_jobs_before_break: None,
+        },
+        false,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+    let skipped_breaks = Arc::new(Mutex::new(Vec::<RequiredBreakSkip>::new()));
+
+    let solution = solve_with_metaheuristic_and_extras(problem, Some(vec![matrix]), |extras| {
+        let skipped_breaks = skipped_breaks.clone();
+        let skip_handler: RequiredBreakSkipFn =
+            Arc::new(move |skip: &RequiredBreakSkip| skipped_breaks.lock().unwrap().push(skip.clone()));
+        extras.set_required_break_skip_handler(Arc::new(skip_handler));
+    });
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(
+        skipped_breaks.lock().unwrap().as_slice(),
+        &[RequiredBreakSkip {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: 0,
+            reason: RequiredBreakSkipReason::AfterTourEnd,
+        }]
+    );
+}
+
+#[test]
+fn can_skip_and_notify_about_required_break_outside_shift() {
+    // tour ends at 996, while the exact break at [995, 1005] exceeds shift end at 1000
+    let problem = create_problem(
+        vec![create_delivery_job_with_times("job1", (5., 0.), vec![(990, 995)], 1.)],
+        VehicleBreak::Required {
+            time: VehicleRequiredBreakTime::ExactTime {
+                earliest: format_time(995.),
+                latest: format_time(995.),
+                utc_offset: None,
+            },
+            duration: 10.,
+            skippable_to_assign: None,
+            min_jobs_before_break: None,
+        },
+        false,
+    );
+    let matrix = create_matrix_from_problem(&problem);
+    let skipped_breaks = Arc::new(Mutex::new(Vec::<RequiredBreakSkip>::new()));
+
+    let solution = solve_with_metaheuristic_and_extras(problem, Some(vec![matrix]), |extras| {
+        let skipped_breaks = skipped_breaks.clone();
+        let skip_handler: RequiredBreakSkipFn =
+            Arc::new(move |skip: &RequiredBreakSkip| skipped_breaks.lock().unwrap().push(skip.clone()));
+        extras.set_required_break_skip_handler(Arc::new(skip_handler));
+    });
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert!(
+        !solution.tours[0]
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .any(|activity| activity.activity_type == "break")
+    );
+    assert_eq!(solution.tours[0].stops.last().unwrap().schedule().arrival, format_time(996.));
+    assert_eq!(
+        skipped_breaks.lock().unwrap().as_slice(),
+        &[RequiredBreakSkip {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: 0,
+            reason: RequiredBreakSkipReason::OutsideShift,
+        }]
+    );
+}
+
+#[test]
+fn can_take_break_during_waiting_time_without_extending_tour() {
+    let create_test_problem = |has_break: bool| {
+        let mut problem = create_problem(
+            vec![create_delivery_job_with_times("job1", (5., 0.), vec![(20, 30)], 1.)],
+            VehicleBreak::Required {
+                time: VehicleRequiredBreakTime::ExactTime {
+                    earliest: format_time(10.),
+                    latest: format_time(10.),
+                    utc_offset: None,
+                },
+                duration: 5.,
+                skippable_to_assign: None,
+                min_jobs_before_break: None,
+            },
+            false,
+        );
+        if !has_break {
+            problem.fleet.vehicles[0].shifts[0].breaks = None;
+        }
+        problem
+    };
+    let solve = |problem: Problem| {
+        let matrix = create_matrix_from_problem(&problem);
+        solve_with_metaheuristic(problem, Some(vec![matrix]))
+    };
+
+    let without_break = solve(create_test_problem(false));
+    let with_break = solve(create_test_problem(true));
+
+    let statistic = &with_break.statistic;
+    assert_eq!(statistic.duration, without_break.statistic.duration);
+    assert_eq!(statistic.times.break_time, 5);
+    assert_eq!(statistic.times.waiting, without_break.statistic.times.waiting - 5);
+    assert_eq!(
+        statistic.duration,
+        statistic.times.driving + statistic.times.serving + statistic.times.waiting + statistic.times.break_time
+    );
+}
@@ -189,7 +189,13 @@ fn validate_solution_breaks(solution: &Solution, expected_break_count: usize, ex
 
 /// Debug formatter for a tour — prints all stops with activities, times, and locations.
 fn format_tour_debug(tour: &Tour) -> String {
-    let mut lines = vec![format!("vehicle={} shift={}", tour.vehicle_id, tour.shift_index)];
+    let mut lines = vec![format!(
+        "vehicle={} shift={} points={} transits={}",
+        tour.vehicle_id,
+        tour.shift_index,
+        tour.point_stops().count(),
+        tour.transit_stops().count()
+    )];
     for (i, stop) in tour.stops.iter().enumerate() {
         let s = stop.schedule();
         let loc = stop.location().map(|l| format!("{l:?}")).unwrap_or_default();
@@ -228,6 +234,8 @@ fn can_assign_offset_break_with_flexible_departure() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 7., latest: 7. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -268,6 +276,8 @@ fn can_assign_offset_break_with_wide_end_window_and_late_jobs() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 7., latest: 7. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -300,6 +310,8 @@ fn can_assign_offset_break_with_recede_departure() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 7., latest: 7. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -345,12 +357,17 @@ fn can_handle_mixed_break_types_in_validation() {
                             time: VehicleRequiredBreakTime::ExactTime {
                                 earliest: format_time(7.),
                                 latest: format_time(7.),
+                                utc_offset: None,
                             },
                             duration: 2.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
                         },
                         VehicleBreak::Required {
                             time: VehicleRequiredBreakTime::OffsetTime { earliest: 22., latest: 22. },
                             duration: 2.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
                         },
                     ]),
                     ..create_default_vehicle_shift()
@@ -394,6 +411,7 @@ fn can_assign_offset_break_with_first_job_cost_span() {
                     distance: 1.,
                     time: 1.,
                     span: Some(RouteCostSpan::FirstJobToLastJob),
+                    exclude_break_time: None,
                 },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
@@ -405,6 +423,8 @@ fn can_assign_offset_break_with_first_job_cost_span() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 7., latest: 7. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -432,6 +452,53 @@ fn can_assign_offset_break_with_first_job_cost_span() {
     );
 }
 
+#[test]
+fn can_anchor_offset_break_to_departure_when_first_job_is_at_depot() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (0., 0.), vec![(0, 5)], 1.),
+                create_delivery_job("job2", (20., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts {
+                    fixed: Some(10.),
+                    distance: 1.,
+                    time: 1.,
+                    span: Some(RouteCostSpan::FirstJobToLastJob),
+                    exclude_break_time: None,
+                },
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(200.), location: (0., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::OffsetTime { earliest: 7., latest: 7. },
+                        duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic_and_iterations_without_check(problem, Some(vec![matrix]), 200);
+
+    assert!(solution.unassigned.is_none());
+    validate_solution_breaks(&solution, 1, 2.0);
+
+    let intervals = collect_activity_intervals(&solution.tours[0]);
+    let departure = intervals.iter().find(|(_, _, t, _)| t == "departure").expect("departure missing");
+    let brk = intervals.iter().find(|(_, _, t, _)| t == "break").expect("break missing");
+    assert_eq!(brk.0 - departure.1, 7., "break should be anchored to departure (at {}), got {}", departure.1, brk.0);
+}
+
 #[test]
 fn can_assign_offset_break_with_first_job_span_and_range_offset() {
     let problem = Problem {
@@ -450,6 +517,7 @@ fn can_assign_offset_break_with_first_job_span_and_range_offset() {
                     distance: 1.,
                     time: 1.,
                     span: Some(RouteCostSpan::FirstJobToLastJob),
+                    exclude_break_time: None,
                 },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
@@ -461,6 +529,8 @@ fn can_assign_offset_break_with_first_job_span_and_range_offset() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 7., latest: 12. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -521,6 +591,8 @@ fn can_assign_wide_range_offset_break_during_long_travel() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 4., latest: 40. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -561,6 +633,8 @@ fn can_place_wide_offset_break_on_transit_leg_with_consistent_times() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 4., latest: 40. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -658,6 +732,60 @@ fn can_place_wide_offset_break_on_transit_leg_with_consistent_times() {
     assert!((offset - 40.0).abs() <= 1.0, "break offset from tour departure should be near 40, got {offset}\n{debug}");
 }
 
+#[test]
+fn can_place_wide_range_offset_break_deterministically() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (5., 0.), vec![(0, 10)], 1.),
+                create_delivery_job_with_times("job2", (50., 0.), vec![(40, 100)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(500.), location: (0., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::OffsetTime { earliest: 4., latest: 40. },
+                        duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let get_break_starts = |problem: Problem| {
+        let matrix = create_matrix_from_problem(&problem);
+        let solution = solve_with_metaheuristic_and_iterations_without_check(problem, Some(vec![matrix]), 200);
+        assert!(solution.unassigned.is_none());
+
+        solution
+            .tours
+            .iter()
+            .flat_map(collect_activity_intervals)
+            .filter(|(_, _, activity_type, _)| activity_type == "break")
+            .map(|(start, ..)| start)
+            .collect::<Vec<_>>()
+    };
+
+    let first = get_break_starts(problem.clone());
+    let second = get_break_starts(problem);
+
+    assert_eq!(first.len(), 1);
+    assert_eq!(first, second);
+}
+
 #[test]
 fn can_keep_job_activity_duration_when_break_starts_at_activity_end_on_same_stop() {
     // Boundary regression: required break starts exactly when job1 activity ends.
@@ -682,6 +810,8 @@ fn can_keep_job_activity_duration_when_break_starts_at_activity_end_on_same_stop
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 5., latest: 6. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -808,8 +938,11 @@ fn can_align_required_break_to_job_boundary_when_reserved_time_hits_mid_activity
                         time: VehicleRequiredBreakTime::ExactTime {
                             earliest: format_time(7.),
                             latest: format_time(7.),
+                            utc_offset: None,
                         },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -892,6 +1025,8 @@ fn can_skip_required_break_when_it_starts_at_tour_end_boundary() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 11., latest: 11. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -957,6 +1092,8 @@ fn can_assign_range_offset_break_without_wrong_departure_shift() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 4., latest: 12. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -1006,6 +1143,8 @@ fn can_assign_break_with_many_closely_spaced_jobs_and_long_service() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 10., latest: 15. },
                         duration: 3.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -1060,6 +1199,8 @@ fn can_assign_break_with_pickup_delivery_jobs() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 8., latest: 12. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -1102,6 +1243,8 @@ fn can_assign_break_with_tight_time_windows_and_long_break() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 8., latest: 12. },
                         duration: 5.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -1148,6 +1291,8 @@ fn can_assign_break_with_multiple_vehicles() {
                         breaks: Some(vec![VehicleBreak::Required {
                             time: VehicleRequiredBreakTime::OffsetTime { earliest: 8., latest: 8. },
                             duration: 2.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
                         }]),
                         ..create_default_vehicle_shift()
                     }],
@@ -1167,6 +1312,8 @@ fn can_assign_break_with_multiple_vehicles() {
                         breaks: Some(vec![VehicleBreak::Required {
                             time: VehicleRequiredBreakTime::OffsetTime { earliest: 10., latest: 10. },
                             duration: 3.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
                         }]),
                         ..create_default_vehicle_shift()
                     }],
@@ -1218,6 +1365,8 @@ fn can_assign_break_with_flexible_departure_and_many_jobs_clustered() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 15., latest: 25. },
                         duration: 3.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -1257,12 +1406,15 @@ fn can_assign_break_with_first_job_span_flexible_departure_and_wide_offset() {
                     distance: 1.,
                     time: 1.,
                     span: Some(RouteCostSpan::FirstJobToLastJob),
+                    exclude_break_time: None,
                 },
                 shifts: vec![VehicleShift {
                     end: Some(ShiftEnd { earliest: None, latest: format_time(500.), location: (0., 0.).to_loc() }),
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 4., latest: 10. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -1317,12 +1469,15 @@ fn can_assign_break_with_first_job_span_late_time_windows_and_wide_offset() {
                     distance: 1.,
                     time: 1.,
                     span: Some(RouteCostSpan::FirstJobToLastJob),
+                    exclude_break_time: None,
                 },
                 shifts: vec![VehicleShift {
                     end: Some(ShiftEnd { earliest: None, latest: format_time(500.), location: (0., 0.).to_loc() }),
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 4., latest: 20. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -1368,6 +1523,8 @@ fn can_assign_break_with_jobs_requiring_long_service_times() {
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 20., latest: 25. },
                         duration: 3.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -1413,10 +1570,14 @@ fn can_assign_two_offset_breaks_with_wide_ranges() {
                         VehicleBreak::Required {
                             time: VehicleRequiredBreakTime::OffsetTime { earliest: 5., latest: 15. },
                             duration: 2.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
                         },
                         VehicleBreak::Required {
                             time: VehicleRequiredBreakTime::OffsetTime { earliest: 25., latest: 40. },
                             duration: 2.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
                         },
                     ]),
                     ..create_default_vehicle_shift()
@@ -1477,12 +1638,17 @@ fn can_assign_exact_and_offset_breaks_with_many_jobs() {
                             time: VehicleRequiredBreakTime::ExactTime {
                                 earliest: format_time(10.),
                                 latest: format_time(10.),
+                                utc_offset: None,
                             },
                             duration: 2.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
                         },
                         VehicleBreak::Required {
                             time: VehicleRequiredBreakTime::OffsetTime { earliest: 30., latest: 40. },
                             duration: 3.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
                         },
                     ]),
                     ..create_default_vehicle_shift()
@@ -1515,6 +1681,73 @@ fn can_assign_exact_and_offset_breaks_with_many_jobs() {
     validate_no_break_job_overlap(tour);
 }
 
+#[test]
+fn can_assign_offset_and_exact_breaks_in_time_order_regardless_of_declaration() {
+    // Offset break at [30, 40] is declared before the ExactTime break at t=10, but has to be taken later.
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("j1", (5., 0.)),
+                create_delivery_job("j2", (20., 0.)),
+                create_delivery_job("j3", (42., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(500.), location: (0., 0.).to_loc() }),
+                    breaks: Some(vec![
+                        VehicleBreak::Required {
+                            time: VehicleRequiredBreakTime::OffsetTime { earliest: 30., latest: 40. },
+                            duration: 3.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
+                        },
+                        VehicleBreak::Required {
+                            time: VehicleRequiredBreakTime::ExactTime {
+                                earliest: format_time(10.),
+                                latest: format_time(10.),
+                                utc_offset: None,
+                            },
+                            duration: 2.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
+                        },
+                    ]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic_and_iterations_without_check(problem, Some(vec![matrix]), 200);
+
+    assert!(solution.unassigned.is_none(), "expected all jobs assigned");
+
+    let tour = &solution.tours[0];
+    let intervals = collect_activity_intervals(tour);
+    let breaks: Vec<_> = intervals.iter().filter(|(_, _, t, _)| t == "break").collect();
+    assert_eq!(breaks.len(), 2, "expected 2 breaks\ntour: {}", format_tour_debug(tour));
+
+    let (b1_start, b1_end, _, _) = breaks[0];
+    let (b2_start, b2_end, _, _) = breaks[1];
+    assert!((b1_end - b1_start - 2.).abs() < 0.001, "exact break should go first\ntour: {}", format_tour_debug(tour));
+    assert!((b2_end - b2_start - 3.).abs() < 0.001, "offset break should go last\ntour: {}", format_tour_debug(tour));
+    assert!(b1_end <= b2_start, "breaks are not in time order\ntour: {}", format_tour_debug(tour));
+
+    validate_tour_schedule_only(tour);
+    validate_no_break_job_overlap(tour);
+}
+
 /// Validates stop schedule consistency only (no break count/duration check).
 fn validate_tour_schedule_only(tour: &Tour) {
     let mut prev_departure: Option<f64> = None;
@@ -1583,3 +1816,50 @@ fn validate_no_break_job_overlap(tour: &Tour) {
         }
     }
 }
+
+#[test]
+fn can_place_wide_range_offset_break_at_latest_offset_with_advanced_departure() {
+    // NOTE job1 time window forces departure to be advanced: the break offset is measured from the advanced
+    // departure and the break is still placed at the latest time of its range
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (5., 0.), vec![(20, 30)], 1.),
+                create_delivery_job_with_times("job2", (38., 0.), vec![(0, 200)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(500.), location: (0., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::OffsetTime { earliest: 4., latest: 40. },
+                        duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations_without_check(problem, Some(vec![matrix]), 200);
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    let departure = parse_time(&tour.stops[0].schedule().departure);
+    let break_starts = collect_activity_intervals(tour)
+        .into_iter()
+        .filter(|(_, _, activity_type, _)| activity_type == "break")
+        .map(|(start, ..)| start - departure)
+        .collect::<Vec<_>>();
+    assert!(departure > 0., "departure is expected to be advanced, tour: {}", format_tour_debug(tour));
+    assert_eq!(break_starts, vec![40.], "tour: {}", format_tour_debug(tour));
+}
@@ -0,0 +1,84 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_skip_required_break_to_assign_job() {
+    // NOTE with the break, only one job fits into the shift: 0 -> 5 -> 10 -> 0 takes 22 time units
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(30.), location: (0., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::OffsetTime { earliest: 5., latest: 10. },
+                        duration: 10.,
+                        skippable_to_assign: Some(true),
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert!(
+        solution.tours[0]
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities().iter())
+            .all(|activity| activity.activity_type != "break")
+    );
+    assert_eq!(
+        solution.violations,
+        Some(vec![Violation::Break {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: 0,
+            reason: Some("enabled_job_assignment".to_string()),
+        }])
+    );
+}
+
+#[test]
+fn can_keep_skippable_break_when_jobs_fit() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (5., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(30.), location: (0., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::OffsetTime { earliest: 5., latest: 10. },
+                        duration: 10.,
+                        skippable_to_assign: Some(true),
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert!(solution.violations.is_none());
+    assert_eq!(solution.tours[0].statistic.times.break_time, 10);
+}
@@ -0,0 +1,66 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+parameterized_test! {can_place_offset_break_with_range_straddling_anchor, (time, span, expected_break_start), {
+    can_place_offset_break_with_range_straddling_anchor_impl(time, span, expected_break_start);
+}}
+
+can_place_offset_break_with_range_straddling_anchor! {
+    case01_departure_anchor: (
+        VehicleRequiredBreakTime::OffsetTime { earliest: -5., latest: 5. }, RouteCostSpan::DepotToDepot, 6.
+    ),
+    case02_first_job_anchor: (
+        VehicleRequiredBreakTime::OffsetTime { earliest: -5., latest: 5. }, RouteCostSpan::FirstJobToLastJob, 11.
+    ),
+    case03_reload_anchor_without_reload: (
+        VehicleRequiredBreakTime::AnchoredOffsetTime { earliest: -5., latest: 5., anchor: BreakOffsetAnchor::Reload },
+        RouteCostSpan::DepotToDepot,
+        6.
+    ),
+}
+
+fn can_place_offset_break_with_range_straddling_anchor_impl(
+    time: VehicleRequiredBreakTime,
+    span: RouteCostSpan,
+    expected_break_start: f64,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (9., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time,
+                        duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                costs: VehicleCosts { span: Some(span), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let break_start = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .find(|(_, activity)| activity.activity_type == "break")
+        .map(|(stop, activity)| parse_time(activity.time.as_ref().map_or(&stop.schedule().arrival, |time| &time.start)))
+        .expect("cannot find break");
+    assert_eq!(break_start, expected_break_start);
+    assert_eq!(solution.tours[0].statistic.times.break_time, 2);
+}
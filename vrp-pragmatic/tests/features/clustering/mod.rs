@@ -45,6 +45,8 @@ impl From<ActivityData> for Activity {
                 forward: convert_expected_commute_info(fwd),
                 backward: convert_expected_commute_info(bak),
             }),
+            source_id: None,
+            break_attachment: None,
         }
     }
 }
@@ -86,6 +88,8 @@ impl From<StopData> for Stop {
                 None
             },
             load: vec![stop.load],
+            slack: None,
+            travel: None,
             activities: stop.activities.into_iter().map(ActivityData::into).collect(),
         })
     }
@@ -103,6 +107,9 @@ fn create_statistic(data: (Float, i64, i64, (i64, i64, i64, i64))) -> Statistic
             parking: data.3.3,
             ..Timing::default()
         },
+        emissions: None,
+        utilization: None,
+        scaled: None,
     }
 }
 
@@ -13,7 +13,7 @@ fn build_problem(objectives: Option<Vec<Objective>>, min_shifts: Option<VehicleM
             type_id: "vehicle_type".to_string(),
             vehicle_ids: vec!["vehicle_1".to_string(), "vehicle_2".to_string()],
             profile: create_default_vehicle_profile(),
-            costs: VehicleCosts { fixed: Some(0.), distance: 1., time: 1., span: None },
+            costs: VehicleCosts { fixed: Some(0.), distance: 1., time: 1., span: None, exclude_break_time: None },
             shifts: vec![create_default_vehicle_shift()],
             capacity: vec![10],
             skills: None,
@@ -0,0 +1,47 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+parameterized_test! {can_minimize_latest_arrival, (objectives, expected_tours, expected_latest_arrival), {
+    can_minimize_latest_arrival_impl(objectives, expected_tours, expected_latest_arrival);
+}}
+
+can_minimize_latest_arrival! {
+    case01_cost_only: (vec![Objective::MinimizeUnassigned { breaks: None }, Objective::MinimizeCost], 1, 42.),
+    case02_latest_arrival: (vec![
+        Objective::MinimizeUnassigned { breaks: None },
+        Objective::MinimizeLatestArrival,
+        Objective::MinimizeCost,
+    ], 2, 21.),
+}
+
+fn can_minimize_latest_arrival_impl(objectives: Vec<Objective>, expected_tours: usize, expected_latest_arrival: f64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (-10., 0.))],
+            ..create_empty_plan()
+        },
+        objectives: Some(objectives),
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let latest_arrival = solution
+        .tours
+        .iter()
+        .filter_map(|tour| tour.stops.last())
+        .map(|stop| parse_time(&stop.schedule().arrival))
+        .fold(0., f64::max);
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), expected_tours);
+    assert_eq!(latest_arrival, expected_latest_arrival);
+}
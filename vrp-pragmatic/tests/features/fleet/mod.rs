@@ -1,6 +1,8 @@
 mod balance_and_min_shifts;
 mod basic_multi_shift;
 mod basic_open_end;
+mod minimize_latest_arrival;
 mod multi_dimens;
+mod profile_emissions;
 mod profile_variation;
 mod unreachable_jobs;
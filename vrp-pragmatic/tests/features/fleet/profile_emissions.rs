@@ -0,0 +1,44 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+parameterized_test! {can_report_route_emissions, (job_distance, emissions, expected), {
+    can_report_route_emissions_impl(job_distance, emissions, expected);
+}}
+
+can_report_route_emissions! {
+    case01_no_emissions_model: (10., None, None),
+    case02_base_distance: (10., Some((2., None)), Some(40.)),
+    case03_double_distance: (20., Some((2., None)), Some(80.)),
+    case04_double_factor: (10., Some((4., None)), Some(80.)),
+    case05_load_weight: (10., Some((2., Some(1.))), Some(42.)),
+}
+
+fn can_report_route_emissions_impl(
+    job_distance: Float,
+    emissions: Option<(Float, Option<Float>)>,
+    expected: Option<Float>,
+) {
+    // vehicle has capacity 10 and leaves depot with one delivery, so load ratio is 0.1 till the job and 0 after it
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (job_distance, 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            profiles: vec![MatrixProfile {
+                name: "car".to_string(),
+                speed: None,
+                emissions: emissions.map(|(factor, load_weight)| ProfileEmissions { factor, load_weight }),
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.distance, (job_distance * 2.) as i64);
+    assert_eq!(solution.tours[0].statistic.emissions, expected);
+    assert_eq!(solution.statistic.emissions, expected);
+}
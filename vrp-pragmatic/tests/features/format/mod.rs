@@ -1,2 +1,4 @@
 mod location_custom;
 mod location_index;
+mod statistic_unit_scale;
+mod tour_utilization;
@@ -0,0 +1,57 @@
+use crate::format::problem::*;
+use crate::format::solution::{ScaledStatistic, Solution};
+use crate::format::{DistanceOutputScaleExtraProperty, DurationOutputScaleExtraProperty};
+use crate::format_time;
+use crate::helpers::*;
+use std::sync::Arc;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (60000., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(200000.), location: (0., 0.).to_loc() }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_stop_distances(solution: &Solution) -> Vec<i64> {
+    solution.tours[0].point_stops().map(|stop| stop.distance).collect()
+}
+
+#[test]
+fn can_scale_reported_distance_and_duration() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_extras(problem, Some(vec![matrix]), |extras| {
+        extras.set_distance_output_scale(Arc::new(0.001));
+        extras.set_duration_output_scale(Arc::new(0.001));
+    });
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.statistic.distance, 120000);
+    assert_eq!(solution.statistic.scaled, Some(ScaledStatistic { distance: 120., duration: 120.001 }));
+    assert_eq!(solution.tours[0].statistic.scaled, Some(ScaledStatistic { distance: 120., duration: 120.001 }));
+    assert_eq!(get_stop_distances(&solution), vec![0, 60000, 120000]);
+}
+
+#[test]
+fn can_keep_matrix_units_by_default() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours[0].statistic.distance, 120000);
+    assert_eq!(solution.tours[0].statistic.scaled, None);
+    assert_eq!(get_stop_distances(&solution), vec![0, 60000, 120000]);
+}
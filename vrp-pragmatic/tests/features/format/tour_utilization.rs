@@ -0,0 +1,49 @@
+use crate::format::TourUtilizationOutputExtraProperty;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+use std::sync::Arc;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (5., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(22.), location: (0., 0.).to_loc() }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_report_tour_utilization_for_half_used_shift() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_extras(problem, Some(vec![matrix]), |extras| {
+        extras.set_tour_utilization_output(Arc::new(true));
+    });
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours[0].statistic.duration, 11);
+    let utilization = solution.tours[0].statistic.utilization.expect("no utilization");
+    assert!((utilization - 0.5).abs() < 1E-6);
+    assert_eq!(solution.statistic.utilization, None);
+}
+
+#[test]
+fn can_omit_tour_utilization_by_default() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours[0].statistic.utilization, None);
+}
@@ -18,13 +18,15 @@ fn create_named_vehicle_with_job_time_constraints(
         shifts: vec![VehicleShift {
             start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
             end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
-            breaks: None,
-            reloads: None,
-            recharges: None,
             job_times: Some(JobTimeConstraints {
                 earliest_first: earliest_first.map(format_time),
                 latest_last: latest_last.map(format_time),
+                first_job_reference: None,
+                last_job_reference: None,
+                departure_reference: None,
+                daylight: None,
             }),
+            ..VehicleShift::default()
         }],
         ..create_default_vehicle_type()
     }
@@ -38,13 +40,15 @@ fn create_open_route_vehicle_with_job_time_constraints(
         shifts: vec![VehicleShift {
             start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
             end: None, // Open route - no return to depot
-            breaks: None,
-            reloads: None,
-            recharges: None,
             job_times: Some(JobTimeConstraints {
                 earliest_first: earliest_first.map(format_time),
                 latest_last: latest_last.map(format_time),
+                first_job_reference: None,
+                last_job_reference: None,
+                departure_reference: None,
+                daylight: None,
             }),
+            ..VehicleShift::default()
         }],
         ..create_default_vehicle_type()
     }
@@ -346,16 +350,22 @@ fn can_work_with_depot_to_depot_span() {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
-                    breaks: None,
-                    reloads: None,
-                    recharges: None,
-                    job_times: Some(JobTimeConstraints { earliest_first: Some(format_time(10.)), latest_last: None }),
+                    job_times: Some(JobTimeConstraints {
+                        earliest_first: Some(format_time(10.)),
+                        latest_last: None,
+                        first_job_reference: None,
+                        last_job_reference: None,
+                        departure_reference: None,
+                        daylight: None,
+                    }),
+                    ..VehicleShift::default()
                 }],
                 costs: VehicleCosts {
                     fixed: Some(10.),
                     distance: 1.,
                     time: 1.,
                     span: Some(RouteCostSpan::DepotToDepot), // Explicit depot-to-depot
+                    exclude_break_time: None,
                 },
                 ..create_default_vehicle_type()
             }],
@@ -621,3 +631,257 @@ fn can_reject_job_on_open_route_when_latest_last_violated() {
     assert!(solution.unassigned.is_some(), "Job should be rejected on open route");
     assert_eq!(solution.unassigned.as_ref().unwrap()[0].job_id, "job1");
 }
+
+parameterized_test! {can_use_first_job_reference, (first_job_reference, expected_assigned), {
+    can_use_first_job_reference_impl(first_job_reference, expected_assigned);
+}}
+
+can_use_first_job_reference! {
+    case01_default: (None, true),
+    case02_service_start: (Some(FirstJobReference::ServiceStart), true),
+    case03_arrival: (Some(FirstJobReference::Arrival), false),
+}
+
+fn can_use_first_job_reference_impl(first_job_reference: Option<FirstJobReference>, expected_assigned: bool) {
+    // Job is at location (5, 0), so arrival at 5 time units, earliest_first is 10:
+    // - service start reference allows to wait on site and serve the job at 10
+    // - arrival reference forbids arriving before 10
+    let mut vehicle = create_vehicle_with_job_time_constraints(Some(10.), None);
+    vehicle.shifts[0].job_times.as_mut().unwrap().first_job_reference = first_job_reference;
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (5., 0.), vec![(0, 100)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![vehicle], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    if expected_assigned {
+        assert!(solution.unassigned.is_none(), "Job should be assigned");
+        assert_eq!(solution.tours.len(), 1);
+    } else {
+        assert!(solution.tours.is_empty());
+        assert_eq!(solution.unassigned.as_ref().unwrap()[0].reasons[0].code, "JOB_TIME_CONSTRAINT");
+    }
+}
+
+parameterized_test! {can_use_last_job_reference, (last_job_reference, expected_assigned), {
+    can_use_last_job_reference_impl(last_job_reference, expected_assigned);
+}}
+
+can_use_last_job_reference! {
+    case01_default: (None, false),
+    case02_departure: (Some(LastJobReference::Departure), false),
+    case03_arrival: (Some(LastJobReference::Arrival), true),
+}
+
+fn can_use_last_job_reference_impl(last_job_reference: Option<LastJobReference>, expected_assigned: bool) {
+    // Job is at location (5, 0) with long service of 20 time units, latest_last is 10:
+    // - departure reference forbids leaving the job at 25
+    // - arrival reference allows arriving at 5 and finishing the service later
+    let mut vehicle = create_vehicle_with_job_time_constraints(None, Some(10.));
+    vehicle.shifts[0].job_times.as_mut().unwrap().last_job_reference = last_job_reference;
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (5., 0.), vec![(0, 100)], 20.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![vehicle], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    if expected_assigned {
+        assert!(solution.unassigned.is_none(), "Job should be assigned");
+        assert_eq!(solution.tours.len(), 1);
+    } else {
+        assert!(solution.tours.is_empty());
+        assert_eq!(solution.unassigned.as_ref().unwrap()[0].reasons[0].code, "JOB_TIME_CONSTRAINT");
+    }
+}
+
+parameterized_test! {can_apply_earliest_first_on_open_start_route, (span, job_end, expected_assigned), {
+    can_apply_earliest_first_on_open_start_route_impl(span, job_end, expected_assigned);
+}}
+
+can_apply_earliest_first_on_open_start_route! {
+    case01_first_job_to_depot_can_wait: (RouteCostSpan::FirstJobToDepot, 100, true),
+    case02_first_job_to_depot_cannot_wait: (RouteCostSpan::FirstJobToDepot, 8, false),
+    case03_first_job_to_last_job_can_wait: (RouteCostSpan::FirstJobToLastJob, 100, true),
+    case04_first_job_to_last_job_cannot_wait: (RouteCostSpan::FirstJobToLastJob, 8, false),
+}
+
+fn can_apply_earliest_first_on_open_start_route_impl(span: RouteCostSpan, job_end: i32, expected_assigned: bool) {
+    // Open start: route costs are counted from the first job, but the first job is still the one
+    // served right after the shift start, so earliest_first applies to it.
+    // Job is at location (5, 0), so arrival at 5 time units, earliest_first is 10.
+    let mut vehicle = create_vehicle_with_job_time_constraints(Some(10.), None);
+    vehicle.costs.span = Some(span);
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (5., 0.), vec![(0, job_end)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![vehicle], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    if expected_assigned {
+        assert!(solution.unassigned.is_none(), "Job should be assigned");
+        assert_eq!(solution.tours.len(), 1);
+    } else {
+        assert!(solution.tours.is_empty());
+        assert_eq!(solution.unassigned.as_ref().unwrap()[0].reasons[0].code, "JOB_TIME_CONSTRAINT");
+    }
+}
+
+parameterized_test! {can_assign_job_to_feasible_vehicle_in_mixed_fleet, (job_times, free_shift_end, expected_vehicle), {
+    can_assign_job_to_feasible_vehicle_in_mixed_fleet_impl(job_times, free_shift_end, expected_vehicle);
+}}
+
+can_assign_job_to_feasible_vehicle_in_mixed_fleet! {
+    case01_constrained_by_earliest_first: ((Some(10.), None), 1000., "vehicle_free"),
+    case02_constrained_by_latest_last: ((None, Some(3.)), 1000., "vehicle_free"),
+    case03_feasible_earliest_first: ((Some(3.), None), 8., "vehicle_constrained"),
+    case04_feasible_latest_last: ((None, Some(50.)), 8., "vehicle_constrained"),
+}
+
+fn can_assign_job_to_feasible_vehicle_in_mixed_fleet_impl(
+    job_times: (Option<f64>, Option<f64>),
+    free_shift_end: f64,
+    expected_vehicle: &str,
+) {
+    // Job at (5, 0) with time window ending at 8: arrival at 5, departure at 6, back at depot at 11.
+    // - vehicle_constrained: has job time constraints and competes with vehicle_free for the same job
+    // - vehicle_free: has no job time constraints, feasible only when shift ends not earlier than 11
+    let (earliest_first, latest_last) = job_times;
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (5., 0.), vec![(0, 8)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_named_vehicle_with_job_time_constraints("vehicle_constrained", earliest_first, latest_last),
+                VehicleType {
+                    shifts: vec![VehicleShift {
+                        end: Some(ShiftEnd {
+                            earliest: None,
+                            latest: format_time(free_shift_end),
+                            location: (0., 0.).to_loc(),
+                        }),
+                        ..create_default_vehicle_shift()
+                    }],
+                    ..create_default_vehicle("vehicle_free")
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none(), "Job should be assigned");
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, format!("{expected_vehicle}_1"));
+}
+
+parameterized_test! {can_minimize_job_time_pressure, (objectives, expected_vehicle), {
+    can_minimize_job_time_pressure_impl(objectives, expected_vehicle);
+}}
+
+can_minimize_job_time_pressure! {
+    case01_min_cost: (
+        vec![Objective::MinimizeUnassigned { breaks: None }, Objective::MinimizeCost],
+        "vehicle_late"
+    ),
+    case02_min_job_time_pressure: (
+        vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::MinimizeJobTimePressure,
+            Objective::MinimizeCost,
+        ],
+        "vehicle_early"
+    ),
+}
+
+fn can_minimize_job_time_pressure_impl(objectives: Vec<Objective>, expected_vehicle: &str) {
+    // Job at (5, 0) with duration 1, both vehicles have latest_last at 100:
+    // - vehicle_early: starts at 0, departs from the job at 6, but has higher fixed cost
+    // - vehicle_late: starts at 90, departs from the job at 96, which is marginally feasible
+    let create_vehicle = |type_id: &str, start: f64, fixed: f64| {
+        let mut vehicle = create_named_vehicle_with_job_time_constraints(type_id, None, Some(100.));
+        vehicle.shifts[0].start.earliest = format_time(start);
+        vehicle.costs.fixed = Some(fixed);
+        vehicle
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (5., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle("vehicle_early", 0., 100.), create_vehicle("vehicle_late", 90., 10.)],
+            ..create_default_fleet()
+        },
+        objectives: Some(objectives),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, format!("{expected_vehicle}_1"));
+}
+
+parameterized_test! {can_derive_earliest_first_from_daylight, (sunrise, after_sunrise, expected_assigned), {
+    can_derive_earliest_first_from_daylight_impl(sunrise, after_sunrise, expected_assigned);
+}}
+
+can_derive_earliest_first_from_daylight! {
+    case01_pre_dawn_job: (10., None, false),
+    case02_pre_dawn_job_with_offset: (5., Some(5.), false),
+    case03_job_after_sunrise: (2., None, true),
+}
+
+fn can_derive_earliest_first_from_daylight_impl(sunrise: f64, after_sunrise: Option<f64>, expected_assigned: bool) {
+    // Job at (5, 0) with time window ending at 8: it has to be served before daylight derived earliest_first
+    let mut vehicle = create_vehicle_with_job_time_constraints(None, None);
+    if let Some(job_times) = vehicle.shifts[0].job_times.as_mut() {
+        job_times.daylight = Some(DaylightWindow {
+            sunrise: format_time(sunrise),
+            sunset: format_time(500.),
+            after_sunrise,
+            before_sunset: None,
+        });
+    }
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (5., 0.), vec![(0, 8)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![vehicle], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    if expected_assigned {
+        assert!(solution.unassigned.is_none());
+        assert_eq!(solution.tours.len(), 1);
+    } else {
+        assert!(solution.tours.is_empty());
+        assert_eq!(solution.unassigned.as_ref().unwrap()[0].reasons[0].code, "JOB_TIME_CONSTRAINT");
+    }
+}
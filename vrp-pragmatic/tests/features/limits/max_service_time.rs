@@ -0,0 +1,59 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_vehicle_type_with_max_service_time(max_service_time: Float) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift { max_service_time: Some(max_service_time), ..create_default_vehicle_shift() }],
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_reject_job_exceeding_max_service_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_duration("job1", (1., 0.), 10.),
+                create_delivery_job_with_duration("job2", (2., 0.), 50.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_type_with_max_service_time(30.)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.times.serving, 10);
+    let unassigned = solution.unassigned.expect("job2 should be unassigned");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].job_id, "job2");
+    assert_eq!(unassigned[0].reasons[0].code, "MAX_SERVICE_TIME_CONSTRAINT");
+}
+
+#[test]
+fn can_assign_jobs_on_travel_heavy_route_within_max_service_time() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_duration("job1", (100., 0.), 5.),
+                create_delivery_job_with_duration("job2", (200., 0.), 5.),
+                create_delivery_job_with_duration("job3", (300., 0.), 5.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_type_with_max_service_time(15.)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].statistic.times.serving, 15);
+    assert!(solution.tours[0].statistic.times.driving > 15);
+}
@@ -0,0 +1,114 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_vehicle_type_with_max_stops(
+    max_stops: usize,
+    breaks: Option<Vec<VehicleBreak>>,
+    reserve_break_stops: Option<bool>,
+) -> VehicleType {
+    VehicleType {
+        shifts: vec![VehicleShift {
+            max_stops: Some(max_stops),
+            reserve_break_stops,
+            breaks,
+            ..create_default_vehicle_shift()
+        }],
+        ..create_default_vehicle_type()
+    }
+}
+
+#[test]
+fn can_limit_stops_counting_jobs_at_same_location_once() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (1., 0.)),
+                create_delivery_job("job3", (2., 0.)),
+                create_delivery_job("job4", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_vehicle_type_with_max_stops(2, None, None)], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].stops.len(), 4);
+    assert!(get_ids_from_tour(&solution.tours[0]).iter().flatten().any(|id| id == "job2"));
+    let unassigned = solution.unassigned.expect("one job should be unassigned");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].reasons[0].code, "MAX_STOPS_CONSTRAINT");
+}
+
+#[test]
+fn can_reserve_stops_for_required_breaks() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_with_max_stops(
+                2,
+                Some(vec![VehicleBreak::Required {
+                    time: VehicleRequiredBreakTime::OffsetTime { earliest: 1., latest: 1. },
+                    duration: 2.,
+                    skippable_to_assign: None,
+                    min_jobs_before_break: None,
+                }]),
+                Some(true),
+            )],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(2));
+}
+
+#[test]
+fn can_count_transit_break_stops() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (10., 0.)),
+                create_delivery_job("job2", (20., 0.)),
+                create_delivery_job("job3", (30., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![create_vehicle_type_with_max_stops(
+                3,
+                Some(vec![VehicleBreak::Required {
+                    time: VehicleRequiredBreakTime::OffsetTime { earliest: 5., latest: 5. },
+                    duration: 2.,
+                    skippable_to_assign: None,
+                    min_jobs_before_break: None,
+                }]),
+                None,
+            )],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].transit_stops().count(), 1);
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(1));
+}
@@ -1,5 +1,7 @@
 mod job_times;
 mod max_distance;
 mod max_duration;
+mod max_service_time;
+mod max_stops;
 mod min_tour_size;
 mod tour_size;
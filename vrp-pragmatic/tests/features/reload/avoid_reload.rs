@@ -29,14 +29,12 @@ fn can_serve_multi_job_and_delivery_in_one_tour_avoiding_reload_impl(generations
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (0., 0.).to_loc() }),
-                    breaks: None,
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
-                    recharges: None,
-                    job_times: None,
+                    ..VehicleShift::default()
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -24,14 +24,12 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (10., 0.).to_loc() }),
-                    breaks: None,
                     reloads: Some(vec![VehicleReload {
                         location: (0., 0.).to_loc(),
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
-                    recharges: None,
-                    job_times: None,
+                    ..VehicleShift::default()
                 }],
                 capacity: vec![2],
                 ..create_default_vehicle_type()
@@ -67,7 +65,13 @@ fn can_properly_handle_load_without_capacity_violation() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: Some(20.0), distance: 0.002, time: 0.003, span: None },
+                costs: VehicleCosts {
+                    fixed: Some(20.0),
+                    distance: 0.002,
+                    time: 0.003,
+                    span: None,
+                    exclude_break_time: None,
+                },
                 shifts: vec![VehicleShift {
                     reloads: Some(vec![
                         VehicleReload {
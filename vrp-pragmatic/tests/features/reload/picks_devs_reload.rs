@@ -20,14 +20,12 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 shifts: vec![VehicleShift {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
                     end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (6., 0.).to_loc() }),
-                    breaks: None,
                     reloads: Some(vec![VehicleReload {
                         location: (3., 0.).to_loc(),
                         duration: 2.0,
                         ..create_default_reload()
                     }]),
-                    recharges: None,
-                    job_times: None,
+                    ..VehicleShift::default()
                 }],
                 capacity: vec![1],
                 ..create_default_vehicle_type()
@@ -0,0 +1 @@
+mod top_solutions;
@@ -0,0 +1,32 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_return_multiple_distinct_solutions() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=8)
+                .map(|idx| create_delivery_job(&format!("job{idx}"), (idx as f64, (idx % 3) as f64)))
+                .collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
+                capacity: vec![5],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solutions = solve_with_metaheuristic_many(problem, Some(vec![matrix]), 3);
+
+    assert_eq!(solutions.len(), 3);
+    assert!(solutions.iter().all(|solution| solution.unassigned.is_none()));
+    assert_ne!(solutions[0], solutions[1]);
+    assert_ne!(solutions[0], solutions[2]);
+    assert_ne!(solutions[1], solutions[2]);
+}
@@ -0,0 +1,46 @@
+use crate::format::DepartureBindingOutputExtraProperty;
+use crate::format::problem::*;
+use crate::format::solution::serialize_solution;
+use crate::helpers::*;
+use std::io::BufWriter;
+use std::sync::Arc;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (1., 0.), vec![(10, 20)], 0.)],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_report_departure_binding() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_extras(problem, Some(vec![matrix]), |extras| {
+        extras.set_departure_binding_output(Arc::new(true));
+    });
+
+    let mut writer = BufWriter::new(Vec::new());
+    serialize_solution(&solution, &mut writer).expect("cannot serialize solution");
+    let json: serde_json::Value =
+        serde_json::from_slice(writer.into_inner().expect("cannot get bytes").as_slice()).expect("cannot read json");
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours[0].departure_binding, Some("first_job_window".to_string()));
+    assert_eq!(json["tours"][0]["departureBinding"], "first_job_window");
+}
+
+#[test]
+fn can_omit_departure_binding_by_default() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours[0].departure_binding, None);
+}
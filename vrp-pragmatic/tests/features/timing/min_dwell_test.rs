@@ -0,0 +1,60 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+#[test]
+fn can_apply_min_dwell_to_quick_service_job() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { min_dwell: Some(5.), ..create_delivery_job_with_times("job1", (1., 0.), vec![(0, 1)], 1.) },
+                create_delivery_job_with_times("job2", (2., 0.), vec![(0, 100)], 1.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let schedules = solution.tours[0]
+        .stops
+        .iter()
+        .map(|stop| (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)))
+        .collect::<Vec<_>>();
+    assert_eq!(schedules, vec![(0., 0.), (1., 6.), (7., 8.), (10., 10.)]);
+    assert_eq!(solution.tours[0].statistic.times.waiting, 4);
+}
+
+#[test]
+fn can_apply_min_dwell_per_stop() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { min_dwell: Some(5.), ..create_delivery_job_with_duration("job1", (1., 0.), 1.) },
+                create_delivery_job_with_duration("job2", (1., 0.), 3.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    // NOTE service of both jobs takes 4, so the stop is delayed only by one to reach the minimum dwell
+    let schedules = solution.tours[0]
+        .stops
+        .iter()
+        .map(|stop| (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)))
+        .collect::<Vec<_>>();
+    assert_eq!(schedules, vec![(0., 0.), (1., 6.), (7., 7.)]);
+    assert_eq!(solution.tours[0].statistic.times.waiting, 1);
+}
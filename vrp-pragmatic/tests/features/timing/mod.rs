@@ -1,4 +1,10 @@
 mod basic_multiple_times;
 mod basic_waiting_time;
+mod departure_binding;
+mod min_dwell_test;
+mod parallel_service_test;
+mod soft_time_windows;
+mod stop_slack;
+mod stop_travel;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
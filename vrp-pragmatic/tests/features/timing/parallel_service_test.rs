@@ -0,0 +1,45 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::parse_time;
+use vrp_core::prelude::Float;
+
+parameterized_test! {can_serve_co_located_jobs_in_parallel, (parallel, expected_departure, expected_serving), {
+    can_serve_co_located_jobs_in_parallel_impl(parallel, expected_departure, expected_serving);
+}}
+
+can_serve_co_located_jobs_in_parallel! {
+    case01_serial: (None, 14., 13),
+    case02_parallel: (Some(true), 9., 8),
+}
+
+fn can_serve_co_located_jobs_in_parallel_impl(
+    parallel: Option<bool>,
+    expected_departure: Float,
+    expected_serving: i64,
+) {
+    let create_job = |id: &str, duration: Float| {
+        let mut job = create_delivery_job_with_duration(id, (1., 0.), duration);
+        job.deliveries.as_mut().unwrap()[0].places[0].parallel_service = parallel;
+        job
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_job("job1", 5.), create_job("job2", 8.)], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = &solution.tours[0];
+    let schedules = tour
+        .stops
+        .iter()
+        .map(|stop| (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)))
+        .collect::<Vec<_>>();
+    assert_eq!(schedules, vec![(0., 0.), (1., expected_departure), (expected_departure + 1., expected_departure + 1.)]);
+    assert_eq!(tour.statistic.times.serving, expected_serving);
+    assert_eq!(tour.statistic.times.waiting, 0);
+}
@@ -0,0 +1,85 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_soft_delivery_job(id: &str, location: (f64, f64), times: Vec<(i32, i32)>, penalty: Option<f64>) -> Job {
+    Job { soft_times: Some(true), lateness_penalty: penalty, ..create_delivery_job_with_times(id, location, times, 1.) }
+}
+
+fn get_arrival(solution: &Solution, job_id: &str) -> f64 {
+    solution.tours[0]
+        .stops
+        .iter()
+        .find(|stop| stop.activities().iter().any(|activity| activity.job_id == job_id))
+        .map(|stop| parse_time(&stop.schedule().arrival))
+        .expect("cannot find job")
+}
+
+#[test]
+fn can_serve_job_late_with_soft_time_windows() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(0, 10)], 1.),
+                create_soft_delivery_job("job2", (20., 0.), vec![(0, 12)], None),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_arrival(&solution, "job2"), 21.);
+}
+
+#[test]
+fn can_prefer_job_with_higher_lateness_penalty() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_soft_delivery_job("job1", (10., 0.), vec![(0, 5)], None),
+                create_soft_delivery_job("job2", (-10., 0.), vec![(0, 5)], Some(10.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_arrival(&solution, "job2"), 10.);
+    assert_eq!(get_arrival(&solution, "job1"), 31.);
+}
+
+#[test]
+fn can_wait_for_later_time_window_instead_of_serving_between_windows() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_soft_delivery_job("job1", (10., 0.), vec![(0, 5), (30, 40)], None)],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        objectives: Some(vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::MinimizeTours,
+            Objective::MinimizeLateness,
+            Objective::MinimizeCost,
+        ]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_arrival(&solution, "job1"), 30.);
+}
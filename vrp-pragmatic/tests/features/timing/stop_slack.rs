@@ -0,0 +1,47 @@
+use crate::format::StopSlackOutputExtraProperty;
+use crate::format::problem::*;
+use crate::format::solution::Solution;
+use crate::helpers::*;
+use std::sync::Arc;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 1)], 0.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(10, 20)], 0.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+fn get_stop_slacks(solution: &Solution) -> Vec<Option<f64>> {
+    solution.tours[0].stops.iter().map(|stop| stop.as_point().unwrap().slack).collect()
+}
+
+#[test]
+fn can_report_stop_slack() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_extras(problem, Some(vec![matrix]), |extras| {
+        extras.set_stop_slack_output(Arc::new(true));
+    });
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_stop_slacks(&solution), vec![None, Some(0.), Some(18.), None]);
+}
+
+#[test]
+fn can_omit_stop_slack_by_default() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_stop_slacks(&solution), vec![None; 4]);
+}
@@ -0,0 +1,60 @@
+use crate::format::StopTravelOutputExtraProperty;
+use crate::format::problem::*;
+use crate::format::solution::{LegTravel, Solution};
+use crate::helpers::*;
+use std::sync::Arc;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (3., 0.)),
+                create_delivery_job("job3", (7., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { span: Some(RouteCostSpan::DepotToDepot), ..create_default_vehicle_costs() },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_stop_travels(solution: &Solution) -> Vec<Option<LegTravel>> {
+    solution.tours[0].stops.iter().map(|stop| stop.as_point().unwrap().travel.clone()).collect()
+}
+
+#[test]
+fn can_report_stop_travel_summing_to_total_distance() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_extras(problem, Some(vec![matrix]), |extras| {
+        extras.set_stop_travel_output(Arc::new(true));
+    });
+
+    assert!(solution.unassigned.is_none());
+    let travels = get_stop_travels(&solution);
+    assert_eq!(travels.len(), 5);
+    assert_eq!(travels[0], None);
+    let travels = travels.into_iter().skip(1).map(|travel| travel.expect("no travel")).collect::<Vec<_>>();
+    assert_eq!(travels.iter().map(|travel| travel.distance).sum::<i64>(), solution.tours[0].statistic.distance);
+    assert_eq!(travels.iter().map(|travel| travel.distance).sum::<i64>(), 14);
+    assert_eq!(travels.iter().map(|travel| travel.duration).sum::<i64>(), solution.tours[0].statistic.times.driving);
+}
+
+#[test]
+fn can_omit_stop_travel_by_default() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_stop_travels(&solution), vec![None; 5]);
+}
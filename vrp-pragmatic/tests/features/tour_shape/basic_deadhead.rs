@@ -0,0 +1,36 @@
+use crate::format::problem::Objective::*;
+use crate::format::problem::*;
+use crate::helpers::*;
+
+#[test]
+fn can_prefer_jobs_close_to_depot_at_tour_ends() {
+    // Jobs at (1,0), (10,0) and (11,0), depot at (0,0): any tour which goes out and back has the same
+    // total distance, but the deadhead is minimal when the farthest job is served in the middle.
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("near", (1., 0.)),
+                create_delivery_job("middle", (10., 0.)),
+                create_delivery_job("far", (11., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], ..create_default_fleet() },
+        objectives: Some(vec![MinimizeUnassigned { breaks: None }, MinimizeDeadhead, MinimizeCost]),
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 500);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let job_ids = solution.tours[0]
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "delivery")
+        .map(|activity| activity.job_id.as_str())
+        .collect::<Vec<_>>();
+    assert!(job_ids == vec!["near", "far", "middle"] || job_ids == vec!["middle", "far", "near"]);
+    assert_eq!(solution.statistic.distance, 22);
+}
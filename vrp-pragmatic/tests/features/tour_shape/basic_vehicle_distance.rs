@@ -34,7 +34,11 @@ fn can_assign_jobs_to_nearest_vehicle() {
             ],
             ..create_default_fleet()
         },
-        objectives: Some(vec![MinimizeUnassigned { breaks: None }, MinimizeVehicleDistance, MinimizeCost]),
+        objectives: Some(vec![
+            MinimizeUnassigned { breaks: None },
+            MinimizeVehicleDistance { reference: None },
+            MinimizeCost,
+        ]),
     };
     let matrix = create_matrix_from_problem(&problem);
 
@@ -139,7 +143,11 @@ fn can_reduce_vehicle_distance_with_many_jobs() {
     let problem_with = Problem {
         plan: Plan { jobs, ..create_empty_plan() },
         fleet: Fleet { vehicles, ..create_default_fleet() },
-        objectives: Some(vec![MinimizeUnassigned { breaks: None }, MinimizeVehicleDistance, MinimizeCost]),
+        objectives: Some(vec![
+            MinimizeUnassigned { breaks: None },
+            MinimizeVehicleDistance { reference: None },
+            MinimizeCost,
+        ]),
     };
     let matrix_with = create_matrix_from_problem(&problem_with);
     let solution_with = solve_with_metaheuristic_and_iterations(problem_with, Some(vec![matrix_with]), 500);
@@ -1,2 +1,3 @@
+mod basic_deadhead;
 mod basic_tour_compactness;
 mod basic_vehicle_distance;
@@ -0,0 +1,71 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::solver::processing::FeasibilityReportExtraProperty;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan { jobs: vec![create_delivery_job_with_demand("job1", (1., 0.), vec![5])], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_vehicle_with_capacity("vehicle1", vec![2]),
+                VehicleType {
+                    shifts: vec![VehicleShift {
+                        end: Some(ShiftEnd { earliest: None, latest: format_time(1.), location: (0., 0.).to_loc() }),
+                        ..create_default_vehicle_shift()
+                    }],
+                    ..create_default_vehicle("vehicle2")
+                },
+            ],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+#[test]
+fn can_report_rejection_reasons_of_all_vehicles() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_extras(problem, Some(vec![matrix]), |extras| {
+        extras.set_feasibility_report(Arc::new(true));
+    });
+
+    assert!(solution.tours.is_empty());
+    let mut unassigned = solution.unassigned.expect("unassigned jobs are expected");
+    assert_eq!(unassigned.len(), 1);
+    let UnassignedJob { job_id, mut reasons } = unassigned.remove(0);
+    reasons.sort_by(|a, b| a.code.cmp(&b.code));
+    assert_eq!(job_id, "job1");
+    assert_eq!(
+        reasons,
+        vec![
+            UnassignedJobReason {
+                code: "CAPACITY_CONSTRAINT".to_string(),
+                description: "does not fit into any vehicle due to capacity".to_string(),
+                details: Some(vec![UnassignedJobDetail { vehicle_id: "vehicle1_1".to_string(), shift_index: 0 }]),
+            },
+            UnassignedJobReason {
+                code: "TIME_WINDOW_CONSTRAINT".to_string(),
+                description: "cannot be visited within time window".to_string(),
+                details: Some(vec![UnassignedJobDetail { vehicle_id: "vehicle2_1".to_string(), shift_index: 0 }]),
+            },
+        ]
+    );
+}
+
+#[test]
+fn can_report_single_reason_without_feasibility_report() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("unassigned jobs are expected");
+    assert_eq!(unassigned.len(), 1);
+    assert_eq!(unassigned[0].reasons.len(), 1);
+    assert!(unassigned[0].reasons[0].details.is_none());
+}
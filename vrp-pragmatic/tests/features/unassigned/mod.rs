@@ -1,2 +1,3 @@
+mod feasibility_report;
 mod multi_reasons;
 mod single_reason;
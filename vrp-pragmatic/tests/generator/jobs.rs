@@ -92,6 +92,9 @@ prop_compose! {
             value,
             group,
             compatibility,
+            soft_times: None,
+            lateness_penalty: None,
+            min_dwell: None,
         }
     }
 }
@@ -138,6 +141,9 @@ prop_compose! {
             value,
             group,
             compatibility,
+            soft_times: None,
+            lateness_penalty: None,
+            min_dwell: None,
         }
     }
 }
@@ -170,7 +176,7 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag }
+      JobPlace { times, location, duration, tag, parallel_service: None }
     }
 }
 
@@ -80,6 +80,7 @@ prop_compose! {
           location,
           duration,
           tag,
+          parallel_service: None,
         }
     }
 }
@@ -111,7 +112,7 @@ prop_compose! {
           breaks,
           reloads,
           recharges,
-          job_times: None,
+          ..VehicleShift::default()
         }
     }
 }
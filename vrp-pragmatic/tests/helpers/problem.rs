@@ -7,7 +7,7 @@ use vrp_core::models::common::Duration;
 use vrp_core::prelude::Float;
 
 pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., tag, parallel_service: None }
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
@@ -25,6 +25,9 @@ pub fn create_job(id: &str) -> Job {
         value: None,
         group: None,
         compatibility: None,
+        soft_times: None,
+        lateness_penalty: None,
+        min_dwell: None,
     }
 }
 
@@ -182,7 +185,13 @@ pub fn create_pickup_delivery_job_with_params(
 pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
     Job {
         deliveries: Some(vec![JobTask {
-            places: vec![JobPlace { times: None, location: Location::Reference { index }, duration: 1., tag: None }],
+            places: vec![JobPlace {
+                times: None,
+                location: Location::Reference { index },
+                duration: 1.,
+                tag: None,
+                parallel_service: None,
+            }],
             demand: Some(vec![1]),
             order: None,
             due_date: None,
@@ -229,10 +238,7 @@ pub fn create_default_open_vehicle_shift() -> VehicleShift {
     VehicleShift {
         start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
         end: None,
-        breaks: None,
-        reloads: None,
-        recharges: None,
-        job_times: None,
+        ..VehicleShift::default()
     }
 }
 
@@ -240,15 +246,12 @@ pub fn create_default_vehicle_shift_with_locations(start: (f64, f64), end: (f64,
     VehicleShift {
         start: ShiftStart { earliest: format_time(0.), latest: None, location: (start.0, start.1).to_loc() },
         end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (end.0, end.1).to_loc() }),
-        breaks: None,
-        reloads: None,
-        recharges: None,
-        job_times: None,
+        ..VehicleShift::default()
     }
 }
 
 pub fn create_default_vehicle_costs() -> VehicleCosts {
-    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., span: None }
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1., span: None, exclude_break_time: None }
 }
 
 pub fn create_default_vehicle_profile() -> VehicleProfile {
@@ -286,7 +289,7 @@ pub fn create_default_fleet() -> Fleet {
 }
 
 pub fn create_default_matrix_profiles() -> Vec<MatrixProfile> {
-    vec![MatrixProfile { name: "car".to_string(), speed: None }]
+    vec![MatrixProfile { name: "car".to_string(), speed: None, emissions: None }]
 }
 
 pub fn create_min_jobs_cost_objective() -> Option<Vec<Objective>> {
@@ -170,6 +170,8 @@ impl Default for StopBuilder {
                 distance: 0,
                 load: vec![],
                 parking: None,
+                slack: None,
+                travel: None,
                 activities: vec![],
             }),
         }
@@ -262,6 +264,8 @@ impl Default for ActivityBuilder {
                 time: None,
                 job_tag: None,
                 commute: None,
+                source_id: None,
+                break_attachment: None,
             },
         }
     }
@@ -372,6 +376,7 @@ impl Default for TourBuilder {
                 shift_index: 0,
                 stops: vec![],
                 statistic: Default::default(),
+                departure_binding: None,
             },
         }
     }
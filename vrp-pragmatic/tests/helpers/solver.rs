@@ -1,8 +1,9 @@
 use crate::checker::CheckerContext;
-use crate::format::problem::{Matrix, PragmaticProblem, Problem};
+use crate::format::problem::{BreakWindowsFn, Matrix, PragmaticProblem, Problem, read_pragmatic_with_break_windows};
 use crate::format::solution::{Solution, create_solution};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::Extras;
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::models::Solution as CoreSolution;
 use vrp_core::rosomaxa::evolution::TelemetryMode;
@@ -49,24 +50,110 @@ pub fn solve_with_metaheuristic_and_iterations_without_check(
 }
 
 pub fn solve(problem: Problem, matrices: Option<Vec<Matrix>>, generations: usize, perform_check: bool) -> Solution {
+    get_core_solution(problem, matrices, perform_check, |problem: Arc<CoreProblem>| {
+        solve_core_problem(problem, generations)
+    })
+}
+
+/// Runs solver with default metaheuristic on the problem with modified extras.
+pub fn solve_with_metaheuristic_and_extras<F: FnOnce(&mut Extras)>(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    extras_fn: F,
+) -> Solution {
+    let format_problem = problem.clone();
+    let format_matrices = matrices.clone();
+
+    let core_problem =
+        if let Some(matrices) = matrices { (problem, matrices).read_pragmatic() } else { problem.read_pragmatic() }
+            .unwrap();
+    let mut extras = core_problem.extras.as_ref().clone();
+    extras_fn(&mut extras);
+
+    let core_problem = Arc::new(CoreProblem { extras: Arc::new(extras), ..core_problem });
+    let core_solution = solve_core_problem(core_problem.clone(), 200);
+
+    let format_solution = sort_all_data(create_solution(&core_problem, &core_solution, &Default::default()));
+    check_solution(core_problem, &format_problem, &format_matrices, &format_solution);
+
+    format_solution
+}
+
+/// Runs solver with default metaheuristic using break windows produced by the given function.
+pub fn solve_with_metaheuristic_and_break_windows(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    break_windows_fn: BreakWindowsFn,
+) -> Solution {
+    let core_problem = Arc::new(read_pragmatic_with_break_windows(problem, matrices, break_windows_fn).unwrap());
+    let core_solution = solve_core_problem(core_problem.clone(), 200);
+
+    sort_all_data(create_solution(&core_problem, &core_solution, &Default::default()))
+}
+
+/// Runs solver with default metaheuristic and returns core problem and solution.
+pub fn solve_core_with_metaheuristic(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+) -> (Arc<CoreProblem>, CoreSolution) {
+    let core_problem = get_core_problem(problem, matrices);
+    let core_solution = solve_core_problem(core_problem.clone(), 200);
+
+    (core_problem, core_solution)
+}
+
+/// Runs solver with default metaheuristic and elitism population, returns up to `count` best checked solutions.
+pub fn solve_with_metaheuristic_many(problem: Problem, matrices: Option<Vec<Matrix>>, count: usize) -> Vec<Solution> {
+    let format_problem = problem.clone();
+    let format_matrices = matrices.clone();
+
+    let core_problem = get_core_problem(problem, matrices);
+    let environment = Arc::new(Environment::default());
+    let population = create_elitism_population(core_problem.goal.clone(), environment.clone());
+
+    VrpConfigBuilder::new(core_problem.clone())
+        .set_environment(environment.clone())
+        .prebuild()
+        .expect("cannot prebuild vrp configuration")
+        .with_context(RefinementContext::new(
+            core_problem.clone(),
+            Box::new(population),
+            TelemetryMode::None,
+            environment,
+        ))
+        .with_max_generations(Some(200))
+        .build()
+        .map(|config| Solver::new(core_problem.clone(), config))
+        .expect("cannot build solver")
+        .solve_many(count)
+        .expect("cannot solve the problem")
+        .into_iter()
+        .map(|core_solution| {
+            let format_solution = create_solution(&core_problem, &core_solution, &Default::default());
+            check_solution(core_problem.clone(), &format_problem, &format_matrices, &format_solution);
+
+            sort_all_data(format_solution)
+        })
+        .collect()
+}
+
+fn solve_core_problem(problem: Arc<CoreProblem>, generations: usize) -> CoreSolution {
     // NOTE: hardcode cpus to guarantee rosomaxa population algorithm is used
     const AVAILABLE_CPUS: usize = 4;
 
-    get_core_solution(problem, matrices, perform_check, |problem: Arc<CoreProblem>| {
-        let environment =
-            Arc::new(Environment { parallelism: Parallelism::new_with_cpus(AVAILABLE_CPUS), ..Environment::default() });
-
-        VrpConfigBuilder::new(problem.clone())
-            .set_environment(environment)
-            .prebuild()
-            .expect("cannot prebuild vrp configuration")
-            .with_max_generations(Some(generations))
-            .build()
-            .map(|config| Solver::new(problem, config))
-            .expect("cannot build solver")
-            .solve()
-            .expect("cannot solve the problem")
-    })
+    let environment =
+        Arc::new(Environment { parallelism: Parallelism::new_with_cpus(AVAILABLE_CPUS), ..Environment::default() });
+
+    VrpConfigBuilder::new(problem.clone())
+        .set_environment(environment)
+        .prebuild()
+        .expect("cannot prebuild vrp configuration")
+        .with_max_generations(Some(generations))
+        .build()
+        .map(|config| Solver::new(problem, config))
+        .expect("cannot build solver")
+        .solve()
+        .expect("cannot solve the problem")
 }
 
 fn get_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Arc<CoreProblem> {
@@ -91,11 +178,24 @@ fn get_core_solution<F: FnOnce(Arc<CoreProblem>) -> CoreSolution>(
 
     let format_solution = sort_all_data(create_solution(&core_problem, &core_solution, &Default::default()));
 
-    if perform_check
-        && let Some(errs) =
-            CheckerContext::new(core_problem, format_problem.clone(), format_matrices, format_solution.clone())
-                .and_then(|ctx| ctx.check())
-                .err()
+    if perform_check {
+        check_solution(core_problem, &format_problem, &format_matrices, &format_solution);
+    }
+
+    sort_all_data(format_solution)
+}
+
+/// Checks solution using the checker and panics when check fails.
+pub fn check_solution(
+    core_problem: Arc<CoreProblem>,
+    format_problem: &Problem,
+    format_matrices: &Option<Vec<Matrix>>,
+    format_solution: &Solution,
+) {
+    if let Some(errs) =
+        CheckerContext::new(core_problem, format_problem.clone(), format_matrices.clone(), format_solution.clone())
+            .and_then(|ctx| ctx.check())
+            .err()
     {
         panic!(
             "check failed: '{}', problem: {:?}, solution: {:?}",
@@ -104,8 +204,6 @@ fn get_core_solution<F: FnOnce(Arc<CoreProblem>) -> CoreSolution>(
             format_solution
         );
     }
-
-    sort_all_data(format_solution)
 }
 
 /// Sorts some solution properties in lexicographical order to simplify test assertions.
@@ -22,6 +22,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T13:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parallel_service: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -39,6 +40,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T11:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parallel_service: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -56,6 +58,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                parallel_service: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -73,6 +76,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T16:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parallel_service: None,
                             }],
                             demand: Some(vec![2]),
                             order: None,
@@ -90,6 +94,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     vec!["2020-07-04T14:00:00Z".to_string(), "2020-07-04T16:00:00Z".to_string()],
                                 ]),
                                 tag: None,
+                                parallel_service: None,
                             }],
                             demand: Some(vec![3]),
                             order: None,
@@ -107,6 +112,7 @@ fn can_handle_properly_invalid_break_removal() {
                                     "2020-07-04T18:00:00Z".to_string(),
                                 ]]),
                                 tag: None,
+                                parallel_service: None,
                             }],
                             demand: Some(vec![1]),
                             order: None,
@@ -122,7 +128,13 @@ fn can_handle_properly_invalid_break_removal() {
                     type_id: "vehicle1".to_string(),
                     vehicle_ids: vec!["vehicle1_1".to_string()],
                     profile: VehicleProfile { matrix: "car".to_string(), scale: None },
-                    costs: VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003, span: None },
+                    costs: VehicleCosts {
+                        fixed: Some(20.),
+                        distance: 0.002,
+                        time: 0.003,
+                        span: None,
+                        exclude_break_time: None,
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: "2020-07-04T09:00:00Z".to_string(),
@@ -142,9 +154,7 @@ fn can_handle_properly_invalid_break_removal() {
                             places: vec![VehicleOptionalBreakPlace { duration: 3600.0, location: None, tag: None }],
                             policy: None,
                         }]),
-                        reloads: None,
-                        recharges: None,
-                        job_times: None,
+                        ..VehicleShift::default()
                     }],
                     capacity: vec![5],
                     skills: None,
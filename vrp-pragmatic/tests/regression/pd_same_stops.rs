@@ -13,6 +13,7 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
             duration: 0.,
             times: None,
             tag: Some(tag.to_string()),
+            parallel_service: None,
         }],
         demand: Some(vec![1]),
         order: None,
@@ -37,7 +38,7 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
         },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance: 0.0, time: 1.0, span: None },
+                costs: VehicleCosts { fixed: None, distance: 0.0, time: 1.0, span: None, exclude_break_time: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: format_time(0.),
@@ -33,6 +33,7 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 shift_index,
                 stops: vec![],
                 statistic: Statistic::default(),
+                departure_binding: None,
             })
             .collect(),
         ..SolutionBuilder::default().build()
@@ -131,6 +132,7 @@ fn check_jobs_impl(
                     duration: 0.0,
                     times: None,
                     tag: Some(format!("{tgt}{idx}")),
+                    parallel_service: None,
                 }],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 order: None,
@@ -167,6 +169,7 @@ fn check_jobs_impl(
                 shift_index,
                 stops: stops.into_iter().map(create_stop).collect(),
                 statistic: Statistic::default(),
+                departure_binding: None,
             })
             .collect(),
         unassigned: Some(
@@ -261,6 +264,116 @@ fn can_detect_job_duration_violation() {
     assert_eq!(result, Err(vec!["cannot match activities to jobs: job1:<no tag>".into()]));
 }
 
+parameterized_test! {can_check_min_dwell_departure, (departure, expected), {
+    can_check_min_dwell_departure_impl(departure, expected);
+}}
+
+can_check_min_dwell_departure! {
+    case01_exact: (4., Ok(())),
+    case02_too_short: (2., Err(vec!["cannot match activities to jobs: job1:<no tag>".into()])),
+    case03_too_long: (5., Err(vec!["cannot match activities to jobs: job1:<no tag>".into()])),
+}
+
+fn can_check_min_dwell_departure_impl(departure: Float, expected: Result<(), Vec<GenericError>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                min_dwell: Some(3.),
+                ..create_delivery_job_with_times("job1", (1., 0.), vec![(0, 10)], 1.)
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., departure)
+                        .load(vec![0])
+                        .distance(1)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(departure + 1., departure + 1.)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_assignment(&ctx);
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_check_parallel_service_departure, (parallel, times, expected), {
+    can_check_parallel_service_departure_impl(parallel, times, expected);
+}}
+
+can_check_parallel_service_departure! {
+    case01_parallel: (Some(true), ((1., 6.), (1., 9.)), Ok(())),
+    case02_serial: (None, ((1., 6.), (6., 14.)), Ok(())),
+    case03_parallel_with_serial_times: (Some(true), ((1., 6.), (6., 14.)), Err(vec!["cannot match activities to jobs: job2:<no tag>".into()])),
+}
+
+fn can_check_parallel_service_departure_impl(
+    parallel: Option<bool>,
+    times: ((Float, Float), (Float, Float)),
+    expected: Result<(), Vec<GenericError>>,
+) {
+    let create_job = |id: &str, duration: Float| {
+        let mut job = create_delivery_job_with_duration(id, (1., 0.), duration);
+        job.deliveries.as_mut().unwrap()[0].places[0].parallel_service = parallel;
+        job
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_job("job1", 5.), create_job("job2", 8.)], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let ((start1, end1), (start2, end2)) = times;
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((1., 0.))
+                        .schedule_stamp(1., end2)
+                        .load(vec![0])
+                        .distance(1)
+                        .activities(vec![
+                            ActivityBuilder::delivery().job_id("job1").time_stamp(start1, end1).build(),
+                            ActivityBuilder::delivery().job_id("job2").time_stamp(start2, end2).build(),
+                        ])
+                        .build(),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(end2 + 1., end2 + 1.)
+                        .load(vec![0])
+                        .distance(2)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, None, solution).unwrap();
+
+    let result = check_assignment(&ctx);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn can_detect_group_violations() {
     let problem = Problem {
@@ -4,7 +4,7 @@ use crate::helpers::*;
 use vrp_core::models::examples::create_example_problem;
 
 fn test_violations() -> Option<Vec<Violation>> {
-    Some(vec![Violation::Break { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }])
+    Some(vec![Violation::Break { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0, reason: None }])
 }
 
 fn get_matched_break_error_msg(matched: usize, actual: usize) -> Result<(), Vec<GenericError>> {
@@ -84,9 +84,7 @@ fn can_check_breaks_impl(
                         places: vec![VehicleOptionalBreakPlace { duration: 2.0, location: None, tag: None }],
                         policy: None,
                     }]),
-                    reloads: None,
-                    recharges: None,
-                    job_times: None,
+                    ..VehicleShift::default()
                 }],
                 capacity: vec![5],
                 ..create_default_vehicle_type()
@@ -103,6 +101,8 @@ fn can_check_breaks_impl(
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
         commute: None,
+        source_id: None,
+        break_attachment: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -112,6 +112,8 @@ fn can_check_breaks_impl(
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
             commute: None,
+            source_id: None,
+            break_attachment: None,
         });
     }
 
@@ -151,3 +153,366 @@ fn can_check_breaks_impl(
 
     assert_eq!(result, expected_result);
 }
+
+parameterized_test! {can_get_shift_break_time_windows, (cost_span, first_job_arrival, expected), {
+    can_get_shift_break_time_windows_impl(cost_span, first_job_arrival, expected);
+}}
+
+can_get_shift_break_time_windows! {
+    case01_depot_anchor: (None, None, vec![(110., 125.), (50., 60.)]),
+    case02_depot_anchor_ignores_first_job: (Some(RouteCostSpan::DepotToLastJob), Some(30.), vec![(110., 125.), (50., 60.)]),
+    case03_first_job_anchor: (Some(RouteCostSpan::FirstJobToDepot), Some(30.), vec![(130., 145.), (50., 60.)]),
+    case04_first_job_anchor_fallback: (Some(RouteCostSpan::FirstJobToLastJob), None, vec![(110., 125.), (50., 60.)]),
+}
+
+fn can_get_shift_break_time_windows_impl(
+    cost_span: Option<RouteCostSpan>,
+    first_job_arrival: Option<Float>,
+    expected: Vec<(Float, Float)>,
+) {
+    let shift = VehicleShift {
+        start: ShiftStart { earliest: format_time(10.), latest: None, location: (0., 0.).to_loc() },
+        breaks: Some(vec![
+            VehicleBreak::Required {
+                time: VehicleRequiredBreakTime::OffsetTime { earliest: 100., latest: 110. },
+                duration: 5.,
+                skippable_to_assign: None,
+                min_jobs_before_break: None,
+            },
+            VehicleBreak::Required {
+                time: VehicleRequiredBreakTime::ExactTime {
+                    earliest: format_time(50.),
+                    latest: format_time(55.),
+                    utc_offset: None,
+                },
+                duration: 5.,
+                skippable_to_assign: None,
+                min_jobs_before_break: None,
+            },
+        ]),
+        ..create_default_vehicle_shift()
+    };
+
+    let windows = get_shift_break_time_windows(&shift, cost_span.as_ref(), first_job_arrival).unwrap();
+
+    assert_eq!(windows.into_iter().map(|tw| (tw.start, tw.end)).collect::<Vec<_>>(), expected);
+}
+
+parameterized_test! {can_validate_fleet_breaks, (time, duration, expected), {
+    can_validate_fleet_breaks_impl(time, duration, expected);
+}}
+
+can_validate_fleet_breaks! {
+    case01_valid: (VehicleRequiredBreakTime::OffsetTime { earliest: 100., latest: 110. }, 5., Ok(())),
+    case02_duration_exceeds_shift: (
+        VehicleRequiredBreakTime::OffsetTime { earliest: 100., latest: 110. }, 1001.,
+        Err(vec!["vehicle type 'my_vehicle', shift 0, break 0: duration '1001' exceeds shift duration '1000'".to_string()])
+    ),
+    case03_offset_past_shift_end: (
+        VehicleRequiredBreakTime::OffsetTime { earliest: 1010., latest: 1020. }, 5.,
+        Err(vec!["vehicle type 'my_vehicle', shift 0, break 0: break starts after shift end".to_string()])
+    ),
+    case04_after_job_is_skipped: (VehicleRequiredBreakTime::AfterJob { job_id: "job1".to_string() }, 5., Ok(())),
+}
+
+fn can_validate_fleet_breaks_impl(time: VehicleRequiredBreakTime, duration: Float, expected: Result<(), Vec<String>>) {
+    let fleet = Fleet {
+        vehicles: vec![VehicleType {
+            shifts: vec![VehicleShift {
+                breaks: Some(vec![VehicleBreak::Required {
+                    time,
+                    duration,
+                    skippable_to_assign: None,
+                    min_jobs_before_break: None,
+                }]),
+                ..create_default_vehicle_shift()
+            }],
+            ..create_default_vehicle_type()
+        }],
+        ..create_default_fleet()
+    };
+
+    let result = validate_fleet_breaks(&fleet);
+
+    assert_eq!(result, expected);
+}
+
+fn create_break_activity(time: (Float, Float)) -> Activity {
+    ActivityBuilder::default().job_id("break").activity_type("break").time_stamp(time.0, time.1).build()
+}
+
+parameterized_test! {can_check_required_break_stop_type, (break_stops, expected_result), {
+    can_check_required_break_stop_type_impl(break_stops, expected_result);
+}}
+
+can_check_required_break_stop_type! {
+    case01_transit_stop: (vec![
+        StopBuilder::default()
+            .coordinate((1., 0.))
+            .schedule_stamp(1., 2.)
+            .load(vec![1])
+            .distance(1)
+            .build_single("job1", "delivery"),
+        StopBuilder::new_transit().schedule_stamp(2., 4.).load(vec![1]).activity(create_break_activity((2., 4.))).build(),
+    ], Ok(())),
+    case02_shared_point_stop: (vec![
+        StopBuilder::default()
+            .coordinate((1., 0.))
+            .schedule_stamp(1., 4.)
+            .load(vec![1])
+            .distance(1)
+            .activities(vec![
+                ActivityBuilder::default().job_id("job1").activity_type("delivery").time_stamp(1., 2.).build(),
+                create_break_activity((2., 4.)),
+            ])
+            .build(),
+    ], Ok(())),
+    case03_separate_point_stop: (vec![
+        StopBuilder::default()
+            .coordinate((1., 0.))
+            .schedule_stamp(1., 2.)
+            .load(vec![1])
+            .distance(1)
+            .build_single("job1", "delivery"),
+        StopBuilder::default()
+            .coordinate((1., 0.))
+            .schedule_stamp(2., 4.)
+            .load(vec![1])
+            .distance(1)
+            .activity(create_break_activity((2., 4.)))
+            .build(),
+    ], Err(vec!["required break cannot be taken at a separate point stop at 'Coordinate { lat: 1.0, lng: 0.0 }'".into()])),
+}
+
+fn can_check_required_break_stop_type_impl(break_stops: Vec<Stop>, expected_result: Result<(), Vec<GenericError>>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::OffsetTime { earliest: 2., latest: 3. },
+                        duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![5],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let stops = std::iter::once(
+        StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+    )
+    .chain(break_stops)
+    .chain(vec![
+        StopBuilder::default()
+            .coordinate((2., 0.))
+            .schedule_stamp(5., 6.)
+            .load(vec![0])
+            .distance(2)
+            .build_single("job2", "delivery"),
+        StopBuilder::default().coordinate((0., 0.)).schedule_stamp(8., 8.).load(vec![0]).distance(4).build_arrival(),
+    ])
+    .collect();
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(stops)
+                .statistic(StatisticBuilder::default().driving(4).serving(2).break_time(2).build())
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_breaks(&ctx);
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_get_required_break_statuses, (offset, expected), {
+    can_get_required_break_statuses_impl(offset, expected);
+}}
+
+can_get_required_break_statuses! {
+    case01_placed: ((2., 3.), "placed"),
+    case02_tour_end_boundary: ((6., 8.), "skipped: ends at/after tour end"),
+    case03_no_intersection: ((10., 12.), "skipped: does not intersect tour time"),
+}
+
+fn can_get_required_break_statuses_impl(offset: (Float, Float), expected: &str) {
+    let shift = VehicleShift {
+        start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+        breaks: Some(vec![VehicleBreak::Required {
+            time: VehicleRequiredBreakTime::OffsetTime { earliest: offset.0, latest: offset.1 },
+            duration: 2.,
+            skippable_to_assign: None,
+            min_jobs_before_break: None,
+        }]),
+        ..create_default_vehicle_shift()
+    };
+    let tour = TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+            StopBuilder::default()
+                .coordinate((2., 0.))
+                .schedule_stamp(2., 3.)
+                .load(vec![0])
+                .distance(2)
+                .build_single("job1", "delivery"),
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .schedule_stamp(8., 8.)
+                .load(vec![0])
+                .distance(4)
+                .build_arrival(),
+        ])
+        .build();
+
+    let statuses = get_required_break_statuses(&create_empty_problem(), &tour, &shift, None).unwrap();
+
+    assert_eq!(statuses.iter().map(|status| status.to_string()).collect::<Vec<_>>(), vec![expected.to_string()]);
+}
+
+parameterized_test! {can_get_required_exact_break_statuses_within_shift, (window, expected), {
+    can_get_required_exact_break_statuses_within_shift_impl(window, expected);
+}}
+
+can_get_required_exact_break_statuses_within_shift! {
+    case01_placed: ((4., 5.), "placed"),
+    case02_window_runs_past_shift_end: ((4., 29.), "skipped: ends at/after tour end"),
+    case03_outside_shift: ((29., 29.), "skipped: does not fit into shift time"),
+}
+
+fn can_get_required_exact_break_statuses_within_shift_impl(window: (Float, Float), expected: &str) {
+    let shift = VehicleShift {
+        start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+        end: Some(ShiftEnd { earliest: None, latest: format_time(30.), location: (0., 0.).to_loc() }),
+        breaks: Some(vec![VehicleBreak::Required {
+            time: VehicleRequiredBreakTime::ExactTime {
+                earliest: format_time(window.0),
+                latest: format_time(window.1),
+                utc_offset: None,
+            },
+            duration: 2.,
+            skippable_to_assign: None,
+            min_jobs_before_break: None,
+        }]),
+        ..create_default_vehicle_shift()
+    };
+    let tour = TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+            StopBuilder::default()
+                .coordinate((2., 0.))
+                .schedule_stamp(2., 3.)
+                .load(vec![0])
+                .distance(2)
+                .build_single("job1", "delivery"),
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .schedule_stamp(20., 20.)
+                .load(vec![0])
+                .distance(4)
+                .build_arrival(),
+        ])
+        .build();
+
+    let statuses = get_required_break_statuses(&create_empty_problem(), &tour, &shift, None).unwrap();
+
+    assert_eq!(statuses.iter().map(|status| status.to_string()).collect::<Vec<_>>(), vec![expected.to_string()]);
+}
+
+parameterized_test! {can_check_offset_break_anchor, (cost_span, break_time, expected_result), {
+    can_check_offset_break_anchor_impl(cost_span, break_time, expected_result);
+}}
+
+can_check_offset_break_anchor! {
+    case01_first_job_anchor_earliest: (Some(RouteCostSpan::FirstJobToLastJob), (15., 17.), Ok(())),
+    case02_first_job_anchor_latest: (Some(RouteCostSpan::FirstJobToDepot), (17., 19.), Ok(())),
+    case03_too_early: (Some(RouteCostSpan::FirstJobToLastJob), (13., 15.),
+        Err(vec!["offset break start '13' is not anchored to the first job arrival '5': expected in '[15, 17]'".into()])),
+    case04_too_late: (Some(RouteCostSpan::FirstJobToDepot), (19., 21.),
+        Err(vec!["offset break start '19' is not anchored to the first job arrival '5': expected in '[15, 17]'".into()])),
+    case05_depot_anchor_not_checked: (Some(RouteCostSpan::DepotToDepot), (13., 15.), Ok(())),
+}
+
+fn can_check_offset_break_anchor_impl(
+    cost_span: Option<RouteCostSpan>,
+    break_time: (Float, Float),
+    expected_result: Result<(), Vec<GenericError>>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { span: cost_span, ..create_default_vehicle_costs() },
+                shifts: vec![VehicleShift {
+                    start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(1000.), location: (0., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time: VehicleRequiredBreakTime::OffsetTime { earliest: 10., latest: 12. },
+                        duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![5],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((5., 0.))
+                        .schedule_stamp(5., 6.)
+                        .load(vec![1])
+                        .distance(5)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::new_transit()
+                        .schedule_stamp(break_time.0, break_time.1)
+                        .load(vec![1])
+                        .activity(create_break_activity(break_time))
+                        .build(),
+                    StopBuilder::default()
+                        .coordinate((10., 0.))
+                        .schedule_stamp(25., 26.)
+                        .load(vec![0])
+                        .distance(10)
+                        .build_single("job2", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(36., 36.)
+                        .load(vec![0])
+                        .distance(20)
+                        .build_arrival(),
+                ])
+                .statistic(StatisticBuilder::default().driving(20).serving(2).break_time(2).build())
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_breaks(&ctx);
+
+    assert_eq!(result, expected_result);
+}
@@ -0,0 +1,159 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+fn create_job_times(
+    bounds: (Option<Float>, Option<Float>),
+    first_job_reference: FirstJobReference,
+    last_job_reference: LastJobReference,
+    daylight: Option<DaylightWindow>,
+) -> JobTimeConstraints {
+    let (earliest_first, latest_last) = bounds;
+
+    JobTimeConstraints {
+        earliest_first: earliest_first.map(format_time),
+        latest_last: latest_last.map(format_time),
+        first_job_reference: Some(first_job_reference),
+        last_job_reference: Some(last_job_reference),
+        departure_reference: None,
+        daylight,
+    }
+}
+
+parameterized_test! {can_check_job_time_limits, (job_times, expected), {
+    can_check_job_time_limits_impl(job_times, expected);
+}}
+
+can_check_job_time_limits! {
+    case01_no_bounds: (
+        create_job_times((None, None), FirstJobReference::Arrival, LastJobReference::Departure, None),
+        Ok(())
+    ),
+    case02_earliest_first_satisfied: (
+        create_job_times((Some(5.), None), FirstJobReference::Arrival, LastJobReference::Departure, None),
+        Ok(())
+    ),
+    case03_earliest_first_violated: (
+        create_job_times((Some(6.), None), FirstJobReference::ServiceStart, LastJobReference::Departure, None),
+        Err("first job 'job1' time '5' is earlier than earliest first '6', vehicle id 'my_vehicle_1', shift index: 0")
+    ),
+    case04_latest_last_satisfied: (
+        create_job_times((None, Some(12.)), FirstJobReference::Arrival, LastJobReference::Departure, None),
+        Ok(())
+    ),
+    case05_latest_last_violated: (
+        create_job_times((None, Some(11.)), FirstJobReference::Arrival, LastJobReference::Departure, None),
+        Err("last job 'job2' time '12' is later than latest last '11', vehicle id 'my_vehicle_1', shift index: 0")
+    ),
+    case06_latest_last_by_arrival: (
+        create_job_times((None, Some(11.)), FirstJobReference::Arrival, LastJobReference::Arrival, None),
+        Ok(())
+    ),
+    case07_daylight_violated: (
+        create_job_times((None, None), FirstJobReference::Arrival, LastJobReference::Departure, Some(DaylightWindow {
+            sunrise: format_time(0.),
+            sunset: format_time(100.),
+            after_sunrise: Some(6.),
+            before_sunset: None,
+        })),
+        Err("first job 'job1' time '5' is earlier than earliest first '6', vehicle id 'my_vehicle_1', shift index: 0")
+    ),
+}
+
+fn can_check_job_time_limits_impl(job_times: JobTimeConstraints, expected: Result<(), &str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (5., 0.)), create_delivery_job("job2", (10., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { job_times: Some(job_times), ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((5., 0.))
+                        .schedule_stamp(5., 6.)
+                        .load(vec![1])
+                        .distance(5)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((10., 0.))
+                        .schedule_stamp(10., 12.)
+                        .load(vec![0])
+                        .distance(10)
+                        .build_single("job2", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(22., 22.)
+                        .load(vec![0])
+                        .distance(20)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_job_time_limits(&ctx);
+
+    assert_eq!(result, expected.map_err(|err| vec![err.into()]));
+}
+
+#[test]
+fn can_ignore_break_as_last_activity() {
+    let job_times = create_job_times((None, Some(12.)), FirstJobReference::Arrival, LastJobReference::Departure, None);
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (5., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { job_times: Some(job_times), ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = SolutionBuilder::default()
+        .tour(
+            TourBuilder::default()
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+                    StopBuilder::default()
+                        .coordinate((5., 0.))
+                        .schedule_stamp(5., 6.)
+                        .load(vec![0])
+                        .distance(5)
+                        .build_single("job1", "delivery"),
+                    StopBuilder::default()
+                        .coordinate((10., 0.))
+                        .schedule_stamp(11., 13.)
+                        .load(vec![0])
+                        .distance(10)
+                        .build_single("break", "break"),
+                    StopBuilder::default()
+                        .coordinate((0., 0.))
+                        .schedule_stamp(23., 23.)
+                        .load(vec![0])
+                        .distance(20)
+                        .build_arrival(),
+                ])
+                .build(),
+        )
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_job_time_limits(&ctx);
+
+    assert_eq!(result, Ok(()));
+}
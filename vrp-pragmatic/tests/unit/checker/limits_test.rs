@@ -25,6 +25,7 @@ fn create_test_solution(statistic: Statistic, stops: Vec<Stop>) -> Solution {
             shift_index: 0,
             stops,
             statistic,
+            departure_binding: None,
         })
         .build()
 }
@@ -271,6 +272,7 @@ fn can_check_recharge_distance() {
                             duration: 0.,
                             times: None,
                             tag: None,
+                            parallel_service: None,
                         }],
                     }),
                     ..create_default_vehicle_shift()
@@ -315,3 +317,46 @@ fn can_check_recharge_distance() {
             .into())
     );
 }
+
+parameterized_test! {can_check_stop_limit, (max_stops, expected), {
+    can_check_stop_limit_impl(max_stops, expected);
+}}
+
+can_check_stop_limit! {
+    case01_within: (2, Ok(())),
+    case02_exceeds: (1, Err("max stops limit violation, expected: not more than 1, got: 2, vehicle id 'some_real_vehicle', shift index: 0".into())),
+}
+
+fn can_check_stop_limit_impl(max_stops: usize, expected: GenericResult<()>) {
+    let mut problem = create_test_problem(None);
+    problem.fleet.vehicles[0].shifts[0].max_stops = Some(max_stops);
+    let solution = create_test_solution(
+        Statistic::default(),
+        vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 1.)
+                .load(vec![1])
+                .distance(1)
+                .build_single("job1", "delivery"),
+            StopBuilder::default()
+                .coordinate((2., 0.))
+                .schedule_stamp(2., 2.)
+                .load(vec![0])
+                .distance(2)
+                .build_single("job2", "delivery"),
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .schedule_stamp(4., 4.)
+                .load(vec![0])
+                .distance(4)
+                .build_arrival(),
+        ],
+    );
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_stop_limits(&ctx);
+
+    assert_eq!(result, expected);
+}
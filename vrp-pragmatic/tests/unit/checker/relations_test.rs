@@ -98,8 +98,7 @@ mod single {
                             duration: 2.0,
                             ..create_default_reload()
                         }]),
-                        recharges: None,
-                        job_times: None,
+                        ..VehicleShift::default()
                     }],
                     capacity: vec![5],
                     skills: None,
@@ -1,11 +1,17 @@
 use crate::format::problem::*;
+use crate::format_time;
 use crate::helpers::*;
 use std::collections::HashSet;
 use std::iter::FromIterator;
 use std::sync::Arc;
+use vrp_core::construction::enablers::VehicleReservedTimesDimension;
 use vrp_core::construction::features::{JobDemandDimension, JobSkillsDimension, VehicleSkillsDimension};
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{JobIdDimension, Jobs, Multi, Place, Single, VehicleIdDimension};
+use vrp_core::models::problem::{
+    JobIdDimension, JobTimeConstraintsDimension, Jobs, Multi, Place, RouteCostSpanDimension,
+    RouteCostSpanExcludeBreakTimeDimension, Single, VehicleIdDimension,
+};
+use vrp_core::solver::processing::ReservedTimesExtraProperty;
 
 fn get_job(index: usize, jobs: &Jobs) -> vrp_core::models::problem::Job {
     jobs.all().get(index).unwrap().clone()
@@ -77,6 +83,7 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 100.0,
                             tag: Some("my_delivery".to_string()),
+                            parallel_service: None,
                         }],
                         demand: Some(vec![0, 1]),
                         order: None,
@@ -95,6 +102,7 @@ fn can_read_complex_problem() {
                             location: (52.48300, 13.4420).to_loc(),
                             duration: 110.0,
                             tag: None,
+                            parallel_service: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
@@ -109,6 +117,7 @@ fn can_read_complex_problem() {
                             location: (52.48325, 13.4436).to_loc(),
                             duration: 120.0,
                             tag: None,
+                            parallel_service: None,
                         }],
                         demand: Some(vec![2]),
                         order: None,
@@ -126,6 +135,7 @@ fn can_read_complex_problem() {
                             location: (52.48321, 13.4438).to_loc(),
                             duration: 90.0,
                             tag: None,
+                            parallel_service: None,
                         }],
                         demand: Some(vec![3]),
                         order: None,
@@ -142,7 +152,7 @@ fn can_read_complex_problem() {
                 type_id: "my_vehicle".to_string(),
                 vehicle_ids: vec!["my_vehicle_1".to_string(), "my_vehicle_2".to_string()],
                 profile: create_default_vehicle_profile(),
-                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2., span: None },
+                costs: VehicleCosts { fixed: Some(100.), distance: 1., time: 2., span: None, exclude_break_time: None },
                 shifts: vec![VehicleShift {
                     start: ShiftStart {
                         earliest: "1970-01-01T00:00:00Z".to_string(),
@@ -166,9 +176,7 @@ fn can_read_complex_problem() {
                         }],
                         policy: None,
                     }]),
-                    reloads: None,
-                    recharges: None,
-                    job_times: None,
+                    ..VehicleShift::default()
                 }],
                 capacity: vec![10, 1],
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
@@ -290,6 +298,20 @@ fn can_deserialize_minimal_problem_and_matrix() {
     );
 }
 
+#[test]
+fn can_deserialize_job_times_with_date_times() {
+    let mut problem: serde_json::Value = serde_json::from_str(SIMPLE_PROBLEM).unwrap();
+    problem["fleet"]["vehicles"][0]["shifts"][0]["jobTimes"] = serde_json::json!({
+        "earliestFirst": "2019-07-04T11:00:00Z",
+        "latestLast": "2019-07-04T15:00:00+02:00"
+    });
+
+    let problem = (problem.to_string(), vec![SIMPLE_MATRIX.to_string()]).read_pragmatic().ok().unwrap();
+
+    let job_times = problem.fleet.vehicles.first().unwrap().dimens.get_job_time_constraints().unwrap();
+    assert_eq!((job_times.earliest_first, job_times.latest_last), (Some(1562238000.), Some(1562245200.)));
+}
+
 #[test]
 fn can_create_approximation_matrices() {
     let problem = Problem {
@@ -303,10 +325,10 @@ fn can_create_approximation_matrices() {
         fleet: Fleet {
             vehicles: vec![],
             profiles: vec![
-                MatrixProfile { name: "car1".to_string(), speed: Some(8.) },
-                MatrixProfile { name: "car2".to_string(), speed: Some(10.) },
-                MatrixProfile { name: "car3".to_string(), speed: Some(5.) },
-                MatrixProfile { name: "car4".to_string(), speed: None },
+                MatrixProfile { name: "car1".to_string(), speed: Some(8.), emissions: None },
+                MatrixProfile { name: "car2".to_string(), speed: Some(10.), emissions: None },
+                MatrixProfile { name: "car3".to_string(), speed: Some(5.), emissions: None },
+                MatrixProfile { name: "car4".to_string(), speed: None, emissions: None },
             ],
             ..create_default_fleet()
         },
@@ -326,3 +348,140 @@ fn can_create_approximation_matrices() {
         assert_eq!(matrix.travel_times, &[0, duration, duration, 0]);
     }
 }
+
+parameterized_test! {can_read_route_cost_span, (span, expected), {
+    can_read_route_cost_span_impl(span, expected);
+}}
+
+can_read_route_cost_span! {
+    case01_depot_to_depot: (Some("depot-to-depot"), Some(vrp_core::models::problem::RouteCostSpan::DepotToDepot)),
+    case02_depot_to_last_job: (Some("depot-to-last-job"), Some(vrp_core::models::problem::RouteCostSpan::DepotToLastJob)),
+    case03_first_job_to_depot: (Some("first-job-to-depot"), Some(vrp_core::models::problem::RouteCostSpan::FirstJobToDepot)),
+    case04_first_job_to_last_job: (Some("first-job-to-last-job"), Some(vrp_core::models::problem::RouteCostSpan::FirstJobToLastJob)),
+    case05_no_span: (None, None),
+}
+
+fn can_read_route_cost_span_impl(span: Option<&str>, expected: Option<vrp_core::models::problem::RouteCostSpan>) {
+    let span_json = span.map_or_else(String::new, |span| format!(r#", "span": "{span}""#));
+    let costs =
+        serde_json::from_str::<VehicleCosts>(&format!(r#"{{ "fixed": 10, "distance": 1, "time": 2{span_json} }}"#))
+            .expect("cannot deserialize vehicle costs");
+    let serialized = serde_json::to_string(&costs).expect("cannot serialize vehicle costs");
+    match span {
+        Some(span) => assert!(serialized.contains(&format!(r#""span":"{span}""#))),
+        None => assert!(!serialized.contains("span")),
+    }
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType { costs, ..create_default_vehicle_type() }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().expect("cannot read problem");
+
+    let vehicle = problem.fleet.vehicles.first().unwrap();
+    assert_eq!(vehicle.dimens.get_route_cost_span().copied(), expected);
+}
+
+parameterized_test! {can_read_exclude_break_time, (exclude_break_time, expected), {
+    can_read_exclude_break_time_impl(exclude_break_time, expected);
+}}
+
+can_read_exclude_break_time! {
+    case01_excluded: (Some(true), (Some(true), Some(1))),
+    case02_not_excluded: (Some(false), (None, Some(1))),
+    case03_not_set: (None, (None, Some(1))),
+}
+
+fn can_read_exclude_break_time_impl(exclude_break_time: Option<bool>, expected: (Option<bool>, Option<usize>)) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                costs: VehicleCosts { exclude_break_time, ..create_default_vehicle_costs() },
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![
+                        VehicleBreak::Required {
+                            time: VehicleRequiredBreakTime::OffsetTime { earliest: 5., latest: 10. },
+                            duration: 2.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
+                        },
+                        VehicleBreak::Required {
+                            time: VehicleRequiredBreakTime::FractionalOffset { earliest_frac: 0.4, latest_frac: 0.6 },
+                            duration: 2.,
+                            skippable_to_assign: None,
+                            min_jobs_before_break: None,
+                        },
+                    ]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().expect("cannot read problem");
+
+    let dimens = &problem.fleet.vehicles.first().unwrap().dimens;
+    assert_eq!(
+        (
+            dimens.get_route_cost_span_exclude_break_time().copied(),
+            dimens.get_vehicle_reserved_times().map(|times| times.len())
+        ),
+        expected
+    );
+}
+
+parameterized_test! {can_read_exact_break_with_utc_offset, (utc_offset, expected), {
+    can_read_exact_break_with_utc_offset_impl(utc_offset, expected);
+}}
+
+can_read_exact_break_with_utc_offset! {
+    case01_no_offset: (None, (43200., 46800.)),
+    case02_positive_offset: (Some(7200.), (36000., 39600.)),
+    case03_negative_offset: (Some(-3600.), (46800., 50400.)),
+}
+
+fn can_read_exact_break_with_utc_offset_impl(utc_offset: Option<Float>, expected: (Float, Float)) {
+    let offset_json = utc_offset.map_or_else(String::new, |offset| format!(r#", "utcOffset": {offset}"#));
+    let time = serde_json::from_str::<VehicleRequiredBreakTime>(&format!(
+        r#"{{ "earliest": "1970-01-01T12:00:00Z", "latest": "1970-01-01T13:00:00Z"{offset_json} }}"#
+    ))
+    .expect("cannot deserialize break time");
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    end: Some(ShiftEnd { earliest: None, latest: format_time(86400.), location: (0., 0.).to_loc() }),
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time,
+                        duration: 1800.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().expect("cannot read problem");
+
+    let reserved_times = problem.extras.get_reserved_times().expect("no reserved times");
+    let spans = reserved_times.values().flat_map(|spans| spans.iter()).collect::<Vec<_>>();
+    assert_eq!(spans.len(), 1);
+    assert_eq!(spans[0].time.to_time_window(0.), TimeWindow::new(expected.0, expected.1));
+}
@@ -0,0 +1,66 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_solution_with_two_breaks() -> ApiSolution {
+    let stops = vec![
+        StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+        StopBuilder::default()
+            .coordinate((10., 0.))
+            .schedule_stamp(10., 16.)
+            .load(vec![1])
+            .distance(10)
+            .activities(vec![
+                ActivityBuilder::default().job_id("job1").activity_type("delivery").time_stamp(10., 11.).build(),
+                ActivityBuilder::default().job_id("break").activity_type("break").time_stamp(11., 16.).build(),
+            ])
+            .build(),
+        StopBuilder::new_transit()
+            .schedule_stamp(20., 30.)
+            .load(vec![1])
+            .activity(ActivityBuilder::default().job_id("break").activity_type("break").build())
+            .build(),
+        StopBuilder::default()
+            .coordinate((30., 0.))
+            .schedule_stamp(40., 41.)
+            .load(vec![0])
+            .distance(30)
+            .build_single("job2", "delivery"),
+        StopBuilder::default().coordinate((0., 0.)).schedule_stamp(71., 71.).load(vec![0]).distance(60).build_arrival(),
+    ];
+
+    SolutionBuilder::default()
+        .tour(TourBuilder::default().stops(stops).build())
+        .tour(
+            TourBuilder::default()
+                .vehicle_id("my_vehicle_2")
+                .stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_arrival(),
+                ])
+                .build(),
+        )
+        .build()
+}
+
+#[test]
+fn can_create_break_schedules() {
+    let solution = create_solution_with_two_breaks();
+
+    let schedules = create_break_schedules(&solution);
+
+    assert_eq!(
+        schedules,
+        vec![
+            TourBreakSchedule {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: 0,
+                breaks: vec![
+                    BreakScheduleEntry { start: format_time(11.), end: format_time(16.), duration: 5., stop_index: 1 },
+                    BreakScheduleEntry { start: format_time(20.), end: format_time(30.), duration: 10., stop_index: 2 },
+                ],
+            },
+            TourBreakSchedule { vehicle_id: "my_vehicle_2".to_string(), shift_index: 0, breaks: vec![] },
+        ]
+    );
+}
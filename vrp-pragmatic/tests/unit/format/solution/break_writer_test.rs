@@ -0,0 +1,107 @@
+use super::*;
+use crate::helpers::*;
+use vrp_core::construction::enablers::JobReservedTime;
+
+const STOPS: usize = 100;
+
+fn create_tour() -> Tour {
+    let stops = std::iter::once(StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 20.).load(vec![0]))
+        .map(|builder| builder.build_departure())
+        .chain((1..=STOPS).map(|idx| {
+            let arrival = (idx * 100) as Float;
+            StopBuilder::default()
+                .coordinate((idx as Float, 0.))
+                .schedule_stamp(arrival, arrival + 20.)
+                .load(vec![0])
+                .build_single_time(&format!("job{idx}"), "delivery", (arrival, arrival + 20.))
+        }))
+        .chain(std::iter::once(
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .schedule_stamp(((STOPS + 1) * 100) as Float, ((STOPS + 1) * 100) as Float)
+                .load(vec![0])
+                .build_arrival(),
+        ))
+        .collect();
+
+    TourBuilder::default().stops(stops).build()
+}
+
+fn create_reserved_times() -> Vec<TimeWindow> {
+    (1..=STOPS)
+        .map(|idx| (idx * 100) as Float)
+        .flat_map(|arrival| match arrival as usize % 300 {
+            // on transit
+            0 => vec![TimeWindow::new(arrival - 50., arrival - 45.)],
+            // at stop
+            100 => vec![TimeWindow::new(arrival + 5., arrival + 10.)],
+            // at stop and then moved from transit
+            _ => vec![TimeWindow::new(arrival + 2., arrival + 4.), TimeWindow::new(arrival + 15., arrival + 25.)],
+        })
+        .collect()
+}
+
+#[test]
+fn can_insert_many_breaks_using_cursor_as_with_full_scan() {
+    let reserved_times = create_reserved_times();
+    let mut tour_with_cursor = create_tour();
+    let mut tour_with_full_scan = create_tour();
+
+    let costs = Costs { fixed: 0., per_distance: 0., per_driving_time: 0., per_waiting_time: 0., per_service_time: 1. };
+
+    let mut cursor = BreakCursor::default();
+    reserved_times.iter().for_each(|reserved_tw| {
+        insert_reserved_time_as_break(
+            &mut tour_with_cursor,
+            (reserved_tw, reserved_tw.duration(), None),
+            (&costs, &[]),
+            &mut cursor,
+        );
+    });
+    reserved_times.iter().for_each(|reserved_tw| {
+        let mut cursor = BreakCursor::default();
+        insert_reserved_time_as_break(
+            &mut tour_with_full_scan,
+            (reserved_tw, reserved_tw.duration(), None),
+            (&costs, &[]),
+            &mut cursor,
+        );
+    });
+
+    let breaks = tour_with_cursor
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities())
+        .filter(|activity| activity.activity_type == "break")
+        .count();
+    assert_eq!(breaks, reserved_times.len());
+    assert!(tour_with_cursor.transit_stops().next().is_some());
+    assert_eq!(tour_with_cursor, tour_with_full_scan);
+}
+
+#[test]
+fn can_skip_job_reserved_time_when_job_is_not_at_point_stop() {
+    let fleet = test_fleet();
+    let route = create_route_with_activities(&fleet, "v1", vec![]);
+    let mut tour = create_tour();
+    let transit_activities = tour.stops[1].activities().clone();
+    tour.stops[1] = Stop::Transit(TransitStop {
+        time: tour.stops[1].schedule().clone(),
+        load: vec![0],
+        activities: transit_activities,
+    });
+    let job_reserved_times_index =
+        vec![(route.actor.clone(), vec![JobReservedTime { job_id: "job1".to_string(), duration: 10. }])]
+            .into_iter()
+            .collect();
+    let skips = Arc::new(std::sync::Mutex::new(vec![]));
+    let skip_handler: RequiredBreakSkipFn = {
+        let skips = skips.clone();
+        Arc::new(move |skip: &RequiredBreakSkip| skips.lock().unwrap().push(skip.reason))
+    };
+
+    insert_job_reserved_times_as_breaks(&route, &mut tour, &job_reserved_times_index, Some(&skip_handler));
+
+    assert_eq!(*skips.lock().unwrap(), vec![RequiredBreakSkipReason::JobNotServed]);
+    assert!(tour.stops.iter().flat_map(|stop| stop.activities()).all(|activity| activity.activity_type != "break"));
+}
@@ -66,6 +66,8 @@ fn can_create_geo_json_for_cluster_geometry() {
         distance: 0,
         load: vec![],
         parking: None,
+        slack: None,
+        travel: None,
         activities: vec![
             Activity {
                 job_id: "job1".to_string(),
@@ -74,6 +76,8 @@ fn can_create_geo_json_for_cluster_geometry() {
                 time: Some(Interval { start: format_time(0.), end: format_time(1.) }),
                 job_tag: None,
                 commute: Some(Commute { forward: None, backward: None }),
+                source_id: None,
+                break_attachment: None,
             },
             Activity {
                 job_id: "job2".to_string(),
@@ -93,6 +97,8 @@ fn can_create_geo_json_for_cluster_geometry() {
                         time: Interval { start: format_time(3.), end: format_time(4.) },
                     }),
                 }),
+                source_id: None,
+                break_attachment: None,
             },
         ],
     };
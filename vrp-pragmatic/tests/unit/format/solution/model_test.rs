@@ -0,0 +1,21 @@
+use crate::helpers::*;
+
+#[test]
+fn can_iterate_stops_of_given_type() {
+    let tour = TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![1]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 2.)
+                .load(vec![0])
+                .build_single("job1", "delivery"),
+            StopBuilder::new_transit().schedule_stamp(2., 4.).load(vec![0]).build_single("break", "break"),
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(5., 5.).load(vec![0]).build_arrival(),
+        ])
+        .build();
+
+    assert_eq!(tour.point_stops().count(), 3);
+    assert_eq!(tour.transit_stops().count(), 1);
+    assert_eq!(tour.transit_stops().next().map(|stop| stop.activities[0].job_id.as_str()), Some("break"));
+}
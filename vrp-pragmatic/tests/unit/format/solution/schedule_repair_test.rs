@@ -0,0 +1,92 @@
+use super::*;
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_test_problem() -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (3., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+fn create_test_tour(job2_schedule: (Float, Float)) -> Tour {
+    TourBuilder::default()
+        .stops(vec![
+            StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![2]).build_departure(),
+            StopBuilder::default()
+                .coordinate((1., 0.))
+                .schedule_stamp(1., 2.)
+                .load(vec![1])
+                .distance(1)
+                .build_single("job1", "delivery"),
+            StopBuilder::default()
+                .coordinate((3., 0.))
+                .schedule_stamp(job2_schedule.0, job2_schedule.1)
+                .load(vec![0])
+                .distance(3)
+                .build_single("job2", "delivery"),
+            StopBuilder::default()
+                .coordinate((0., 0.))
+                .schedule_stamp(job2_schedule.1 + 3., job2_schedule.1 + 3.)
+                .load(vec![0])
+                .distance(6)
+                .build_arrival(),
+        ])
+        .build()
+}
+
+fn get_schedules(tour: &Tour) -> Vec<(String, String)> {
+    tour.stops.iter().map(|stop| (stop.schedule().arrival.clone(), stop.schedule().departure.clone())).collect()
+}
+
+#[test]
+fn can_repair_schedule_drift() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let coord_index = CoordIndex::new(&problem);
+    let mut tour = create_test_tour((10., 11.));
+
+    let result = recheck_and_repair_schedule(&mut tour, &matrix, &coord_index);
+
+    assert!(result.is_ok());
+    assert_eq!(get_schedules(&tour), get_schedules(&create_test_tour((4., 5.))));
+    assert_eq!(tour.stops.iter().map(|stop| stop.as_point().unwrap().distance).collect::<Vec<_>>(), vec![0, 1, 3, 6]);
+}
+
+#[test]
+fn can_keep_consistent_schedule() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let coord_index = CoordIndex::new(&problem);
+    let mut tour = create_test_tour((4., 5.));
+
+    let result = recheck_and_repair_schedule(&mut tour, &matrix, &coord_index);
+
+    assert!(result.is_ok());
+    assert_eq!(get_schedules(&tour), get_schedules(&create_test_tour((4., 5.))));
+}
+
+#[test]
+fn can_report_departure_before_arrival() {
+    let problem = create_test_problem();
+    let matrix = create_matrix_from_problem(&problem);
+    let coord_index = CoordIndex::new(&problem);
+    let mut tour = create_test_tour((5., 4.));
+
+    let result = recheck_and_repair_schedule(&mut tour, &matrix, &coord_index);
+
+    assert_eq!(
+        result,
+        Err(vec![format!(
+            "departure '{}' is before arrival '{}' at stop 2 in tour 'my_vehicle_1'",
+            format_time(4.),
+            format_time(5.)
+        )])
+    );
+    assert_eq!(get_schedules(&tour), get_schedules(&create_test_tour((5., 4.))));
+}
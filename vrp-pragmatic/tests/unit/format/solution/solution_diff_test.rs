@@ -0,0 +1,84 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_tour(vehicle_id: &str, jobs: &[(&str, Float)], break_start: Option<Float>) -> Tour {
+    let departure = StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![0]).build_departure();
+    let job_stops = jobs.iter().map(|&(job_id, time)| {
+        let stop = StopBuilder::default()
+            .coordinate((time, 0.))
+            .schedule_stamp(time, time + 1.)
+            .load(vec![0])
+            .distance(time as i64);
+        match break_start {
+            Some(start) if start == time + 1. => stop
+                .schedule_stamp(time, start + 5.)
+                .activities(vec![
+                    ActivityBuilder::default().job_id(job_id).activity_type("delivery").time_stamp(time, start).build(),
+                    ActivityBuilder::default()
+                        .job_id("break")
+                        .activity_type("break")
+                        .time_stamp(start, start + 5.)
+                        .build(),
+                ])
+                .build(),
+            _ => stop.build_single(job_id, "delivery"),
+        }
+    });
+    let arrival = StopBuilder::default().coordinate((0., 0.)).schedule_stamp(100., 100.).load(vec![0]).build_arrival();
+
+    TourBuilder::default()
+        .vehicle_id(vehicle_id)
+        .stops(std::iter::once(departure).chain(job_stops).chain(std::iter::once(arrival)).collect())
+        .build()
+}
+
+#[test]
+fn can_detect_moved_job_and_shifted_break() {
+    let old = SolutionBuilder::default()
+        .tour(create_tour("v1", &[("job1", 10.), ("job2", 20.)], Some(11.)))
+        .tour(create_tour("v2", &[("job3", 10.)], None))
+        .build();
+    let new = SolutionBuilder::default()
+        .tour(create_tour("v1", &[("job1", 14.)], Some(15.)))
+        .tour(create_tour("v2", &[("job3", 10.), ("job2", 20.)], None))
+        .build();
+
+    let diff = solution_diff(&old, &new);
+
+    assert_eq!(
+        diff,
+        SolutionDiff {
+            tours: vec![
+                TourDiff {
+                    vehicle_id: "v1".to_string(),
+                    shift_index: 0,
+                    added_jobs: vec![],
+                    removed_jobs: vec!["job2".to_string()],
+                    breaks: vec![BreakTimeDelta {
+                        break_index: 0,
+                        old_start: Some(format_time(11.)),
+                        new_start: Some(format_time(15.)),
+                        delta: Some(4.),
+                    }],
+                },
+                TourDiff {
+                    vehicle_id: "v2".to_string(),
+                    shift_index: 0,
+                    added_jobs: vec!["job2".to_string()],
+                    removed_jobs: vec![],
+                    breaks: vec![],
+                },
+            ]
+        }
+    );
+}
+
+#[test]
+fn can_return_empty_diff_for_same_solutions() {
+    let solution = SolutionBuilder::default().tour(create_tour("v1", &[("job1", 10.)], Some(11.))).build();
+
+    let diff = solution_diff(&solution, &solution);
+
+    assert!(diff.tours.is_empty());
+}
@@ -1,10 +1,11 @@
 use crate::format::problem::*;
-use crate::format::solution::solution_writer::create_tour;
+use crate::format::solution::solution_writer::{create_tour, get_skipped_break_reason};
 use crate::format::solution::*;
 use crate::helpers::*;
 use crate::parse_time;
 use std::sync::Arc;
 use vrp_core::construction::enablers::ReservedTimeSpan;
+use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::{TimeSpan, TimeWindow};
 use vrp_core::models::examples::create_example_problem;
 
@@ -98,6 +99,9 @@ fn can_merge_activities_with_same_location_in_one_stop() {
             distance: 10,
             duration: 12,
             times: Timing { driving: 10, serving: 2, ..Timing::default() },
+            emissions: None,
+            utilization: None,
+            scaled: None,
         }
     );
     assert_eq!(solution.tours.len(), 1);
@@ -353,3 +357,14 @@ fn can_align_break_to_activity_boundary_when_reserved_time_hits_mid_service() {
     assert!((break_end - 10.).abs() < 1e-9, "unexpected break end: {break_end}, tour: {tour:?}");
     assert!(job_end <= break_start + 1e-9, "job overlaps break, tour: {tour:?}");
 }
+
+parameterized_test! {can_get_skipped_break_reason, (info, expected), {
+    assert_eq!(get_skipped_break_reason(&info), expected);
+}}
+
+can_get_skipped_break_reason! {
+    case01_time: (UnassignmentInfo::Simple(crate::format::TIME_CONSTRAINT_CODE), "enabled_job_assignment"),
+    case02_job_time: (UnassignmentInfo::Simple(crate::format::JOB_TIME_CONSTRAINT_CODE), "enabled_job_assignment"),
+    case03_break: (UnassignmentInfo::Simple(crate::format::BREAK_CONSTRAINT_CODE), "BREAK_CONSTRAINT"),
+    case04_unknown: (UnassignmentInfo::Unknown, "NO_REASON_FOUND"),
+}
@@ -262,3 +262,89 @@ fn can_detect_missing_min_tour_size_objective_impl(
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+parameterized_test! {can_detect_missing_lateness_objective, (objectives, soft_times, expected), {
+    can_detect_missing_lateness_objective_impl(objectives, soft_times, expected);
+}}
+
+can_detect_missing_lateness_objective! {
+    case01_missing_objective: (Some(vec![
+                MinimizeUnassigned { breaks: None },
+                MinimizeCost,
+            ]), Some(true), Some("E1609".to_string())),
+    case02_has_objective: (Some(vec![
+                MinimizeUnassigned { breaks: None },
+                MinimizeLateness,
+                MinimizeCost,
+            ]), Some(true), None),
+    case03_no_objectives_defined: (None, Some(true), None),
+    case04_no_soft_times: (Some(vec![
+                MinimizeUnassigned { breaks: None },
+                MinimizeCost,
+            ]), None, None),
+}
+
+fn can_detect_missing_lateness_objective_impl(
+    objectives: Option<Vec<Objective>>,
+    soft_times: Option<bool>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![Job { soft_times, ..create_delivery_job("job1", (1., 0.)) }], ..create_empty_plan() },
+        objectives,
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1609_jobs_with_soft_times_but_no_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_missing_break_on_transit_objective, (objectives, prefer_break_on_transit, expected), {
+    can_detect_missing_break_on_transit_objective_impl(objectives, prefer_break_on_transit, expected);
+}}
+
+can_detect_missing_break_on_transit_objective! {
+    case01_missing_objective: (Some(vec![
+                MinimizeUnassigned { breaks: None },
+                MinimizeCost,
+            ]), Some(true), Some("E1610".to_string())),
+    case02_has_objective: (Some(vec![
+                MinimizeUnassigned { breaks: None },
+                PreferBreakOnTransit,
+                MinimizeCost,
+            ]), Some(true), None),
+    case03_no_objectives_defined: (None, Some(true), None),
+    case04_no_preference: (Some(vec![
+                MinimizeUnassigned { breaks: None },
+                MinimizeCost,
+            ]), Some(false), None),
+}
+
+fn can_detect_missing_break_on_transit_objective_impl(
+    objectives: Option<Vec<Objective>>,
+    prefer_break_on_transit: Option<bool>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { prefer_break_on_transit, ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        objectives,
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1610_shifts_with_break_on_transit_but_no_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
@@ -29,6 +29,77 @@ fn can_detect_invalid_break_time() {
     assert_eq!(result.err().map(|err| err.code), Some("E1303".to_string()));
 }
 
+parameterized_test! {can_detect_invalid_conditional_break_time, (time, condition, expected), {
+    can_detect_invalid_conditional_break_time_impl(time, condition, expected);
+}}
+
+can_detect_invalid_conditional_break_time! {
+    case01_skippable_offset: (VehicleRequiredBreakTime::OffsetTime { earliest: 10., latest: 20. }, (Some(true), None), None),
+    case02_skippable_fractional: (
+        VehicleRequiredBreakTime::FractionalOffset { earliest_frac: 0.4, latest_frac: 0.5 },
+        (Some(true), None),
+        Some("E1303".to_string())
+    ),
+    case03_skippable_after_job: (
+        VehicleRequiredBreakTime::AfterJob { job_id: "job1".to_string() },
+        (Some(true), None),
+        Some("E1303".to_string())
+    ),
+    case04_min_jobs_offset: (VehicleRequiredBreakTime::OffsetTime { earliest: 10., latest: 20. }, (None, Some(2)), None),
+    case05_min_jobs_fractional: (
+        VehicleRequiredBreakTime::FractionalOffset { earliest_frac: 0.4, latest_frac: 0.5 },
+        (None, Some(2)),
+        Some("E1303".to_string())
+    ),
+    case06_straddling_offset: (VehicleRequiredBreakTime::OffsetTime { earliest: -5., latest: 5. }, (None, None), None),
+    case07_negative_offset: (
+        VehicleRequiredBreakTime::OffsetTime { earliest: -10., latest: -5. },
+        (None, None),
+        Some("E1303".to_string())
+    ),
+    case08_exact_with_utc_offset: (
+        VehicleRequiredBreakTime::ExactTime { earliest: format_time(10.), latest: format_time(20.), utc_offset: Some(-5.) },
+        (None, None),
+        None
+    ),
+    case09_exact_malformed: (
+        VehicleRequiredBreakTime::ExactTime { earliest: "12:00".to_string(), latest: format_time(20.), utc_offset: None },
+        (None, None),
+        Some("E1303".to_string())
+    ),
+}
+
+fn can_detect_invalid_conditional_break_time_impl(
+    time: VehicleRequiredBreakTime,
+    condition: (Option<bool>, Option<usize>),
+    expected: Option<String>,
+) {
+    let (skippable_to_assign, min_jobs_before_break) = condition;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    breaks: Some(vec![VehicleBreak::Required {
+                        time,
+                        duration: 2.,
+                        skippable_to_assign,
+                        min_jobs_before_break,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1303_vehicle_breaks_time_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
 parameterized_test! {can_detect_zero_costs, (costs, expected), {
     can_detect_zero_costs_impl(costs, expected);
 }}
@@ -45,7 +116,7 @@ fn can_detect_zero_costs_impl(costs: (Float, Float), expected: Option<String>) {
     let problem = Problem {
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                costs: VehicleCosts { fixed: None, distance, time, span: None },
+                costs: VehicleCosts { fixed: None, distance, time, span: None, exclude_break_time: None },
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -82,6 +153,8 @@ fn can_handle_rescheduling_with_required_break_impl(latest: Option<Float>, expec
                     breaks: Some(vec![VehicleBreak::Required {
                         time: VehicleRequiredBreakTime::OffsetTime { earliest: 10., latest: 10. },
                         duration: 2.,
+                        skippable_to_assign: None,
+                        min_jobs_before_break: None,
                     }]),
                     ..create_default_vehicle_shift()
                 }],
@@ -134,3 +207,189 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+fn offset_break(earliest: Float, latest: Float) -> VehicleBreak {
+    VehicleBreak::Required {
+        time: VehicleRequiredBreakTime::OffsetTime { earliest, latest },
+        duration: 2.,
+        skippable_to_assign: None,
+        min_jobs_before_break: None,
+    }
+}
+
+fn exact_break(earliest: Float, latest: Float) -> VehicleBreak {
+    VehicleBreak::Required {
+        time: VehicleRequiredBreakTime::ExactTime {
+            earliest: format_time(earliest),
+            latest: format_time(latest),
+            utc_offset: None,
+        },
+        duration: 2.,
+        skippable_to_assign: None,
+        min_jobs_before_break: None,
+    }
+}
+
+parameterized_test! {can_detect_overlapping_required_breaks, (breaks, expected), {
+    can_detect_overlapping_required_breaks_impl(breaks, expected);
+}}
+
+can_detect_overlapping_required_breaks! {
+    case01_offset_separated: (vec![offset_break(5., 15.), offset_break(25., 40.)], None),
+    case02_offset_overlapping: (vec![offset_break(5., 15.), offset_break(10., 20.)], Some("E1309".to_string())),
+    case03_offset_touching: (vec![offset_break(5., 10.), offset_break(10., 20.)], Some("E1309".to_string())),
+    case04_exact_separated: (vec![exact_break(5., 15.), exact_break(25., 40.)], None),
+    case05_exact_overlapping: (vec![exact_break(25., 40.), exact_break(5., 30.)], Some("E1309".to_string())),
+    case06_offset_and_exact: (vec![offset_break(5., 15.), exact_break(10., 20.)], None),
+    case07_single: (vec![offset_break(5., 15.)], None),
+}
+
+fn can_detect_overlapping_required_breaks_impl(breaks: Vec<VehicleBreak>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { breaks: Some(breaks), ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1309_vehicle_required_breaks_do_not_overlap(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_job_times_daylight, (daylight, expected), {
+    can_detect_invalid_job_times_daylight_impl(daylight, expected);
+}}
+
+can_detect_invalid_job_times_daylight! {
+    case01_valid: ((10., 100., None, None), None),
+    case02_valid_with_offsets: ((10., 100., Some(10.), Some(20.)), None),
+    case03_sunset_before_sunrise: ((100., 10., None, None), Some("E1310".to_string())),
+    case04_negative_offset: ((10., 100., Some(-1.), None), Some("E1310".to_string())),
+    case05_offsets_do_not_fit: ((10., 100., Some(50.), Some(50.)), Some("E1310".to_string())),
+}
+
+fn can_detect_invalid_job_times_daylight_impl(
+    daylight: (Float, Float, Option<Float>, Option<Float>),
+    expected: Option<String>,
+) {
+    let (sunrise, sunset, after_sunrise, before_sunset) = daylight;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    job_times: Some(JobTimeConstraints {
+                        earliest_first: None,
+                        latest_last: None,
+                        first_job_reference: None,
+                        last_job_reference: None,
+                        departure_reference: None,
+                        daylight: Some(DaylightWindow {
+                            sunrise: format_time(sunrise),
+                            sunset: format_time(sunset),
+                            after_sunrise,
+                            before_sunset,
+                        }),
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1310_vehicle_job_times_daylight_is_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_max_break_fraction, (max_break_fraction, expected), {
+    can_detect_invalid_max_break_fraction_impl(max_break_fraction, expected);
+}}
+
+can_detect_invalid_max_break_fraction! {
+    case01_not_set: (None, None),
+    case02_valid: (Some(0.1), None),
+    case03_full: (Some(1.), None),
+    case04_zero: (Some(0.), Some("E1311".to_string())),
+    case05_above_one: (Some(1.5), Some("E1311".to_string())),
+}
+
+fn can_detect_invalid_max_break_fraction_impl(max_break_fraction: Option<Float>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift { max_break_fraction, ..create_default_vehicle_shift() }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1311_vehicle_max_break_fraction_is_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_job_times_bounds, (bounds, expected), {
+    can_detect_invalid_job_times_bounds_impl(bounds, expected);
+}}
+
+can_detect_invalid_job_times_bounds! {
+    case01_valid: ((Some("1970-01-01T00:00:10Z"), Some("1970-01-01T00:01:40Z")), None),
+    case02_valid_only_earliest: ((Some("1970-01-01T00:00:10Z"), None), None),
+    case03_not_set: ((None, None), None),
+    case04_wrong_order: ((Some("1970-01-01T00:01:40Z"), Some("1970-01-01T00:00:10Z")), Some("E1312".to_string())),
+    case05_not_date_time: ((Some("10"), None), Some("E1312".to_string())),
+}
+
+fn can_detect_invalid_job_times_bounds_impl(bounds: (Option<&str>, Option<&str>), expected: Option<String>) {
+    let (earliest_first, latest_last) = bounds;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    job_times: Some(JobTimeConstraints {
+                        earliest_first: earliest_first.map(|time| time.to_string()),
+                        latest_last: latest_last.map(|time| time.to_string()),
+                        first_job_reference: None,
+                        last_job_reference: None,
+                        departure_reference: None,
+                        daylight: None,
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1312_vehicle_job_times_bounds_are_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}